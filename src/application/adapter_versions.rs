@@ -0,0 +1,248 @@
+//! Adapter versioning (TD-18)
+//!
+//! - Compares locally installed tools against the version range each adapter
+//!   declares (`calvin check`, `calvin version`).
+//! - Compares the adapter output format recorded in `calvin.lock` with the
+//!   current adapters (`calvin migrate --adapter`).
+
+use crate::domain::entities::Lockfile;
+use crate::domain::ports::{DetectedTool, TargetAdapter, ToolProbe};
+use crate::domain::value_objects::{Target, ToolVersion};
+
+/// How an installed tool relates to the adapter's tested version range
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum ToolCompatibility {
+    /// Tool not found on this machine
+    NotInstalled,
+    /// Tool found, but its version could not be determined
+    Unknown,
+    /// Within the adapter's tested range
+    Supported,
+    /// Older than the adapter's minimum version
+    TooOld,
+    /// Newer major/minor release than the adapter was tested against
+    Untested,
+}
+
+/// Version information for one adapter and its locally installed tool
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ToolVersionReport {
+    pub target: Target,
+    pub output_format_version: u32,
+    pub min_ide_version: Option<ToolVersion>,
+    pub max_tested_version: Option<ToolVersion>,
+    pub detected: Option<DetectedTool>,
+    pub compatibility: ToolCompatibility,
+}
+
+impl ToolVersionReport {
+    /// Human-readable tested range, e.g. `1.0.0 – 2.0.x`
+    pub fn tested_range(&self) -> Option<String> {
+        let max = self
+            .max_tested_version
+            .map(|v| format!("{}.{}.x", v.major(), v.minor()));
+        match (self.min_ide_version, max) {
+            (Some(min), Some(max)) => Some(format!("{} – {}", min, max)),
+            (Some(min), None) => Some(format!(">= {}", min)),
+            (None, Some(max)) => Some(format!("<= {}", max)),
+            (None, None) => None,
+        }
+    }
+}
+
+/// Detect installed tools and classify them against each adapter's version range
+pub fn detect_tool_versions(
+    adapters: &[Box<dyn TargetAdapter>],
+    probe: &dyn ToolProbe,
+) -> Vec<ToolVersionReport> {
+    adapters
+        .iter()
+        .map(|adapter| {
+            let min = adapter.min_ide_version();
+            let max = adapter.max_tested_version();
+            let detected = adapter.detect_version(probe);
+            let compatibility = match detected.as_ref().map(|d| d.version) {
+                None => ToolCompatibility::NotInstalled,
+                Some(None) => ToolCompatibility::Unknown,
+                Some(Some(version)) if min.is_some_and(|min| version < min) => {
+                    ToolCompatibility::TooOld
+                }
+                Some(Some(version))
+                    if max.is_some_and(|max| version.is_newer_release_than(&max)) =>
+                {
+                    ToolCompatibility::Untested
+                }
+                Some(Some(_)) => ToolCompatibility::Supported,
+            };
+            ToolVersionReport {
+                target: adapter.target(),
+                output_format_version: adapter.output_format_version(),
+                min_ide_version: min,
+                max_tested_version: max,
+                detected,
+                compatibility,
+            }
+        })
+        .collect()
+}
+
+/// Outputs of one target that need regenerating
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct AdapterMigration {
+    pub target: Target,
+    /// Format recorded in `calvin.lock` (`None` for lockfiles written before
+    /// adapter versions were recorded)
+    pub from: Option<u32>,
+    /// Current adapter output format
+    pub to: u32,
+}
+
+/// Plan which targets' outputs must be regenerated to match the current adapters
+///
+/// Targets whose recorded format is current are skipped. A lockfile without any
+/// tracked files has nothing to migrate.
+pub fn plan_adapter_migrations(
+    lockfile: &Lockfile,
+    adapters: &[Box<dyn TargetAdapter>],
+    targets: &[Target],
+) -> Result<Vec<AdapterMigration>, String> {
+    if lockfile.is_empty() {
+        return Ok(Vec::new());
+    }
+
+    let mut migrations = Vec::new();
+    for adapter in adapters {
+        let target = adapter.target();
+        if !targets.iter().any(|t| t.is_all() || *t == target) {
+            continue;
+        }
+
+        let to = adapter.output_format_version();
+        match lockfile.adapter_version(target) {
+            Some(from) if from == to => {}
+            Some(from) if from > to => {
+                return Err(format!(
+                    "{} outputs were generated with adapter format v{}, but this version of Calvin only supports v{}. Upgrade Calvin.",
+                    target.display_name(),
+                    from,
+                    to
+                ));
+            }
+            from => migrations.push(AdapterMigration { target, from, to }),
+        }
+    }
+    Ok(migrations)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::domain::entities::{Asset, OutputFile};
+    use crate::domain::ports::{AdapterDiagnostic, AdapterError, FakeToolProbe};
+
+    struct VersionedAdapter {
+        target: Target,
+        format: u32,
+    }
+
+    impl TargetAdapter for VersionedAdapter {
+        fn target(&self) -> Target {
+            self.target
+        }
+
+        fn output_format_version(&self) -> u32 {
+            self.format
+        }
+
+        fn min_ide_version(&self) -> Option<ToolVersion> {
+            Some(ToolVersion::new(1, 0, 0))
+        }
+
+        fn max_tested_version(&self) -> Option<ToolVersion> {
+            Some(ToolVersion::new(1, 4, 0))
+        }
+
+        fn detect_version(&self, probe: &dyn ToolProbe) -> Option<DetectedTool> {
+            DetectedTool::probe(probe, "tool", ".tool")
+        }
+
+        fn compile(&self, _asset: &Asset) -> Result<Vec<OutputFile>, AdapterError> {
+            Ok(Vec::new())
+        }
+
+        fn validate(&self, _output: &OutputFile) -> Vec<AdapterDiagnostic> {
+            Vec::new()
+        }
+    }
+
+    fn adapters(format: u32) -> Vec<Box<dyn TargetAdapter>> {
+        vec![Box::new(VersionedAdapter {
+            target: Target::Cursor,
+            format,
+        })]
+    }
+
+    fn compatibility(output: Option<&'static str>) -> ToolCompatibility {
+        detect_tool_versions(&adapters(1), &FakeToolProbe { output, dir: false })[0].compatibility
+    }
+
+    #[test]
+    fn classifies_detected_versions() {
+        assert_eq!(compatibility(None), ToolCompatibility::NotInstalled);
+        assert_eq!(compatibility(Some("1.4.7")), ToolCompatibility::Supported);
+        assert_eq!(compatibility(Some("1.5.0")), ToolCompatibility::Untested);
+        assert_eq!(compatibility(Some("0.9.0")), ToolCompatibility::TooOld);
+    }
+
+    #[test]
+    fn tested_range_shows_minor_wildcard() {
+        let report = &detect_tool_versions(&adapters(1), &FakeToolProbe::default())[0];
+        assert_eq!(report.tested_range().as_deref(), Some("1.0.0 – 1.4.x"));
+    }
+
+    #[test]
+    fn plan_skips_targets_at_current_format() {
+        let mut lockfile = Lockfile::new();
+        lockfile.set("project:.cursor/rules/a.mdc", "sha256:a");
+        lockfile.set_adapter_version(Target::Cursor, 1);
+
+        let plan = plan_adapter_migrations(&lockfile, &adapters(1), &[Target::All]).unwrap();
+        assert!(plan.is_empty());
+    }
+
+    #[test]
+    fn plan_regenerates_stale_and_unrecorded_targets() {
+        let mut lockfile = Lockfile::new();
+        lockfile.set("project:.cursor/rules/a.mdc", "sha256:a");
+
+        let plan = plan_adapter_migrations(&lockfile, &adapters(2), &[Target::Cursor]).unwrap();
+        assert_eq!(
+            plan,
+            vec![AdapterMigration {
+                target: Target::Cursor,
+                from: None,
+                to: 2
+            }]
+        );
+
+        lockfile.set_adapter_version(Target::Cursor, 1);
+        let plan = plan_adapter_migrations(&lockfile, &adapters(2), &[Target::Cursor]).unwrap();
+        assert_eq!(plan[0].from, Some(1));
+    }
+
+    #[test]
+    fn plan_rejects_outputs_from_newer_adapter() {
+        let mut lockfile = Lockfile::new();
+        lockfile.set("project:.cursor/rules/a.mdc", "sha256:a");
+        lockfile.set_adapter_version(Target::Cursor, 3);
+
+        let err = plan_adapter_migrations(&lockfile, &adapters(1), &[Target::Cursor]).unwrap_err();
+        assert!(err.contains("Upgrade Calvin"), "{}", err);
+    }
+
+    #[test]
+    fn plan_is_empty_without_deployed_outputs() {
+        let plan = plan_adapter_migrations(&Lockfile::new(), &adapters(2), &[Target::All]).unwrap();
+        assert!(plan.is_empty());
+    }
+}
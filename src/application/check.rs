@@ -3,7 +3,11 @@
 //! This module defines the `CheckUseCase` which orchestrates security checks
 //! across all deployed targets.
 
+use crate::application::adapter_versions::{
+    detect_tool_versions, ToolCompatibility, ToolVersionReport,
+};
 use crate::config::{Config, SecurityMode};
+use crate::domain::ports::{AssetRepository, DiagnosticSeverity, TargetAdapter, ToolProbe};
 use anyhow::Result;
 use std::path::Path;
 
@@ -64,20 +68,68 @@ impl CheckResult {
     pub fn is_clean(&self) -> bool {
         self.errors == 0 && self.warnings == 0
     }
+
+    /// Add an item and update the counters
+    fn record(&mut self, item: CheckItem) {
+        match item.status {
+            CheckStatus::Pass => self.passed += 1,
+            CheckStatus::Warning => self.warnings += 1,
+            CheckStatus::Error => self.errors += 1,
+        }
+        self.items.push(item);
+    }
 }
 
 /// Check Use Case
 ///
 /// Orchestrates security checks for all deployed targets.
 pub struct CheckUseCase {
-    #[allow(dead_code)] // Will be used for custom security rules
     config: Config,
+    /// Adapters and probe used to check installed tool versions (TD-18)
+    tool_detection: Option<ToolDetection>,
+    /// Adapters and repository used to report what targets cannot represent
+    downgrade_check: Option<DowngradeCheck>,
+}
+
+struct ToolDetection {
+    adapters: Vec<Box<dyn TargetAdapter>>,
+    probe: Box<dyn ToolProbe>,
+}
+
+struct DowngradeCheck {
+    adapters: Vec<Box<dyn TargetAdapter>>,
+    assets: Box<dyn AssetRepository>,
 }
 
 impl CheckUseCase {
     /// Create a new CheckUseCase
     pub fn new(config: Config) -> Self {
-        Self { config }
+        Self {
+            config,
+            tool_detection: None,
+            downgrade_check: None,
+        }
+    }
+
+    /// Builder: also check installed tool versions against each adapter's tested range
+    pub fn with_tool_detection(
+        mut self,
+        adapters: Vec<Box<dyn TargetAdapter>>,
+        probe: Box<dyn ToolProbe>,
+    ) -> Self {
+        self.tool_detection = Some(ToolDetection { adapters, probe });
+        self
+    }
+
+    /// Builder: also report project assets the enabled targets cannot represent
+    /// (e.g. agents skipped or compiled without their `tools`)
+    pub fn with_downgrade_check(
+        mut self,
+        adapters: Vec<Box<dyn TargetAdapter>>,
+        assets: Box<dyn AssetRepository>,
+    ) -> Self {
+        self.downgrade_check = Some(DowngradeCheck { adapters, assets });
+        self
     }
 
     /// Execute the check operation
@@ -111,6 +163,13 @@ impl CheckUseCase {
             });
         }
 
+        for item in self.tool_version_items() {
+            result.record(item);
+        }
+        for item in self.downgrade_items(project_root) {
+            result.record(item);
+        }
+
         Ok(result)
     }
 
@@ -154,13 +213,154 @@ impl CheckUseCase {
             result.items.push(item);
         });
 
+        for item in self
+            .tool_version_items()
+            .into_iter()
+            .chain(self.downgrade_items(project_root))
+        {
+            on_check(&item);
+            result.record(item);
+        }
+
         Ok(result)
     }
+
+    /// One check item per installed tool, comparing it with the adapter's tested range
+    fn tool_version_items(&self) -> Vec<CheckItem> {
+        let Some(detection) = &self.tool_detection else {
+            return Vec::new();
+        };
+        detect_tool_versions(&detection.adapters, detection.probe.as_ref())
+            .iter()
+            .filter_map(tool_version_item)
+            .collect()
+    }
+
+    /// One warning per asset an enabled target skips or compiles without some fields
+    fn downgrade_items(&self, project_root: &Path) -> Vec<CheckItem> {
+        let Some(check) = &self.downgrade_check else {
+            return Vec::new();
+        };
+        let source = project_root.join(".promptpack");
+        if !source.is_dir() {
+            return Vec::new();
+        }
+        let assets = match check.assets.load_all(&source) {
+            Ok(assets) => assets,
+            Err(e) => {
+                return vec![CheckItem {
+                    platform: "promptpack".to_string(),
+                    name: "asset_downgrade".to_string(),
+                    status: CheckStatus::Warning,
+                    message: format!("Could not load assets: {}", e),
+                    recommendation: Some("Run `calvin deploy --dry-run` for details".to_string()),
+                    details: Vec::new(),
+                }];
+            }
+        };
+
+        let custom_targets = self.config.custom_targets();
+        let assets: Vec<_> = assets
+            .into_iter()
+            .map(|asset| asset.with_custom_targets(custom_targets.clone()))
+            .collect();
+        let enabled = self.config.enabled_targets();
+        let mut items = Vec::new();
+        for adapter in &check.adapters {
+            let target = adapter.target();
+            if !enabled.contains(&target) {
+                continue;
+            }
+            for asset in &assets {
+                if !asset.effective_targets().contains(&target) {
+                    continue;
+                }
+                for diag in adapter.downgrades(&asset.for_target(target)) {
+                    let status = match diag.severity {
+                        DiagnosticSeverity::Error => CheckStatus::Error,
+                        DiagnosticSeverity::Warning => CheckStatus::Warning,
+                        DiagnosticSeverity::Info => continue,
+                    };
+                    items.push(CheckItem {
+                        platform: target.id().to_string(),
+                        name: "asset_downgrade".to_string(),
+                        status,
+                        message: diag.message,
+                        recommendation: Some(format!(
+                            "Remove {} from the asset's `targets` if the downgrade is unwanted",
+                            target.id()
+                        )),
+                        details: vec![format!("source: {}", asset.source_path_normalized())],
+                    });
+                }
+            }
+        }
+        items
+    }
+}
+
+fn tool_version_item(report: &ToolVersionReport) -> Option<CheckItem> {
+    let detected = report.detected.as_ref()?;
+    let name = report.target.display_name();
+    let tested = report.tested_range();
+    let mut details = vec![format!("detected via {}", detected.location)];
+    if let Some(range) = &tested {
+        details.push(format!("tested range: {}", range));
+    }
+
+    let (status, message, recommendation) = match (report.compatibility, detected.version) {
+        (ToolCompatibility::Untested, Some(version)) => (
+            CheckStatus::Warning,
+            format!(
+                "{} {} is newer than Calvin's adapter was tested against ({})",
+                name,
+                version,
+                tested.as_deref().unwrap_or("unknown")
+            ),
+            Some(
+                "Upgrade Calvin, or verify the generated files still work with this version"
+                    .to_string(),
+            ),
+        ),
+        (ToolCompatibility::TooOld, Some(version)) => (
+            CheckStatus::Warning,
+            format!(
+                "{} {} is older than the minimum supported version {}",
+                name,
+                version,
+                report
+                    .min_ide_version
+                    .map(|v| v.to_string())
+                    .unwrap_or_default()
+            ),
+            Some(format!("Upgrade {} to use Calvin's generated files", name)),
+        ),
+        (_, Some(version)) => (
+            CheckStatus::Pass,
+            format!("{} {} detected", name, version),
+            None,
+        ),
+        (_, None) => (
+            CheckStatus::Pass,
+            format!("{} installed (version unknown)", name),
+            None,
+        ),
+    };
+
+    Some(CheckItem {
+        platform: report.target.id().to_string(),
+        name: "tool_version".to_string(),
+        status,
+        message,
+        recommendation,
+        details,
+    })
 }
 
 #[cfg(test)]
 mod tests {
     use super::*;
+    use crate::domain::ports::FakeToolProbe;
     use tempfile::tempdir;
 
     #[test]
@@ -248,4 +448,64 @@ mod tests {
 
         assert_eq!(callback_count, result.items.len());
     }
+
+    #[test]
+    fn check_warns_when_installed_tool_is_newer_than_tested() {
+        let dir = tempdir().unwrap();
+        let use_case = CheckUseCase::new(Config::default()).with_tool_detection(
+            vec![Box::new(crate::infrastructure::ClaudeCodeAdapter::new())],
+            Box::new(FakeToolProbe::with_output("99.0.0 (Claude Code)")),
+        );
+
+        let result = use_case
+            .execute(dir.path(), CheckOptions::default())
+            .unwrap();
+
+        let item = result
+            .items
+            .iter()
+            .find(|i| i.name == "tool_version")
+            .expect("tool version item");
+        assert_eq!(item.platform, "claude-code");
+        assert_eq!(item.status, CheckStatus::Warning);
+        assert!(
+            item.message.contains("Claude Code 99.0.0 is newer"),
+            "{}",
+            item.message
+        );
+    }
+
+    #[test]
+    fn check_warns_when_enabled_target_downgrades_an_agent() {
+        let dir = tempdir().unwrap();
+        std::fs::create_dir_all(dir.path().join(".promptpack/agents")).unwrap();
+        std::fs::write(
+            dir.path().join(".promptpack/agents/reviewer.md"),
+            "---\nkind: agent\ndescription: Reviewer\ntools:\n  - Read\n---\nReview.\n",
+        )
+        .unwrap();
+        let use_case = CheckUseCase::new(Config::default()).with_downgrade_check(
+            vec![
+                Box::new(crate::infrastructure::ClaudeCodeAdapter::new()),
+                Box::new(crate::infrastructure::adapters::AntigravityAdapter::new()),
+            ],
+            Box::new(crate::infrastructure::FsAssetRepository::new()),
+        );
+
+        let result = use_case
+            .execute(dir.path(), CheckOptions::default())
+            .unwrap();
+
+        let items: Vec<_> = result
+            .items
+            .iter()
+            .filter(|i| i.name == "asset_downgrade")
+            .collect();
+        assert_eq!(items.len(), 1);
+        assert_eq!(items[0].platform, "antigravity");
+        assert_eq!(items[0].status, CheckStatus::Warning);
+        assert!(items[0]
+            .message
+            .contains("Agent 'reviewer' compiled as a workflow"));
+    }
 }
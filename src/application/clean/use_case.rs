@@ -11,14 +11,16 @@ use std::path::{Path, PathBuf};
 
 use sha2::{Digest, Sha256};
 
-use crate::domain::entities::Lockfile;
+use crate::domain::entities::{Lockfile, LockfileEntry};
 use crate::domain::ports::{FileSystem, LockfileRepository};
-use crate::domain::services::has_calvin_signature;
-use crate::domain::value_objects::Scope;
+use crate::domain::services::{has_calvin_signature, is_empty_document, merge_document};
+use crate::domain::value_objects::DocumentFormat;
 
 use super::options::CleanOptions;
 use super::result::{CleanError, CleanResult, SkipReason};
-use crate::application::skills::skill_root_from_path;
+use crate::application::skills::{
+    hook_root_from_path, skill_fallback_root_from_path, skill_root_from_path,
+};
 
 /// Clean use case - removes deployed files tracked in lockfile
 pub struct CleanUseCase<LR, FS>
@@ -98,7 +100,8 @@ where
 
             // If lockfile is now empty, delete it entirely
             // An empty lockfile contains no meaningful information
-            if updated_lockfile.is_empty() {
+            // (remote layer pins are kept: they describe sources, not outputs)
+            if updated_lockfile.is_empty() && updated_lockfile.layer_pins().next().is_none() {
                 if let Err(e) = self.lockfile_repo.delete(lockfile_path) {
                     eprintln!("Warning: Failed to delete empty lockfile: {}", e);
                 }
@@ -126,22 +129,15 @@ where
         let mut skill_dirs_to_prune: HashSet<PathBuf> = HashSet::new();
 
         // Get entries to process based on scope
-        let entries_to_process: Vec<(&str, &str)> = match options.scope {
-            Some(Scope::User) => lockfile
-                .keys_for_scope(Scope::User)
-                .filter_map(|key| lockfile.get_hash(key).map(|hash| (key, hash)))
-                .collect(),
-            Some(Scope::Project) => lockfile
-                .keys_for_scope(Scope::Project)
-                .filter_map(|key| lockfile.get_hash(key).map(|hash| (key, hash)))
-                .collect(),
-            None => lockfile
-                .entries()
-                .map(|(key, entry)| (key, entry.hash()))
+        let entries_to_process: Vec<(&str, &LockfileEntry)> = match options.scope {
+            Some(scope) => lockfile
+                .keys_for_scope(scope)
+                .filter_map(|key| lockfile.get(key).map(|entry| (key, entry)))
                 .collect(),
+            None => lockfile.entries().collect(),
         };
 
-        for (key, expected_hash) in entries_to_process {
+        for (key, entry) in entries_to_process {
             // If selected_keys is specified, skip keys not in the selection
             if let Some(ref selected) = options.selected_keys {
                 if !selected.contains(key) {
@@ -178,6 +174,35 @@ where
                 }
             };
 
+            // Merged files are shared with the user: remove only Calvin's keys.
+            // They carry no signature, and user edits elsewhere change the hash.
+            if entry.is_merged() {
+                let format = DocumentFormat::detect(&path, &content);
+                let remaining = match merge_document(format, &content, "", entry.owned_keys()) {
+                    Ok(remaining) => remaining,
+                    Err(_) => {
+                        result.add_skipped(path, SkipReason::Modified, key.to_string());
+                        continue;
+                    }
+                };
+                if actually_delete {
+                    let outcome = if is_empty_document(format, &remaining) {
+                        self.fs.remove(&path)
+                    } else {
+                        self.fs.write(&path, &remaining)
+                    };
+                    if let Err(e) = outcome {
+                        result.add_error(CleanError::io_error(
+                            path.clone(),
+                            format!("Failed to remove Calvin keys: {}", e),
+                        ));
+                        continue;
+                    }
+                }
+                result.add_deleted(path, key.to_string());
+                continue;
+            }
+
             // Check signature (unless force)
             if !options.force && !has_calvin_signature(&content) {
                 // Skill supplemental files may not include Calvin signature markers (scripts, etc.).
@@ -193,7 +218,7 @@ where
                 let mut hasher = Sha256::new();
                 hasher.update(content.as_bytes());
                 let actual_hash = format!("sha256:{:x}", hasher.finalize());
-                if actual_hash != expected_hash {
+                if actual_hash != entry.hash() {
                     result.add_skipped(path, SkipReason::Modified, key.to_string());
                     continue;
                 }
@@ -210,7 +235,10 @@ where
                 }
 
                 // Best-effort: prune empty skill directories after deleting managed files.
-                if let Some(skill_root) = skill_root_from_path(&path) {
+                if let Some(skill_root) = skill_root_from_path(&path)
+                    .or_else(|| hook_root_from_path(&path))
+                    .or_else(|| skill_fallback_root_from_path(&path))
+                {
                     let mut current = path.parent();
                     while let Some(dir) = current {
                         if !dir.starts_with(&skill_root) {
@@ -241,6 +269,11 @@ where
     }
 
     fn is_part_of_calvin_skill(&self, path: &Path) -> bool {
+        // Hook script and fallback skill directories have no signed entrypoint; the path
+        // is lockfile-tracked.
+        if hook_root_from_path(path).is_some() || skill_fallback_root_from_path(path).is_some() {
+            return true;
+        }
         let Some(skill_root) = skill_root_from_path(path) else {
             return false;
         };
@@ -259,6 +292,7 @@ where
 #[cfg(test)]
 mod tests {
     use super::*;
+    use crate::domain::value_objects::Scope;
     use crate::infrastructure::{LocalFs, TomlLockfileRepository};
     use tempfile::tempdir;
 
@@ -273,6 +307,59 @@ mod tests {
         path.to_string_lossy().replace('\\', "/")
     }
 
+    #[test]
+    fn clean_removes_only_owned_keys_from_merged_files() {
+        let dir = tempdir().unwrap();
+        let lockfile_path = dir.path().join("calvin.lock");
+
+        let shared = dir.path().join("opencode.json");
+        std::fs::write(
+            &shared,
+            r#"{"theme":"dark","mcp":{"github":{"type":"local"},"mine":{"type":"local"}}}"#,
+        )
+        .unwrap();
+        let owned_only = dir.path().join(".mcp.json");
+        std::fs::write(
+            &owned_only,
+            r#"{"mcpServers":{"github":{"command":"npx"}}}"#,
+        )
+        .unwrap();
+
+        // Hashes are stale on purpose: merged files are not hash-checked.
+        std::fs::write(
+            &lockfile_path,
+            format!(
+                r#"
+version = 1
+
+[files."project:{}"]
+hash = "sha256:stale"
+owned_keys = ["/mcp/github"]
+
+[files."project:{}"]
+hash = "sha256:stale"
+owned_keys = ["/mcpServers/github"]
+"#,
+                normalize_path_for_key(&shared),
+                normalize_path_for_key(&owned_only)
+            ),
+        )
+        .unwrap();
+
+        let use_case = CleanUseCase::new(TomlLockfileRepository::new(), LocalFs::new());
+        let options = CleanOptions::new().with_scope(Some(Scope::Project));
+        let result = use_case.execute_confirmed(&lockfile_path, &options);
+
+        assert_eq!(result.deleted.len(), 2, "{:?}", result);
+        let remaining: serde_json::Value =
+            serde_json::from_str(&std::fs::read_to_string(&shared).unwrap()).unwrap();
+        assert_eq!(
+            remaining,
+            serde_json::json!({"theme": "dark", "mcp": {"mine": {"type": "local"}}})
+        );
+        assert!(!owned_only.exists(), "file with only owned keys is removed");
+    }
+
     #[test]
     fn clean_deletes_files_from_lockfile() {
         let dir = tempdir().unwrap();
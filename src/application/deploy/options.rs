@@ -4,6 +4,7 @@
 
 use std::path::PathBuf;
 
+use crate::config::Config;
 use crate::domain::value_objects::{Scope, Target};
 
 /// Options for the deploy use case
@@ -29,6 +30,9 @@ pub struct DeployOptions {
     pub targets: Vec<Target>,
     /// Remote deploy mode: only use project layer (PRD §14.3)
     pub remote_mode: bool,
+    /// Remote being deployed to; its state is kept in that remote's section of
+    /// `calvin.lock` so hosts deployed together don't share hashes or orphans
+    pub remote_name: Option<String>,
     /// Force overwrite without conflict detection
     pub force: bool,
     /// Interactive conflict resolution
@@ -37,6 +41,12 @@ pub struct DeployOptions {
     pub dry_run: bool,
     /// Clean orphan files
     pub clean_orphans: bool,
+    /// Structurally merge shared config outputs (`opencode.json`, MCP configs, ...)
+    /// into existing files, owning only the keys Calvin generates
+    pub merge: bool,
+    /// Merged layer configuration used to generate security baselines
+    /// (e.g. `.claude/settings.json`). `None` skips baseline generation.
+    pub config: Option<Config>,
 }
 
 impl DeployOptions {
@@ -63,10 +73,13 @@ impl DeployOptions {
             scope: Scope::default(),
             targets: Vec::new(),
             remote_mode: false,
+            remote_name: None,
             force: false,
             interactive: false,
             dry_run: false,
             clean_orphans: false,
+            merge: false,
+            config: None,
         }
     }
 
@@ -115,6 +128,11 @@ impl DeployOptions {
         self
     }
 
+    pub fn with_remote_name(mut self, name: impl Into<String>) -> Self {
+        self.remote_name = Some(name.into());
+        self
+    }
+
     pub fn with_force(mut self, force: bool) -> Self {
         self.force = force;
         self
@@ -134,6 +152,16 @@ impl DeployOptions {
         self.clean_orphans = clean;
         self
     }
+
+    pub fn with_merge(mut self, merge: bool) -> Self {
+        self.merge = merge;
+        self
+    }
+
+    pub fn with_config(mut self, config: Config) -> Self {
+        self.config = Some(config);
+        self
+    }
 }
 
 /// Options for deploying pre-compiled outputs (used by watcher)
@@ -2,6 +2,7 @@
 //!
 //! Result types for deploy operations.
 
+use std::collections::{BTreeMap, BTreeSet};
 use std::path::PathBuf;
 
 /// Result of a deploy operation
@@ -21,6 +22,8 @@ pub struct DeployResult {
     pub asset_count: usize,
     /// Total output count
     pub output_count: usize,
+    /// Include edges: partial source file → IDs of the assets that include it
+    pub includes: BTreeMap<PathBuf, BTreeSet<String>>,
 }
 
 impl DeployResult {
@@ -33,6 +36,7 @@ impl DeployResult {
             warnings: Vec::new(),
             asset_count: 0,
             output_count: 0,
+            includes: BTreeMap::new(),
         }
     }
 
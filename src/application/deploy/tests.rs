@@ -326,6 +326,39 @@ fn deploy_errors_when_skill_has_no_supported_targets() {
     );
 }
 
+#[test]
+fn deploy_errors_when_asset_names_an_undeclared_custom_target() {
+    let config: crate::config::Config = toml::from_str(
+        "[[targets.custom]]\nname = \"aider\"\naggregate.path = \"CONVENTIONS.md\"\n",
+    )
+    .unwrap();
+    let declared = Asset::new("style", "policies/style.md", "Style", "x")
+        .with_targets(vec![Target::custom("aider")]);
+    let typo = Asset::new("lint", "policies/lint.md", "Lint", "x")
+        .with_targets(vec![Target::ClaudeCode, Target::custom("aidr")]);
+
+    let use_case = create_use_case_with_assets(vec![declared.clone()]);
+    let options = DeployOptions::new(".promptpack")
+        .with_targets(vec![Target::ClaudeCode])
+        .with_config(config.clone());
+    assert!(use_case.execute(&options).is_success());
+
+    let use_case = create_use_case_with_assets(vec![declared, typo]);
+    let options = DeployOptions::new(".promptpack")
+        .with_targets(vec![Target::ClaudeCode])
+        .with_config(config);
+    let result = use_case.execute(&options);
+
+    assert!(
+        result
+            .errors
+            .iter()
+            .any(|e| e.contains("policies/lint.md") && e.contains("Did you mean 'aider'?")),
+        "expected error, got: {:?}",
+        result.errors
+    );
+}
+
 #[test]
 fn deploy_result_default_is_empty() {
     let result = DeployResult::default();
@@ -713,3 +746,80 @@ fn deploy_tracks_binary_files_in_lockfile_with_is_binary_flag() {
         result.written
     );
 }
+
+/// Writes one mergeable `.test/shared.json` owning `/<key>`
+struct SharedJsonAdapter {
+    target: Target,
+    key: &'static str,
+    content: &'static str,
+}
+
+impl TargetAdapter for SharedJsonAdapter {
+    fn target(&self) -> Target {
+        self.target
+    }
+
+    fn compile(&self, _asset: &Asset) -> Result<Vec<OutputFile>, AdapterError> {
+        Ok(vec![])
+    }
+
+    fn post_compile(&self, _assets: &[Asset]) -> Result<Vec<OutputFile>, AdapterError> {
+        let spec = crate::domain::value_objects::MergeSpec::new(vec![
+            crate::domain::value_objects::json_pointer(&[self.key]),
+        ]);
+        Ok(vec![OutputFile::new(
+            ".test/shared.json",
+            self.content,
+            self.target,
+        )
+        .with_merge(spec.always())])
+    }
+
+    fn validate(&self, _output: &OutputFile) -> Vec<AdapterDiagnostic> {
+        vec![]
+    }
+}
+
+#[test]
+fn deploy_fails_when_shared_outputs_cannot_be_combined() {
+    let adapters: Vec<Box<dyn TargetAdapter>> = vec![
+        Box::new(SharedJsonAdapter {
+            target: Target::ClaudeCode,
+            key: "a",
+            content: "{\"a\": 1}\n",
+        }),
+        Box::new(SharedJsonAdapter {
+            target: Target::Cursor,
+            key: "b",
+            content: "{not json\n",
+        }),
+    ];
+    let file_system = MockFileSystem {
+        files: RefCell::new(HashMap::new()),
+    };
+    let use_case = DeployUseCase::new(
+        MockAssetRepository {
+            assets: vec![Asset::new("test", "test.md", "Test asset", "# Test")],
+        },
+        MockLockfileRepository {
+            lockfile: RefCell::new(Lockfile::new()),
+        },
+        file_system,
+        adapters,
+    );
+    let options =
+        DeployOptions::new(".promptpack").with_targets(vec![Target::ClaudeCode, Target::Cursor]);
+
+    let result = use_case.execute(&options);
+
+    assert!(!result.is_success());
+    assert!(
+        result
+            .errors
+            .iter()
+            .any(|e| e.starts_with("Cannot combine Cursor outputs for .test/shared.json")),
+        "{:?}",
+        result.errors
+    );
+    assert!(result.written.is_empty());
+}
@@ -17,28 +17,42 @@
 //! - Splitting would break encapsulation of private helper methods
 //! - The struct follows the UseCase pattern from Clean Architecture
 
+use std::collections::{BTreeMap, HashMap};
 use std::path::{Path, PathBuf};
-use std::sync::Arc;
+use std::sync::{Arc, Mutex};
 
-use crate::domain::entities::{Asset, BinaryOutputFile, Lockfile, OutputFile};
+use crate::domain::entities::{
+    assets_for_target, Asset, BinaryOutputFile, Layer, Lockfile, McpServer, OutputFile,
+};
+use crate::domain::policies::SecurityPolicy;
 use crate::domain::ports::{
     AssetRepository, ConflictChoice, ConflictContext, ConflictResolver, DeployEvent,
     DeployEventSink, FileSystem, ForceResolver, FsResult, LockfileRepository, NoopEventSink,
     SafeResolver, TargetAdapter,
 };
 use crate::domain::services::{
-    has_calvin_signature, merge_layers, FileAction, LayerResolveError, LayerResolver, MergedAsset,
+    extract_managed_regions, has_calvin_signature, is_empty_document, merge_document, merge_layers,
+    FileAction, LayerResolveError, LayerResolver, MergedAsset, MergedPartial,
     OrphanDetectionResult, OrphanDetector, PlannedFile, Planner, SyncPlan, TargetFileState,
 };
-use crate::domain::value_objects::{Scope, Target};
+use crate::domain::value_objects::{
+    ContentHash, DocumentFormat, FormatSupport, FormatVersion, MergeSpec, Scope, Target,
+};
 
 use super::options::{DeployOptions, DeployOutputOptions};
 use super::result::DeployResult;
 use crate::application::layer_ops::load_resolved_layers;
-use crate::application::skills::skill_root_from_path;
+use crate::application::skills::{
+    hook_root_from_path, skill_fallback_root_from_path, skill_root_from_path,
+};
+use crate::application::templates::render_asset_templates;
 use crate::application::RegistryUseCase;
 use crate::config::default_user_layer_path;
 
+/// Serializes lockfile and registry updates between deploys running on several
+/// threads (`deploy --remote a,b` fans out one deploy per host)
+static STATE_UPDATE: Mutex<()> = Mutex::new(());
+
 /// Deploy use case - orchestrates the deployment flow
 ///
 /// This use case is parameterized by its dependencies (ports),
@@ -205,11 +219,15 @@ where
                 scope: options.scope,
                 targets: vec![],
                 remote_mode: false,
+                remote_name: None,
                 force: false,
                 interactive: false,
                 dry_run: options.dry_run,
                 clean_orphans: options.clean_orphans,
+                merge: false,
+                config: None,
             },
+            &HashMap::new(),
         );
 
         // Step 3: Resolve conflicts
@@ -246,6 +264,8 @@ where
             );
             self.delete_orphans_with_events(
                 &orphans,
+                &lockfile,
+                options.scope,
                 &mut result,
                 &event_sink,
                 &project_root,
@@ -301,13 +321,82 @@ where
             result.add_warning(warning);
         }
         let assets = layered_assets.assets;
+        let mcp_servers = layered_assets.mcp_servers;
+        let partials = layered_assets.partials;
 
         // Step 1.5: Apply scope policy - when deploying to User scope, force all assets to User
         let assets = self.apply_scope_policy(assets, options.scope);
         result.asset_count = assets.len();
 
+        // Step 1.55: Custom names in asset `targets:` must be declared in `[[targets.custom]]`
+        let declared = options
+            .config
+            .as_ref()
+            .map(|config| config.custom_target_names())
+            .unwrap_or_default();
+        if let Err(err) = validate_asset_targets(&assets, &declared) {
+            result.errors.push(err);
+            return result;
+        }
+        // Un-targeted assets go to declared custom targets too, as they do to built-ins
+        let custom_targets = options
+            .config
+            .as_ref()
+            .map(|config| config.custom_targets())
+            .unwrap_or_default();
+        let assets: Vec<Asset> = assets
+            .into_iter()
+            .map(|asset| asset.with_custom_targets(custom_targets.clone()))
+            .collect();
+
+        // Step 1.6: Render `{{ var }}` / `{% if %}` / `{{> partial }}` templates for each target
+        let no_vars = BTreeMap::new();
+        let vars = options
+            .config
+            .as_ref()
+            .map_or(&no_vars, |config| &config.vars);
+        let assets = match render_asset_templates(assets, vars, &partials) {
+            Ok(assets) => assets,
+            Err(e) => {
+                result.errors.push(e);
+                return result;
+            }
+        };
+        for asset in &assets {
+            for include in asset.includes() {
+                result
+                    .includes
+                    .entry(include.clone())
+                    .or_default()
+                    .insert(asset.id().to_string());
+            }
+        }
+
         // Step 1.75: Validate skills targets (never fail silently)
-        match validate_skill_targets(&assets) {
+        let skill_fallback = options
+            .config
+            .as_ref()
+            .map(|config| config.skill_fallback_targets())
+            .unwrap_or_default();
+        match validate_skill_targets(&assets, &skill_fallback) {
+            Ok(warnings) => {
+                for warning in warnings {
+                    result.add_warning(warning);
+                }
+            }
+            Err(err) => {
+                result.errors.push(err);
+                return result;
+            }
+        }
+
+        // Step 1.76: Validate hooks (targets, dangerous commands per security mode)
+        let security_mode = options
+            .config
+            .as_ref()
+            .map(|config| config.security.mode)
+            .unwrap_or_default();
+        match validate_hooks(&assets, SecurityPolicy::new(security_mode)) {
             Ok(warnings) => {
                 for warning in warnings {
                     result.add_warning(warning);
@@ -321,8 +410,11 @@ where
 
         // Step 1.8: Warn if deploy targets include platforms that don't support skills.
         // This prevents silent skips when deploying to VS Code / Antigravity.
-        for warning in warn_skills_skipped_for_unsupported_deploy_targets(&assets, &options.targets)
-        {
+        for warning in warn_skills_skipped_for_unsupported_deploy_targets(
+            &assets,
+            &options.targets,
+            &skill_fallback,
+        ) {
             result.add_warning(warning);
         }
 
@@ -339,7 +431,7 @@ where
         });
 
         // Step 2: Compile assets
-        let (outputs, binary_outputs, provenance_by_output_path) = match self.compile_assets(
+        let (mut outputs, binary_outputs, provenance_by_output_path) = match self.compile_assets(
             &assets,
             &options.targets,
             &layered_assets.merged_assets_by_id,
@@ -350,10 +442,34 @@ where
                 return result;
             }
         };
+
+        // Step 2.05: Generate security baselines (e.g. `.claude/settings.json`).
+        // They are always merged into existing user-authored settings (see Step 3.5).
+        if let Some(config) = &options.config {
+            match self.compile_security_baselines(config, options) {
+                Ok(baseline_outputs) => outputs.extend(baseline_outputs),
+                Err(e) => {
+                    result.errors.push(format!("Compilation failed: {}", e));
+                    return result;
+                }
+            }
+        }
+
+        // Step 2.06: Compile MCP server definitions (allowlist-filtered).
+        match self.compile_mcp_servers(&mcp_servers, options, &mut result) {
+            Ok(mcp_outputs) => outputs.extend(mcp_outputs),
+            Err(e) => {
+                result.errors.push(e);
+                return result;
+            }
+        }
         result.output_count = outputs.len();
 
-        // Step 2.1: Surface adapter validation warnings for skill outputs.
-        self.validate_skill_outputs(&outputs, &mut result);
+        // Step 2.1: Surface adapter validation warnings (skill rules, size limits, ...).
+        self.validate_outputs(&outputs, &mut result);
+
+        // Step 2.15: Surface what targets cannot represent (skipped agents, ignored fields).
+        self.report_downgrades(&assets, &options.targets, &mut result);
 
         // Emit compiled event
         event_sink.on_event(DeployEvent::Compiled {
@@ -384,7 +500,11 @@ where
             result.add_warning(warning);
         }
         let lockfile = match self.lockfile_repo.load(&lockfile_path) {
-            Ok(lockfile) => lockfile,
+            // A remote only sees its own state, never the local files or other hosts
+            Ok(lockfile) => match &options.remote_name {
+                Some(name) => lockfile.remote(name).cloned().unwrap_or_default(),
+                None => lockfile,
+            },
             Err(e) => {
                 result
                     .errors
@@ -393,8 +513,19 @@ where
             }
         };
 
+        // Step 3.5: Structurally merge shared config files, owning only Calvin's keys
+        outputs = match combine_shared_outputs(outputs) {
+            Ok(outputs) => outputs,
+            Err(e) => {
+                result.errors.push(e);
+                return result;
+            }
+        };
+        let (owned_keys_by_path, unmerged_outputs) =
+            self.apply_structured_merges(&mut outputs, &lockfile, options, &mut result);
+
         // Step 4: Plan sync
-        let plan = self.plan_sync(&outputs, &lockfile, options);
+        let plan = self.plan_sync(&outputs, &lockfile, options, &owned_keys_by_path);
 
         // Step 4.5: Resolve conflicts
         let resolved_plan = match self.resolve_conflicts(
@@ -411,11 +542,14 @@ where
             }
         };
 
-        // Step 5: Detect orphans (including binary outputs)
+        // Step 5: Detect orphans (including binary outputs). Files whose merge failed
+        // are still generated, so they are never orphans.
         let orphans = if options.clean_orphans {
+            let current: Vec<OutputFile> =
+                outputs.iter().chain(&unmerged_outputs).cloned().collect();
             self.detect_orphans_with_binaries(
                 &lockfile,
-                &outputs,
+                &current,
                 &binary_outputs,
                 options.scope,
                 &options.project_root,
@@ -443,18 +577,24 @@ where
             );
             self.delete_orphans_with_events(
                 &orphans,
+                &lockfile,
+                options.scope,
                 &mut result,
                 &event_sink,
                 &options.project_root,
                 options.remote_mode,
             );
+            let _guard = STATE_UPDATE.lock().unwrap_or_else(|e| e.into_inner());
             if let Some(warning) = self.update_lockfile_with_binaries(
                 &lockfile_path,
+                options.remote_name.as_deref(),
                 &resolved_plan,
                 &binary_outputs,
                 &result,
                 options.scope,
                 Some(&provenance_by_output_path),
+                &owned_keys_by_path,
+                &self.adapter_versions(&options.targets),
             ) {
                 result.add_warning(warning);
             }
@@ -558,11 +698,24 @@ where
             .collect();
 
         let mut warnings = resolution.warnings;
+        for layer in &layers {
+            warnings.extend(check_layer_format(layer)?);
+        }
         for override_info in &merge_result.overrides {
-            warnings.push(format!(
-                "Asset '{}' from {} overridden by {}",
-                override_info.asset_id, override_info.from_layer, override_info.by_layer
-            ));
+            warnings.push(if override_info.is_replacement() {
+                format!(
+                    "Asset '{}' from {} overridden by {}",
+                    override_info.asset_id, override_info.from_layer, override_info.by_layer
+                )
+            } else {
+                format!(
+                    "Asset '{}' from {} extended by {} (merge: {})",
+                    override_info.asset_id,
+                    override_info.from_layer,
+                    override_info.by_layer,
+                    override_info.strategy
+                )
+            });
         }
 
         // Collect warnings from individual assets (e.g., skipped binary files)
@@ -570,9 +723,22 @@ where
             warnings.extend(asset.warnings().iter().cloned());
         }
 
+        // `[mcp.servers]` from config is the lowest-priority source; `mcp/*.toml` files win.
+        let mut mcp_servers: Vec<McpServer> = options
+            .config
+            .iter()
+            .flat_map(|config| config.mcp.servers.iter())
+            .filter(|(name, _)| !merge_result.mcp_servers.iter().any(|s| s.name() == *name))
+            .map(|(name, server)| server.clone().into_server(name.as_str()))
+            .collect();
+        mcp_servers.extend(merge_result.mcp_servers);
+        mcp_servers.sort_by(|a, b| a.name().cmp(b.name()));
+
         Ok(LayeredAssets {
             assets,
             merged_assets_by_id: merge_result.assets,
+            mcp_servers,
+            partials: merge_result.partials,
             warnings,
         })
     }
@@ -622,6 +788,7 @@ where
         fn merge_key_for_asset(asset: &Asset) -> String {
             match asset.kind() {
                 AssetKind::Skill => format!("skill:{}", asset.id()),
+                AssetKind::Hook => format!("hook:{}", asset.id()),
                 _ => asset.id().to_string(),
             }
         }
@@ -631,21 +798,7 @@ where
         let mut provenance_by_output_path: std::collections::HashMap<PathBuf, OutputProvenance> =
             std::collections::HashMap::new();
 
-        // Determine which adapters to use
-        // Empty targets list means "no targets" (not "all targets")
-        let active_adapters: Vec<&Box<dyn TargetAdapter>> = if targets.is_empty() {
-            // Empty targets = no deployment
-            Vec::new()
-        } else if targets.iter().any(|t| t.is_all()) {
-            // Target::All = all adapters
-            self.adapters.iter().collect()
-        } else {
-            // Specific targets = filter to matching adapters
-            self.adapters
-                .iter()
-                .filter(|a| targets.contains(&a.target()))
-                .collect()
-        };
+        let active_adapters = self.active_adapters(targets);
 
         // Check if Cursor needs to generate its own commands
         // (when Cursor is selected but Claude Code is not)
@@ -674,6 +827,10 @@ where
                     continue;
                 }
 
+                // Apply this target's `overrides:` frontmatter
+                let asset = asset.for_target(adapter.target());
+                let asset = asset.as_ref();
+
                 match adapter.compile(asset) {
                     Ok(adapter_outputs) => {
                         let provenance =
@@ -686,6 +843,12 @@ where
                                         asset.id().to_string(),
                                         m.source_file.clone(),
                                     );
+                                    let base = base.with_includes(asset.includes().to_vec());
+                                    let base = if m.contributing_layers.len() > 1 {
+                                        base.with_contributing_layers(m.contributing_layers.clone())
+                                    } else {
+                                        base
+                                    };
                                     match &m.overrides {
                                         Some(overrides) => base.with_overrides(overrides.clone()),
                                         None => base,
@@ -742,8 +905,13 @@ where
         }
 
         // Post-compile for each adapter (e.g., generate AGENTS.md)
+        let has_opencode = active_adapters
+            .iter()
+            .any(|a| a.target() == Target::OpenCode);
         for adapter in &active_adapters {
-            match adapter.post_compile(assets) {
+            let post_assets =
+                CompilerService::post_compile_assets(assets, adapter.target(), has_opencode);
+            match adapter.post_compile(&post_assets) {
                 Ok(post_outputs) => outputs.extend(post_outputs),
                 Err(e) => {
                     return Err(format!(
@@ -758,6 +926,258 @@ where
         Ok((outputs, binary_outputs, provenance_by_output_path))
     }
 
+    /// Determine which adapters to use for the given targets
+    ///
+    /// Empty targets list means "no targets" (not "all targets").
+    fn active_adapters(&self, targets: &[Target]) -> Vec<&dyn TargetAdapter> {
+        if targets.is_empty() {
+            // Empty targets = no deployment
+            Vec::new()
+        } else if targets.iter().any(|t| t.is_all()) {
+            // Target::All = all adapters
+            self.adapters.iter().map(|a| a.as_ref()).collect()
+        } else {
+            // Specific targets = filter to matching adapters
+            self.adapters
+                .iter()
+                .filter(|a| targets.contains(&a.target()))
+                .map(|a| a.as_ref())
+                .collect()
+        }
+    }
+
+    /// Output format versions of the active adapters (recorded in the lockfile)
+    fn adapter_versions(&self, targets: &[Target]) -> Vec<(Target, u32)> {
+        self.active_adapters(targets)
+            .into_iter()
+            .map(|adapter| (adapter.target(), adapter.output_format_version()))
+            .collect()
+    }
+
+    /// Compile security baselines for the active adapters
+    ///
+    /// Baseline files may already contain user-authored settings, so adapters mark them
+    /// as always-merged; only the baseline entries are owned by Calvin.
+    fn compile_security_baselines(
+        &self,
+        config: &crate::config::Config,
+        options: &DeployOptions,
+    ) -> Result<Vec<OutputFile>, String> {
+        let mut outputs = Vec::new();
+
+        for adapter in self.active_adapters(&options.targets) {
+            let baseline = adapter.security_baseline(config).map_err(|e| {
+                format!(
+                    "Security baseline for {} failed: {}",
+                    adapter.target().display_name(),
+                    e
+                )
+            })?;
+
+            for output in baseline {
+                // Adapters emit project-relative paths; home deploys target `~/...`.
+                let path = match options.scope {
+                    Scope::User => PathBuf::from("~").join(output.path()),
+                    Scope::Project => output.path().clone(),
+                };
+                let mut rerooted =
+                    OutputFile::new(path, output.content().to_string(), output.target());
+                if let Some(spec) = output.merge_spec() {
+                    rerooted = rerooted.with_merge(spec.clone());
+                }
+                outputs.push(rerooted);
+            }
+        }
+
+        Ok(outputs)
+    }
+
+    /// Merge mergeable outputs into their existing files
+    ///
+    /// An output is merged when `deploy --merge` is set, when its spec is marked
+    /// `always`, or when the lockfile already tracks owned keys for it. Keys owned by
+    /// the previous deploy but no longer generated are removed from the file.
+    ///
+    /// A file that can't be read or merged (e.g. JSONC) is left unchanged and reported
+    /// as an error; its output is returned separately so the file keeps its lockfile
+    /// entry and is never treated as an orphan.
+    ///
+    /// Returns the owned keys for each merged output path, and the unmerged outputs.
+    fn apply_structured_merges(
+        &self,
+        outputs: &mut Vec<OutputFile>,
+        lockfile: &Lockfile,
+        options: &DeployOptions,
+        result: &mut DeployResult,
+    ) -> (HashMap<PathBuf, Vec<String>>, Vec<OutputFile>) {
+        let mut owned_keys_by_path = HashMap::new();
+        let mut merged_outputs = Vec::with_capacity(outputs.len());
+        let mut unmerged = Vec::new();
+
+        for output in std::mem::take(outputs) {
+            let Some(spec) = output.merge_spec().cloned() else {
+                merged_outputs.push(output);
+                continue;
+            };
+
+            let path = output.path().clone();
+            let lockfile_key = Lockfile::make_key(options.scope, &path.display().to_string());
+            let previous_keys = lockfile
+                .get(&lockfile_key)
+                .map(|entry| entry.owned_keys().to_vec())
+                .unwrap_or_default();
+            if !options.merge && !spec.is_always() && previous_keys.is_empty() {
+                merged_outputs.push(output);
+                continue;
+            }
+
+            let mut keys = spec.owned_keys().to_vec();
+            for key in previous_keys {
+                if !keys.contains(&key) {
+                    keys.push(key);
+                }
+            }
+
+            let resolved = self.resolve_fs_path(&options.project_root, &path, options.remote_mode);
+            let existing = if self.file_system.exists(&resolved) {
+                match self.file_system.read(&resolved) {
+                    Ok(content) => content,
+                    Err(e) => {
+                        result.errors.push(format!(
+                            "Structured merge failed for {} (left unchanged): {}",
+                            path.display(),
+                            e
+                        ));
+                        unmerged.push(output);
+                        continue;
+                    }
+                }
+            } else {
+                String::new()
+            };
+            // A whole file Calvin generated before it was region-merged is replaced,
+            // not kept as user text, unless it was edited since.
+            let existing = match lockfile.get(&lockfile_key) {
+                Some(entry)
+                    if !entry.is_merged()
+                        && DocumentFormat::from_path(&path) == DocumentFormat::Markdown
+                        && self.file_system.hash(&resolved).ok().as_deref()
+                            == Some(entry.hash()) =>
+                {
+                    String::new()
+                }
+                _ => existing,
+            };
+
+            match merge_document(
+                DocumentFormat::detect(&path, &existing),
+                &existing,
+                output.content(),
+                &keys,
+            ) {
+                Ok(content) => {
+                    owned_keys_by_path.insert(path, spec.owned_keys().to_vec());
+                    merged_outputs.push(output.with_content(content));
+                }
+                Err(e) => {
+                    result.errors.push(format!(
+                        "Structured merge failed for {} (left unchanged): {}",
+                        path.display(),
+                        e
+                    ));
+                    unmerged.push(output);
+                }
+            }
+        }
+
+        *outputs = merged_outputs;
+        (owned_keys_by_path, unmerged)
+    }
+
+    /// Compile MCP server definitions for the active adapters
+    ///
+    /// Servers outside the MCP allowlist are refused: skipped with a warning in balanced
+    /// mode, a deploy error in strict mode. Yolo mode allows every server.
+    fn compile_mcp_servers(
+        &self,
+        servers: &[McpServer],
+        options: &DeployOptions,
+        result: &mut DeployResult,
+    ) -> Result<Vec<OutputFile>, String> {
+        if servers.is_empty() {
+            return Ok(Vec::new());
+        }
+
+        let security = options
+            .config
+            .as_ref()
+            .map(|config| config.security.clone())
+            .unwrap_or_default();
+        let policy = SecurityPolicy::new(security.mode);
+        let custom_allowlist: Vec<String> = security
+            .mcp
+            .allowlist
+            .iter()
+            .chain(security.mcp.additional_allowlist.iter())
+            .cloned()
+            .collect();
+
+        let mut allowed = Vec::new();
+        for server in servers {
+            if policy.is_mcp_server_allowed(server, &custom_allowlist) {
+                allowed.push(server.clone());
+                continue;
+            }
+            let message = format!(
+                "MCP server '{}' (`{}`) is not in the MCP allowlist. \
+                 Add a pattern to [security.mcp] additional_allowlist to allow it.",
+                server.name(),
+                server.command_line()
+            );
+            if policy.warnings_as_errors() {
+                return Err(message);
+            }
+            result.add_warning(format!("{} Skipped.", message));
+        }
+
+        let mut outputs = Vec::new();
+        for adapter in self.active_adapters(&options.targets) {
+            let target = adapter.target();
+            let applicable: Vec<McpServer> = allowed
+                .iter()
+                .filter(|server| server.applies_to(target))
+                .cloned()
+                .collect();
+            if applicable.is_empty() {
+                continue;
+            }
+
+            let compiled = adapter
+                .compile_mcp(&applicable, options.scope)
+                .map_err(|e| {
+                    format!(
+                        "Compilation failed: MCP config for {}: {}",
+                        target.display_name(),
+                        e
+                    )
+                })?;
+            if compiled.is_empty() {
+                result.add_warning(format!(
+                    "{} has no {} MCP configuration; skipped {} MCP server(s)",
+                    target.display_name(),
+                    match options.scope {
+                        Scope::Project => "project-level",
+                        Scope::User => "user-level",
+                    },
+                    applicable.len()
+                ));
+            }
+            outputs.extend(compiled);
+        }
+
+        Ok(outputs)
+    }
+
     /// Plan the sync operation
     /// Resolve conflicts in the plan using the provided resolver
     fn resolve_conflicts(
@@ -883,11 +1303,16 @@ where
             .to_string()
     }
 
+    /// Plan the sync operation
+    ///
+    /// `owned_keys_by_path` holds outputs that were structurally merged with the existing
+    /// file; they already preserve user content, so they never raise a conflict.
     fn plan_sync(
         &self,
         outputs: &[OutputFile],
         lockfile: &Lockfile,
         options: &DeployOptions,
+        owned_keys_by_path: &HashMap<PathBuf, Vec<String>>,
     ) -> SyncPlan {
         let mut plan = SyncPlan::new();
 
@@ -925,7 +1350,27 @@ where
             };
 
             // Plan this file
-            let action = if options.force {
+            let action = if owned_keys_by_path.contains_key(path)
+                && DocumentFormat::from_path(path) == DocumentFormat::Markdown
+            {
+                let entry = lockfile.get(&lockfile_key).filter(|e| e.is_merged());
+                let current_region_hash = entry.and_then(|entry| {
+                    let content = self.file_system.read(&resolved_path).ok()?;
+                    extract_managed_regions(&content, entry.owned_keys())
+                        .map(|regions| ContentHash::from_content(&regions).to_string())
+                });
+                match Planner::plan_region(
+                    &new_hash,
+                    &target_state,
+                    current_region_hash.as_deref(),
+                    entry.map(|e| e.hash()),
+                ) {
+                    FileAction::Conflict(_) if options.force => FileAction::Merge,
+                    action => action,
+                }
+            } else if owned_keys_by_path.contains_key(path) {
+                Planner::plan_merge(&new_hash, &target_state)
+            } else if options.force {
                 // Force mode - skip content-identical files, overwrite all others
                 if target_state.exists && target_state.current_hash.as_ref() == Some(&new_hash) {
                     FileAction::Skip
@@ -984,8 +1429,13 @@ where
                     .get(&lockfile_key)
                     .is_some_and(|entry| entry.is_binary());
 
-                if is_lockfile_binary {
-                    // Binary files tracked in lockfile are safe to delete
+                let is_lockfile_merged = lockfile
+                    .get(&lockfile_key)
+                    .is_some_and(|entry| entry.is_merged());
+
+                if is_lockfile_binary || is_lockfile_merged {
+                    // Binary files tracked in lockfile are safe to delete;
+                    // merged files only lose the keys Calvin owns.
                     orphan.has_signature = true;
                 } else if let Ok(content) = self.file_system.read(&resolved) {
                     orphan.has_signature = has_calvin_signature(&content);
@@ -999,18 +1449,30 @@ where
         result
     }
 
-    fn validate_skill_outputs(&self, outputs: &[OutputFile], result: &mut DeployResult) {
+    fn report_downgrades(&self, assets: &[Asset], targets: &[Target], result: &mut DeployResult) {
         use crate::domain::ports::DiagnosticSeverity;
 
-        for output in outputs {
-            if output
-                .path()
-                .file_name()
-                .is_none_or(|n| n != std::ffi::OsStr::new("SKILL.md"))
-            {
-                continue;
+        for adapter in self.active_adapters(targets) {
+            let target = adapter.target();
+            for asset in assets_for_target(assets, target).iter() {
+                if !asset.effective_targets().contains(&target) {
+                    continue;
+                }
+                for diag in adapter.downgrades(asset) {
+                    match diag.severity {
+                        DiagnosticSeverity::Warning => result.add_warning(diag.message),
+                        DiagnosticSeverity::Error => result.errors.push(diag.message),
+                        DiagnosticSeverity::Info => {}
+                    }
+                }
             }
+        }
+    }
 
+    fn validate_outputs(&self, outputs: &[OutputFile], result: &mut DeployResult) {
+        use crate::domain::ports::DiagnosticSeverity;
+
+        for output in outputs {
             let adapter = self
                 .adapters
                 .iter()
@@ -1020,38 +1482,49 @@ where
                 continue;
             };
 
-            let skill_id = output
+            let is_skill = output
                 .path()
-                .parent()
-                .and_then(|p| p.file_name())
-                .and_then(|n| n.to_str())
-                .unwrap_or("unknown");
+                .file_name()
+                .is_some_and(|n| n == std::ffi::OsStr::new("SKILL.md"));
+            let subject = if is_skill {
+                let skill_id = output
+                    .path()
+                    .parent()
+                    .and_then(|p| p.file_name())
+                    .and_then(|n| n.to_str())
+                    .unwrap_or("unknown");
+                format!("Skill '{}'", skill_id)
+            } else {
+                output.path().display().to_string()
+            };
 
             for diag in adapter.validate(output) {
+                let message = format!(
+                    "{} ({}): {}",
+                    subject,
+                    output.target().display_name(),
+                    diag.message
+                );
                 match diag.severity {
-                    DiagnosticSeverity::Warning => result.add_warning(format!(
-                        "Skill '{}' ({}): {}",
-                        skill_id,
-                        output.target().display_name(),
-                        diag.message
-                    )),
-                    DiagnosticSeverity::Error => result.errors.push(format!(
-                        "Skill '{}' ({}): {}",
-                        skill_id,
-                        output.target().display_name(),
-                        diag.message
-                    )),
+                    DiagnosticSeverity::Warning => result.add_warning(message),
+                    DiagnosticSeverity::Error => result.errors.push(message),
                     DiagnosticSeverity::Info => {}
                 }
             }
         }
     }
 
-    /// Treat skill supplemental files as safe-to-delete if their SKILL.md is Calvin-signed.
+    /// Treat skill supplemental files as safe-to-delete if their SKILL.md is Calvin-signed
+    /// (hook scripts under `.claude/hooks/<id>/` always are).
     ///
     /// This allows `calvin deploy --cleanup` and `calvin clean` to fully remove skill directories
     /// without requiring Calvin signature markers inside arbitrary supplemental files (scripts, etc.).
     fn is_part_of_calvin_skill(&self, path: &Path) -> bool {
+        // Hook script and fallback skill directories have no signed entrypoint; the path
+        // is lockfile-tracked.
+        if hook_root_from_path(path).is_some() || skill_fallback_root_from_path(path).is_some() {
+            return true;
+        }
         let Some(skill_root) = skill_root_from_path(path) else {
             return false;
         };
@@ -1078,7 +1551,7 @@ where
     ) {
         for (index, file) in plan.files.iter().enumerate() {
             match &file.action {
-                FileAction::Write => {
+                FileAction::Write | FileAction::Merge => {
                     match self.write_file(project_root, remote_mode, &file.path, &file.content) {
                         Ok(_) => {
                             result.written.push(file.path.clone());
@@ -1176,9 +1649,12 @@ where
 
     /// Delete orphan files
     /// Delete orphan files with event reporting
+    #[allow(clippy::too_many_arguments)]
     fn delete_orphans_with_events(
         &self,
         orphans: &OrphanDetectionResult,
+        lockfile: &Lockfile,
+        scope: Scope,
         result: &mut DeployResult,
         event_sink: &Arc<dyn DeployEventSink>,
         project_root: &Path,
@@ -1205,6 +1681,27 @@ where
             let original = PathBuf::from(&orphan.path);
             let resolved = self.resolve_fs_path(project_root, &original, remote_mode);
             if orphan.exists && orphan.is_safe_to_delete() {
+                let owned_keys = lockfile
+                    .get(&Lockfile::make_key(scope, &orphan.path))
+                    .map(|entry| entry.owned_keys())
+                    .unwrap_or_default();
+                if !owned_keys.is_empty() {
+                    // Shared file: strip Calvin's keys and keep the user's
+                    match self.remove_owned_keys(&resolved, owned_keys) {
+                        Ok(()) => {
+                            result.deleted.push(original.clone());
+                            event_sink.on_event(DeployEvent::OrphanDeleted {
+                                path: original.clone(),
+                            });
+                        }
+                        Err(e) => result.errors.push(format!(
+                            "Failed to remove Calvin keys from {}: {}",
+                            original.display(),
+                            e
+                        )),
+                    }
+                    continue;
+                }
                 if let Err(e) = self.file_system.remove(&resolved) {
                     result
                         .errors
@@ -1217,7 +1714,10 @@ where
 
                     // Best-effort: prune empty skill directories after deleting orphan files.
                     // Skills are directory-based outputs (`.claude/skills/<id>/...`, `.codex/skills/<id>/...`).
-                    if let Some(skill_root) = skill_root_from_path(&resolved) {
+                    if let Some(skill_root) = skill_root_from_path(&resolved)
+                        .or_else(|| hook_root_from_path(&resolved))
+                        .or_else(|| skill_fallback_root_from_path(&resolved))
+                    {
                         let mut current = resolved.parent();
                         while let Some(dir) = current {
                             if !dir.starts_with(&skill_root) {
@@ -1244,6 +1744,20 @@ where
         }
     }
 
+    /// Remove owned keys from a merged file, deleting it if nothing else remains
+    fn remove_owned_keys(&self, resolved: &Path, owned_keys: &[String]) -> Result<(), String> {
+        let existing = self.file_system.read(resolved).map_err(|e| e.to_string())?;
+        let format = DocumentFormat::detect(resolved, &existing);
+        let remaining =
+            merge_document(format, &existing, "", owned_keys).map_err(|e| e.to_string())?;
+        if is_empty_document(format, &remaining) {
+            self.file_system.remove(resolved)
+        } else {
+            self.file_system.write(resolved, &remaining)
+        }
+        .map_err(|e| e.to_string())
+    }
+
     /// Write a file
     fn write_file(
         &self,
@@ -1300,18 +1814,25 @@ where
     ) -> Option<String> {
         self.update_lockfile_with_binaries(
             path,
+            None,
             plan,
             &[],
             result,
             scope,
             provenance_by_output_path,
+            &HashMap::new(),
+            &[],
         )
     }
 
     /// Update lockfile including binary outputs
+    ///
+    /// With `remote`, only that remote's section of the lockfile is updated.
+    #[allow(clippy::too_many_arguments)]
     fn update_lockfile_with_binaries(
         &self,
         path: &Path,
+        remote: Option<&str>,
         plan: &SyncPlan,
         binary_outputs: &[BinaryOutputFile],
         result: &DeployResult,
@@ -1319,51 +1840,55 @@ where
         provenance_by_output_path: Option<
             &std::collections::HashMap<PathBuf, crate::domain::entities::OutputProvenance>,
         >,
+        owned_keys_by_path: &HashMap<PathBuf, Vec<String>>,
+        adapter_versions: &[(Target, u32)],
     ) -> Option<String> {
         use crate::domain::entities::LockfileEntry;
         use sha2::{Digest, Sha256};
         use std::collections::HashSet;
 
-        let mut lockfile = match self.lockfile_repo.load(path) {
+        let mut full_lockfile = match self.lockfile_repo.load(path) {
             Ok(lockfile) => lockfile,
             Err(e) => {
                 return Some(format!("Failed to load lockfile for update: {}", e));
             }
         };
+        let lockfile = match remote {
+            Some(name) => full_lockfile.remote_mut(name),
+            None => &mut full_lockfile,
+        };
 
         // Build set of written and skipped paths
         let written_set: HashSet<_> = result.written.iter().collect();
         let skipped_set: HashSet<_> = result.skipped.iter().collect();
 
-        // Update hashes for written and skipped files (and keep provenance in sync)
+        // Update hashes for written and skipped files (and keep provenance in sync).
+        // Skipped files (content identical, or conflict resolved to skip) are still
+        // tracked going forward.
         for file in &plan.files {
+            if !written_set.contains(&file.path) && !skipped_set.contains(&file.path) {
+                continue;
+            }
             let key = Lockfile::make_key(scope, &file.path.display().to_string());
-
-            if written_set.contains(&file.path) {
-                let mut hasher = Sha256::new();
-                hasher.update(file.content.as_bytes());
-                let hash = format!("sha256:{:x}", hasher.finalize());
-                match provenance_by_output_path
-                    .and_then(|m| m.get(&file.path))
-                    .cloned()
-                {
-                    Some(provenance) => lockfile.set_with_provenance(&key, &hash, provenance),
-                    None => lockfile.set(&key, &hash),
-                }
-            } else if skipped_set.contains(&file.path) {
-                // File was skipped (content identical, or conflict resolved to skip).
-                // Still update lockfile so we track it going forward, and keep provenance in sync.
-                let mut hasher = Sha256::new();
-                hasher.update(file.content.as_bytes());
-                let hash = format!("sha256:{:x}", hasher.finalize());
-                match provenance_by_output_path
-                    .and_then(|m| m.get(&file.path))
-                    .cloned()
-                {
-                    Some(provenance) => lockfile.set_with_provenance(&key, &hash, provenance),
-                    None => lockfile.set(&key, &hash),
-                }
+            // Region-merged files are tracked by their managed regions only
+            let regions = owned_keys_by_path
+                .get(&file.path)
+                .filter(|_| DocumentFormat::from_path(&file.path) == DocumentFormat::Markdown)
+                .and_then(|owned_keys| extract_managed_regions(&file.content, owned_keys));
+            let mut hasher = Sha256::new();
+            hasher.update(regions.as_deref().unwrap_or(&file.content).as_bytes());
+            let hash = format!("sha256:{:x}", hasher.finalize());
+            let mut entry = match provenance_by_output_path
+                .and_then(|m| m.get(&file.path))
+                .cloned()
+            {
+                Some(provenance) => LockfileEntry::with_provenance(hash, provenance),
+                None => LockfileEntry::new(hash),
+            };
+            if let Some(owned_keys) = owned_keys_by_path.get(&file.path) {
+                entry = entry.with_owned_keys(owned_keys.clone());
             }
+            lockfile.set_entry(&key, entry);
         }
 
         // Update entries for binary files
@@ -1376,6 +1901,11 @@ where
             }
         }
 
+        // Record which adapter output format each deployed target was generated with
+        for (target, version) in adapter_versions {
+            lockfile.set_adapter_version(*target, *version);
+        }
+
         // Remove deleted files from lockfile
         for deleted in &result.deleted {
             let key = Lockfile::make_key(scope, &deleted.display().to_string());
@@ -1383,7 +1913,7 @@ where
         }
 
         // Save lockfile
-        if let Err(e) = self.lockfile_repo.save(&lockfile, path) {
+        if let Err(e) = self.lockfile_repo.save(&full_lockfile, path) {
             Some(format!("Failed to save lockfile: {}", e))
         } else {
             None
@@ -1395,10 +1925,127 @@ where
 struct LayeredAssets {
     assets: Vec<Asset>,
     merged_assets_by_id: std::collections::HashMap<String, MergedAsset>,
+    mcp_servers: Vec<McpServer>,
+    partials: BTreeMap<String, MergedPartial>,
     warnings: Vec<String>,
 }
 
-fn validate_skill_targets(assets: &[Asset]) -> Result<Vec<String>, String> {
+/// Check a layer's declared source format against the formats this build compiles.
+///
+/// Returns a deprecation warning, or an error for formats this build cannot compile.
+fn check_layer_format(layer: &Layer) -> Result<Option<String>, String> {
+    let Some(declared) = &layer.format_version else {
+        return Ok(None);
+    };
+    let version = FormatVersion::parse(declared).map_err(|e| {
+        format!(
+            "Layer '{}' ({}): {}",
+            layer.name,
+            layer.path.original().display(),
+            e
+        )
+    })?;
+
+    match version.support() {
+        FormatSupport::Current => Ok(None),
+        FormatSupport::Deprecated => Ok(Some(format!(
+            "Layer '{}' uses deprecated source format {} (current: {}). Run `calvin migrate --format {}` to upgrade.",
+            layer.name,
+            version,
+            FormatVersion::CURRENT,
+            FormatVersion::CURRENT
+        ))),
+        FormatSupport::TooNew => Err(format!(
+            "Layer '{}' ({}) declares source format {}, but this version of Calvin supports up to {}. Upgrade Calvin to deploy it.",
+            layer.name,
+            layer.path.original().display(),
+            version,
+            FormatVersion::CURRENT
+        )),
+        FormatSupport::TooOld => Err(format!(
+            "Layer '{}' ({}) declares source format {}, which is no longer supported (oldest: {}).",
+            layer.name,
+            layer.path.original().display(),
+            version,
+            FormatVersion::OLDEST_SUPPORTED
+        )),
+    }
+}
+
+/// Combine mergeable outputs that target the same file
+///
+/// Several outputs may own parts of one shared file: managed regions of `AGENTS.md`
+/// written by different adapters, or the MCP servers and security baseline keys of
+/// `.codex/config.toml`. Their owned keys are merged into a single output so that no
+/// output overwrites another's; outputs that can't be merged are an error.
+fn combine_shared_outputs(outputs: Vec<OutputFile>) -> Result<Vec<OutputFile>, String> {
+    let mut combined: Vec<OutputFile> = Vec::with_capacity(outputs.len());
+
+    for output in outputs {
+        let shared = output.merge_spec().and_then(|spec| {
+            combined
+                .iter()
+                .position(|o| o.path() == output.path() && o.merge_spec().is_some())
+                .map(|index| (index, spec.clone()))
+        });
+        let Some((index, spec)) = shared else {
+            combined.push(output);
+            continue;
+        };
+
+        let first = &combined[index];
+        let format = DocumentFormat::from_path(output.path());
+        let content = merge_document(format, first.content(), output.content(), spec.owned_keys())
+            .map_err(|e| {
+                format!(
+                    "Cannot combine {} outputs for {}: {}",
+                    output.target().display_name(),
+                    output.path().display(),
+                    e
+                )
+            })?;
+        let first_spec = first
+            .merge_spec()
+            .expect("shared outputs have a merge spec");
+        let mut keys = first_spec.owned_keys().to_vec();
+        for key in spec.owned_keys() {
+            if !keys.contains(key) {
+                keys.push(key.clone());
+            }
+        }
+        let mut merged_spec = MergeSpec::new(keys);
+        if first_spec.is_always() || spec.is_always() {
+            merged_spec = merged_spec.always();
+        }
+        combined[index] = first.clone().with_content(content).with_merge(merged_spec);
+    }
+
+    Ok(combined)
+}
+
+/// Reject asset `targets:` and `overrides:` entries naming a custom target that
+/// config doesn't declare
+fn validate_asset_targets(assets: &[Asset], declared: &[&str]) -> Result<(), String> {
+    for asset in assets {
+        for target in asset
+            .targets()
+            .iter()
+            .chain(asset.target_overrides().keys())
+        {
+            target
+                .check_declared(declared)
+                .map_err(|e| format!("{}: {}", asset.source_path_normalized(), e))?;
+        }
+    }
+    Ok(())
+}
+
+/// `fallback` lists targets without native skills that compile them anyway
+/// (`[targets.<id>] skills = "fallback"`).
+fn validate_skill_targets(
+    assets: &[Asset],
+    fallback: &[crate::domain::value_objects::Target],
+) -> Result<Vec<String>, String> {
     use crate::domain::entities::AssetKind;
     use crate::domain::value_objects::Target;
 
@@ -1421,7 +2068,7 @@ fn validate_skill_targets(assets: &[Asset]) -> Result<Vec<String>, String> {
             if t.is_all() {
                 continue;
             }
-            if t.supports_skills() {
+            if t.supports_skills() || fallback.contains(t) {
                 has_supported = true;
             } else {
                 unsupported.push(*t);
@@ -1447,9 +2094,70 @@ fn validate_skill_targets(assets: &[Asset]) -> Result<Vec<String>, String> {
     Ok(warnings)
 }
 
+/// Check hook assets: they only compile for Claude Code, and commands running a
+/// dangerous tool warn (or fail in strict mode, as do hook scripts running one).
+fn validate_hooks(assets: &[Asset], policy: SecurityPolicy) -> Result<Vec<String>, String> {
+    use crate::domain::entities::AssetKind;
+    use crate::domain::policies::dangerous_hook_command_tool;
+    use crate::domain::value_objects::Target;
+
+    let mut warnings = Vec::new();
+
+    for asset in assets {
+        if asset.kind() != AssetKind::Hook {
+            continue;
+        }
+
+        if !asset.effective_targets().contains(&Target::ClaudeCode) {
+            warnings.push(format!(
+                "Hook '{}' does not target claude-code, the only platform with hooks; skipping.",
+                asset.id()
+            ));
+            continue;
+        }
+
+        let Some(hook) = asset.hook() else {
+            continue;
+        };
+        if let Some(tool) = dangerous_hook_command_tool(&hook.command) {
+            let message = format!(
+                "Hook '{}' runs a dangerous tool ('{}'): {}",
+                asset.id(),
+                tool,
+                hook.command
+            );
+            if policy.is_strict() {
+                return Err(format!("{} (rejected in strict security mode)", message));
+            }
+            if !policy.is_yolo() {
+                warnings.push(message);
+            }
+        }
+
+        // Scripts shipped with the hook run with the same rights as its command
+        if policy.is_strict() {
+            let mut scripts: Vec<_> = asset.supplementals().iter().collect();
+            scripts.sort_by(|a, b| a.0.cmp(b.0));
+            for (path, script) in scripts {
+                if let Some(tool) = dangerous_hook_command_tool(script) {
+                    return Err(format!(
+                        "Hook '{}' script {} runs a dangerous tool ('{}') (rejected in strict security mode)",
+                        asset.id(),
+                        path.display(),
+                        tool
+                    ));
+                }
+            }
+        }
+    }
+
+    Ok(warnings)
+}
+
 fn warn_skills_skipped_for_unsupported_deploy_targets(
     assets: &[Asset],
     deploy_targets: &[crate::domain::value_objects::Target],
+    fallback: &[crate::domain::value_objects::Target],
 ) -> Vec<String> {
     use crate::domain::entities::AssetKind;
     use crate::domain::value_objects::Target;
@@ -1466,7 +2174,7 @@ fn warn_skills_skipped_for_unsupported_deploy_targets(
 
     let mut unsupported: Vec<Target> = active_targets
         .into_iter()
-        .filter(|t| !t.supports_skills())
+        .filter(|t| !t.supports_skills() && !fallback.contains(t))
         .collect();
     unsupported.sort_by_key(|t| t.display_name());
     unsupported.dedup();
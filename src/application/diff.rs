@@ -13,10 +13,12 @@
 //! calvin-no-split: This module keeps DiffUseCase, supporting types, and its unit tests together
 //! to make behavior changes easy to audit during the multi-layer migration. Refactor/split later.
 
+use std::collections::BTreeMap;
 use std::path::{Path, PathBuf};
 
 use crate::application::layer_ops::load_resolved_layers;
-use crate::domain::entities::{Lockfile, OutputFile};
+use crate::application::templates::render_asset_templates;
+use crate::domain::entities::{Asset, Lockfile, OutputFile};
 use crate::domain::ports::{AssetRepository, FileSystem, LockfileRepository, TargetAdapter};
 use crate::domain::services::{merge_layers, FileAction, Planner, TargetFileState};
 use crate::domain::value_objects::{Scope, Target};
@@ -42,6 +44,8 @@ pub struct DiffOptions {
     pub use_additional_layers: bool,
     /// Additional layer paths
     pub additional_layers: Vec<PathBuf>,
+    /// Template variables (`[vars]` from the merged config)
+    pub vars: BTreeMap<String, String>,
 }
 
 impl DiffOptions {
@@ -56,6 +60,7 @@ impl DiffOptions {
             user_layer_path: None,
             use_additional_layers: false,
             additional_layers: Vec::new(),
+            vars: BTreeMap::new(),
         }
     }
 
@@ -98,6 +103,11 @@ impl DiffOptions {
         self.additional_layers = layers;
         self
     }
+
+    pub fn with_vars(mut self, vars: BTreeMap<String, String>) -> Self {
+        self.vars = vars;
+        self
+    }
 }
 
 /// A file that would be modified
@@ -206,7 +216,7 @@ where
         let project_root = options.project_root.clone();
 
         // Step 1: Load assets
-        let assets = match self.load_assets_from_layers(options) {
+        let (assets, partials) = match self.load_assets_from_layers(options) {
             Ok(a) => a,
             Err(_) => return result,
         };
@@ -214,6 +224,22 @@ where
         // Step 1.5: Apply scope policy - when targeting User scope, force all assets to User
         let assets = self.apply_scope_policy(assets, options.scope);
         result.asset_count = assets.len();
+        let custom_targets: Vec<Target> = options
+            .targets
+            .iter()
+            .copied()
+            .filter(|t| t.is_custom())
+            .collect();
+        let assets: Vec<Asset> = assets
+            .into_iter()
+            .map(|asset| asset.with_custom_targets(custom_targets.clone()))
+            .collect();
+
+        // Step 1.6: Render asset body templates for each target
+        let assets = match render_asset_templates(assets, &options.vars, &partials) {
+            Ok(a) => a,
+            Err(_) => return result,
+        };
 
         // Step 2: Compile assets using adapters
         let outputs = match self.compile_assets(&assets, options) {
@@ -298,13 +324,13 @@ where
             let entry = DiffEntry {
                 path: output_path.clone(),
                 change_type: match &action {
-                    FileAction::Write if !exists => ChangeType::Create,
-                    FileAction::Write => ChangeType::Update,
+                    FileAction::Write | FileAction::Merge if !exists => ChangeType::Create,
+                    FileAction::Write | FileAction::Merge => ChangeType::Update,
                     FileAction::Skip => ChangeType::Skip,
                     FileAction::Conflict(_) => ChangeType::Conflict,
                 },
                 new_content: match &action {
-                    FileAction::Write | FileAction::Conflict(_) => {
+                    FileAction::Write | FileAction::Merge | FileAction::Conflict(_) => {
                         Some(output.content().to_string())
                     }
                     FileAction::Skip => None,
@@ -338,7 +364,13 @@ where
     fn load_assets_from_layers(
         &self,
         options: &DiffOptions,
-    ) -> Result<Vec<crate::domain::entities::Asset>, String> {
+    ) -> Result<
+        (
+            Vec<crate::domain::entities::Asset>,
+            std::collections::BTreeMap<String, crate::domain::services::MergedPartial>,
+        ),
+        String,
+    > {
         use crate::config::default_user_layer_path;
         use crate::domain::services::{LayerResolveError, LayerResolver};
 
@@ -377,7 +409,8 @@ where
             .map_err(|e| e.to_string())?;
 
         let merge = merge_layers(&resolution.layers);
-        Ok(merge.assets.values().map(|m| m.asset.clone()).collect())
+        let assets = merge.assets.values().map(|m| m.asset.clone()).collect();
+        Ok((assets, merge.partials))
     }
 
     /// Compile assets using adapters
@@ -418,6 +451,10 @@ where
                     continue;
                 }
 
+                // Apply this target's `overrides:` frontmatter
+                let asset = asset.for_target(adapter.target());
+                let asset = asset.as_ref();
+
                 match adapter.compile(asset) {
                     Ok(adapter_outputs) => outputs.extend(adapter_outputs),
                     Err(e) => return Err(e.to_string()),
@@ -441,8 +478,13 @@ where
         }
 
         // Post-compile for each adapter (e.g., generate AGENTS.md)
+        let has_opencode = active_adapters
+            .iter()
+            .any(|a| a.target() == Target::OpenCode);
         for adapter in &active_adapters {
-            match adapter.post_compile(assets) {
+            let post_assets =
+                CompilerService::post_compile_assets(assets, adapter.target(), has_opencode);
+            match adapter.post_compile(&post_assets) {
                 Ok(post_outputs) => outputs.extend(post_outputs),
                 Err(e) => return Err(e.to_string()),
             }
@@ -0,0 +1,270 @@
+//! Source format migration (`calvin migrate --format`).
+//!
+//! Plans and applies rewrites of every layer's asset frontmatter and `config.toml`
+//! using the domain [`FormatMigrator`] registry. Planning never writes, so the
+//! caller can preview the rewrites as a diff (dry run) before applying them.
+
+use std::path::PathBuf;
+
+use crate::application::layer_ops::load_resolved_layers;
+use crate::domain::entities::Layer;
+use crate::domain::ports::{AssetRepository, FileSystem};
+use crate::domain::services::FormatMigrator;
+use crate::domain::value_objects::FormatVersion;
+
+/// A single file rewrite produced by a migration
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct SourceRewrite {
+    /// Absolute path of the file
+    pub path: PathBuf,
+    /// Current content (empty if the file does not exist yet)
+    pub original: String,
+    /// Migrated content
+    pub migrated: String,
+}
+
+/// Planned migration for one layer
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct LayerMigration {
+    /// Layer name (`user`, `project`, custom layer name)
+    pub layer: String,
+    /// Format the layer is written in
+    pub from: FormatVersion,
+    /// Format the layer is migrated to
+    pub to: FormatVersion,
+    /// Files that change
+    pub rewrites: Vec<SourceRewrite>,
+}
+
+/// Migrate promptpack layers to a newer source format
+pub struct MigrateFormatUseCase<AR, FS>
+where
+    AR: AssetRepository,
+    FS: FileSystem,
+{
+    asset_repo: AR,
+    file_system: FS,
+    migrator: FormatMigrator,
+}
+
+impl<AR, FS> MigrateFormatUseCase<AR, FS>
+where
+    AR: AssetRepository,
+    FS: FileSystem,
+{
+    /// Create a use case with the built-in migration steps
+    pub fn new(asset_repo: AR, file_system: FS) -> Self {
+        Self {
+            asset_repo,
+            file_system,
+            migrator: FormatMigrator::default(),
+        }
+    }
+
+    /// Builder: use a custom migration registry
+    pub fn with_migrator(mut self, migrator: FormatMigrator) -> Self {
+        self.migrator = migrator;
+        self
+    }
+
+    /// Plan the rewrites needed to bring every layer to `target`.
+    ///
+    /// Layers without `[format] version` are treated as [`FormatVersion::LEGACY`].
+    /// Layers already at `target` are omitted.
+    pub fn plan(
+        &self,
+        mut layers: Vec<Layer>,
+        target: FormatVersion,
+    ) -> Result<Vec<LayerMigration>, String> {
+        load_resolved_layers(&self.asset_repo, &mut layers).map_err(|e| e.to_string())?;
+
+        let mut migrations = Vec::new();
+        for layer in &layers {
+            let from = match &layer.format_version {
+                Some(declared) => FormatVersion::parse(declared)
+                    .map_err(|e| format!("Layer '{}': {}", layer.name, e))?,
+                None => FormatVersion::LEGACY,
+            };
+            if from == target && layer.format_version.is_some() {
+                continue;
+            }
+
+            let plan = self
+                .migrator
+                .plan(from, target)
+                .map_err(|e| format!("Layer '{}': {}", layer.name, e))?;
+
+            let layer_root = layer.path.resolved();
+            let mut rewrites = Vec::new();
+
+            let mut sources: Vec<PathBuf> = layer
+                .assets
+                .iter()
+                .map(|asset| layer_root.join(asset.source_path()))
+                .collect();
+            sources.sort();
+            for path in sources {
+                let original = self
+                    .file_system
+                    .read(&path)
+                    .map_err(|e| format!("Failed to read {}: {}", path.display(), e))?;
+                let migrated = plan.migrate_source(&original);
+                if migrated != original {
+                    rewrites.push(SourceRewrite {
+                        path,
+                        original,
+                        migrated,
+                    });
+                }
+            }
+
+            let config_path = layer_root.join("config.toml");
+            let original = if self.file_system.exists(&config_path) {
+                self.file_system
+                    .read(&config_path)
+                    .map_err(|e| format!("Failed to read {}: {}", config_path.display(), e))?
+            } else {
+                String::new()
+            };
+            let migrated = plan
+                .migrate_config(&original)
+                .map_err(|e| format!("{}: {}", config_path.display(), e))?;
+            if migrated != original {
+                rewrites.push(SourceRewrite {
+                    path: config_path,
+                    original,
+                    migrated,
+                });
+            }
+
+            migrations.push(LayerMigration {
+                layer: layer.name.clone(),
+                from,
+                to: target,
+                rewrites,
+            });
+        }
+
+        Ok(migrations)
+    }
+
+    /// Write planned rewrites to disk
+    pub fn apply(&self, migrations: &[LayerMigration]) -> Result<usize, String> {
+        let mut written = 0;
+        for rewrite in migrations.iter().flat_map(|m| &m.rewrites) {
+            self.file_system
+                .write(&rewrite.path, &rewrite.migrated)
+                .map_err(|e| format!("Failed to write {}: {}", rewrite.path.display(), e))?;
+            written += 1;
+        }
+        Ok(written)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::domain::entities::{LayerPath, LayerType};
+    use crate::infrastructure::{FsAssetRepository, LocalFs};
+    use tempfile::tempdir;
+
+    fn project_layer(root: &std::path::Path) -> Layer {
+        Layer::new(
+            "project",
+            LayerPath::new(root.to_path_buf(), root.to_path_buf()),
+            LayerType::Project,
+        )
+    }
+
+    #[test]
+    fn plan_rewrites_frontmatter_and_config_then_apply_writes_them() {
+        let dir = tempdir().unwrap();
+        std::fs::create_dir_all(dir.path().join("agents")).unwrap();
+        std::fs::write(
+            dir.path().join("agents/reviewer.md"),
+            "---\ndescription: Reviewer\nkind: agent\npermissionMode: plan\n---\nReview.\n",
+        )
+        .unwrap();
+        std::fs::write(
+            dir.path().join("config.toml"),
+            "[format]\nversion = \"1.0\"\n\n[security]\ndeny = [\"*.secret\"]\n",
+        )
+        .unwrap();
+
+        let use_case = MigrateFormatUseCase::new(FsAssetRepository::new(), LocalFs::new())
+            .with_migrator(crate::domain::services::format_migration_test_steps::migrator());
+        let migrations = use_case
+            .plan(vec![project_layer(dir.path())], FormatVersion::new(1, 1))
+            .unwrap();
+
+        assert_eq!(migrations.len(), 1);
+        assert_eq!(migrations[0].from, FormatVersion::new(1, 0));
+        assert_eq!(migrations[0].rewrites.len(), 2);
+
+        // Planning never writes.
+        let agent = std::fs::read_to_string(dir.path().join("agents/reviewer.md")).unwrap();
+        assert!(agent.contains("permissionMode"));
+
+        assert_eq!(use_case.apply(&migrations).unwrap(), 2);
+        let agent = std::fs::read_to_string(dir.path().join("agents/reviewer.md")).unwrap();
+        assert!(agent.contains("permission-mode: plan"));
+        let config = std::fs::read_to_string(dir.path().join("config.toml")).unwrap();
+        assert!(config.contains("version = \"1.1\""), "{}", config);
+        assert!(config.contains("[security.deny]"), "{}", config);
+    }
+
+    #[test]
+    fn plan_declares_current_format_in_unversioned_layers() {
+        let dir = tempdir().unwrap();
+        std::fs::write(dir.path().join("config.toml"), "# Team settings\n").unwrap();
+
+        let use_case = MigrateFormatUseCase::new(FsAssetRepository::new(), LocalFs::new());
+        let migrations = use_case
+            .plan(vec![project_layer(dir.path())], FormatVersion::CURRENT)
+            .unwrap();
+
+        assert_eq!(migrations.len(), 1);
+        assert_eq!(migrations[0].rewrites.len(), 1);
+        let config = &migrations[0].rewrites[0].migrated;
+        assert!(config.contains("version = \"1.0\""), "{}", config);
+        assert!(config.contains("# Team settings"), "{}", config);
+    }
+
+    #[test]
+    fn plan_skips_layers_already_at_target() {
+        let dir = tempdir().unwrap();
+        std::fs::write(
+            dir.path().join("config.toml"),
+            format!("[format]\nversion = \"{}\"\n", FormatVersion::CURRENT),
+        )
+        .unwrap();
+
+        let use_case = MigrateFormatUseCase::new(FsAssetRepository::new(), LocalFs::new());
+        let migrations = use_case
+            .plan(vec![project_layer(dir.path())], FormatVersion::CURRENT)
+            .unwrap();
+
+        assert!(migrations.is_empty());
+    }
+
+    #[test]
+    fn plan_rejects_layers_newer_than_target() {
+        let dir = tempdir().unwrap();
+        std::fs::write(
+            dir.path().join("config.toml"),
+            "[format]\nversion = \"1.1\"\n",
+        )
+        .unwrap();
+
+        let use_case = MigrateFormatUseCase::new(FsAssetRepository::new(), LocalFs::new());
+        let err = use_case
+            .plan(vec![project_layer(dir.path())], FormatVersion::new(1, 0))
+            .unwrap_err();
+
+        assert!(
+            err.contains("cannot migrate from format 1.1 down to 1.0"),
+            "{}",
+            err
+        );
+    }
+}
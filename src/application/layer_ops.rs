@@ -26,7 +26,11 @@ use crate::domain::value_objects::IgnorePatterns;
 /// 1. Reads `.calvinignore` from each layer
 /// 2. Loads assets using `AssetRepository` with ignore filtering
 /// 3. Ensures unique asset IDs within each layer
-/// 4. Populates `layer.assets` and `layer.ignored_count`
+/// 4. Loads MCP server definitions (`mcp/*.toml`)
+/// 5. Loads include snippets (`partials/**`)
+/// 6. Reads the declared source format version (`[format] version`)
+/// 7. Populates `layer.assets`, `layer.mcp_servers`, `layer.partials`,
+///    `layer.format_version` and `layer.ignored_count`
 ///
 /// # Errors
 ///
@@ -53,7 +57,31 @@ pub fn load_resolved_layers<AR: AssetRepository>(
 
         ensure_unique_asset_ids(&layer.name, &assets)?;
 
+        let mcp_servers =
+            asset_repo
+                .load_mcp_servers(layer_root)
+                .map_err(|e| LayerLoadError::LoadFailed {
+                    message: format!("Failed to load MCP servers for '{}': {}", layer.name, e),
+                })?;
+
+        let partials =
+            asset_repo
+                .load_partials(layer_root)
+                .map_err(|e| LayerLoadError::LoadFailed {
+                    message: format!("Failed to load partials for '{}': {}", layer.name, e),
+                })?;
+
+        let format_version =
+            asset_repo
+                .load_format_version(layer_root)
+                .map_err(|e| LayerLoadError::LoadFailed {
+                    message: format!("Failed to read format version for '{}': {}", layer.name, e),
+                })?;
+
         layer.assets = assets;
+        layer.mcp_servers = mcp_servers;
+        layer.partials = partials;
+        layer.format_version = format_version;
         layer.ignored_count = ignored_count;
     }
     Ok(())
@@ -12,30 +12,45 @@
 //! - `CheckUseCase` - Orchestrates security checks
 //! - `WatchUseCase` - Orchestrates file watching with auto-deploy
 //! - `DiffUseCase` - Orchestrates diff preview
+//! - `MigrateFormatUseCase` - Rewrites layer sources to a newer format version
 //!
 //! ## Shared Operations
 //!
 //! - `layer_ops` - Unified asset loading from resolved layers
+//! - `adapter_versions` - Installed tool detection and adapter output migrations (TD-18)
+//! - `templates` - Per-target rendering of templated asset bodies
+//! - `remote_layers` - Fetching and pinning git/tarball layers
 
+pub mod adapter_versions;
 pub mod check;
 pub mod clean;
 pub mod deploy;
 pub mod diff;
+pub mod format_migration;
 pub mod layer_ops;
 pub mod layers;
 mod lockfile_migration;
 pub mod provenance;
 pub mod registry;
+pub mod remote_layers;
 pub(crate) mod skills;
+pub mod templates;
 pub mod watch;
 
+pub use adapter_versions::{
+    detect_tool_versions, plan_adapter_migrations, AdapterMigration, ToolCompatibility,
+    ToolVersionReport,
+};
 pub use check::{CheckItem, CheckOptions, CheckResult, CheckStatus, CheckUseCase};
 pub use clean::{CleanOptions, CleanResult, CleanUseCase, SkipReason, SkippedFile};
 pub use deploy::{DeployOptions, DeployOutputOptions, DeployResult, DeployUseCase};
 pub use diff::{ChangeType, DiffEntry, DiffOptions, DiffResult, DiffUseCase};
+pub use format_migration::{LayerMigration, MigrateFormatUseCase, SourceRewrite};
 pub use lockfile_migration::global_lockfile_path;
 pub use lockfile_migration::resolve_lockfile_path;
 pub use registry::RegistryUseCase;
+pub use remote_layers::{LayerUpdate, RemoteLayerUseCase};
+pub use templates::render_asset_templates;
 pub use watch::{
     compute_content_hash, parse_incremental, IncrementalCache, SyncResult, WatchEvent,
     WatchOptions, WatchUseCase, WatcherState, DEBOUNCE_MS,
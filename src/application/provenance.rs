@@ -12,6 +12,10 @@ pub fn to_json(lockfile: &Lockfile, filter: Option<&str>) -> anyhow::Result<Stri
         source_asset: Option<String>,
         source_file: Option<String>,
         overrides: Option<String>,
+        #[serde(skip_serializing_if = "Vec::is_empty")]
+        includes: Vec<String>,
+        #[serde(skip_serializing_if = "Vec::is_empty")]
+        contributing_layers: Vec<String>,
     }
 
     let entries: Vec<JsonEntry> = lockfile
@@ -29,6 +33,12 @@ pub fn to_json(lockfile: &Lockfile, filter: Option<&str>) -> anyhow::Result<Stri
                 source_asset: entry.source_asset().map(|s| s.to_string()),
                 source_file: entry.source_file().map(|p| p.display().to_string()),
                 overrides: entry.overrides().map(|s| s.to_string()),
+                includes: entry
+                    .includes()
+                    .iter()
+                    .map(|p| p.display().to_string())
+                    .collect(),
+                contributing_layers: entry.contributing_layers().to_vec(),
             })
         })
         .collect();
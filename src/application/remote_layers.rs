@@ -0,0 +1,286 @@
+//! Remote Layers
+//!
+//! Additional layers may be git repositories (`git+<url>#<ref>`) or tarballs. Before
+//! layers are resolved, these entries are replaced by their directories in the layer
+//! cache. Each source is pinned to a commit and content hash in the `[layers]` section
+//! of the project's `calvin.lock`; pinned sources are not re-resolved until
+//! `calvin layers update`.
+
+use std::path::{Path, PathBuf};
+
+use crate::domain::entities::{LayerPin, Lockfile};
+use crate::domain::ports::{FetchedLayer, LayerFetchError, LayerFetcher, LockfileRepository};
+use crate::domain::value_objects::RemoteLayer;
+use crate::infrastructure::{CachedLayerFetcher, TomlLockfileRepository};
+
+/// A remote layer re-resolved by `calvin layers update`
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct LayerUpdate {
+    /// Source as configured (e.g. `git+file:///srv/team.git#v1.2`)
+    pub source: String,
+    /// Pin before the update (`None` if the source was not pinned yet)
+    pub previous: Option<LayerPin>,
+    /// Pin after the update
+    pub current: LayerPin,
+}
+
+impl LayerUpdate {
+    pub fn is_changed(&self) -> bool {
+        self.previous.as_ref() != Some(&self.current)
+    }
+}
+
+/// Fetches remote layers and keeps their pins in `calvin.lock`
+pub struct RemoteLayerUseCase<F: LayerFetcher, LR: LockfileRepository> {
+    fetcher: F,
+    lockfile_repo: LR,
+}
+
+impl<F: LayerFetcher, LR: LockfileRepository> RemoteLayerUseCase<F, LR> {
+    pub fn new(fetcher: F, lockfile_repo: LR) -> Self {
+        Self {
+            fetcher,
+            lockfile_repo,
+        }
+    }
+
+    /// Replace remote entries of `layers` with their cached directories
+    ///
+    /// Local entries are returned unchanged. Sources without a pin are fetched at the
+    /// current revision of their ref and pinned in the lockfile at `lockfile_path`.
+    pub fn resolve(
+        &self,
+        lockfile_path: &Path,
+        layers: &[PathBuf],
+    ) -> Result<Vec<PathBuf>, String> {
+        self.fetch_all(lockfile_path, layers, false, true)
+            .map(|(paths, _)| paths)
+    }
+
+    /// Like [`resolve`](Self::resolve), but never writes pins to the lockfile
+    ///
+    /// Used by dry runs and read-only commands (`calvin diff`): unpinned sources are
+    /// fetched at the revision a real deploy would pin.
+    pub fn resolve_without_pinning(
+        &self,
+        lockfile_path: &Path,
+        layers: &[PathBuf],
+    ) -> Result<Vec<PathBuf>, String> {
+        self.fetch_all(lockfile_path, layers, false, false)
+            .map(|(paths, _)| paths)
+    }
+
+    /// Re-resolve every remote entry of `layers` and re-pin it
+    pub fn update(
+        &self,
+        lockfile_path: &Path,
+        layers: &[PathBuf],
+    ) -> Result<Vec<LayerUpdate>, String> {
+        self.fetch_all(lockfile_path, layers, true, true)
+            .map(|(_, updates)| updates)
+    }
+
+    fn fetch_all(
+        &self,
+        lockfile_path: &Path,
+        layers: &[PathBuf],
+        refresh: bool,
+        pin: bool,
+    ) -> Result<(Vec<PathBuf>, Vec<LayerUpdate>), String> {
+        if !layers.iter().any(|l| RemoteLayer::from_path(l).is_some()) {
+            return Ok((layers.to_vec(), Vec::new()));
+        }
+
+        let mut lockfile = self
+            .lockfile_repo
+            .load(lockfile_path)
+            .map_err(|e| e.to_string())?;
+        let mut paths = Vec::with_capacity(layers.len());
+        let mut updates = Vec::new();
+
+        for entry in layers {
+            let Some(remote) = RemoteLayer::from_path(entry) else {
+                paths.push(entry.clone());
+                continue;
+            };
+            let source = entry.to_string_lossy().into_owned();
+            let previous = lockfile.layer_pin(&source).cloned();
+            let current = if refresh { None } else { previous.as_ref() };
+
+            let fetched = self
+                .fetcher
+                .fetch(&remote, current)
+                .map_err(|e| format_fetch_error(&e))?;
+
+            paths.push(layer_root(&fetched));
+            updates.push(LayerUpdate {
+                source,
+                previous,
+                current: fetched.pin,
+            });
+        }
+
+        if pin && updates.iter().any(LayerUpdate::is_changed) {
+            save_pins(&self.lockfile_repo, lockfile_path, &mut lockfile, &updates)?;
+        }
+        Ok((paths, updates))
+    }
+}
+
+impl Default for RemoteLayerUseCase<CachedLayerFetcher, TomlLockfileRepository> {
+    fn default() -> Self {
+        Self::new(CachedLayerFetcher::default(), TomlLockfileRepository::new())
+    }
+}
+
+/// A fetched tree with a top-level `.promptpack/` uses that as the layer root
+fn layer_root(fetched: &FetchedLayer) -> PathBuf {
+    let nested = fetched.path.join(".promptpack");
+    if nested.is_dir() {
+        nested
+    } else {
+        fetched.path.clone()
+    }
+}
+
+fn save_pins<LR: LockfileRepository>(
+    lockfile_repo: &LR,
+    lockfile_path: &Path,
+    lockfile: &mut Lockfile,
+    updates: &[LayerUpdate],
+) -> Result<(), String> {
+    for update in updates {
+        lockfile.set_layer_pin(update.source.clone(), update.current.clone());
+    }
+    lockfile_repo
+        .save(lockfile, lockfile_path)
+        .map_err(|e| format!("Failed to save layer pins: {}", e))
+}
+
+fn format_fetch_error(err: &LayerFetchError) -> String {
+    match err {
+        LayerFetchError::HashMismatch { .. } => format!(
+            "{}\nHint: Run `calvin layers update` to re-pin the layer if the change is expected",
+            err
+        ),
+        _ => err.to_string(),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::infrastructure::TomlLockfileRepository;
+    use std::cell::RefCell;
+    use tempfile::tempdir;
+
+    /// Serves revisions from a list; each fetch without a pin takes the next one
+    struct FakeFetcher {
+        dir: PathBuf,
+        revisions: RefCell<Vec<&'static str>>,
+    }
+
+    impl LayerFetcher for FakeFetcher {
+        fn fetch(
+            &self,
+            _layer: &RemoteLayer,
+            pin: Option<&LayerPin>,
+        ) -> Result<FetchedLayer, LayerFetchError> {
+            let pin = match pin {
+                Some(pin) => pin.clone(),
+                None => {
+                    let revision = self.revisions.borrow_mut().remove(0);
+                    LayerPin::new(Some(revision.to_string()), format!("sha256:{}", revision))
+                }
+            };
+            Ok(FetchedLayer {
+                path: self.dir.join(pin.commit().unwrap()),
+                pin,
+            })
+        }
+    }
+
+    fn use_case(
+        dir: &Path,
+        revisions: Vec<&'static str>,
+    ) -> RemoteLayerUseCase<FakeFetcher, TomlLockfileRepository> {
+        RemoteLayerUseCase::new(
+            FakeFetcher {
+                dir: dir.to_path_buf(),
+                revisions: RefCell::new(revisions),
+            },
+            TomlLockfileRepository::new(),
+        )
+    }
+
+    #[test]
+    fn local_layers_do_not_touch_the_lockfile() {
+        let dir = tempdir().unwrap();
+        let lockfile_path = dir.path().join("calvin.lock");
+        let layers = vec![PathBuf::from("~/team/.promptpack")];
+
+        let resolved = use_case(dir.path(), vec![])
+            .resolve(&lockfile_path, &layers)
+            .unwrap();
+
+        assert_eq!(resolved, layers);
+        assert!(!lockfile_path.exists());
+    }
+
+    #[test]
+    fn resolve_pins_once_and_update_re_pins() {
+        let dir = tempdir().unwrap();
+        let lockfile_path = dir.path().join("calvin.lock");
+        let source = "git+file:///srv/team.git#main";
+        let layers = vec![PathBuf::from("/local"), PathBuf::from(source)];
+        let use_case = use_case(dir.path(), vec!["aaa", "bbb"]);
+
+        let resolved = use_case.resolve(&lockfile_path, &layers).unwrap();
+        assert_eq!(
+            resolved,
+            vec![PathBuf::from("/local"), dir.path().join("aaa")]
+        );
+
+        // Pinned: resolving again does not move to a newer revision
+        let resolved = use_case.resolve(&lockfile_path, &layers).unwrap();
+        assert_eq!(resolved[1], dir.path().join("aaa"));
+
+        let updates = use_case.update(&lockfile_path, &layers).unwrap();
+        assert_eq!(updates.len(), 1);
+        assert_eq!(updates[0].previous.as_ref().unwrap().commit(), Some("aaa"));
+        assert_eq!(updates[0].current.commit(), Some("bbb"));
+        assert!(updates[0].is_changed());
+
+        let lockfile = TomlLockfileRepository::new().load(&lockfile_path).unwrap();
+        assert_eq!(lockfile.layer_pin(source).unwrap().hash(), "sha256:bbb");
+    }
+
+    #[test]
+    fn resolve_without_pinning_leaves_the_lockfile_alone() {
+        let dir = tempdir().unwrap();
+        let lockfile_path = dir.path().join("calvin.lock");
+        let layers = vec![PathBuf::from("git+file:///srv/team.git#main")];
+
+        let resolved = use_case(dir.path(), vec!["aaa"])
+            .resolve_without_pinning(&lockfile_path, &layers)
+            .unwrap();
+
+        assert_eq!(resolved, vec![dir.path().join("aaa")]);
+        assert!(!lockfile_path.exists());
+    }
+
+    #[test]
+    fn nested_promptpack_is_the_layer_root() {
+        let dir = tempdir().unwrap();
+        std::fs::create_dir_all(dir.path().join("aaa/.promptpack")).unwrap();
+
+        let resolved = use_case(dir.path(), vec!["aaa"])
+            .resolve(
+                &dir.path().join("calvin.lock"),
+                &[PathBuf::from("/srv/pack.tgz")],
+            )
+            .unwrap();
+
+        assert_eq!(resolved, vec![dir.path().join("aaa/.promptpack")]);
+    }
+}
@@ -1,6 +1,7 @@
 //! Skills application helpers
 //!
-//! Shared helpers used by multiple use cases when reasoning about directory-based skill outputs.
+//! Shared helpers used by multiple use cases when reasoning about directory-based skill outputs
+//! (and the script directories of hooks).
 
 use std::path::{Path, PathBuf};
 
@@ -22,6 +23,52 @@ pub(crate) fn skill_root_from_path(path: &Path) -> Option<PathBuf> {
     None
 }
 
+/// Files directory of a skill compiled through a target's fallback (`.github/skills/<id>`,
+/// `.agent/skills/<id>`, and their user-scope counterparts).
+///
+/// These directories have no `SKILL.md`; the skill's signed entrypoint is the target's
+/// instruction or workflow file, so files under them that are tracked in the lockfile
+/// are treated as managed.
+pub(crate) fn skill_fallback_root_from_path(path: &Path) -> Option<PathBuf> {
+    const FALLBACK_PARENTS: [&str; 4] = [".github", ".agent", ".vscode", "antigravity"];
+
+    for ancestor in path.ancestors() {
+        let parent = ancestor.parent()?;
+        if parent.file_name()? != std::ffi::OsStr::new("skills") {
+            continue;
+        }
+        let Some(name) = parent.parent().and_then(|p| p.file_name()) else {
+            continue;
+        };
+        if FALLBACK_PARENTS
+            .iter()
+            .any(|p| name == std::ffi::OsStr::new(p))
+        {
+            return Some(ancestor.to_path_buf());
+        }
+    }
+
+    None
+}
+
+/// Script directory (`.claude/hooks/<id>`) of a hook supplemental file.
+///
+/// Hook scripts carry no signature; only Calvin writes to this directory, so files under it
+/// that are tracked in the lockfile are treated as managed.
+pub(crate) fn hook_root_from_path(path: &Path) -> Option<PathBuf> {
+    for ancestor in path.ancestors() {
+        let parent = ancestor.parent()?;
+        if parent.file_name()? != std::ffi::OsStr::new("hooks") {
+            continue;
+        }
+        if parent.parent()?.file_name() == Some(std::ffi::OsStr::new(".claude")) {
+            return Some(ancestor.to_path_buf());
+        }
+    }
+
+    None
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -56,4 +103,42 @@ mod tests {
         let path = PathBuf::from(".codex/skills");
         assert_eq!(skill_root_from_path(&path), None);
     }
+
+    #[test]
+    fn skill_fallback_root_from_path_detects_target_skill_dirs() {
+        assert_eq!(
+            skill_fallback_root_from_path(Path::new(".github/skills/draft/scripts/run.sh")),
+            Some(PathBuf::from(".github/skills/draft"))
+        );
+        assert_eq!(
+            skill_fallback_root_from_path(Path::new("~/.gemini/antigravity/skills/draft/ref.md")),
+            Some(PathBuf::from("~/.gemini/antigravity/skills/draft"))
+        );
+        assert_eq!(
+            skill_fallback_root_from_path(Path::new(".claude/skills/draft/ref.md")),
+            None
+        );
+        assert_eq!(
+            skill_fallback_root_from_path(Path::new(".agent/skills")),
+            None
+        );
+    }
+
+    #[test]
+    fn hook_root_from_path_detects_claude_hook_dir_from_nested_file() {
+        let path = PathBuf::from(".claude/hooks/format/scripts/fmt.sh");
+        assert_eq!(
+            hook_root_from_path(&path),
+            Some(PathBuf::from(".claude/hooks/format"))
+        );
+    }
+
+    #[test]
+    fn hook_root_from_path_returns_none_outside_hooks() {
+        assert_eq!(
+            hook_root_from_path(&PathBuf::from(".git/hooks/pre-commit")),
+            None
+        );
+        assert_eq!(hook_root_from_path(&PathBuf::from(".claude/hooks")), None);
+    }
 }
@@ -0,0 +1,362 @@
+//! Asset Body Templating
+//!
+//! Renders `{{ var }}` / `{% if %}` templates in asset bodies once per target,
+//! between loading assets and compiling them. Variables come from the merged
+//! `[vars]` config section; see [`crate::domain::services::Template`].
+//!
+//! `{{> partials/… }}` includes are resolved against the merged partials of the
+//! layer stack, so a project partial replaces a user-layer partial of the same
+//! path. Partials are templates too and may include other partials.
+
+use std::collections::BTreeMap;
+use std::path::{Path, PathBuf};
+
+use crate::docs;
+use crate::domain::entities::Asset;
+use crate::domain::services::{Include, MergedPartial, Template, TemplateContext, TemplateError};
+
+/// Render templated asset bodies for each of the asset's targets
+///
+/// Assets without template syntax are returned unchanged. Rendered bodies are
+/// stored as per-target content, and the partial files each asset includes are
+/// recorded on it ([`Asset::includes`]). Run this after the scope policy is
+/// applied (the `scope` built-in reads the asset's final scope).
+pub fn render_asset_templates(
+    assets: Vec<Asset>,
+    vars: &BTreeMap<String, String>,
+    partials: &BTreeMap<String, MergedPartial>,
+) -> Result<Vec<Asset>, String> {
+    let mut resolver = PartialResolver {
+        vars,
+        partials,
+        parsed: BTreeMap::new(),
+    };
+    assets
+        .into_iter()
+        .map(|asset| render_asset(asset, &mut resolver))
+        .collect()
+}
+
+fn render_asset(asset: Asset, resolver: &mut PartialResolver<'_>) -> Result<Asset, String> {
+    if !Template::contains_syntax(asset.content()) {
+        return Ok(asset);
+    }
+
+    let source = Source::Asset(&asset);
+    let template = Template::parse(asset.content())
+        .and_then(|template| template.check_vars(resolver.vars).map(|()| template))
+        .map_err(|e| format_template_error(source, &e))?;
+
+    let mut includes = Vec::new();
+    resolver.resolve(&template, source, &mut Vec::new(), &mut includes)?;
+
+    let mut asset = asset.with_includes(includes);
+    for target in asset.effective_targets() {
+        let content = template.render(&TemplateContext {
+            vars: resolver.vars,
+            target,
+            scope: asset.scope(),
+            partials: &resolver.parsed,
+        });
+        asset = asset.with_target_content(target, content);
+    }
+    Ok(asset)
+}
+
+/// Where a template was read from, to report error positions
+#[derive(Clone, Copy)]
+enum Source<'a> {
+    /// An asset body (possibly combined from several layers)
+    Asset(&'a Asset),
+    /// A whole partial file
+    Partial(&'a Path),
+}
+
+impl Source<'_> {
+    /// File and line of a 1-based template line
+    fn locate(&self, line: usize) -> (&Path, usize) {
+        match self {
+            Source::Asset(asset) => asset.source_location(line),
+            Source::Partial(file) => (file, line),
+        }
+    }
+}
+
+/// Parses and checks partials on first use
+struct PartialResolver<'a> {
+    vars: &'a BTreeMap<String, String>,
+    partials: &'a BTreeMap<String, MergedPartial>,
+    parsed: BTreeMap<String, Template>,
+}
+
+impl PartialResolver<'_> {
+    /// Resolve the includes of `template` (read from `source`), recursively
+    ///
+    /// `stack` holds the partial paths currently being resolved, to detect cycles;
+    /// `included` collects the source files of every partial reached.
+    fn resolve(
+        &mut self,
+        template: &Template,
+        source: Source<'_>,
+        stack: &mut Vec<String>,
+        included: &mut Vec<PathBuf>,
+    ) -> Result<(), String> {
+        for include in template.includes() {
+            let partial = self
+                .partials
+                .get(&include.path)
+                .ok_or_else(|| self.missing_partial(include, source))?;
+
+            if stack.contains(&include.path) {
+                let mut cycle = stack.clone();
+                cycle.push(include.path.clone());
+                let err = include_error(include, format!("include cycle: {}", cycle.join(" → ")));
+                return Err(format_template_error(source, &err));
+            }
+
+            if !included.contains(&partial.source_file) {
+                included.push(partial.source_file.clone());
+            }
+
+            let partial_source = Source::Partial(&partial.source_file);
+            let nested = match self.parsed.get(&include.path) {
+                Some(nested) => nested.clone(),
+                None => {
+                    let nested = Template::parse(partial.partial.content())
+                        .and_then(|t| t.check_vars(self.vars).map(|()| t))
+                        .map_err(|e| format_template_error(partial_source, &e))?;
+                    self.parsed.insert(include.path.clone(), nested.clone());
+                    nested
+                }
+            };
+
+            stack.push(include.path.clone());
+            self.resolve(&nested, partial_source, stack, included)?;
+            stack.pop();
+        }
+        Ok(())
+    }
+
+    fn missing_partial(&self, include: &Include, source: Source<'_>) -> String {
+        let err = include_error(
+            include,
+            format!("partial `{}` not found in any layer", include.path),
+        );
+        let mut message = error_location(source, &err);
+        if !self.partials.is_empty() {
+            let available: Vec<&str> = self.partials.keys().map(String::as_str).collect();
+            message.push_str(&format!("Available partials: {}\n", available.join(", ")));
+        }
+        message.push_str(
+            "Hint: Partials live in `partials/` of any layer; include them by their path from the layer root\n",
+        );
+        message.push_str(&format!("Docs: {}", docs::templates_url()));
+        message
+    }
+}
+
+fn include_error(include: &Include, message: String) -> TemplateError {
+    TemplateError {
+        line: include.line,
+        column: include.column,
+        message,
+    }
+}
+
+fn format_template_error(source: Source<'_>, err: &TemplateError) -> String {
+    let mut message = error_location(source, err);
+    if err.message.starts_with("undefined variable") {
+        message.push_str(
+            "Hint: Define it under [vars] in config.toml, or wrap literal braces in {% raw %} … {% endraw %}\n",
+        );
+    }
+    message.push_str(&format!("Docs: {}", docs::templates_url()));
+    message
+}
+
+fn error_location(source: Source<'_>, err: &TemplateError) -> String {
+    let (file, line) = source.locate(err.line);
+    format!(
+        "invalid template in {}: Line {}, column {}: {}\n",
+        file.display(),
+        line,
+        err.column,
+        err.message
+    )
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::domain::value_objects::{Scope, Target};
+
+    fn vars() -> BTreeMap<String, String> {
+        BTreeMap::from([("project".to_string(), "calvin".to_string())])
+    }
+
+    fn partials(entries: &[(&str, &str, &str)]) -> BTreeMap<String, MergedPartial> {
+        entries
+            .iter()
+            .map(|(layer, path, content)| {
+                (
+                    path.to_string(),
+                    MergedPartial {
+                        partial: crate::domain::entities::Partial::new(*path, *content),
+                        source_layer: layer.to_string(),
+                        source_file: PathBuf::from(layer).join(path),
+                    },
+                )
+            })
+            .collect()
+    }
+
+    #[test]
+    fn renders_per_target_content() {
+        let asset = Asset::new(
+            "style",
+            "policies/style.md",
+            "Style",
+            "{{ project }} on {{ target }}{% if scope == \"user\" %} (global){% endif %}",
+        )
+        .with_targets(vec![Target::Cursor, Target::Codex])
+        .with_scope(Scope::User);
+
+        let assets = render_asset_templates(vec![asset], &vars(), &BTreeMap::new()).unwrap();
+
+        assert_eq!(
+            assets[0].for_target(Target::Cursor).content(),
+            "calvin on cursor (global)"
+        );
+        assert_eq!(
+            assets[0].for_target(Target::Codex).content(),
+            "calvin on codex (global)"
+        );
+    }
+
+    #[test]
+    fn plain_assets_are_untouched() {
+        let asset = Asset::new("plain", "plain.md", "Plain", "No templates here");
+        let assets = render_asset_templates(vec![asset], &vars(), &BTreeMap::new()).unwrap();
+        assert!(assets[0].target_overrides().is_empty());
+    }
+
+    #[test]
+    fn undefined_variable_reports_source_line() {
+        let asset = Asset::new("style", "policies/style.md", "Style", "Intro\n{{ projct }}")
+            .with_content_line(5);
+
+        let err = render_asset_templates(vec![asset], &vars(), &BTreeMap::new()).unwrap_err();
+
+        assert!(
+            err.starts_with(
+                "invalid template in policies/style.md: Line 6, column 1: undefined variable `projct`"
+            ),
+            "{}",
+            err
+        );
+        assert!(err.contains("Hint: Define it under [vars]"), "{}", err);
+    }
+
+    #[test]
+    fn includes_render_nested_partials_and_record_files() {
+        let partials = partials(&[
+            (
+                "user",
+                "partials/secrets.md",
+                "Keep {{ project }} secrets out of git.\n{{> partials/env.md }}\n",
+            ),
+            ("project", "partials/env.md", "Use .env files."),
+        ]);
+        let asset = Asset::new(
+            "security",
+            "policies/security.md",
+            "Security",
+            "# Security\n{{> partials/secrets.md }}\nDone.\n",
+        )
+        .with_targets(vec![Target::Cursor]);
+
+        let assets = render_asset_templates(vec![asset], &vars(), &partials).unwrap();
+
+        assert_eq!(
+            assets[0].for_target(Target::Cursor).content(),
+            "# Security\nKeep calvin secrets out of git.\nUse .env files.\nDone.\n"
+        );
+        assert_eq!(
+            assets[0].includes(),
+            &[
+                PathBuf::from("user/partials/secrets.md"),
+                PathBuf::from("project/partials/env.md")
+            ]
+        );
+    }
+
+    #[test]
+    fn missing_partial_reports_include_position() {
+        let partials = partials(&[("user", "partials/secrets.md", "x")]);
+        let asset = Asset::new(
+            "style",
+            "policies/style.md",
+            "Style",
+            "Intro\n{{> partials/secret.md }}",
+        )
+        .with_content_line(4);
+
+        let err = render_asset_templates(vec![asset], &vars(), &partials).unwrap_err();
+
+        assert!(
+            err.starts_with(
+                "invalid template in policies/style.md: Line 5, column 1: partial `partials/secret.md` not found in any layer"
+            ),
+            "{}",
+            err
+        );
+        assert!(
+            err.contains("Available partials: partials/secrets.md"),
+            "{}",
+            err
+        );
+    }
+
+    #[test]
+    fn errors_inside_partials_point_at_the_partial() {
+        let partials = partials(&[("user", "partials/a.md", "ok\n{{ missing }}")]);
+        let asset = Asset::new(
+            "style",
+            "policies/style.md",
+            "Style",
+            "{{> partials/a.md }}",
+        );
+
+        let err = render_asset_templates(vec![asset], &vars(), &partials).unwrap_err();
+
+        assert!(
+            err.starts_with(
+                "invalid template in user/partials/a.md: Line 2, column 1: undefined variable `missing`"
+            ),
+            "{}",
+            err
+        );
+    }
+
+    #[test]
+    fn include_cycles_are_rejected() {
+        let partials = partials(&[
+            ("user", "partials/a.md", "{{> partials/b.md }}"),
+            ("user", "partials/b.md", "{{> partials/a.md }}"),
+        ]);
+        let asset = Asset::new(
+            "style",
+            "policies/style.md",
+            "Style",
+            "{{> partials/a.md }}",
+        );
+
+        let err = render_asset_templates(vec![asset], &vars(), &partials).unwrap_err();
+
+        assert!(
+            err.contains("include cycle: partials/a.md → partials/b.md → partials/a.md"),
+            "{}",
+            err
+        );
+    }
+}
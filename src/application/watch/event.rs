@@ -91,6 +91,8 @@ pub enum WatchEvent {
     },
     /// File changed
     FileChanged { path: String },
+    /// A partial changed; the listed assets include it and are redeployed
+    PartialChanged { path: String, assets: Vec<String> },
     /// Sync started
     SyncStarted,
     /// Sync completed
@@ -0,0 +1,54 @@
+//! Include edge tracking
+//!
+//! Records which assets include which partials (`{{> partials/… }}`) after each
+//! sync, so a partial change can be reported together with every asset that is
+//! redeployed because of it.
+
+use std::collections::{BTreeMap, BTreeSet};
+use std::path::{Path, PathBuf};
+
+use crate::application::DeployResult;
+
+/// Partial file → IDs of the assets that include it
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct IncludeGraph {
+    dependents: BTreeMap<PathBuf, BTreeSet<String>>,
+}
+
+impl IncludeGraph {
+    /// Create an empty graph
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Replace the edges with those of the latest sync
+    ///
+    /// A failed sync reports no edges; the previous ones are kept so that fixing
+    /// a broken partial is still attributed to its dependents.
+    pub fn update(&mut self, result: &DeployResult) {
+        if !result.is_success() && result.includes.is_empty() {
+            return;
+        }
+        self.dependents = result
+            .includes
+            .iter()
+            .map(|(path, assets)| (canonical(path), assets.clone()))
+            .collect();
+    }
+
+    /// IDs of the assets that include `path`, sorted
+    pub fn dependents_of(&self, path: &Path) -> Vec<String> {
+        self.dependents
+            .get(&canonical(path))
+            .map(|assets| assets.iter().cloned().collect())
+            .unwrap_or_default()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.dependents.is_empty()
+    }
+}
+
+fn canonical(path: &Path) -> PathBuf {
+    path.canonicalize().unwrap_or_else(|_| path.to_path_buf())
+}
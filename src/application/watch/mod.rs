@@ -5,6 +5,7 @@
 //! - File system monitoring (via `notify` crate)
 //! - Debouncing (100ms default)
 //! - Incremental compilation (only reparse changed files)
+//! - Include tracking (a changed partial redeploys every asset that includes it)
 //! - Auto-deploy to target locations
 //!
 //! ## Architecture
@@ -12,6 +13,7 @@
 //! The watch functionality is fully contained in the application layer:
 //! - `WatchUseCase` - Main orchestrator
 //! - `IncrementalCache` - Caches parsed assets for efficient reparsing
+//! - `IncludeGraph` - Tracks which assets include which partials
 //! - `WatchEvent` - Events emitted during watch operation
 //!
 //! ## Usage
@@ -24,6 +26,7 @@
 
 mod cache;
 mod event;
+mod includes;
 mod use_case;
 
 #[cfg(test)]
@@ -31,4 +34,5 @@ mod tests;
 
 pub use cache::{compute_content_hash, parse_incremental, IncrementalCache};
 pub use event::{WatchEvent, WatchOptions, WatcherState, DEBOUNCE_MS};
+pub use includes::IncludeGraph;
 pub use use_case::{SyncResult, WatchUseCase};
@@ -250,3 +250,50 @@ fn test_compute_content_hash() {
     assert_eq!(hash1, hash2);
     assert_ne!(hash1, hash3);
 }
+
+// === IncludeGraph tests ===
+
+#[test]
+fn test_include_graph_tracks_dependents_of_partials() {
+    use super::includes::IncludeGraph;
+    use crate::application::DeployResult;
+
+    let dir = tempdir().unwrap();
+    let partial = dir.path().join("partials/secrets.md");
+    fs::create_dir_all(partial.parent().unwrap()).unwrap();
+    fs::write(&partial, "Never commit secrets.").unwrap();
+
+    let mut result = DeployResult::new();
+    result.includes.insert(
+        partial.clone(),
+        ["security".to_string(), "review".to_string()].into(),
+    );
+
+    let mut graph = IncludeGraph::new();
+    graph.update(&result);
+
+    assert_eq!(
+        graph.dependents_of(&partial.canonicalize().unwrap()),
+        vec!["review".to_string(), "security".to_string()]
+    );
+    assert!(graph
+        .dependents_of(&dir.path().join("policies/style.md"))
+        .is_empty());
+
+    // A failed sync keeps the last known edges
+    let mut failed = DeployResult::new();
+    failed.errors.push("invalid template".to_string());
+    graph.update(&failed);
+    assert_eq!(graph.dependents_of(&partial).len(), 2);
+}
+
+#[test]
+fn test_watch_event_to_json_partial_changed() {
+    let event = WatchEvent::PartialChanged {
+        path: "partials/secrets.md".to_string(),
+        assets: vec!["security".to_string()],
+    };
+    let json = event.to_json();
+    assert!(json.contains("\"event\":\"partial_changed\""));
+    assert!(json.contains("\"assets\":[\"security\"]"));
+}
@@ -20,6 +20,7 @@ use crate::infrastructure::repositories::{
 
 use super::cache::compute_content_hash;
 use super::event::{WatchEvent, WatchOptions, WatcherState};
+use super::includes::IncludeGraph;
 
 /// Result of a single sync operation
 #[derive(Debug, Clone, Default)]
@@ -106,7 +107,9 @@ impl WatchUseCase {
 
         self.seed_content_hashes(&paths_to_watch, &mut content_hashes);
 
-        self.do_sync(&on_event)?;
+        // Which assets include which partials, as of the last sync
+        let mut include_graph = IncludeGraph::new();
+        include_graph.update(&self.do_sync(&on_event)?);
 
         let mut last_poll = Instant::now();
 
@@ -141,7 +144,8 @@ impl WatchUseCase {
                             }
 
                             // Only watch .md assets + config.toml changes.
-                            // Skills are directory-based assets and may include non-.md supplementals (scripts, etc.).
+                            // Skills are directory-based assets and may include non-.md supplementals (scripts, etc.),
+                            // and partials may have any extension.
                             let is_md = canonical_path
                                 .extension()
                                 .map(|e| e == "md")
@@ -152,8 +156,13 @@ impl WatchUseCase {
                                 .unwrap_or(false);
                             let is_skill_file =
                                 is_path_under_any_skills_dir(&canonical_path, &paths_to_watch);
+                            let is_partial =
+                                is_path_under_any_partials_dir(&canonical_path, &paths_to_watch);
+                            let is_hook_file =
+                                is_path_under_any_hooks_dir(&canonical_path, &paths_to_watch);
 
-                            if !(is_md || is_config || is_skill_file) {
+                            if !(is_md || is_config || is_skill_file || is_partial || is_hook_file)
+                            {
                                 continue;
                             }
 
@@ -201,9 +210,17 @@ impl WatchUseCase {
                     on_event(WatchEvent::FileChanged {
                         path: path.display().to_string(),
                     });
+                    let assets = include_graph.dependents_of(path);
+                    if !assets.is_empty() {
+                        on_event(WatchEvent::PartialChanged {
+                            path: path.display().to_string(),
+                            assets,
+                        });
+                    }
                 }
-                // Sync using full multi-layer deploy (PRD §11.4).
-                self.do_sync(&on_event)?;
+                // Sync using full multi-layer deploy (PRD §11.4); this redeploys every
+                // asset that includes a changed partial.
+                include_graph.update(&self.do_sync(&on_event)?);
             }
         }
 
@@ -271,7 +288,8 @@ impl WatchUseCase {
             .map(|n| n == "config.toml")
             .unwrap_or(false);
         let is_skill_file = is_path_under_skills_dir(path, root);
-        if !(is_md || is_config || is_skill_file) {
+        let is_partial = is_path_under_partials_dir(path, root);
+        if !(is_md || is_config || is_skill_file || is_partial) {
             return;
         }
 
@@ -294,7 +312,7 @@ impl WatchUseCase {
         state.add_change(canonical_path);
     }
 
-    fn do_sync(&self, callback: &impl Fn(WatchEvent)) -> CalvinResult<()> {
+    fn do_sync(&self, callback: &impl Fn(WatchEvent)) -> CalvinResult<DeployResult> {
         callback(WatchEvent::SyncStarted);
 
         let result = self.perform_sync();
@@ -305,7 +323,7 @@ impl WatchUseCase {
             errors: result.errors.len(),
         });
 
-        Ok(())
+        Ok(result)
     }
 
     fn perform_sync(&self) -> DeployResult {
@@ -327,7 +345,8 @@ impl WatchUseCase {
             .with_additional_layers_enabled(use_additional_layers)
             .with_scope(self.options.scope)
             .with_targets(self.options.targets.clone())
-            .with_clean_orphans(true);
+            .with_clean_orphans(true)
+            .with_config(self.options.config.clone());
         if let Some(path) = self.options.config.sources.user_layer_path.clone() {
             deploy_options = deploy_options.with_user_layer_path(path);
         }
@@ -338,7 +357,8 @@ impl WatchUseCase {
         let fs = LocalFs::new();
         let lockfile_repo = TomlLockfileRepository::new();
         let asset_repo = FsAssetRepository::new();
-        let adapters = crate::infrastructure::adapters::all_adapters();
+        let adapters =
+            crate::infrastructure::adapters::all_adapters_for_config(&self.options.config);
 
         DeployUseCase::new(asset_repo, lockfile_repo, fs, adapters)
             .with_registry_use_case(registry_use_case)
@@ -436,7 +456,8 @@ impl WatchUseCase {
             .map(|n| n == "config.toml")
             .unwrap_or(false);
         let is_skill_file = is_path_under_skills_dir(path, root);
-        if !(is_md || is_config || is_skill_file) {
+        let is_partial = is_path_under_partials_dir(path, root);
+        if !(is_md || is_config || is_skill_file || is_partial) {
             return;
         }
 
@@ -455,12 +476,32 @@ fn is_path_under_any_skills_dir(path: &Path, roots: &[PathBuf]) -> bool {
 }
 
 fn is_path_under_skills_dir(path: &Path, root: &Path) -> bool {
+    is_path_under_top_level_dir(path, root, "skills")
+}
+
+fn is_path_under_any_hooks_dir(path: &Path, roots: &[PathBuf]) -> bool {
+    roots
+        .iter()
+        .any(|root| is_path_under_top_level_dir(path, root, "hooks"))
+}
+
+fn is_path_under_any_partials_dir(path: &Path, roots: &[PathBuf]) -> bool {
+    roots
+        .iter()
+        .any(|root| is_path_under_partials_dir(path, root))
+}
+
+fn is_path_under_partials_dir(path: &Path, root: &Path) -> bool {
+    is_path_under_top_level_dir(path, root, "partials")
+}
+
+fn is_path_under_top_level_dir(path: &Path, root: &Path, dir: &str) -> bool {
     let Ok(rel) = path.strip_prefix(root) else {
         return false;
     };
     rel.components()
         .next()
-        .is_some_and(|c| c.as_os_str() == std::ffi::OsStr::new("skills"))
+        .is_some_and(|c| c.as_os_str() == std::ffi::OsStr::new(dir))
 }
 
 #[cfg(test)]
@@ -478,4 +519,18 @@ mod tests {
         assert!(is_path_under_skills_dir(&markdown, &root));
         assert!(!is_path_under_skills_dir(&policy, &root));
     }
+
+    #[test]
+    fn partial_changes_are_considered_relevant() {
+        let root = PathBuf::from(".promptpack");
+
+        assert!(is_path_under_partials_dir(
+            &root.join("partials/snippets/license.txt"),
+            &root
+        ));
+        assert!(!is_path_under_partials_dir(
+            &root.join("policies/partials.md"),
+            &root
+        ));
+    }
 }
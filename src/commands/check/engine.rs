@@ -17,7 +17,7 @@ pub fn cmd_check(
     color: Option<ColorWhen>,
     no_animation: bool,
 ) -> Result<()> {
-    use calvin::application::{CheckOptions, CheckUseCase};
+    use calvin::application::CheckOptions;
     use calvin::config::SecurityMode;
 
     let security_mode = super::parse_security_mode(mode, SecurityMode::Balanced);
@@ -76,8 +76,8 @@ pub fn cmd_check(
         );
     }
 
-    // Create CheckUseCase
-    let use_case = CheckUseCase::new(config.clone());
+    // Create CheckUseCase (security checks + installed tool versions)
+    let use_case = calvin::presentation::factory::create_check_use_case(config.clone());
 
     let result = if json {
         execute_json(&use_case, &project_root, options, all_layers)?
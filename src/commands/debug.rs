@@ -3,6 +3,7 @@ use std::path::Path;
 use anyhow::Result;
 
 use crate::ui::output::print_config_warnings;
+use calvin::domain::value_objects::FormatVersion;
 use calvin::presentation::ColorWhen;
 
 pub fn cmd_version(
@@ -12,6 +13,10 @@ pub fn cmd_version(
     no_animation: bool,
 ) -> Result<()> {
     let adapters = calvin::infrastructure::adapters::all_adapters();
+    let reports = calvin::application::detect_tool_versions(
+        &adapters,
+        &calvin::infrastructure::SystemToolProbe::new(),
+    );
     let cwd = std::env::current_dir()?;
     let config = calvin::config::Config::load_or_default(Some(&cwd));
     let ui = crate::ui::context::UiContext::new(json, verbose, color, no_animation, &config);
@@ -22,19 +27,26 @@ pub fn cmd_version(
             "command": "version"
         }))?;
 
-        let adapter_info: Vec<_> = adapters
+        let adapter_info: Vec<_> = reports
             .iter()
-            .map(|a| {
+            .map(|r| {
                 serde_json::json!({
-                    "target": format!("{:?}", a.target()).to_lowercase(),
-                    "version": a.version(),
+                    "target": r.target.id(),
+                    "output_format_version": r.output_format_version,
+                    "min_ide_version": r.min_ide_version.map(|v| v.to_string()),
+                    "max_tested_version": r.max_tested_version.map(|v| v.to_string()),
+                    "detected": r.detected.as_ref().map(|d| serde_json::json!({
+                        "version": d.version.map(|v| v.to_string()),
+                        "location": d.location,
+                    })),
+                    "compatibility": format!("{:?}", r.compatibility).to_lowercase(),
                 })
             })
             .collect();
 
         let output = serde_json::json!({
             "calvin": env!("CARGO_PKG_VERSION"),
-            "source_format": "1.0",
+            "source_format": FormatVersion::CURRENT.to_string(),
             "adapters": adapter_info
         });
         crate::ui::json::emit(serde_json::json!({
@@ -43,15 +55,11 @@ pub fn cmd_version(
             "data": output
         }))?;
     } else {
-        let adapters: Vec<(String, String)> = adapters
-            .into_iter()
-            .map(|a| (format!("{:?}", a.target()), a.version().to_string()))
-            .collect();
         print!(
             "{}",
             crate::ui::views::version::render_version(
                 env!("CARGO_PKG_VERSION"),
-                &adapters,
+                &reports,
                 ui.color,
                 ui.unicode
             )
@@ -107,24 +115,84 @@ pub fn cmd_migrate(
         }));
     }
 
+    // Source format migration: rewrite frontmatter and config.toml in every layer.
+    let format_use_case = calvin::presentation::factory::create_migrate_format_use_case();
+    let migrations = match format.as_deref() {
+        Some(format) => {
+            let target = FormatVersion::parse(format).map_err(anyhow::Error::msg)?;
+            let layers = resolve_migration_layers(&cwd, &config)?;
+            format_use_case
+                .plan(layers, target)
+                .map_err(anyhow::Error::msg)?
+        }
+        None => Vec::new(),
+    };
+    for migration in &migrations {
+        for rewrite in &migration.rewrites {
+            changes.push(serde_json::json!({
+                "type": "rewrite_source",
+                "layer": migration.layer,
+                "from": migration.from.to_string(),
+                "to": migration.to.to_string(),
+                "path": rewrite.path.display().to_string(),
+            }));
+        }
+    }
+
+    // Adapter output migration: regenerate outputs whose recorded adapter format is stale.
+    let adapter_migrations = match adapter.as_deref() {
+        Some(name) => {
+            use calvin::domain::ports::LockfileRepository;
+
+            let target = calvin::Target::from_str_with_suggestion(name)
+                .map_err(|e| anyhow::anyhow!("{}", e))?;
+            let lockfile_path = if new_lockfile.exists() {
+                &new_lockfile
+            } else {
+                &old_lockfile
+            };
+            let lockfile = calvin::infrastructure::TomlLockfileRepository::new()
+                .load(lockfile_path)
+                .map_err(|e| anyhow::anyhow!("{}", e))?;
+            calvin::application::plan_adapter_migrations(
+                &lockfile,
+                &calvin::infrastructure::adapters::all_adapters(),
+                &[target],
+            )
+            .map_err(anyhow::Error::msg)?
+        }
+        None => Vec::new(),
+    };
+    for migration in &adapter_migrations {
+        changes.push(serde_json::json!({
+            "type": "regenerate_outputs",
+            "target": migration.target.id(),
+            "from": migration.from,
+            "to": migration.to,
+        }));
+    }
+
     if changes.is_empty() {
+        let message = format!(
+            "Already at latest version ({}). No migration needed.",
+            format
+                .as_deref()
+                .and_then(|f| FormatVersion::parse(f).ok())
+                .unwrap_or(FormatVersion::CURRENT)
+        );
         if json {
             crate::ui::json::emit(serde_json::json!({
                 "event": "complete",
                 "command": "migrate",
                 "status": "success",
-                "message": "Already at latest version (1.0). No migration needed.",
+                "message": message,
                 "changes": []
             }))?;
         } else {
             println!();
             print!(
                 "{}",
-                crate::ui::views::migrate::render_migrate_complete(
-                    "Already at latest version (1.0). No migration needed.",
-                    ui.color,
-                    ui.unicode
-                )
+                crate::ui::views::migrate::render_migrate_complete(&message, ui.color, ui.unicode)
             );
         }
         return Ok(());
@@ -140,6 +208,22 @@ pub fn cmd_migrate(
                 "changes": changes
             }))?;
         } else {
+            for rewrite in migrations.iter().flat_map(|m| &m.rewrites) {
+                println!();
+                print!(
+                    "{}",
+                    crate::ui::views::diff::render_file_diff(
+                        &display_relative(&cwd, &rewrite.path),
+                        &rewrite.original,
+                        &rewrite.migrated,
+                        ui.color
+                    )
+                );
+            }
+            for migration in &adapter_migrations {
+                println!();
+                println!("{}", describe_adapter_migration(migration));
+            }
             println!();
             print!(
                 "{}",
@@ -164,6 +248,37 @@ pub fn cmd_migrate(
         std::fs::write(&new_lockfile, content)?;
         let _ = std::fs::remove_file(&old_lockfile);
     }
+    format_use_case
+        .apply(&migrations)
+        .map_err(anyhow::Error::msg)?;
+    if !adapter_migrations.is_empty() {
+        if !json {
+            for migration in &adapter_migrations {
+                println!("{}", describe_adapter_migration(migration));
+            }
+        }
+        // Redeploy the affected targets, overwriting outputs generated by older adapters.
+        let targets: Vec<calvin::Target> = adapter_migrations.iter().map(|m| m.target).collect();
+        crate::commands::deploy::cmd_deploy_with_explicit_target(
+            Path::new(".promptpack"),
+            false, // home
+            true,  // explicit_project
+            None,  // remote
+            &Some(targets),
+            &[],   // layers
+            false, // no_user_layer
+            false, // no_additional_layers
+            true,  // force
+            false, // interactive
+            false, // dry_run
+            false, // cleanup
+            false, // merge
+            json,
+            verbose,
+            color,
+            no_animation,
+        )?;
+    }
 
     if json {
         crate::ui::json::emit(serde_json::json!({
@@ -188,10 +303,59 @@ pub fn cmd_migrate(
     Ok(())
 }
 
+/// Resolve the promptpack layers (user, custom, project) that `migrate --format` rewrites.
+fn resolve_migration_layers(
+    project_root: &Path,
+    config: &calvin::config::Config,
+) -> Result<Vec<calvin::domain::entities::Layer>> {
+    use calvin::domain::services::{LayerResolveError, LayerResolver};
+
+    let mut resolver = LayerResolver::new(project_root.to_path_buf())
+        .with_project_layer_path(project_root.join(".promptpack"))
+        .with_disable_project_layer(config.sources.disable_project_layer);
+    if !config.sources.ignore_additional_layers {
+        resolver = resolver.with_additional_layers(config.sources.additional_layers.clone());
+    }
+    if config.sources.use_user_layer && !config.sources.ignore_user_layer {
+        resolver = resolver.with_user_layer_path(
+            config
+                .sources
+                .user_layer_path
+                .clone()
+                .unwrap_or_else(calvin::config::default_user_layer_path),
+        );
+    }
+
+    match resolver.resolve() {
+        Ok(resolution) => Ok(resolution.layers),
+        Err(LayerResolveError::NoLayersFound) => Ok(Vec::new()),
+        Err(e) => Err(anyhow::Error::new(e)),
+    }
+}
+
+fn describe_adapter_migration(migration: &calvin::application::AdapterMigration) -> String {
+    format!(
+        "Regenerate {} outputs (adapter format {} → v{})",
+        migration.target.display_name(),
+        migration
+            .from
+            .map(|v| format!("v{}", v))
+            .unwrap_or_else(|| "unrecorded".to_string()),
+        migration.to
+    )
+}
+
+fn display_relative(root: &Path, path: &Path) -> String {
+    path.strip_prefix(root)
+        .unwrap_or(path)
+        .display()
+        .to_string()
+}
+
 pub fn cmd_diff(source: &Path, home: bool, json: bool) -> Result<()> {
     use calvin::application::DiffOptions;
     use calvin::domain::value_objects::{DeployTarget, Scope};
-    use calvin::presentation::factory::create_diff_use_case;
+    use calvin::presentation::factory::create_diff_use_case_with_adapters;
     use std::fs;
 
     let project_root = std::env::current_dir()?;
@@ -210,7 +374,13 @@ pub fn cmd_diff(source: &Path, home: bool, json: bool) -> Result<()> {
     {
         Vec::new()
     } else {
-        base_config.sources.additional_layers.clone()
+        // Git/tarball layers resolve like in deploy; diff never writes pins
+        calvin::application::RemoteLayerUseCase::default()
+            .resolve_without_pinning(
+                &project_root.join("calvin.lock"),
+                &base_config.sources.additional_layers,
+            )
+            .map_err(anyhow::Error::msg)?
     };
     let use_additional_layers = !base_config.sources.ignore_additional_layers;
 
@@ -267,10 +437,12 @@ pub fn cmd_diff(source: &Path, home: bool, json: bool) -> Result<()> {
     }
 
     // Create and execute DiffUseCase
-    let use_case = create_diff_use_case();
+    let use_case = create_diff_use_case_with_adapters(
+        calvin::infrastructure::adapters::all_adapters_for_config(&config),
+    );
     let mut targets = config.enabled_targets();
     if targets.contains(&calvin::Target::All) {
-        targets = calvin::Target::ALL_CONCRETE.to_vec();
+        targets = config.all_targets();
     }
     let options = DiffOptions::new(source)
         .with_scope(scope)
@@ -279,7 +451,8 @@ pub fn cmd_diff(source: &Path, home: bool, json: bool) -> Result<()> {
         .with_project_layer_enabled(!base_config.sources.disable_project_layer)
         .with_user_layer_enabled(use_user_layer)
         .with_additional_layers_enabled(use_additional_layers)
-        .with_additional_layers(additional_layers);
+        .with_additional_layers(additional_layers)
+        .with_vars(config.vars.clone());
     let options = if let Some(path) = base_config.sources.user_layer_path.clone() {
         options.with_user_layer_path(path)
     } else {
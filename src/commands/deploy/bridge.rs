@@ -4,9 +4,10 @@
 //! allowing for a gradual migration.
 
 use calvin::application::{DeployOptions as UseCaseOptions, DeployResult as UseCaseResult};
+use calvin::config::RemoteConfig;
 use calvin::domain::value_objects::{Scope, Target as DomainTarget};
 use calvin::presentation::factory::{
-    create_adapters_for_targets, create_deploy_use_case_for_remote_with_adapters,
+    create_adapters_for_config, create_deploy_use_case_for_remote_with_adapters,
     ConcreteDeployUseCase,
 };
 
@@ -64,6 +65,10 @@ pub fn convert_options(
             calvin::Target::Antigravity => DomainTarget::Antigravity,
             calvin::Target::Codex => DomainTarget::Codex,
             calvin::Target::OpenCode => DomainTarget::OpenCode,
+            calvin::Target::GeminiCli => DomainTarget::GeminiCli,
+            calvin::Target::Windsurf => DomainTarget::Windsurf,
+            calvin::Target::Cline => DomainTarget::Cline,
+            calvin::Target::Custom(name) => DomainTarget::Custom(*name),
             calvin::Target::All => DomainTarget::All,
         })
         .collect();
@@ -79,38 +84,95 @@ pub fn convert_options(
         scope,
         targets,
         remote_mode: matches!(target, DeployTarget::Remote(_)),
+        remote_name: None,
         force: runner_options.force,
         interactive: runner_options.interactive,
         dry_run: runner_options.dry_run,
         clean_orphans: cleanup, // Pass through cleanup flag
+        merge: runner_options.merge,
+        config: None,
     }
 }
 
 /// Create a deploy use case for the given targets (local destinations)
-pub fn create_use_case_for_targets(targets: &[calvin::Target]) -> ConcreteDeployUseCase {
-    let adapters = create_adapters_for_legacy_targets(targets);
+pub fn create_use_case_for_targets(
+    targets: &[calvin::Target],
+    config: &calvin::config::Config,
+) -> ConcreteDeployUseCase {
+    let adapters = create_adapters_for_legacy_targets(targets, config);
     calvin::presentation::factory::create_deploy_use_case_with_adapters(adapters)
 }
 
 /// Run remote deployment using new engine
+///
+/// The remote's deploy state is read from and written to its own section of
+/// `calvin.lock` (keyed by `remote.name`).
 pub fn run_remote_deployment(
-    remote_spec: &str,
+    remote: &RemoteConfig,
     source: &std::path::Path,
     options: &UseCaseOptions,
     targets: &[calvin::Target],
 ) -> UseCaseResult {
-    let adapters = create_adapters_for_legacy_targets(targets);
-    let use_case = create_deploy_use_case_for_remote_with_adapters(
-        remote_spec,
-        source.to_path_buf(),
-        adapters,
-    );
-    use_case.execute(options)
+    let default_config = calvin::config::Config::default();
+    let config = options.config.as_ref().unwrap_or(&default_config);
+    let adapters = create_adapters_for_legacy_targets(targets, config);
+    let use_case =
+        create_deploy_use_case_for_remote_with_adapters(remote, source.to_path_buf(), adapters);
+    let options = options.clone().with_remote_name(&remote.name);
+    use_case.execute(&options)
+}
+
+/// Result of deploying to one remote
+#[derive(Debug, Clone)]
+pub struct RemoteDeployOutcome {
+    pub remote: RemoteConfig,
+    pub result: UseCaseResult,
+}
+
+/// Deploy to several remotes concurrently, one thread per host
+///
+/// Each host gets its own destination and SSH connections, so a host that fails only
+/// records errors in its own result. Hosts track their state in separate lockfile
+/// sections, and lockfile and registry updates are applied one host at a time.
+/// Outcomes are returned in `remotes` order.
+pub fn run_remote_deployments(
+    remotes: &[RemoteConfig],
+    source: &std::path::Path,
+    options: &UseCaseOptions,
+    targets: &[calvin::Target],
+) -> Vec<RemoteDeployOutcome> {
+    std::thread::scope(|scope| {
+        let handles: Vec<_> = remotes
+            .iter()
+            .map(|remote| {
+                scope.spawn(move || run_remote_deployment(remote, source, options, targets))
+            })
+            .collect();
+
+        remotes
+            .iter()
+            .zip(handles)
+            .map(|(remote, handle)| {
+                let result = handle.join().unwrap_or_else(|_| {
+                    let mut result = UseCaseResult::new();
+                    result
+                        .errors
+                        .push(format!("Deploy to '{}' panicked", remote.name));
+                    result
+                });
+                RemoteDeployOutcome {
+                    remote: remote.clone(),
+                    result,
+                }
+            })
+            .collect()
+    })
 }
 
 /// Convert legacy targets to domain targets and create adapters
 fn create_adapters_for_legacy_targets(
     targets: &[calvin::Target],
+    config: &calvin::config::Config,
 ) -> Vec<Box<dyn calvin::domain::ports::TargetAdapter>> {
     let domain_targets: Vec<DomainTarget> = targets
         .iter()
@@ -121,11 +183,15 @@ fn create_adapters_for_legacy_targets(
             calvin::Target::Antigravity => DomainTarget::Antigravity,
             calvin::Target::Codex => DomainTarget::Codex,
             calvin::Target::OpenCode => DomainTarget::OpenCode,
+            calvin::Target::GeminiCli => DomainTarget::GeminiCli,
+            calvin::Target::Windsurf => DomainTarget::Windsurf,
+            calvin::Target::Cline => DomainTarget::Cline,
+            calvin::Target::Custom(name) => DomainTarget::Custom(*name),
             calvin::Target::All => DomainTarget::All,
         })
         .collect();
 
-    create_adapters_for_targets(&domain_targets)
+    create_adapters_for_config(&domain_targets, config)
 }
 
 #[cfg(test)]
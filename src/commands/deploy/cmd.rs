@@ -10,9 +10,13 @@ use super::options::DeployOptions;
 use super::targets::DeployTarget;
 use crate::commands::project_root::discover_project_root;
 use crate::ui::context::UiContext;
+use crate::ui::json::emit_event;
+use crate::ui::json::events::{RemoteDeployCompleteEvent, RemoteDeployEvent};
 use crate::ui::primitives::icon::Icon;
 use crate::ui::primitives::text::display_with_tilde;
-use crate::ui::views::deploy::{render_deploy_header, render_deploy_summary};
+use crate::ui::views::deploy::{
+    render_deploy_header, render_deploy_summary, render_remote_deploy_summary,
+};
 use calvin::presentation::ColorWhen;
 
 /// Deploy command entry point
@@ -36,6 +40,7 @@ pub fn cmd_deploy(
     interactive: bool,
     dry_run: bool,
     cleanup: bool,
+    merge: bool,
     json: bool,
     verbose: u8,
     color: Option<ColorWhen>,
@@ -54,6 +59,7 @@ pub fn cmd_deploy(
         interactive,
         dry_run,
         cleanup,
+        merge,
         json,
         verbose,
         color,
@@ -78,6 +84,7 @@ pub fn cmd_deploy_with_explicit_target(
     interactive: bool,
     dry_run: bool,
     cleanup: bool,
+    merge: bool,
     json: bool,
     verbose: u8,
     color: Option<ColorWhen>,
@@ -118,6 +125,14 @@ pub fn cmd_deploy_with_explicit_target(
         DeployTarget::Project(project_root.clone())
     };
 
+    // Resolve `--remote` to the hosts to deploy to (named `[[remotes]]` or `host:path` specs)
+    let remotes = match &target {
+        DeployTarget::Remote(selector) => config
+            .resolve_remotes(selector)
+            .map_err(anyhow::Error::msg)?,
+        _ => Vec::new(),
+    };
+
     // Determine scope policy based on effective target
     let _scope_policy = if use_home {
         ScopePolicy::ForceUser
@@ -130,6 +145,7 @@ pub fn cmd_deploy_with_explicit_target(
     options.force = force;
     options.interactive = interactive;
     options.dry_run = dry_run;
+    options.merge = merge;
     options.json = json;
     options.verbose = verbose;
     options.no_animation = no_animation;
@@ -165,6 +181,18 @@ pub fn cmd_deploy_with_explicit_target(
         additional_layers.clear();
     }
     let use_additional_layers = !is_remote_target && !no_additional_layers;
+    if use_additional_layers {
+        // Git/tarball layers are fetched into the layer cache and pinned in calvin.lock
+        // (a dry run fetches them without pinning)
+        let remote_layers = calvin::application::RemoteLayerUseCase::default();
+        let lockfile_path = project_root.join("calvin.lock");
+        additional_layers = if dry_run {
+            remote_layers.resolve_without_pinning(&lockfile_path, &additional_layers)
+        } else {
+            remote_layers.resolve(&lockfile_path, &additional_layers)
+        }
+        .map_err(anyhow::Error::msg)?;
+    }
 
     let project_layer_path = if source.is_relative() {
         invocation_dir.join(source)
@@ -317,10 +345,12 @@ pub fn cmd_deploy_with_explicit_target(
                         let mut sorted_assets: Vec<_> = merge_result.assets.iter().collect();
                         sorted_assets.sort_by(|a, b| a.0.cmp(b.0));
                         for (id, merged) in sorted_assets {
-                            let override_note = if merged.overrides.is_some() {
-                                " (override)"
+                            let override_note = if merged.contributing_layers.len() > 1 {
+                                format!(" (merged: {})", merged.contributing_layers.join(" + "))
+                            } else if merged.overrides.is_some() {
+                                " (override)".to_string()
                             } else {
-                                ""
+                                String::new()
                             };
                             println!(
                                 "  • {:<20} ← {}:{}{}",
@@ -371,10 +401,17 @@ pub fn cmd_deploy_with_explicit_target(
                 if !merge_result.overrides.is_empty() {
                     println!("\nOverrides:");
                     for ov in &merge_result.overrides {
-                        println!(
-                            "  • {:<20} {} overrides {}",
-                            ov.asset_id, ov.by_layer, ov.from_layer
-                        );
+                        if ov.is_replacement() {
+                            println!(
+                                "  • {:<20} {} overrides {}",
+                                ov.asset_id, ov.by_layer, ov.from_layer
+                            );
+                        } else {
+                            println!(
+                                "  • {:<20} {} extends {} (merge: {})",
+                                ov.asset_id, ov.by_layer, ov.from_layer, ov.strategy
+                            );
+                        }
                     }
                 }
 
@@ -436,7 +473,7 @@ pub fn cmd_deploy_with_explicit_target(
             vec!["Auto".to_string()] // --yes mode: skip conflicts silently
         };
         let (target_display, remote_display) = match &target_for_bridge {
-            DeployTarget::Remote(r) => (None, Some(r.as_str())),
+            DeployTarget::Remote(_) => (None, Some(remotes_display(&remotes))),
             _ => (
                 target_for_bridge
                     .destination_display()
@@ -451,7 +488,7 @@ pub fn cmd_deploy_with_explicit_target(
                 action,
                 &source_for_display,
                 target_display.as_deref(),
-                remote_display,
+                remote_display.as_deref(),
                 &modes,
                 ui.color,
                 ui.unicode,
@@ -470,7 +507,7 @@ pub fn cmd_deploy_with_explicit_target(
 
     // Determine effective targets: CLI > config > default
     // This is resolved once and used for both adapters and options
-    let effective_targets = super::layer_config::resolve_effective_targets(
+    let (effective_targets, merged_config) = super::layer_config::resolve_effective_targets(
         &config,
         &options_for_bridge.targets,
         interactive,
@@ -488,34 +525,39 @@ pub fn cmd_deploy_with_explicit_target(
     )?;
 
     // Run deploy
-    let result = if is_remote_target {
-        // Remote: use new engine with SyncDestination abstraction
-        if let DeployTarget::Remote(remote_spec) = &target_for_bridge {
-            let use_case_options = super::bridge::convert_options(
-                &project_root,
-                &project_layer_path,
-                &target_for_bridge,
-                &options_for_bridge,
-                cleanup,
-                &effective_targets,
-                super::bridge::LayerInputs {
-                    use_project_layer: true,
-                    user_layer_path: None,
-                    use_user_layer: false,
-                    additional_layers: Vec::new(),
-                    use_additional_layers: false,
-                },
-            );
-            super::bridge::run_remote_deployment(
-                remote_spec,
-                &project_layer_path,
-                &use_case_options,
-                &effective_targets,
-            )
+    if is_remote_target {
+        // Remote: fan out to every selected host; each host reports its own result
+        let use_case_options = super::bridge::convert_options(
+            &project_root,
+            &project_layer_path,
+            &target_for_bridge,
+            &options_for_bridge,
+            cleanup,
+            &effective_targets,
+            super::bridge::LayerInputs {
+                use_project_layer: true,
+                user_layer_path: None,
+                use_user_layer: false,
+                additional_layers: Vec::new(),
+                use_additional_layers: false,
+            },
+        )
+        .with_config(merged_config);
+        let outcomes = super::bridge::run_remote_deployments(
+            &remotes,
+            &project_layer_path,
+            &use_case_options,
+            &effective_targets,
+        );
+        let action = if dry_run {
+            "Deploy (dry run)"
         } else {
-            unreachable!("is_remote_target check failed")
-        }
-    } else if json {
+            "Deploy"
+        };
+        return finish_remote_deploy(&outcomes, action, effective_targets.len().max(1), json, &ui);
+    }
+
+    let result = if json {
         // JSON mode: use new engine with JsonEventSink
         use calvin::infrastructure::JsonEventSink;
         use std::sync::Arc;
@@ -534,8 +576,10 @@ pub fn cmd_deploy_with_explicit_target(
                 additional_layers: additional_layers.clone(),
                 use_additional_layers,
             },
-        );
-        let use_case = super::bridge::create_use_case_for_targets(&effective_targets);
+        )
+        .with_config(merged_config.clone());
+        let use_case =
+            super::bridge::create_use_case_for_targets(&effective_targets, &merged_config);
         let json_sink = Arc::new(JsonEventSink::stdout());
         use_case.execute_with_events(&use_case_options, json_sink)
     } else {
@@ -554,8 +598,10 @@ pub fn cmd_deploy_with_explicit_target(
                 additional_layers: additional_layers.clone(),
                 use_additional_layers,
             },
-        );
-        let use_case = super::bridge::create_use_case_for_targets(&effective_targets);
+        )
+        .with_config(merged_config.clone());
+        let use_case =
+            super::bridge::create_use_case_for_targets(&effective_targets, &merged_config);
         use_case.execute(&use_case_options)
     };
 
@@ -627,3 +673,117 @@ pub fn cmd_deploy_with_explicit_target(
 
     Ok(())
 }
+
+/// Header label for the selected remotes
+fn remotes_display(remotes: &[calvin::config::RemoteConfig]) -> String {
+    match remotes {
+        [remote] => remote.destination(),
+        _ => remotes
+            .iter()
+            .map(|r| r.name.as_str())
+            .collect::<Vec<_>>()
+            .join(", "),
+    }
+}
+
+/// Report per-host results of a remote deploy; fails if any host failed
+fn finish_remote_deploy(
+    outcomes: &[super::bridge::RemoteDeployOutcome],
+    action: &str,
+    target_count: usize,
+    json: bool,
+    ui: &UiContext,
+) -> Result<()> {
+    let failed: Vec<_> = outcomes.iter().filter(|o| !o.result.is_success()).collect();
+
+    if json {
+        for outcome in outcomes {
+            emit_event(&RemoteDeployEvent::new(
+                &outcome.remote.name,
+                outcome.remote.destination(),
+                &outcome.result,
+            ))?;
+        }
+        emit_event(&RemoteDeployCompleteEvent::new(
+            outcomes.len(),
+            failed.len(),
+        ))?;
+    } else {
+        let asset_count = outcomes
+            .iter()
+            .map(|o| o.result.asset_count)
+            .max()
+            .unwrap_or(0);
+        if let [outcome] = outcomes {
+            print!(
+                "{}",
+                render_deploy_summary(
+                    action,
+                    asset_count,
+                    target_count,
+                    &outcome.result,
+                    ui.color,
+                    ui.unicode,
+                )
+            );
+        } else {
+            let destinations: Vec<String> =
+                outcomes.iter().map(|o| o.remote.destination()).collect();
+            let hosts: Vec<_> = outcomes
+                .iter()
+                .zip(&destinations)
+                .map(|(o, d)| (o.remote.name.as_str(), d.as_str(), &o.result))
+                .collect();
+            print!(
+                "{}",
+                render_remote_deploy_summary(
+                    action,
+                    asset_count,
+                    target_count,
+                    &hosts,
+                    ui.color,
+                    ui.unicode,
+                )
+            );
+        }
+
+        // Compile warnings repeat on every host; show each once.
+        let mut warnings: Vec<&String> = Vec::new();
+        for warning in outcomes.iter().flat_map(|o| &o.result.warnings) {
+            if !warnings.contains(&warning) {
+                warnings.push(warning);
+            }
+        }
+        for warning in warnings {
+            eprintln!("Warning: {}", warning);
+        }
+    }
+
+    match (outcomes, failed.as_slice()) {
+        (_, []) => Ok(()),
+        ([outcome], _) => {
+            let mut message = format!(
+                "Deploy failed with {} error(s):",
+                outcome.result.errors.len()
+            );
+            for err in &outcome.result.errors {
+                message.push_str("\n- ");
+                message.push_str(err);
+            }
+            anyhow::bail!(message)
+        }
+        (_, failed_hosts) => {
+            let mut message = format!(
+                "Deploy failed on {} of {} remotes:",
+                failed_hosts.len(),
+                outcomes.len()
+            );
+            for outcome in failed_hosts {
+                for err in &outcome.result.errors {
+                    message.push_str(&format!("\n- {}: {}", outcome.remote.name, err));
+                }
+            }
+            anyhow::bail!(message)
+        }
+    }
+}
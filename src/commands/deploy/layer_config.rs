@@ -1,22 +1,17 @@
 use anyhow::Result;
 use calvin::Target;
 
+/// Resolve the effective deploy targets and the layer-merged configuration.
+///
+/// The merged configuration is always returned (it drives the security baseline), even
+/// when targets come from the CLI or `CALVIN_TARGETS`.
 pub(crate) fn resolve_effective_targets(
     config: &calvin::config::Config,
     explicit_targets: &[Target],
     interactive: bool,
     json: bool,
     layer_inputs: calvin::config::PromptpackLayerInputs,
-) -> Result<Vec<Target>> {
-    if !explicit_targets.is_empty() {
-        return Ok(normalize_targets(explicit_targets.to_vec()));
-    }
-
-    // Env var takes precedence over all config files, and should not prompt.
-    if std::env::var("CALVIN_TARGETS").is_ok() {
-        return Ok(normalize_targets(config.enabled_targets()));
-    }
-
+) -> Result<(Vec<Target>, calvin::config::Config)> {
     let (merged_config, warnings) =
         calvin::config::merge_promptpack_layer_configs(config, layer_inputs)?;
     for warning in warnings {
@@ -32,26 +27,47 @@ pub(crate) fn resolve_effective_targets(
         );
     }
 
-    let base_targets = normalize_targets(merged_config.enabled_targets());
+    if !explicit_targets.is_empty() {
+        // `--targets` is parsed before config is loaded; custom names are checked here
+        let declared = merged_config.custom_target_names();
+        for target in explicit_targets {
+            target
+                .check_declared(&declared)
+                .map_err(|e| anyhow::anyhow!("{}", e))?;
+        }
+        let targets = normalize_targets(explicit_targets.to_vec(), &merged_config);
+        return Ok((targets, merged_config));
+    }
+
+    // Env var takes precedence over all config files, and should not prompt.
+    if std::env::var("CALVIN_TARGETS").is_ok() {
+        return Ok((
+            normalize_targets(config.enabled_targets(), &merged_config),
+            merged_config,
+        ));
+    }
+
+    let base_targets = normalize_targets(merged_config.enabled_targets(), &merged_config);
 
     if !interactive {
-        return Ok(base_targets);
+        return Ok((base_targets, merged_config));
     }
 
     // Ensure the interactive defaults are always concrete targets (no `all` meta-target).
     let mut config_for_prompt = merged_config.clone();
     config_for_prompt.targets.enabled = Some(base_targets);
 
-    crate::ui::menu::select_targets_interactive(&config_for_prompt, json)
-        .ok_or_else(|| anyhow::anyhow!("Aborted"))
+    let targets = crate::ui::menu::select_targets_interactive(&config_for_prompt, json)
+        .ok_or_else(|| anyhow::anyhow!("Aborted"))?;
+    Ok((targets, merged_config))
 }
 
-fn normalize_targets(targets: Vec<Target>) -> Vec<Target> {
+fn normalize_targets(targets: Vec<Target>, config: &calvin::config::Config) -> Vec<Target> {
     if targets.is_empty() {
         return targets;
     }
     if targets.contains(&Target::All) {
-        return Target::ALL_CONCRETE.to_vec();
+        return config.all_targets();
     }
     targets
 }
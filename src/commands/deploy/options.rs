@@ -11,6 +11,8 @@ pub struct DeployOptions {
     pub interactive: bool,
     /// Dry run - don't write files
     pub dry_run: bool,
+    /// Structurally merge shared config files instead of replacing them
+    pub merge: bool,
     /// JSON output mode
     pub json: bool,
     /// Verbosity level
@@ -27,6 +29,7 @@ impl DeployOptions {
             force: false,
             interactive: true,
             dry_run: false,
+            merge: false,
             json: false,
             verbose: 0,
             no_animation: false,
@@ -97,6 +97,7 @@ pub fn interactive_global_layers_only(
             true,
             false,
             false,
+            false, // merge
             false,
             verbose,
             color,
@@ -115,6 +116,7 @@ pub fn interactive_global_layers_only(
             true,
             false,
             false,
+            false, // merge
             false,
             verbose,
             color,
@@ -189,6 +191,7 @@ pub fn interactive_existing_project(
             true,
             false,
             false, // cleanup - interactive mode handles it separately
+            false, // merge
             false,
             verbose,
             color,
@@ -207,6 +210,7 @@ pub fn interactive_existing_project(
             true,
             false,
             false, // cleanup
+            false, // merge
             false,
             verbose,
             color,
@@ -229,6 +233,7 @@ pub fn interactive_existing_project(
                 true,
                 false,
                 false, // cleanup
+                false, // merge
                 false,
                 verbose,
                 color,
@@ -240,6 +240,10 @@ fn target_kebab(target: calvin::Target) -> &'static str {
         calvin::Target::Antigravity => "antigravity",
         calvin::Target::Codex => "codex",
         calvin::Target::OpenCode => "opencode",
+        calvin::Target::GeminiCli => "gemini-cli",
+        calvin::Target::Windsurf => "windsurf",
+        calvin::Target::Cline => "cline",
+        calvin::Target::Custom(name) => name.as_str(),
         calvin::Target::All => "all",
     }
 }
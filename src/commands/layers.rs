@@ -2,10 +2,11 @@
 
 use anyhow::Result;
 
+use calvin::application::RemoteLayerUseCase;
 use calvin::presentation::ColorWhen;
 
 use crate::ui::context::UiContext;
-use crate::ui::views::layers::LayersView;
+use crate::ui::views::layers::{LayersUpdateView, LayersView};
 
 pub fn cmd_layers(
     json: bool,
@@ -14,9 +15,18 @@ pub fn cmd_layers(
     no_animation: bool,
 ) -> Result<()> {
     let project_root = std::env::current_dir()?;
-    let config = calvin::config::Config::load_or_default(Some(&project_root));
+    let mut config = calvin::config::Config::load_or_default(Some(&project_root));
     let ui = UiContext::new(json, verbose, color, no_animation, &config);
 
+    if !config.sources.ignore_additional_layers {
+        config.sources.additional_layers = RemoteLayerUseCase::default()
+            .resolve(
+                &project_root.join("calvin.lock"),
+                &config.sources.additional_layers,
+            )
+            .map_err(anyhow::Error::msg)?;
+    }
+
     let use_case = calvin::application::layers::LayerQueryUseCase::default();
     let result = use_case.query(&project_root, &config)?;
 
@@ -40,3 +50,52 @@ pub fn cmd_layers(
     );
     Ok(())
 }
+
+/// `calvin layers update`: re-resolve git/tarball layers and re-pin them in calvin.lock
+pub fn cmd_layers_update(
+    json: bool,
+    verbose: u8,
+    color: Option<ColorWhen>,
+    no_animation: bool,
+) -> Result<()> {
+    let project_root = std::env::current_dir()?;
+    let config = calvin::config::Config::load_or_default(Some(&project_root));
+    let ui = UiContext::new(json, verbose, color, no_animation, &config);
+
+    let layers = if config.sources.ignore_additional_layers {
+        Vec::new()
+    } else {
+        config.sources.additional_layers.clone()
+    };
+    let updates = RemoteLayerUseCase::default()
+        .update(&project_root.join("calvin.lock"), &layers)
+        .map_err(anyhow::Error::msg)?;
+
+    if json {
+        let layers: Vec<_> = updates
+            .iter()
+            .map(|u| {
+                serde_json::json!({
+                    "source": u.source,
+                    "commit": u.current.commit(),
+                    "hash": u.current.hash(),
+                    "previous_commit": u.previous.as_ref().and_then(|p| p.commit()),
+                    "changed": u.is_changed(),
+                })
+            })
+            .collect();
+        crate::ui::json::emit(serde_json::json!({
+            "event": "data",
+            "command": "layers update",
+            "layers": layers,
+        }))?;
+        return Ok(());
+    }
+
+    let view = LayersUpdateView::new(&updates);
+    print!(
+        "{}",
+        view.render(ui.caps.supports_color, ui.caps.supports_unicode)
+    );
+    Ok(())
+}
@@ -28,7 +28,16 @@ pub fn cmd_watch(
     };
 
     // Load base config (user config + project config, if present).
-    let base_config = calvin::config::Config::load_or_default(Some(&project_root));
+    let mut base_config = calvin::config::Config::load_or_default(Some(&project_root));
+    if !base_config.sources.ignore_additional_layers {
+        // Git/tarball layers are fetched into the layer cache and pinned in calvin.lock
+        base_config.sources.additional_layers = calvin::application::RemoteLayerUseCase::default()
+            .resolve(
+                &project_root.join("calvin.lock"),
+                &base_config.sources.additional_layers,
+            )
+            .map_err(anyhow::Error::msg)?;
+    }
 
     // Merge config across resolved promptpack layers (user/custom/project).
     let additional_layers: Vec<std::path::PathBuf> = if base_config.sources.ignore_additional_layers
@@ -70,7 +79,7 @@ pub fn cmd_watch(
 
     let mut targets = config.enabled_targets();
     if targets.contains(&calvin::Target::All) {
-        targets = calvin::Target::ALL_CONCRETE.to_vec();
+        targets = config.all_targets();
     }
 
     let ui = crate::ui::context::UiContext::new(json, 0, color, no_animation, &config);
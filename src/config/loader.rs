@@ -183,13 +183,17 @@ pub fn with_env_overrides(mut config: Config) -> Config {
     if let Ok(targets) = std::env::var("CALVIN_TARGETS") {
         let mut parsed: Vec<Target> = Vec::new();
         let mut had_invalid = false;
+        let declared = config.custom_target_names();
 
         for s in targets.split(',') {
             let trimmed = s.trim();
             if trimmed.is_empty() {
                 continue;
             }
-            match Target::from_str_with_suggestion(trimmed) {
+            let target = trimmed
+                .parse::<Target>()
+                .and_then(|target| target.check_declared(&declared).map(|()| target));
+            match target {
                 Ok(target) if target != Target::All => parsed.push(target),
                 Ok(_) => {} // Ignore 'all' - it's meta
                 Err(e) => {
@@ -407,6 +411,7 @@ fn suggest_key(unknown: &str) -> Option<String> {
         "additional_allowlist",
         "targets",
         "enabled",
+        "policies",
         "sync",
         "atomic_writes",
         "respect_lockfile",
@@ -418,6 +423,8 @@ fn suggest_key(unknown: &str) -> Option<String> {
         "servers",
         "command",
         "args",
+        "vars",
+        "remotes",
     ];
 
     let mut best: Option<(&str, usize)> = None;
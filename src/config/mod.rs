@@ -22,9 +22,13 @@ pub use crate::domain::value_objects::ConfigWarning;
 pub use crate::domain::value_objects::DeployTarget;
 
 pub use types::{
-    AnimationMode, ColorMode, Config, DenyConfig, DeployConfig, FormatConfig, McpConfig,
-    McpServerConfig, OutputConfig, SecurityConfig, SecurityMcpConfig, SecurityMode, SourcesConfig,
-    SyncConfig, TargetsConfig, Verbosity,
+    AnimationMode, AntigravityTargetConfig, ClaudeCodeTargetConfig, ClaudePolicyMode, ClineFlavor,
+    ClineTargetConfig, ColorMode, Config, CustomAssetKind, CustomTargetAggregate,
+    CustomTargetConfig, CustomTargetPaths, DenyConfig, DeployConfig, FormatConfig, McpConfig,
+    McpServerConfig, OutputConfig, PathTemplate, RemoteConfig, RemoteTransfer, ScopedPathTemplates,
+    SecurityConfig, SecurityMcpConfig, SecurityMode, SkillFallbackMode, SourcesConfig, SyncConfig,
+    TargetsConfig, VSCodePolicyMode, VSCodeTargetConfig, Verbosity, ALL_REMOTES,
+    CUSTOM_FRONTMATTER_FIELDS,
 };
 
 pub use types::default_user_layer_path;
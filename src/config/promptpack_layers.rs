@@ -101,6 +101,12 @@ pub fn merge_promptpack_layer_configs(
         if has_non_empty_table(table, "deploy") {
             merged.deploy = parsed.deploy;
         }
+        if table.contains_key("remotes") {
+            merged.remotes = parsed.remotes;
+        }
+        // Variables merge per key so a project can add to the user layer's variables
+        // without restating them.
+        merged.vars.extend(parsed.vars);
     }
 
     if env_overrides_present() {
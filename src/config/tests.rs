@@ -63,7 +63,7 @@ fn test_enabled_targets_default() {
     let config = Config::default();
     let targets = config.enabled_targets();
 
-    assert_eq!(targets.len(), 6);
+    assert_eq!(targets.len(), 9);
 }
 
 #[test]
@@ -238,8 +238,8 @@ fn test_enabled_targets_field_missing_means_all() {
     // Missing field should mean "all targets"
     assert_eq!(
         targets.len(),
-        6,
-        "missing enabled field should return all 6 targets"
+        9,
+        "missing enabled field should return all 9 targets"
     );
 }
 
@@ -255,8 +255,8 @@ version = "1.0"
     let targets = config.enabled_targets();
     assert_eq!(
         targets.len(),
-        6,
-        "missing [targets] section should return all 6 targets"
+        9,
+        "missing [targets] section should return all 9 targets"
     );
 }
 
@@ -496,3 +496,92 @@ fn test_verbosity_from_str_invalid_values() {
     assert_eq!(Verbosity::parse_str(""), None);
     assert_eq!(Verbosity::parse_str("quite"), None); // typo
 }
+
+// === Named remotes ([[remotes]]) ===
+
+const REMOTES_TOML: &str = r#"
+[[remotes]]
+name = "web-1"
+host = "deploy@10.0.0.1"
+path = "~/app"
+port = 2222
+identity_file = "~/.ssh/fleet"
+transfer = "scp"
+
+[[remotes]]
+name = "web-2"
+host = "web-2"
+
+[[remotes]]
+name = "web-3"
+host = "web-3"
+transfer = "session"
+"#;
+
+#[test]
+fn test_config_parse_remotes() {
+    let config: Config = toml::from_str(REMOTES_TOML).unwrap();
+
+    assert_eq!(config.remotes.len(), 3);
+    let first = &config.remotes[0];
+    assert_eq!(first.port, Some(2222));
+    assert_eq!(
+        first.identity_file,
+        Some(std::path::PathBuf::from("~/.ssh/fleet"))
+    );
+    assert_eq!(first.transfer, RemoteTransfer::Scp);
+    assert_eq!(first.destination(), "deploy@10.0.0.1:~/app");
+
+    let second = &config.remotes[1];
+    assert_eq!(second.path, ".");
+    assert_eq!(second.port, None);
+    assert_eq!(second.transfer, RemoteTransfer::Auto);
+    assert_eq!(config.remotes[2].transfer, RemoteTransfer::Session);
+}
+
+#[test]
+fn test_config_rejects_invalid_remotes() {
+    for (toml, message) in [
+        (
+            "[[remotes]]\nname = \"a\"\nhost = \"h\"\n[[remotes]]\nname = \"a\"\nhost = \"h\"\n",
+            "declared twice",
+        ),
+        ("[[remotes]]\nname = \"all\"\nhost = \"h\"\n", "reserved"),
+        (
+            "[[remotes]]\nname = \"a,b\"\nhost = \"h\"\n",
+            "without commas",
+        ),
+        ("[[remotes]]\nname = \"a\"\nhost = \"\"\n", "empty host"),
+    ] {
+        let err = toml::from_str::<Config>(toml).unwrap_err().to_string();
+        assert!(err.contains(message), "{}", err);
+    }
+}
+
+#[test]
+fn test_resolve_remotes_all_and_names() {
+    let config: Config = toml::from_str(REMOTES_TOML).unwrap();
+
+    let all = config.resolve_remotes("all").unwrap();
+    assert_eq!(all, config.remotes);
+
+    let named = config.resolve_remotes("web-2, web-1,web-2").unwrap();
+    let names: Vec<_> = named.iter().map(|r| r.name.as_str()).collect();
+    assert_eq!(names, ["web-2", "web-1"]);
+}
+
+#[test]
+fn test_resolve_remotes_falls_back_to_ad_hoc_spec() {
+    let config: Config = toml::from_str(REMOTES_TOML).unwrap();
+
+    let remotes = config.resolve_remotes("web-1,user@other:/srv").unwrap();
+    assert_eq!(remotes[1].host, "user@other");
+    assert_eq!(remotes[1].path, "/srv");
+    assert_eq!(remotes[1].name, "user@other:/srv");
+}
+
+#[test]
+fn test_resolve_remotes_all_requires_configured_remotes() {
+    let err = Config::default().resolve_remotes("all").unwrap_err();
+    assert!(err.contains("[[remotes]]"), "{}", err);
+}
@@ -1,13 +1,14 @@
 //! Configuration type definitions
 
-use std::collections::HashMap;
+use std::collections::{BTreeMap, HashMap};
 use std::path::Path;
 use std::path::PathBuf;
 
 use serde::{Deserialize, Serialize};
 
+use crate::domain::entities::McpServer;
 use crate::domain::ports::DomainConfig;
-use crate::domain::value_objects::{ConfigWarning, Target};
+use crate::domain::value_objects::{ConfigWarning, CustomTargetName, Target};
 use crate::error::CalvinResult;
 
 use super::loader;
@@ -115,6 +116,26 @@ pub struct TargetsConfig {
     /// Enabled targets. None = all targets, Some([]) = no targets, Some([...]) = specified targets
     #[serde(default)]
     pub enabled: Option<Vec<Target>>,
+
+    /// Claude Code settings (`[targets.claude-code]`)
+    #[serde(default, rename = "claude-code")]
+    pub claude_code: ClaudeCodeTargetConfig,
+
+    /// Cline settings (`[targets.cline]`)
+    #[serde(default)]
+    pub cline: ClineTargetConfig,
+
+    /// VS Code settings (`[targets.vscode]`)
+    #[serde(default)]
+    pub vscode: VSCodeTargetConfig,
+
+    /// Antigravity settings (`[targets.antigravity]`)
+    #[serde(default)]
+    pub antigravity: AntigravityTargetConfig,
+
+    /// Declarative targets (`[[targets.custom]]`), each enabled by its own name
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub custom: Vec<CustomTargetConfig>,
 }
 
 // Custom deserialize to distinguish between missing field and empty list
@@ -126,15 +147,344 @@ impl<'de> Deserialize<'de> for TargetsConfig {
         #[derive(Deserialize)]
         struct TargetsConfigHelper {
             enabled: Option<Vec<Target>>,
+            #[serde(default, rename = "claude-code")]
+            claude_code: ClaudeCodeTargetConfig,
+            #[serde(default)]
+            cline: ClineTargetConfig,
+            #[serde(default, alias = "vs-code")]
+            vscode: VSCodeTargetConfig,
+            #[serde(default)]
+            antigravity: AntigravityTargetConfig,
+            #[serde(default)]
+            custom: Vec<CustomTargetConfig>,
         }
 
         let helper = TargetsConfigHelper::deserialize(deserializer)?;
+        validate_custom_targets(&helper.custom).map_err(serde::de::Error::custom)?;
+        let declared: Vec<&str> = helper.custom.iter().map(|t| t.name.as_str()).collect();
+        for target in helper.enabled.iter().flatten() {
+            target
+                .check_declared(&declared)
+                .map_err(serde::de::Error::custom)?;
+        }
         Ok(TargetsConfig {
             enabled: helper.enabled,
+            claude_code: helper.claude_code,
+            cline: helper.cline,
+            vscode: helper.vscode,
+            antigravity: helper.antigravity,
+            custom: helper.custom,
         })
     }
 }
 
+/// Claude Code target configuration (`[targets.claude-code]`)
+#[derive(Debug, Clone, Serialize, Deserialize, Default)]
+pub struct ClaudeCodeTargetConfig {
+    /// Where policies are compiled to
+    #[serde(default)]
+    pub policies: ClaudePolicyMode,
+}
+
+/// Output used for Claude Code policies
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize, Default)]
+#[serde(rename_all = "lowercase")]
+pub enum ClaudePolicyMode {
+    /// Slash commands in `.claude/commands/`
+    #[default]
+    Commands,
+    /// A managed section of `CLAUDE.md` (`~/.claude/CLAUDE.md` for user scope)
+    Memory,
+    /// Rule files in `.claude/rules/`, path-scoped by `apply`
+    Rules,
+}
+
+/// Cline target configuration (`[targets.cline]`)
+#[derive(Debug, Clone, Serialize, Deserialize, Default)]
+pub struct ClineTargetConfig {
+    /// Which Cline-family extension the output is for
+    #[serde(default)]
+    pub flavor: ClineFlavor,
+}
+
+/// Cline-family extension targeted by the `cline` target
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize, Default)]
+#[serde(rename_all = "lowercase")]
+pub enum ClineFlavor {
+    /// Cline: agents become workflows
+    #[default]
+    Cline,
+    /// Roo Code: agents become custom modes in `.roomodes`
+    Roo,
+}
+
+/// VS Code target configuration (`[targets.vscode]`)
+#[derive(Debug, Clone, Serialize, Deserialize, Default)]
+pub struct VSCodeTargetConfig {
+    /// Where always-active project policies are compiled to
+    #[serde(default)]
+    pub policies: VSCodePolicyMode,
+
+    /// Whether skills fall back to instruction files
+    #[serde(default)]
+    pub skills: SkillFallbackMode,
+}
+
+/// Output used for VS Code project policies that are not path-scoped
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize, Default)]
+#[serde(rename_all = "lowercase")]
+pub enum VSCodePolicyMode {
+    /// One `.github/instructions/<id>.instructions.md` file per policy
+    #[default]
+    Instructions,
+    /// A managed section of `.github/copilot-instructions.md`
+    Merged,
+}
+
+/// Antigravity target configuration (`[targets.antigravity]`)
+#[derive(Debug, Clone, Serialize, Deserialize, Default)]
+pub struct AntigravityTargetConfig {
+    /// Whether skills fall back to workflows
+    #[serde(default)]
+    pub skills: SkillFallbackMode,
+}
+
+/// Handling of skills on a target without native `SKILL.md` support
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize, Default)]
+#[serde(rename_all = "lowercase")]
+pub enum SkillFallbackMode {
+    /// Skills are not compiled for the target
+    #[default]
+    Skip,
+    /// The skill body and an index of its files become the target's instruction or
+    /// workflow format; supplementals are copied next to it
+    Fallback,
+}
+
+/// A declarative target (`[[targets.custom]]`)
+///
+/// Describes where a tool Calvin has no adapter for reads its files. The target is
+/// enabled and selected in asset `targets:` by its `name`.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct CustomTargetConfig {
+    /// Target name (kebab-case, must not shadow a built-in target)
+    pub name: String,
+
+    /// Output path template per asset kind (`{id}`, `{scope_root}`)
+    #[serde(default)]
+    pub paths: CustomTargetPaths,
+
+    /// Output frontmatter: output key → asset field (`id`, `name`, `description`,
+    /// `kind`, `scope`, `apply`, `model`, `tools`)
+    #[serde(default)]
+    pub frontmatter: BTreeMap<String, String>,
+
+    /// Single file collecting assets of some kinds instead of one file per asset
+    #[serde(default)]
+    pub aggregate: Option<CustomTargetAggregate>,
+}
+
+/// Per-kind output path templates of a custom target
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize, Default)]
+pub struct CustomTargetPaths {
+    pub policy: Option<PathTemplate>,
+    pub action: Option<PathTemplate>,
+    pub agent: Option<PathTemplate>,
+}
+
+/// Output path template, shared by both scopes or given per scope
+///
+/// `{id}` expands to the asset id and `{scope_root}` to `.` (project) or `~` (user).
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(untagged)]
+pub enum PathTemplate {
+    Shared(String),
+    PerScope(ScopedPathTemplates),
+}
+
+/// Per-scope templates (`{ project = "...", user = "..." }`)
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(deny_unknown_fields)]
+pub struct ScopedPathTemplates {
+    pub project: Option<String>,
+    pub user: Option<String>,
+}
+
+impl PathTemplate {
+    /// Template for one scope (`None` if the kind is not emitted at that scope)
+    pub fn for_scope(&self, is_user_scope: bool) -> Option<&str> {
+        match self {
+            PathTemplate::Shared(template) => Some(template),
+            PathTemplate::PerScope(scoped) => {
+                if is_user_scope {
+                    scoped.user.as_deref()
+                } else {
+                    scoped.project.as_deref()
+                }
+            }
+        }
+    }
+
+    fn templates(&self) -> Vec<&str> {
+        match self {
+            PathTemplate::Shared(template) => vec![template],
+            PathTemplate::PerScope(scoped) => scoped
+                .project
+                .iter()
+                .chain(&scoped.user)
+                .map(String::as_str)
+                .collect(),
+        }
+    }
+}
+
+/// Aggregate file of a custom target (e.g. Aider's `CONVENTIONS.md`)
+///
+/// Assets of the listed kinds are written as sections of a managed region named
+/// after the target, so hand-written text in the file is preserved.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct CustomTargetAggregate {
+    /// Path template (`{scope_root}` only)
+    pub path: PathTemplate,
+    /// Asset kinds collected into the file
+    #[serde(default = "default_aggregate_kinds")]
+    pub kinds: Vec<CustomAssetKind>,
+}
+
+fn default_aggregate_kinds() -> Vec<CustomAssetKind> {
+    vec![CustomAssetKind::Policy]
+}
+
+/// Asset kinds a custom target can emit
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "lowercase")]
+pub enum CustomAssetKind {
+    Policy,
+    Action,
+    Agent,
+}
+
+/// Asset fields usable as `[targets.custom.frontmatter]` values
+pub const CUSTOM_FRONTMATTER_FIELDS: &[&str] = &[
+    "id",
+    "name",
+    "description",
+    "kind",
+    "scope",
+    "apply",
+    "model",
+    "tools",
+];
+
+/// Whether a custom path template stays inside its scope root
+///
+/// Templates are relative paths, optionally prefixed with `{scope_root}/`; absolute
+/// paths, `~`, drive prefixes and `..` components would write outside the project.
+fn is_contained_template(template: &str) -> bool {
+    let relative = template.strip_prefix("{scope_root}/").unwrap_or(template);
+    !relative.is_empty()
+        && !relative.starts_with(['/', '\\', '~'])
+        && !relative.contains("{scope_root}")
+        && relative.as_bytes().get(1) != Some(&b':')
+        && relative
+            .split(['/', '\\'])
+            .all(|component| component != "..")
+}
+
+fn validate_custom_targets(targets: &[CustomTargetConfig]) -> Result<(), String> {
+    let mut seen = Vec::new();
+    for target in targets {
+        let name = target.name.as_str();
+        if !CustomTargetName::is_valid(name) {
+            return Err(format!(
+                "custom target name '{}' must be kebab-case (a-z, 0-9, -)",
+                name
+            ));
+        }
+        if Target::VALID_NAMES.contains(&name) {
+            return Err(format!(
+                "custom target '{}' shadows a built-in target",
+                name
+            ));
+        }
+        if seen.contains(&name) {
+            return Err(format!("custom target '{}' is declared twice", name));
+        }
+        seen.push(name);
+
+        let paths = [
+            &target.paths.policy,
+            &target.paths.action,
+            &target.paths.agent,
+        ];
+        let declared = paths
+            .iter()
+            .copied()
+            .flatten()
+            .chain(target.aggregate.as_ref().map(|a| &a.path));
+        if declared.clone().any(|t| t.templates().is_empty()) {
+            return Err(format!(
+                "custom target '{}': per-scope paths need `project` or `user`",
+                name
+            ));
+        }
+        if let Some(template) = declared
+            .clone()
+            .flat_map(PathTemplate::templates)
+            .find(|t| !is_contained_template(t))
+        {
+            return Err(format!(
+                "custom target '{}': path '{}' must be relative (or start with {{scope_root}}/) and cannot contain '..'",
+                name, template
+            ));
+        }
+        for template in paths
+            .into_iter()
+            .flatten()
+            .flat_map(PathTemplate::templates)
+        {
+            if !template.contains("{id}") {
+                return Err(format!(
+                    "custom target '{}': path '{}' must contain {{id}}",
+                    name, template
+                ));
+            }
+        }
+        if let Some(aggregate) = &target.aggregate {
+            if aggregate
+                .path
+                .templates()
+                .iter()
+                .any(|t| t.contains("{id}"))
+            {
+                return Err(format!(
+                    "custom target '{}': aggregate path cannot contain {{id}}",
+                    name
+                ));
+            }
+        }
+        if paths.iter().all(|p| p.is_none()) && target.aggregate.is_none() {
+            return Err(format!(
+                "custom target '{}' declares no paths and no aggregate",
+                name
+            ));
+        }
+        if let Some(field) = target
+            .frontmatter
+            .values()
+            .find(|field| !CUSTOM_FRONTMATTER_FIELDS.contains(&field.as_str()))
+        {
+            return Err(format!(
+                "custom target '{}': unknown frontmatter field '{}' (valid: {})",
+                name,
+                field,
+                CUSTOM_FRONTMATTER_FIELDS.join(", ")
+            ));
+        }
+    }
+    Ok(())
+}
+
 /// Sync configuration
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct SyncConfig {
@@ -217,6 +567,106 @@ pub struct DeployConfig {
     pub target: DeployTarget,
 }
 
+/// A named remote for `calvin deploy --remote` (`[[remotes]]`)
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct RemoteConfig {
+    /// Name used to select the remote (`--remote web-1,web-2`)
+    pub name: String,
+    /// SSH host (`host`, `user@host`, or an alias from `~/.ssh/config`)
+    pub host: String,
+    /// Remote base path
+    #[serde(default = "default_remote_path")]
+    pub path: String,
+    /// SSH port (default: ssh's own default)
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub port: Option<u16>,
+    /// SSH private key (`ssh -i`)
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub identity_file: Option<PathBuf>,
+    /// Batch transfer strategy
+    #[serde(default)]
+    pub transfer: RemoteTransfer,
+}
+
+impl RemoteConfig {
+    /// Build an unnamed remote from a `host[:path]` spec
+    ///
+    /// The spec doubles as the name so it can be reported like a configured remote.
+    pub fn from_spec(spec: &str) -> Self {
+        let (host, path) = match spec.split_once(':') {
+            Some((host, path)) => (host.to_string(), path.to_string()),
+            None => (spec.to_string(), default_remote_path()),
+        };
+        Self {
+            name: spec.to_string(),
+            host,
+            path,
+            port: None,
+            identity_file: None,
+            transfer: RemoteTransfer::default(),
+        }
+    }
+
+    /// Destination shown in output (`host:path`)
+    pub fn destination(&self) -> String {
+        format!("{}:{}", self.host, self.path)
+    }
+}
+
+fn default_remote_path() -> String {
+    ".".to_string()
+}
+
+/// How files are copied to a remote in a batch
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize, Default)]
+#[serde(rename_all = "lowercase")]
+pub enum RemoteTransfer {
+    /// rsync when installed, otherwise scp
+    #[default]
+    Auto,
+    Rsync,
+    Scp,
+    /// Write files over the remote's shared SSH session (no rsync or scp needed)
+    Session,
+}
+
+/// Selector for every configured remote (`--remote all`)
+pub const ALL_REMOTES: &str = "all";
+
+fn deserialize_remotes<'de, D>(deserializer: D) -> Result<Vec<RemoteConfig>, D::Error>
+where
+    D: serde::Deserializer<'de>,
+{
+    let remotes = Vec::<RemoteConfig>::deserialize(deserializer)?;
+    validate_remotes(&remotes).map_err(serde::de::Error::custom)?;
+    Ok(remotes)
+}
+
+fn validate_remotes(remotes: &[RemoteConfig]) -> Result<(), String> {
+    let mut seen = Vec::new();
+    for remote in remotes {
+        let name = remote.name.as_str();
+        if name.is_empty() || name.contains(',') || name.contains(char::is_whitespace) {
+            return Err(format!(
+                "remote name '{}' must be non-empty without commas or spaces",
+                name
+            ));
+        }
+        if name == ALL_REMOTES {
+            return Err(format!("remote name '{}' is reserved", ALL_REMOTES));
+        }
+        if seen.contains(&name) {
+            return Err(format!("remote '{}' is declared twice", name));
+        }
+        seen.push(name);
+
+        if remote.host.is_empty() {
+            return Err(format!("remote '{}' has an empty host", name));
+        }
+    }
+    Ok(())
+}
+
 /// Output configuration
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct OutputConfig {
@@ -294,11 +744,31 @@ impl Verbosity {
 }
 
 /// MCP Server configuration
+///
+/// Used both for `[mcp.servers.<name>]` in config and for `.promptpack/mcp/<name>.toml`.
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct McpServerConfig {
     pub command: String,
     #[serde(default)]
     pub args: Vec<String>,
+    #[serde(default)]
+    pub env: BTreeMap<String, String>,
+    /// Restrict the server to specific targets (default: all targets)
+    #[serde(default)]
+    pub targets: Option<Vec<Target>>,
+}
+
+impl McpServerConfig {
+    /// Convert into a domain `McpServer` with the given name
+    pub fn into_server(self, name: impl Into<String>) -> McpServer {
+        let server = McpServer::new(name, self.command)
+            .with_args(self.args)
+            .with_env(self.env);
+        match self.targets {
+            Some(targets) => server.with_targets(targets),
+            None => server,
+        }
+    }
 }
 
 /// MCP configuration
@@ -334,6 +804,18 @@ pub struct Config {
 
     #[serde(default)]
     pub sources: SourcesConfig,
+
+    /// Named deploy remotes (`[[remotes]]`)
+    #[serde(
+        default,
+        deserialize_with = "deserialize_remotes",
+        skip_serializing_if = "Vec::is_empty"
+    )]
+    pub remotes: Vec<RemoteConfig>,
+
+    /// Template variables for asset bodies (`{{ name }}`)
+    #[serde(default, skip_serializing_if = "BTreeMap::is_empty")]
+    pub vars: BTreeMap<String, String>,
 }
 
 impl Config {
@@ -361,16 +843,88 @@ impl Config {
     /// Get enabled targets based on configuration.
     ///
     /// Semantics:
-    /// - `None` (field missing): All targets (default behavior)
+    /// - `None` (field missing): All built-in and declared custom targets (default behavior)
     /// - `Some([])` (empty list): No targets (explicitly disabled)
     /// - `Some([...])` (list with items): Only specified targets
     pub fn enabled_targets(&self) -> Vec<Target> {
         match &self.targets.enabled {
-            None => Target::ALL_CONCRETE.to_vec(),
+            None => self.all_targets(),
             Some(targets) => targets.clone(),
         }
     }
 
+    /// All built-in targets plus those declared in `[[targets.custom]]` (what `all` expands to)
+    pub fn all_targets(&self) -> Vec<Target> {
+        Target::ALL_CONCRETE
+            .into_iter()
+            .chain(self.custom_targets())
+            .collect()
+    }
+
+    /// Targets declared in `[[targets.custom]]`
+    pub fn custom_targets(&self) -> Vec<Target> {
+        self.targets
+            .custom
+            .iter()
+            .map(|target| Target::custom(&target.name))
+            .collect()
+    }
+
+    /// Names declared in `[[targets.custom]]` (for [`Target::check_declared`])
+    pub fn custom_target_names(&self) -> Vec<&str> {
+        self.targets
+            .custom
+            .iter()
+            .map(|target| target.name.as_str())
+            .collect()
+    }
+
+    /// Targets without native skills that compile them through a fallback
+    /// (`[targets.<id>] skills = "fallback"`)
+    pub fn skill_fallback_targets(&self) -> Vec<Target> {
+        [
+            (Target::VSCode, self.targets.vscode.skills),
+            (Target::Antigravity, self.targets.antigravity.skills),
+        ]
+        .into_iter()
+        .filter(|(_, mode)| *mode == SkillFallbackMode::Fallback)
+        .map(|(target, _)| target)
+        .collect()
+    }
+
+    /// Resolve a `--remote` value to the remotes to deploy to.
+    ///
+    /// - `all`: every `[[remotes]]` entry
+    /// - `a,b`: each item is a configured remote name or an ad-hoc `host[:path]` spec
+    pub fn resolve_remotes(&self, selector: &str) -> Result<Vec<RemoteConfig>, String> {
+        if selector.trim() == ALL_REMOTES {
+            if self.remotes.is_empty() {
+                return Err(
+                    "`--remote all` needs at least one [[remotes]] entry in config.toml"
+                        .to_string(),
+                );
+            }
+            return Ok(self.remotes.clone());
+        }
+
+        let mut resolved: Vec<RemoteConfig> = Vec::new();
+        for item in selector.split(',').map(str::trim).filter(|s| !s.is_empty()) {
+            let remote = self
+                .remotes
+                .iter()
+                .find(|r| r.name == item)
+                .cloned()
+                .unwrap_or_else(|| RemoteConfig::from_spec(item));
+            if !resolved.iter().any(|r| r.name == remote.name) {
+                resolved.push(remote);
+            }
+        }
+        if resolved.is_empty() {
+            return Err("`--remote` needs a remote name or `host:path`".to_string());
+        }
+        Ok(resolved)
+    }
+
     /// Save deploy target to config file
     ///
     /// Updates or creates the [deploy] section in config.toml with the target.
@@ -42,6 +42,11 @@ pub fn frontmatter_kind_url() -> String {
     format!("{}/api/frontmatter#kind", DOCS_BASE_URL)
 }
 
+/// Get the full URL for the frontmatter#templates section
+pub fn templates_url() -> String {
+    format!("{}/api/frontmatter#templates", DOCS_BASE_URL)
+}
+
 /// Get the full URL for the scope guide
 pub fn scope_guide_url() -> String {
     format!("{}/guides/scope-guide", DOCS_BASE_URL)
@@ -91,6 +96,13 @@ mod tests {
         assert!(url.starts_with(&frontmatter_url()));
     }
 
+    #[test]
+    fn test_templates_url() {
+        let url = templates_url();
+        assert!(url.contains("#templates"));
+        assert!(url.starts_with(&frontmatter_url()));
+    }
+
     #[test]
     fn test_scope_guide_url() {
         let url = scope_guide_url();
@@ -119,6 +131,7 @@ mod tests {
         let urls = vec![
             frontmatter_url(),
             frontmatter_kind_url(),
+            templates_url(),
             scope_guide_url(),
             configuration_url(),
         ];
@@ -3,9 +3,10 @@
 //! Assets are the "source code" of Calvin - markdown files with YAML frontmatter
 //! that define policies, actions, and agents.
 
-use crate::domain::value_objects::{Scope, Target};
+use crate::domain::value_objects::{Activation, HookSpec, MergeStrategy, Scope, Target};
+use std::borrow::Cow;
 use std::collections::HashMap;
-use std::path::PathBuf;
+use std::path::{Path, PathBuf};
 
 /// Kind of prompt asset
 #[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
@@ -19,6 +20,43 @@ pub enum AssetKind {
     Agent,
     /// Directory-based skills (SKILL.md + supplementals)
     Skill,
+    /// Lifecycle hooks (Claude Code settings `hooks`, optional script supplementals)
+    Hook,
+}
+
+/// Fields of an asset overridden for a single target (`overrides:` frontmatter)
+///
+/// `None` keeps the asset's own value. `extra_frontmatter` is merged over the
+/// asset's extra fields.
+#[derive(Debug, Clone, Default, PartialEq)]
+pub struct AssetOverride {
+    pub content: Option<String>,
+    pub description: Option<String>,
+    pub apply: Option<String>,
+    pub activation: Option<Activation>,
+    pub allowed_tools: Option<Vec<String>>,
+    pub agent_name: Option<String>,
+    pub agent_tools: Option<Vec<String>>,
+    pub agent_model: Option<String>,
+    pub agent_permission_mode: Option<String>,
+    pub agent_skills: Option<Vec<String>>,
+    pub opencode_mode: Option<String>,
+    pub temperature: Option<f32>,
+    pub opencode_model: Option<String>,
+    pub command_agent: Option<String>,
+    pub command_subtask: Option<bool>,
+    pub extra_frontmatter: HashMap<String, serde_yaml_ng::Value>,
+}
+
+/// Where a run of content lines comes from, for assets merged from several layers
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ContentOrigin {
+    /// First line of the run in the merged content (1-based)
+    pub line: usize,
+    /// Source file the run was read from
+    pub source_file: PathBuf,
+    /// Line of `source_file` where the run starts
+    pub source_line: usize,
 }
 
 /// A prompt asset from .promptpack/
@@ -38,10 +76,24 @@ pub struct Asset {
     scope: Scope,
     /// Target platforms
     targets: Vec<Target>,
+    /// Custom targets declared in config, which also receive un-targeted assets
+    custom_targets: Vec<Target>,
     /// Content body (after frontmatter)
     content: String,
+    /// Line of the source file where `content` starts (for error positions)
+    content_line: usize,
+    /// Origins of `content` lines when it was combined from several layers
+    ///
+    /// Empty when the content comes from `source_path` alone.
+    content_origins: Vec<ContentOrigin>,
+    /// How this asset combines with the same asset from a lower layer
+    merge: MergeStrategy,
+    /// Partial files included into `content` (`{{> … }}`), in include order
+    includes: Vec<PathBuf>,
     /// Optional apply glob pattern
     apply: Option<String>,
+    /// Explicit policy activation (from `activation` frontmatter)
+    activation: Option<Activation>,
 
     /// Skill supplemental files (path relative to skill root → content)
     ///
@@ -80,6 +132,11 @@ pub struct Asset {
     /// OpenCode command: run in isolated subtask session
     command_subtask: Option<bool>,
 
+    /// Hook event, matcher and command
+    ///
+    /// `None` for non-hook assets.
+    hook: Option<HookSpec>,
+
     /// Warnings generated during asset loading (e.g., skipped binary files)
     ///
     /// These are non-fatal issues that should be surfaced to the user.
@@ -87,6 +144,9 @@ pub struct Asset {
 
     /// Extra frontmatter fields not recognized by Calvin (e.g., version, version_date, changelog)
     extra_frontmatter: HashMap<String, serde_yaml_ng::Value>,
+
+    /// Per-target field overrides, applied by [`Asset::for_target`]
+    target_overrides: HashMap<Target, AssetOverride>,
 }
 
 impl Asset {
@@ -110,8 +170,14 @@ impl Asset {
             kind: AssetKind::default(),
             scope: Scope::default(),
             targets: Vec::new(),
+            custom_targets: Vec::new(),
             content: content.into(),
+            content_line: 1,
+            content_origins: Vec::new(),
+            merge: MergeStrategy::default(),
+            includes: Vec::new(),
             apply: None,
+            activation: None,
             supplementals: HashMap::new(),
             binary_supplementals: HashMap::new(),
             allowed_tools: Vec::new(),
@@ -125,8 +191,10 @@ impl Asset {
             opencode_model: None,
             command_agent: None,
             command_subtask: None,
+            hook: None,
             warnings: Vec::new(),
             extra_frontmatter: HashMap::new(),
+            target_overrides: HashMap::new(),
         }
     }
 
@@ -148,12 +216,55 @@ impl Asset {
         self
     }
 
+    /// Builder: set the declared custom targets
+    ///
+    /// Like built-in targets, they receive assets without `targets:` (or with `all`).
+    pub fn with_custom_targets(mut self, custom: Vec<Target>) -> Self {
+        self.custom_targets = custom;
+        self
+    }
+
+    /// Builder: set the source line where the content starts
+    pub fn with_content_line(mut self, line: usize) -> Self {
+        self.content_line = line;
+        self
+    }
+
+    /// Builder: set the layer merge strategy
+    pub fn with_merge(mut self, merge: MergeStrategy) -> Self {
+        self.merge = merge;
+        self
+    }
+
+    /// Builder: replace the content with one combined from several layers
+    pub fn with_merged_content(
+        mut self,
+        content: impl Into<String>,
+        origins: Vec<ContentOrigin>,
+    ) -> Self {
+        self.content = content.into();
+        self.content_origins = origins;
+        self
+    }
+
+    /// Builder: set the partial files included into the content
+    pub fn with_includes(mut self, includes: Vec<PathBuf>) -> Self {
+        self.includes = includes;
+        self
+    }
+
     /// Builder: set the apply pattern
     pub fn with_apply(mut self, apply: impl Into<String>) -> Self {
         self.apply = Some(apply.into());
         self
     }
 
+    /// Builder: set the policy activation
+    pub fn with_activation(mut self, activation: Option<Activation>) -> Self {
+        self.activation = activation;
+        self
+    }
+
     /// Builder: set supplemental files (skill-only)
     pub fn with_supplementals(mut self, supplementals: HashMap<PathBuf, String>) -> Self {
         self.supplementals = supplementals;
@@ -231,11 +342,29 @@ impl Asset {
         self
     }
 
+    /// Builder: set the hook event, matcher and command
+    pub fn with_hook(mut self, hook: HookSpec) -> Self {
+        self.hook = Some(hook);
+        self
+    }
+
     pub fn with_extra_frontmatter(mut self, extra: HashMap<String, serde_yaml_ng::Value>) -> Self {
         self.extra_frontmatter = extra;
         self
     }
 
+    /// Builder: override fields for a single target
+    pub fn with_target_override(mut self, target: Target, overrides: AssetOverride) -> Self {
+        self.target_overrides.insert(target, overrides);
+        self
+    }
+
+    /// Builder: replace the content for a single target (e.g. rendered templates)
+    pub fn with_target_content(mut self, target: Target, content: impl Into<String>) -> Self {
+        self.target_overrides.entry(target).or_default().content = Some(content.into());
+        self
+    }
+
     // --- Getters ---
 
     /// Get the asset ID
@@ -277,10 +406,14 @@ impl Asset {
         &self.targets
     }
 
-    /// Get effective targets (expands empty/All to all platforms)
+    /// Get effective targets (expands empty/All to all platforms and declared custom targets)
     pub fn effective_targets(&self) -> Vec<Target> {
         if self.targets.is_empty() || self.targets.iter().any(|t| t.is_all()) {
-            Target::ALL_CONCRETE.to_vec()
+            Target::ALL_CONCRETE
+                .iter()
+                .chain(&self.custom_targets)
+                .copied()
+                .collect()
         } else {
             self.targets.clone()
         }
@@ -291,11 +424,69 @@ impl Asset {
         &self.content
     }
 
+    /// Get the source line where the content starts
+    pub fn content_line(&self) -> usize {
+        self.content_line
+    }
+
+    /// Get the layer merge strategy
+    pub fn merge(&self) -> MergeStrategy {
+        self.merge
+    }
+
+    /// Get the origins of combined content (empty unless merged from several layers)
+    pub fn content_origins(&self) -> &[ContentOrigin] {
+        &self.content_origins
+    }
+
+    /// Map a 1-based line of `content` to the file and line it was read from
+    pub fn source_location(&self, line: usize) -> (&Path, usize) {
+        match self
+            .content_origins
+            .iter()
+            .rev()
+            .find(|origin| origin.line <= line)
+        {
+            Some(origin) => (
+                origin.source_file.as_path(),
+                line - origin.line + origin.source_line,
+            ),
+            None => (self.source_path.as_path(), line + self.content_line - 1),
+        }
+    }
+
+    /// Get the partial files included into the content
+    pub fn includes(&self) -> &[PathBuf] {
+        &self.includes
+    }
+
     /// Get the apply pattern
     pub fn apply(&self) -> Option<&str> {
         self.apply.as_deref()
     }
 
+    /// Get the explicit activation (`None` when the frontmatter does not set one)
+    pub fn activation(&self) -> Option<Activation> {
+        self.activation
+    }
+
+    /// Activation of a policy: `activation` if set, else `glob` with `apply` and `always` without
+    pub fn effective_activation(&self) -> Activation {
+        match (self.activation, &self.apply) {
+            (Some(activation), _) => activation,
+            (None, Some(_)) => Activation::Glob,
+            (None, None) => Activation::Always,
+        }
+    }
+
+    /// Glob patterns of `apply`, if the policy is glob-activated
+    pub fn activation_globs(&self) -> Option<&str> {
+        match self.effective_activation() {
+            Activation::Glob => self.apply(),
+            _ => None,
+        }
+    }
+
     /// Get skill supplemental files
     pub fn supplementals(&self) -> &HashMap<PathBuf, String> {
         &self.supplementals
@@ -356,9 +547,99 @@ impl Asset {
         self.command_subtask
     }
 
+    pub fn hook(&self) -> Option<&HookSpec> {
+        self.hook.as_ref()
+    }
+
     pub fn extra_frontmatter(&self) -> &HashMap<String, serde_yaml_ng::Value> {
         &self.extra_frontmatter
     }
+
+    /// Get the per-target overrides
+    pub fn target_overrides(&self) -> &HashMap<Target, AssetOverride> {
+        &self.target_overrides
+    }
+
+    /// The asset as seen by `target`, with that target's overrides applied
+    ///
+    /// Borrows `self` when the target has no overrides.
+    pub fn for_target(&self, target: Target) -> Cow<'_, Asset> {
+        let Some(o) = self.target_overrides.get(&target) else {
+            return Cow::Borrowed(self);
+        };
+
+        let mut asset = self.clone();
+        asset.target_overrides.clear();
+        if let Some(content) = &o.content {
+            asset.content = content.clone();
+        }
+        if let Some(description) = &o.description {
+            asset.description = description.clone();
+        }
+        if o.apply.is_some() {
+            asset.apply = o.apply.clone();
+        }
+        if o.activation.is_some() {
+            asset.activation = o.activation;
+        }
+        if let Some(allowed_tools) = &o.allowed_tools {
+            asset.allowed_tools = allowed_tools.clone();
+        }
+        if o.agent_name.is_some() {
+            asset.agent_name = o.agent_name.clone();
+        }
+        if let Some(agent_tools) = &o.agent_tools {
+            asset.agent_tools = agent_tools.clone();
+        }
+        if o.agent_model.is_some() {
+            asset.agent_model = o.agent_model.clone();
+        }
+        if o.agent_permission_mode.is_some() {
+            asset.agent_permission_mode = o.agent_permission_mode.clone();
+        }
+        if let Some(agent_skills) = &o.agent_skills {
+            asset.agent_skills = agent_skills.clone();
+        }
+        if o.opencode_mode.is_some() {
+            asset.opencode_mode = o.opencode_mode.clone();
+        }
+        if o.temperature.is_some() {
+            asset.temperature = o.temperature;
+        }
+        if o.opencode_model.is_some() {
+            asset.opencode_model = o.opencode_model.clone();
+        }
+        if o.command_agent.is_some() {
+            asset.command_agent = o.command_agent.clone();
+        }
+        if o.command_subtask.is_some() {
+            asset.command_subtask = o.command_subtask;
+        }
+        asset.extra_frontmatter.extend(
+            o.extra_frontmatter
+                .iter()
+                .map(|(k, v)| (k.clone(), v.clone())),
+        );
+        Cow::Owned(asset)
+    }
+}
+
+/// All assets as seen by `target` (see [`Asset::for_target`])
+///
+/// Borrows `assets` when none of them override fields for `target`.
+pub fn assets_for_target(assets: &[Asset], target: Target) -> Cow<'_, [Asset]> {
+    if assets
+        .iter()
+        .all(|a| !a.target_overrides.contains_key(&target))
+    {
+        return Cow::Borrowed(assets);
+    }
+    Cow::Owned(
+        assets
+            .iter()
+            .map(|a| a.for_target(target).into_owned())
+            .collect(),
+    )
 }
 
 // === From implementations ===
@@ -370,6 +651,7 @@ impl From<crate::models::PromptAsset> for Asset {
             crate::models::AssetKind::Action => AssetKind::Action,
             crate::models::AssetKind::Agent => AssetKind::Agent,
             crate::models::AssetKind::Skill => AssetKind::Skill,
+            crate::models::AssetKind::Hook => AssetKind::Hook,
         };
 
         let scope = match pa.frontmatter.scope {
@@ -388,6 +670,10 @@ impl From<crate::models::PromptAsset> for Asset {
                 crate::models::Target::Antigravity => Target::Antigravity,
                 crate::models::Target::Codex => Target::Codex,
                 crate::models::Target::OpenCode => Target::OpenCode,
+                crate::models::Target::GeminiCli => Target::GeminiCli,
+                crate::models::Target::Windsurf => Target::Windsurf,
+                crate::models::Target::Cline => Target::Cline,
+                crate::models::Target::Custom(name) => Target::Custom(*name),
                 crate::models::Target::All => Target::All,
             })
             .collect();
@@ -407,11 +693,14 @@ impl From<crate::models::PromptAsset> for Asset {
         )
         .with_kind(kind)
         .with_scope(scope)
-        .with_targets(targets);
+        .with_targets(targets)
+        .with_content_line(pa.body_line)
+        .with_merge(pa.frontmatter.merge);
 
         if let Some(apply) = pa.frontmatter.apply {
             asset = asset.with_apply(apply);
         }
+        asset = asset.with_activation(pa.frontmatter.activation);
 
         if !pa.frontmatter.allowed_tools.is_empty() {
             asset = asset.with_allowed_tools(pa.frontmatter.allowed_tools);
@@ -432,6 +721,15 @@ impl From<crate::models::PromptAsset> for Asset {
             asset = asset.with_agent_skills(effective_skills);
         }
 
+        if let (Some(event), Some(command)) = (pa.frontmatter.event, pa.frontmatter.command) {
+            asset = asset.with_hook(HookSpec {
+                event,
+                matcher: pa.frontmatter.matcher,
+                command,
+                timeout: pa.frontmatter.timeout,
+            });
+        }
+
         asset = asset
             .with_opencode_mode(pa.frontmatter.mode)
             .with_temperature(pa.frontmatter.temperature)
@@ -440,6 +738,28 @@ impl From<crate::models::PromptAsset> for Asset {
             .with_command_subtask(pa.frontmatter.subtask)
             .with_extra_frontmatter(pa.frontmatter.extra);
 
+        for (target, o) in pa.frontmatter.overrides {
+            let overrides = AssetOverride {
+                content: None,
+                agent_tools: o.effective_tools(),
+                agent_permission_mode: o.effective_permission_mode().map(str::to_string),
+                agent_skills: o.effective_skills(),
+                description: o.description,
+                apply: o.apply,
+                activation: o.activation,
+                allowed_tools: o.allowed_tools,
+                agent_name: o.name,
+                agent_model: o.model,
+                opencode_mode: o.mode,
+                temperature: o.temperature,
+                opencode_model: o.opencode_model,
+                command_agent: o.agent,
+                command_subtask: o.subtask,
+                extra_frontmatter: o.extra,
+            };
+            asset = asset.with_target_override(target, overrides);
+        }
+
         asset
     }
 }
@@ -581,7 +901,7 @@ mod tests {
         let asset = Asset::new("test", "test.md", "desc", "content");
 
         let targets = asset.effective_targets();
-        assert_eq!(targets.len(), 6);
+        assert_eq!(targets.len(), 9);
     }
 
     #[test]
@@ -590,7 +910,18 @@ mod tests {
             Asset::new("test", "test.md", "desc", "content").with_targets(vec![Target::All]);
 
         let targets = asset.effective_targets();
-        assert_eq!(targets.len(), 6);
+        assert_eq!(targets.len(), 9);
+    }
+
+    #[test]
+    fn asset_effective_targets_include_custom_targets_when_untargeted() {
+        let zed = Target::custom("zed");
+        let asset = Asset::new("test", "test.md", "desc", "content").with_custom_targets(vec![zed]);
+        assert_eq!(asset.effective_targets().len(), 10);
+        assert!(asset.effective_targets().contains(&zed));
+
+        let asset = asset.with_targets(vec![Target::Cursor]);
+        assert_eq!(asset.effective_targets(), vec![Target::Cursor]);
     }
 
     #[test]
@@ -631,6 +962,7 @@ mod tests {
             scope: ModelScope::User,
             targets: vec![crate::models::Target::Cursor],
             apply: Some("*.rs".to_string()),
+            activation: None,
             allowed_tools: vec![],
             tools: None,
             agent_tools: vec![],
@@ -640,11 +972,17 @@ mod tests {
             opencode_model: None,
             agent: None,
             subtask: None,
+            event: None,
+            matcher: None,
+            command: None,
+            timeout: None,
             permission_mode_camel: None,
             permission_mode: None,
             skills: None,
             agent_skills: vec![],
             extra: std::collections::HashMap::new(),
+            merge: crate::models::MergeStrategy::default(),
+            overrides: std::collections::HashMap::new(),
         };
         let prompt_asset = PromptAsset::new("test-id", "test.md", frontmatter, "Test content");
 
@@ -672,6 +1010,7 @@ mod tests {
             scope: ModelScope::Project,
             targets: vec![crate::models::Target::ClaudeCode],
             apply: None,
+            activation: None,
             allowed_tools: vec![],
             tools: None,
             agent_tools: vec!["Read".to_string(), "Grep".to_string()],
@@ -681,11 +1020,17 @@ mod tests {
             opencode_model: None,
             agent: None,
             subtask: None,
+            event: None,
+            matcher: None,
+            command: None,
+            timeout: None,
             permission_mode_camel: Some("acceptEdits".to_string()),
             permission_mode: None,
             skills: None,
             agent_skills: vec!["skill-a".to_string()],
             extra: std::collections::HashMap::new(),
+            merge: crate::models::MergeStrategy::default(),
+            overrides: std::collections::HashMap::new(),
         };
         let prompt_asset =
             PromptAsset::new("test-agent", "agents/test.md", frontmatter, "Agent content");
@@ -699,4 +1044,87 @@ mod tests {
         assert_eq!(asset.agent_permission_mode(), Some("acceptEdits"));
         assert_eq!(asset.agent_skills(), &["skill-a"]);
     }
+
+    #[test]
+    fn for_target_applies_only_that_targets_overrides() {
+        let asset = Asset::new("style", "policies/style.md", "Style", "Content")
+            .with_apply("**/*.rs")
+            .with_extra_frontmatter(HashMap::from([(
+                "version".to_string(),
+                serde_yaml_ng::Value::from("1"),
+            )]))
+            .with_target_override(
+                Target::Cursor,
+                AssetOverride {
+                    apply: Some("src/**/*.rs".to_string()),
+                    extra_frontmatter: HashMap::from([(
+                        "globs-mode".to_string(),
+                        serde_yaml_ng::Value::from("strict"),
+                    )]),
+                    ..Default::default()
+                },
+            );
+
+        let cursor = asset.for_target(Target::Cursor);
+        assert!(matches!(cursor, Cow::Owned(_)));
+        assert_eq!(cursor.apply(), Some("src/**/*.rs"));
+        assert_eq!(cursor.description(), "Style");
+        assert_eq!(cursor.extra_frontmatter().len(), 2);
+        assert!(cursor.target_overrides().is_empty());
+
+        let claude = asset.for_target(Target::ClaudeCode);
+        assert!(matches!(claude, Cow::Borrowed(_)));
+        assert_eq!(claude.apply(), Some("**/*.rs"));
+        assert!(!claude.extra_frontmatter().contains_key("globs-mode"));
+    }
+
+    #[test]
+    fn assets_for_target_borrows_without_overrides() {
+        let plain = Asset::new("a", "a.md", "A", "A");
+        let overridden = Asset::new("b", "b.md", "B", "B").with_target_override(
+            Target::Codex,
+            AssetOverride {
+                description: Some("B for Codex".to_string()),
+                ..Default::default()
+            },
+        );
+        let assets = vec![plain, overridden];
+
+        assert!(matches!(
+            assets_for_target(&assets, Target::Cursor),
+            Cow::Borrowed(_)
+        ));
+        let codex = assets_for_target(&assets, Target::Codex);
+        assert_eq!(codex[1].description(), "B for Codex");
+    }
+
+    #[test]
+    fn from_prompt_asset_converts_overrides() {
+        use crate::models::PromptAsset;
+        use crate::parser::parse_frontmatter;
+
+        let frontmatter = parse_frontmatter(
+            "description: Reviewer\nkind: agent\noverrides:\n  claude-code:\n    tools: Read, Grep\n    permissionMode: plan\n    color: blue\n",
+            std::path::Path::new("agents/reviewer.md"),
+        )
+        .unwrap();
+        let asset = Asset::from(PromptAsset::new(
+            "reviewer",
+            "agents/reviewer.md",
+            frontmatter,
+            "Body",
+        ));
+
+        let o = &asset.target_overrides()[&Target::ClaudeCode];
+        assert_eq!(
+            o.agent_tools.as_deref(),
+            Some(&["Read".to_string(), "Grep".to_string()][..])
+        );
+        assert_eq!(o.agent_permission_mode.as_deref(), Some("plan"));
+        assert!(o.extra_frontmatter.contains_key("color"));
+
+        let claude = asset.for_target(Target::ClaudeCode);
+        assert_eq!(claude.agent_tools(), &["Read", "Grep"]);
+        assert!(asset.agent_tools().is_empty());
+    }
 }
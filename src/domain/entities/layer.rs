@@ -4,7 +4,7 @@
 
 use std::path::PathBuf;
 
-use crate::domain::entities::Asset;
+use crate::domain::entities::{Asset, McpServer, Partial};
 
 #[derive(Debug, Clone, Copy, PartialEq, Eq)]
 pub enum LayerType {
@@ -40,6 +40,12 @@ pub struct Layer {
     pub path: LayerPath,
     pub layer_type: LayerType,
     pub assets: Vec<Asset>,
+    /// MCP server definitions from `mcp/*.toml`
+    pub mcp_servers: Vec<McpServer>,
+    /// Include snippets from `partials/`
+    pub partials: Vec<Partial>,
+    /// Source format version declared in `config.toml` (`[format] version`)
+    pub format_version: Option<String>,
     /// Number of files skipped due to .calvinignore patterns
     pub ignored_count: usize,
 }
@@ -51,6 +57,9 @@ impl Layer {
             path,
             layer_type,
             assets: Vec::new(),
+            mcp_servers: Vec::new(),
+            partials: Vec::new(),
+            format_version: None,
             ignored_count: 0,
         }
     }
@@ -60,6 +69,21 @@ impl Layer {
         self
     }
 
+    pub fn with_mcp_servers(mut self, servers: Vec<McpServer>) -> Self {
+        self.mcp_servers = servers;
+        self
+    }
+
+    pub fn with_partials(mut self, partials: Vec<Partial>) -> Self {
+        self.partials = partials;
+        self
+    }
+
+    pub fn with_format_version(mut self, version: impl Into<String>) -> Self {
+        self.format_version = Some(version.into());
+        self
+    }
+
     pub fn with_ignored_count(mut self, count: usize) -> Self {
         self.ignored_count = count;
         self
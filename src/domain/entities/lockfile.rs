@@ -6,7 +6,7 @@
 use std::collections::BTreeMap;
 use std::path::{Path, PathBuf};
 
-use crate::domain::value_objects::Scope;
+use crate::domain::value_objects::{Scope, Target};
 
 /// Normalize a path for lockfile storage (always use forward slashes).
 pub(crate) fn normalize_lockfile_path(path: &Path) -> String {
@@ -30,6 +30,8 @@ pub struct OutputProvenance {
     source_asset: String,
     source_file: PathBuf,
     overrides: Option<String>,
+    includes: Vec<PathBuf>,
+    contributing_layers: Vec<String>,
 }
 
 impl OutputProvenance {
@@ -45,6 +47,8 @@ impl OutputProvenance {
             source_asset: source_asset.into(),
             source_file: source_file.into(),
             overrides: None,
+            includes: Vec::new(),
+            contributing_layers: Vec::new(),
         }
     }
 
@@ -53,6 +57,18 @@ impl OutputProvenance {
         self
     }
 
+    /// Record the partial files included into the source asset
+    pub fn with_includes(mut self, includes: Vec<PathBuf>) -> Self {
+        self.includes = includes;
+        self
+    }
+
+    /// Record the layers merged into the source asset (`merge: append`, ...)
+    pub fn with_contributing_layers(mut self, layers: Vec<String>) -> Self {
+        self.contributing_layers = layers;
+        self
+    }
+
     pub fn source_layer(&self) -> &str {
         &self.source_layer
     }
@@ -72,6 +88,14 @@ impl OutputProvenance {
     pub fn overrides(&self) -> Option<&str> {
         self.overrides.as_deref()
     }
+
+    pub fn includes(&self) -> &[PathBuf] {
+        &self.includes
+    }
+
+    pub fn contributing_layers(&self) -> &[String] {
+        &self.contributing_layers
+    }
 }
 
 /// Lockfile entry for a tracked file
@@ -89,8 +113,15 @@ pub struct LockfileEntry {
     source_file: Option<PathBuf>,
     /// Overrides applied (if any)
     overrides: Option<String>,
+    /// Partial files included into the source asset (normalized for lockfile storage)
+    includes: Vec<PathBuf>,
+    /// Layers merged into the source asset, lowest first (empty unless merged)
+    contributing_layers: Vec<String>,
     /// Whether this is a binary file (for skills with binary assets)
     is_binary: bool,
+    /// Keys Calvin owns in a structurally merged file (JSON Pointers).
+    /// Empty for files Calvin owns entirely.
+    owned_keys: Vec<String>,
 }
 
 impl LockfileEntry {
@@ -103,7 +134,10 @@ impl LockfileEntry {
             source_asset: None,
             source_file: None,
             overrides: None,
+            includes: Vec::new(),
+            contributing_layers: Vec::new(),
             is_binary: false,
+            owned_keys: Vec::new(),
         }
     }
 
@@ -116,6 +150,8 @@ impl LockfileEntry {
             Some(provenance.source_file),
             provenance.overrides,
         )
+        .with_includes(provenance.includes)
+        .with_contributing_layers(provenance.contributing_layers)
     }
 
     pub fn with_parts(
@@ -133,10 +169,25 @@ impl LockfileEntry {
             source_asset,
             source_file,
             overrides,
+            includes: Vec::new(),
+            contributing_layers: Vec::new(),
             is_binary: false,
+            owned_keys: Vec::new(),
         }
     }
 
+    /// Record the partial files included into the source asset
+    pub fn with_includes(mut self, includes: Vec<PathBuf>) -> Self {
+        self.includes = includes;
+        self
+    }
+
+    /// Record the layers merged into the source asset
+    pub fn with_contributing_layers(mut self, layers: Vec<String>) -> Self {
+        self.contributing_layers = layers;
+        self
+    }
+
     /// Mark this entry as a binary file
     pub fn with_binary(mut self, is_binary: bool) -> Self {
         self.is_binary = is_binary;
@@ -168,22 +219,84 @@ impl LockfileEntry {
         self.overrides.as_deref()
     }
 
+    pub fn includes(&self) -> &[PathBuf] {
+        &self.includes
+    }
+
+    pub fn contributing_layers(&self) -> &[String] {
+        &self.contributing_layers
+    }
+
     /// Check if this entry is a binary file
     pub fn is_binary(&self) -> bool {
         self.is_binary
     }
+
+    /// Record the keys Calvin owns in a merged file
+    pub fn with_owned_keys(mut self, owned_keys: Vec<String>) -> Self {
+        self.owned_keys = owned_keys;
+        self
+    }
+
+    /// Keys Calvin owns in a merged file (empty if Calvin owns the whole file)
+    pub fn owned_keys(&self) -> &[String] {
+        &self.owned_keys
+    }
+
+    /// Check if this entry tracks a structurally merged file
+    pub fn is_merged(&self) -> bool {
+        !self.owned_keys.is_empty()
+    }
+}
+
+/// Pinned revision of a remote layer (`calvin.lock` `[layers]` section)
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct LayerPin {
+    /// Commit the layer was fetched at (`None` for tarballs)
+    commit: Option<String>,
+    /// Content hash of the fetched layer tree (`sha256:…`)
+    hash: String,
+}
+
+impl LayerPin {
+    pub fn new(commit: Option<String>, hash: impl Into<String>) -> Self {
+        Self {
+            commit,
+            hash: hash.into(),
+        }
+    }
+
+    pub fn commit(&self) -> Option<&str> {
+        self.commit.as_deref()
+    }
+
+    pub fn hash(&self) -> &str {
+        &self.hash
+    }
 }
 
 /// The lockfile tracks deployed file hashes
 ///
 /// Keys are formatted as `{namespace}:{path}` where namespace is "home" or "project".
 /// This allows a single lockfile to track multiple deployment scopes.
-#[derive(Debug, Clone, Default, PartialEq)]
+#[derive(Debug, Clone, PartialEq)]
 pub struct Lockfile {
     /// Format version
     version: u32,
     /// Map of keys to entries
     entries: BTreeMap<String, LockfileEntry>,
+    /// Adapter output format version each target was last deployed with
+    adapter_versions: BTreeMap<String, u32>,
+    /// Pinned remote layers, keyed by their configured source
+    layer_pins: BTreeMap<String, LayerPin>,
+    /// Deploy state of each `[[remotes]]` host, keyed by remote name
+    remotes: BTreeMap<String, Lockfile>,
+}
+
+impl Default for Lockfile {
+    fn default() -> Self {
+        Self::new()
+    }
 }
 
 impl Lockfile {
@@ -192,6 +305,9 @@ impl Lockfile {
         Self {
             version: 1,
             entries: BTreeMap::new(),
+            adapter_versions: BTreeMap::new(),
+            layer_pins: BTreeMap::new(),
+            remotes: BTreeMap::new(),
         }
     }
 
@@ -200,6 +316,55 @@ impl Lockfile {
         self.version
     }
 
+    /// Adapter output format version recorded for `target`
+    pub fn adapter_version(&self, target: Target) -> Option<u32> {
+        self.adapter_versions.get(target.id()).copied()
+    }
+
+    /// Record the adapter output format version `target` was deployed with
+    pub fn set_adapter_version(&mut self, target: Target, version: u32) {
+        self.adapter_versions
+            .insert(target.id().to_string(), version);
+    }
+
+    /// All recorded adapter versions, keyed by target id (e.g. `claude-code`)
+    pub fn adapter_versions(&self) -> impl Iterator<Item = (&str, u32)> {
+        self.adapter_versions.iter().map(|(k, v)| (k.as_str(), *v))
+    }
+
+    /// Pin recorded for a remote layer source (e.g. `git+file:///srv/team.git#v1.2`)
+    pub fn layer_pin(&self, source: &str) -> Option<&LayerPin> {
+        self.layer_pins.get(source)
+    }
+
+    /// Record the revision a remote layer source was fetched at
+    pub fn set_layer_pin(&mut self, source: impl Into<String>, pin: LayerPin) {
+        self.layer_pins.insert(source.into(), pin);
+    }
+
+    /// All pinned remote layers, keyed by source
+    pub fn layer_pins(&self) -> impl Iterator<Item = (&str, &LayerPin)> {
+        self.layer_pins.iter().map(|(k, v)| (k.as_str(), v))
+    }
+
+    /// Deploy state recorded for a remote (`deploy --remote <name>`)
+    ///
+    /// Each remote host has its own files, so its entries and adapter versions are
+    /// kept apart from the local ones and from every other remote.
+    pub fn remote(&self, name: &str) -> Option<&Lockfile> {
+        self.remotes.get(name)
+    }
+
+    /// Deploy state for a remote, created empty on first use
+    pub fn remote_mut(&mut self, name: &str) -> &mut Lockfile {
+        self.remotes.entry(name.to_string()).or_default()
+    }
+
+    /// All remote deploy states, keyed by remote name
+    pub fn remotes(&self) -> impl Iterator<Item = (&str, &Lockfile)> {
+        self.remotes.iter().map(|(k, v)| (k.as_str(), v))
+    }
+
     /// Check if the lockfile is empty
     pub fn is_empty(&self) -> bool {
         self.entries.is_empty()
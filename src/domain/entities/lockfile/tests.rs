@@ -235,6 +235,37 @@ fn lockfile_set_hash_is_alias_for_set() {
     assert_eq!(lockfile.get_hash("test:path"), Some("hash"));
 }
 
+#[test]
+fn lockfile_records_adapter_versions_by_target_id() {
+    let mut lockfile = Lockfile::new();
+    assert_eq!(lockfile.adapter_version(Target::Cursor), None);
+
+    lockfile.set_adapter_version(Target::Cursor, 2);
+    assert_eq!(lockfile.adapter_version(Target::Cursor), Some(2));
+    assert_eq!(
+        lockfile.adapter_versions().collect::<Vec<_>>(),
+        vec![("cursor", 2)]
+    );
+}
+
+#[test]
+fn lockfile_records_layer_pins_by_source() {
+    let mut lockfile = Lockfile::new();
+    let source = "git+file:///srv/team.git#v1.2";
+    assert!(lockfile.layer_pin(source).is_none());
+
+    lockfile.set_layer_pin(
+        source,
+        LayerPin::new(Some("abc123".to_string()), "sha256:def"),
+    );
+    let pin = lockfile.layer_pin(source).unwrap();
+    assert_eq!(pin.commit(), Some("abc123"));
+    assert_eq!(pin.hash(), "sha256:def");
+    assert_eq!(lockfile.layer_pins().count(), 1);
+    // Layer pins are not tracked files
+    assert!(lockfile.is_empty());
+}
+
 #[test]
 fn normalize_windows_path() {
     let path = Path::new("C:\\Users\\me\\project\\.claude\\commands\\test.md");
@@ -0,0 +1,126 @@
+//! McpServer entity - an MCP server definition
+//!
+//! MCP servers are declared in `.promptpack/mcp/<name>.toml` (or `[mcp.servers]`
+//! in config) and compiled into each target's native MCP configuration file.
+
+use std::collections::BTreeMap;
+
+use crate::domain::value_objects::Target;
+
+/// A stdio MCP server definition
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct McpServer {
+    /// Server name (file stem or `[mcp.servers.<name>]` key)
+    name: String,
+    /// Executable to launch
+    command: String,
+    /// Command-line arguments
+    args: Vec<String>,
+    /// Environment variables passed to the server
+    env: BTreeMap<String, String>,
+    /// Targets this server is compiled for (`None` = all targets)
+    targets: Option<Vec<Target>>,
+}
+
+impl McpServer {
+    /// Create a new MCP server definition
+    pub fn new(name: impl Into<String>, command: impl Into<String>) -> Self {
+        Self {
+            name: name.into(),
+            command: command.into(),
+            args: Vec::new(),
+            env: BTreeMap::new(),
+            targets: None,
+        }
+    }
+
+    /// Builder: set arguments
+    pub fn with_args(mut self, args: Vec<String>) -> Self {
+        self.args = args;
+        self
+    }
+
+    /// Builder: set environment variables
+    pub fn with_env(mut self, env: BTreeMap<String, String>) -> Self {
+        self.env = env;
+        self
+    }
+
+    /// Builder: restrict to specific targets
+    pub fn with_targets(mut self, targets: Vec<Target>) -> Self {
+        self.targets = Some(targets);
+        self
+    }
+
+    /// Get the server name
+    pub fn name(&self) -> &str {
+        &self.name
+    }
+
+    /// Get the command
+    pub fn command(&self) -> &str {
+        &self.command
+    }
+
+    /// Get the arguments
+    pub fn args(&self) -> &[String] {
+        &self.args
+    }
+
+    /// Get the environment variables
+    pub fn env(&self) -> &BTreeMap<String, String> {
+        &self.env
+    }
+
+    /// Get the target restriction, if any
+    pub fn targets(&self) -> Option<&[Target]> {
+        self.targets.as_deref()
+    }
+
+    /// Full command line (command followed by arguments)
+    pub fn command_line(&self) -> String {
+        std::iter::once(self.command.as_str())
+            .chain(self.args.iter().map(String::as_str))
+            .collect::<Vec<_>>()
+            .join(" ")
+    }
+
+    /// Check if this server should be compiled for the given target
+    pub fn applies_to(&self, target: Target) -> bool {
+        match &self.targets {
+            None => true,
+            Some(targets) => targets.iter().any(|t| t.is_all() || *t == target),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn command_line_joins_command_and_args() {
+        let server = McpServer::new("github", "npx").with_args(vec![
+            "-y".into(),
+            "@modelcontextprotocol/server-github".into(),
+        ]);
+        assert_eq!(
+            server.command_line(),
+            "npx -y @modelcontextprotocol/server-github"
+        );
+    }
+
+    #[test]
+    fn applies_to_all_targets_by_default() {
+        let server = McpServer::new("fs", "npx");
+        assert!(server.applies_to(Target::ClaudeCode));
+        assert!(server.applies_to(Target::Codex));
+    }
+
+    #[test]
+    fn applies_to_respects_target_restriction() {
+        let server = McpServer::new("fs", "npx").with_targets(vec![Target::Cursor]);
+        assert!(server.applies_to(Target::Cursor));
+        assert!(!server.applies_to(Target::ClaudeCode));
+    }
+}
@@ -4,16 +4,22 @@
 //! - `Asset` - A source file from .promptpack/
 //! - `OutputFile` - A compiled output file
 //! - `Lockfile` - Tracks deployed file hashes
+//! - `McpServer` - An MCP server definition from .promptpack/mcp/
+//! - `Partial` - A snippet from .promptpack/partials/ included by assets
 
 mod asset;
 mod layer;
 mod lockfile;
+mod mcp_server;
 mod output_file;
+mod partial;
 mod registry;
 
-pub use asset::{Asset, AssetKind};
+pub use asset::{assets_for_target, Asset, AssetKind, AssetOverride, ContentOrigin};
 pub use layer::{Layer, LayerPath, LayerType};
 pub(crate) use lockfile::{normalize_lockfile_path, parse_lockfile_path};
-pub use lockfile::{Lockfile, LockfileEntry, OutputProvenance};
+pub use lockfile::{LayerPin, Lockfile, LockfileEntry, OutputProvenance};
+pub use mcp_server::McpServer;
 pub use output_file::{BinaryOutputFile, OutputFile};
+pub use partial::Partial;
 pub use registry::{ProjectEntry, Registry};
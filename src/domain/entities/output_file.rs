@@ -3,7 +3,7 @@
 //! OutputFiles are the result of compiling Assets for a specific target platform.
 //! They represent what will be written to the file system.
 
-use crate::domain::value_objects::Target;
+use crate::domain::value_objects::{MergeSpec, Target};
 use sha2::{Digest, Sha256};
 use std::path::PathBuf;
 
@@ -18,6 +18,8 @@ pub struct OutputFile {
     target: Target,
     /// Cached content hash
     hash: Option<String>,
+    /// Owned keys, if this output is a shared config file that can be merged
+    merge: Option<MergeSpec>,
 }
 
 impl OutputFile {
@@ -28,6 +30,7 @@ impl OutputFile {
             content: content.into(),
             target,
             hash: None,
+            merge: None,
         }
     }
 
@@ -38,9 +41,28 @@ impl OutputFile {
             content: content.into(),
             target: Target::All, // Default, should be overridden
             hash: None,
+            merge: None,
         }
     }
 
+    /// Builder: mark this output as a mergeable shared config file
+    pub fn with_merge(mut self, spec: MergeSpec) -> Self {
+        self.merge = Some(spec);
+        self
+    }
+
+    /// Replace the content, keeping path, target and merge spec
+    pub fn with_content(mut self, content: impl Into<String>) -> Self {
+        self.content = content.into();
+        self.hash = None;
+        self
+    }
+
+    /// Get the merge spec (owned keys), if any
+    pub fn merge_spec(&self) -> Option<&MergeSpec> {
+        self.merge.as_ref()
+    }
+
     /// Get the output path
     pub fn path(&self) -> &PathBuf {
         &self.path
@@ -0,0 +1,33 @@
+//! Partial entity - a snippet included by other assets
+//!
+//! Partials live under `.promptpack/partials/` and are inserted into asset bodies
+//! with `{{> partials/<name> }}`. They have no frontmatter and are never compiled
+//! on their own.
+
+/// A partial snippet from a layer's `partials/` directory
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Partial {
+    /// Include path relative to the layer root, with `/` separators
+    /// (e.g. `partials/secrets.md`)
+    path: String,
+    /// Raw snippet content
+    content: String,
+}
+
+impl Partial {
+    /// Create a partial
+    pub fn new(path: impl Into<String>, content: impl Into<String>) -> Self {
+        Self {
+            path: path.into(),
+            content: content.into(),
+        }
+    }
+
+    pub fn path(&self) -> &str {
+        &self.path
+    }
+
+    pub fn content(&self) -> &str {
+        &self.content
+    }
+}
@@ -11,4 +11,4 @@ mod skill_allowed_tools;
 pub use scope_policy::{DeploymentTarget, ScopePolicy};
 pub use security::SecurityPolicy;
 pub use security_baseline::{effective_claude_deny_patterns, MINIMUM_DENY};
-pub use skill_allowed_tools::is_dangerous_skill_tool;
+pub use skill_allowed_tools::{dangerous_hook_command_tool, is_dangerous_skill_tool};
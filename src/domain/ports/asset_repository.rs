@@ -3,7 +3,7 @@
 //! This trait allows the domain layer to load assets without
 //! knowing about file system details.
 
-use crate::domain::entities::Asset;
+use crate::domain::entities::{Asset, McpServer, Partial};
 use crate::domain::value_objects::IgnorePatterns;
 use anyhow::Result;
 use std::path::Path;
@@ -34,6 +34,27 @@ pub trait AssetRepository {
 
     /// Load a single asset by path
     fn load_by_path(&self, path: &Path) -> Result<Asset>;
+
+    /// Load MCP server definitions (`mcp/*.toml`) from a source directory.
+    ///
+    /// Defaults to no servers for repositories that don't support MCP definitions.
+    fn load_mcp_servers(&self, _source: &Path) -> Result<Vec<McpServer>> {
+        Ok(Vec::new())
+    }
+
+    /// Load include snippets (`partials/**`) from a source directory.
+    ///
+    /// Defaults to no partials for repositories that don't support includes.
+    fn load_partials(&self, _source: &Path) -> Result<Vec<Partial>> {
+        Ok(Vec::new())
+    }
+
+    /// Load the source format version declared in `<source>/config.toml`.
+    ///
+    /// Returns `None` when the layer does not declare `[format] version`.
+    fn load_format_version(&self, _source: &Path) -> Result<Option<String>> {
+        Ok(None)
+    }
 }
 
 #[cfg(test)]
@@ -110,6 +110,9 @@ mod tests {
                 Target::Antigravity,
                 Target::Codex,
                 Target::OpenCode,
+                Target::GeminiCli,
+                Target::Windsurf,
+                Target::Cline,
             ]
         }
 
@@ -238,6 +241,6 @@ mod tests {
         assert_eq!(config.format_version(), "1.0");
         assert!(config.atomic_writes());
         assert!(config.respect_lockfile());
-        assert_eq!(config.enabled_targets().len(), 6);
+        assert_eq!(config.enabled_targets().len(), 9);
     }
 }
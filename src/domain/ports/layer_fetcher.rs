@@ -0,0 +1,48 @@
+//! LayerFetcher port - materialize remote layers on the local filesystem
+//!
+//! Remote layers (git repositories, tarballs) are fetched into a content-addressed
+//! cache and then loaded like any other layer directory.
+
+use std::path::PathBuf;
+
+use crate::domain::entities::LayerPin;
+use crate::domain::value_objects::RemoteLayer;
+
+/// A remote layer available on the local filesystem
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct FetchedLayer {
+    /// Cached layer directory
+    pub path: PathBuf,
+    /// Revision the directory holds
+    pub pin: LayerPin,
+}
+
+/// Errors from fetching a remote layer
+#[derive(Debug, thiserror::Error, PartialEq, Eq)]
+pub enum LayerFetchError {
+    #[error("failed to fetch {layer}: {message}")]
+    Fetch { layer: String, message: String },
+
+    #[error("{layer} does not match calvin.lock (expected {expected}, got {actual})")]
+    HashMismatch {
+        layer: String,
+        expected: String,
+        actual: String,
+    },
+
+    #[error("layer cache error: {0}")]
+    Io(String),
+}
+
+/// Port for fetching remote layers
+pub trait LayerFetcher {
+    /// Fetch `layer` at `pin`, or at the current revision of its ref when unpinned
+    ///
+    /// A pinned layer already in the cache is returned without touching the source.
+    /// Fetched content must hash to the pinned hash.
+    fn fetch(
+        &self,
+        layer: &RemoteLayer,
+        pin: Option<&LayerPin>,
+    ) -> Result<FetchedLayer, LayerFetchError>;
+}
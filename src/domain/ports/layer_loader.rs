@@ -65,6 +65,7 @@ pub(crate) fn ensure_unique_asset_ids(
 fn asset_key_for_uniqueness(asset: &Asset) -> String {
     match asset.kind() {
         AssetKind::Skill => format!("skill:{}", asset.id()),
+        AssetKind::Hook => format!("hook:{}", asset.id()),
         _ => asset.id().to_string(),
     }
 }
@@ -3,8 +3,9 @@
 //! This port defines how domain entities (Assets) are transformed into
 //! platform-specific output files (OutputFiles).
 
-use crate::domain::entities::{Asset, BinaryOutputFile, OutputFile};
-use crate::domain::value_objects::Target;
+use crate::domain::entities::{Asset, BinaryOutputFile, McpServer, OutputFile};
+use crate::domain::ports::tool_probe::{DetectedTool, ToolProbe};
+use crate::domain::value_objects::{Scope, Target, ToolVersion};
 use std::fmt;
 
 /// Error type for adapter operations
@@ -74,11 +75,34 @@ pub trait TargetAdapter: Send + Sync {
     /// Target identifier for this adapter
     fn target(&self) -> Target;
 
-    /// Adapter version (for cache invalidation, etc.)
-    fn version(&self) -> u8 {
+    /// Version of the output layout this adapter generates
+    ///
+    /// Bumped whenever generated paths or file formats change. Recorded per target
+    /// in `calvin.lock` so `calvin migrate --adapter` can regenerate stale outputs.
+    fn output_format_version(&self) -> u32 {
         1
     }
 
+    /// Oldest tool version that understands this adapter's output
+    fn min_ide_version(&self) -> Option<ToolVersion> {
+        None
+    }
+
+    /// Newest tool version this adapter was tested against
+    ///
+    /// `calvin check` warns when an installed tool is a newer release.
+    fn max_tested_version(&self) -> Option<ToolVersion> {
+        None
+    }
+
+    /// Detect the locally installed tool for this target
+    ///
+    /// Returns `None` if the tool is not installed (or detection is unsupported).
+    fn detect_version(&self, probe: &dyn ToolProbe) -> Option<DetectedTool> {
+        let _ = probe;
+        None
+    }
+
     /// Compile a single asset into platform-specific output files
     ///
     /// Returns empty Vec if this asset type is not supported by the target.
@@ -96,6 +120,15 @@ pub trait TargetAdapter: Send + Sync {
     /// Validate generated output against platform best practices
     fn validate(&self, output: &OutputFile) -> Vec<AdapterDiagnostic>;
 
+    /// Report what of an asset this target cannot represent
+    ///
+    /// E.g. an agent skipped for lack of a native format, or compiled as a
+    /// workflow without its `tools` and `model`. Surfaced by deploy and `calvin check`.
+    fn downgrades(&self, asset: &Asset) -> Vec<AdapterDiagnostic> {
+        let _ = asset;
+        Vec::new()
+    }
+
     /// Perform post-compilation tasks
     ///
     /// Called after all individual assets have been compiled.
@@ -117,6 +150,19 @@ pub trait TargetAdapter: Send + Sync {
         Ok(Vec::new())
     }
 
+    /// Compile MCP server definitions into the platform's MCP configuration file
+    ///
+    /// `servers` are already filtered to those allowed for this target.
+    /// Returns empty Vec if the platform has no MCP configuration for `scope`.
+    fn compile_mcp(
+        &self,
+        servers: &[McpServer],
+        scope: Scope,
+    ) -> Result<Vec<OutputFile>, AdapterError> {
+        let _ = (servers, scope);
+        Ok(Vec::new())
+    }
+
     /// Generate header marker for generated files
     fn header(&self, source_path: &str) -> String {
         // Note: Version removed to avoid snapshot churn on version bumps
@@ -208,9 +254,11 @@ mod tests {
     }
 
     #[test]
-    fn adapter_has_default_version() {
+    fn adapter_has_default_versions() {
         let adapter = MockAdapter::new(Target::ClaudeCode);
-        assert_eq!(adapter.version(), 1);
+        assert_eq!(adapter.output_format_version(), 1);
+        assert_eq!(adapter.min_ide_version(), None);
+        assert_eq!(adapter.max_tested_version(), None);
     }
 
     #[test]
@@ -242,6 +290,14 @@ mod tests {
         assert!(outputs.is_empty());
     }
 
+    #[test]
+    fn downgrades_default_is_empty() {
+        let adapter = MockAdapter::new(Target::ClaudeCode);
+        let asset = Asset::new("test", "test.md", "Test asset", "# Content");
+
+        assert!(adapter.downgrades(&asset).is_empty());
+    }
+
     #[test]
     fn compile_binary_default_returns_empty() {
         let adapter = MockAdapter::new(Target::ClaudeCode);
@@ -0,0 +1,112 @@
+//! ToolProbe port - inspect locally installed AI coding tools
+//!
+//! Adapters stay free of I/O: they describe *what* identifies their tool
+//! (a binary, a config directory) and use this port to look for it.
+
+use crate::domain::value_objects::ToolVersion;
+
+/// Port for probing the local machine for installed tools
+pub trait ToolProbe: Send + Sync {
+    /// Run `program args` and return its stdout, or `None` if it is not
+    /// installed or exits unsuccessfully
+    fn command_output(&self, program: &str, args: &[&str]) -> Option<String>;
+
+    /// Check whether `~/<relative>` exists
+    fn home_path_exists(&self, relative: &str) -> bool;
+}
+
+/// An installed tool found by [`ToolProbe`]
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct DetectedTool {
+    /// Reported version (`None` if only the config directory was found)
+    pub version: Option<ToolVersion>,
+    /// Where the tool was found (e.g. `claude --version`, `~/.cursor`)
+    pub location: String,
+}
+
+impl DetectedTool {
+    /// Detect a tool by running `<binary> --version`, falling back to its config directory
+    pub fn probe(probe: &dyn ToolProbe, binary: &str, config_dir: &str) -> Option<Self> {
+        if let Some(version) = probe
+            .command_output(binary, &["--version"])
+            .and_then(|output| ToolVersion::find_in(&output))
+        {
+            return Some(Self {
+                version: Some(version),
+                location: format!("{} --version", binary),
+            });
+        }
+
+        probe.home_path_exists(config_dir).then(|| Self {
+            version: None,
+            location: format!("~/{}", config_dir),
+        })
+    }
+}
+
+/// Test double that answers every probe with fixed results
+#[cfg(test)]
+#[derive(Debug, Clone, Copy, Default)]
+pub struct FakeToolProbe {
+    /// Output of every command (`None` = binary not installed)
+    pub output: Option<&'static str>,
+    /// Whether every config directory exists
+    pub dir: bool,
+}
+
+#[cfg(test)]
+impl FakeToolProbe {
+    /// A probe whose binaries print `output` and whose config directories are absent
+    pub fn with_output(output: &'static str) -> Self {
+        Self {
+            output: Some(output),
+            dir: false,
+        }
+    }
+}
+
+#[cfg(test)]
+impl ToolProbe for FakeToolProbe {
+    fn command_output(&self, _program: &str, _args: &[&str]) -> Option<String> {
+        self.output.map(str::to_string)
+    }
+
+    fn home_path_exists(&self, _relative: &str) -> bool {
+        self.dir
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    #[test]
+    fn probe_prefers_binary_version() {
+        let probe = FakeToolProbe {
+            output: Some("1.0.30 (Claude Code)"),
+            dir: true,
+        };
+        let detected = DetectedTool::probe(&probe, "claude", ".claude").unwrap();
+        assert_eq!(detected.version, Some(ToolVersion::new(1, 0, 30)));
+        assert_eq!(detected.location, "claude --version");
+    }
+
+    #[test]
+    fn probe_falls_back_to_config_dir() {
+        let probe = FakeToolProbe {
+            output: None,
+            dir: true,
+        };
+        let detected = DetectedTool::probe(&probe, "cursor", ".cursor").unwrap();
+        assert_eq!(detected.version, None);
+        assert_eq!(detected.location, "~/.cursor");
+    }
+
+    #[test]
+    fn probe_returns_none_when_not_installed() {
+        let probe = FakeToolProbe {
+            output: Some("garbage"),
+            dir: false,
+        };
+        assert!(DetectedTool::probe(&probe, "codex", ".codex").is_none());
+    }
+}
@@ -146,17 +146,31 @@ impl PathGenerator {
                 };
                 Some(base.join(format!("{}.md", asset_id)))
             }
-            Target::Codex => {
-                // Codex uses: ~/.codex/prompts/<id>.md or .codex/prompts/<id>.md
+            Target::Windsurf => {
+                // Windsurf uses: .windsurf/rules/<id>.md
+                // User-scope policies share ~/.codeium/windsurf/memories/global_rules.md
+                if is_user_scope {
+                    return None;
+                }
+                let base = PathBuf::from(".windsurf").join("rules");
+                Some(base.join(format!("{}.md", asset_id)))
+            }
+            Target::Cline => {
+                // Cline uses: .clinerules/<id>.md
+                // User scope: ~/Documents/Cline/Rules/<id>.md
                 let base = if is_user_scope {
-                    PathBuf::from("~").join(".codex").join("prompts")
+                    PathBuf::from("~")
+                        .join("Documents")
+                        .join("Cline")
+                        .join("Rules")
                 } else {
-                    PathBuf::from(".codex").join("prompts")
+                    PathBuf::from(".clinerules")
                 };
                 Some(base.join(format!("{}.md", asset_id)))
             }
-            Target::OpenCode => {
-                // OpenCode policies are aggregated to AGENTS.md (not per-policy files).
+            Target::Custom(_) => None, // Paths come from `[[targets.custom]]` templates
+            Target::Codex | Target::OpenCode | Target::GeminiCli => {
+                // Policies are aggregated to AGENTS.md / GEMINI.md (not per-policy files).
                 None
             }
             Target::All => None, // All is expanded before this
@@ -203,6 +217,23 @@ impl PathGenerator {
                 };
                 Some(base.join(format!("{}.md", asset_id)))
             }
+            Target::VSCode => {
+                // VS Code/Copilot prompt files: .github/prompts/<id>.prompt.md
+                let base = if is_user_scope {
+                    PathBuf::from("~").join(".vscode").join("prompts")
+                } else {
+                    PathBuf::from(".github").join("prompts")
+                };
+                Some(base.join(format!("{}.prompt.md", asset_id)))
+            }
+            Target::GeminiCli => {
+                let base = if is_user_scope {
+                    PathBuf::from("~").join(".gemini").join("commands")
+                } else {
+                    PathBuf::from(".gemini").join("commands")
+                };
+                Some(base.join(format!("{}.toml", asset_id)))
+            }
             _ => None, // Other targets don't have slash commands
         }
     }
@@ -383,19 +414,15 @@ mod tests {
     }
 
     #[test]
-    fn path_generator_codex_both_scopes() {
-        // Project scope
-        let path = PathGenerator::rules_path(Target::Codex, "test-rule", false);
-        let expected = PathBuf::from(".codex").join("prompts").join("test-rule.md");
-        assert_eq!(path, Some(expected));
-
-        // User scope
-        let path = PathGenerator::rules_path(Target::Codex, "test-rule", true);
-        let expected = PathBuf::from("~")
-            .join(".codex")
-            .join("prompts")
-            .join("test-rule.md");
-        assert_eq!(path, Some(expected));
+    fn path_generator_codex_policies_have_no_file() {
+        assert_eq!(
+            PathGenerator::rules_path(Target::Codex, "test-rule", false),
+            None
+        );
+        assert_eq!(
+            PathGenerator::rules_path(Target::Codex, "test-rule", true),
+            None
+        );
     }
 
     #[test]
@@ -418,6 +445,15 @@ mod tests {
         assert_eq!(path, Some(expected));
     }
 
+    #[test]
+    fn path_generator_vscode_prompts() {
+        let path = PathGenerator::commands_path(Target::VSCode, "my-cmd", false);
+        let expected = PathBuf::from(".github")
+            .join("prompts")
+            .join("my-cmd.prompt.md");
+        assert_eq!(path, Some(expected));
+    }
+
     #[test]
     fn path_generator_cursor_commands() {
         let path = PathGenerator::commands_path(Target::Cursor, "my-cmd", false);
@@ -427,10 +463,6 @@ mod tests {
 
     #[test]
     fn path_generator_no_commands_for_other_targets() {
-        assert_eq!(
-            PathGenerator::commands_path(Target::VSCode, "cmd", false),
-            None
-        );
         assert_eq!(
             PathGenerator::commands_path(Target::Antigravity, "cmd", false),
             None
@@ -8,10 +8,13 @@
 //!
 //! - `cursor_needs_commands`: When Cursor is the only target (no Claude Code),
 //!   generate commands for Action/Agent assets to `.cursor/commands/`
+//! - `post_compile_assets`: When OpenCode is compiled too, Codex leaves the project
+//!   policies both targets share to OpenCode's `AGENTS.md` region
 
+use std::borrow::Cow;
 use std::path::PathBuf;
 
-use crate::domain::entities::{Asset, AssetKind, OutputFile};
+use crate::domain::entities::{assets_for_target, Asset, AssetKind, OutputFile};
 use crate::domain::ports::TargetAdapter;
 use crate::domain::value_objects::{Scope, Target};
 
@@ -76,6 +79,34 @@ impl CompilerService {
         has_cursor && !has_claude_code
     }
 
+    /// Assets `target` aggregates in `post_compile()`, as seen by `target`
+    ///
+    /// Codex and OpenCode both load the project `AGENTS.md`. When OpenCode is compiled
+    /// too, project policies targeting both are written only to its region, so the
+    /// Codex region keeps just the policies OpenCode does not receive.
+    pub fn post_compile_assets(
+        assets: &[Asset],
+        target: Target,
+        has_opencode: bool,
+    ) -> Cow<'_, [Asset]> {
+        let assets = assets_for_target(assets, target);
+        if target != Target::Codex || !has_opencode {
+            return assets;
+        }
+
+        Cow::Owned(
+            assets
+                .iter()
+                .filter(|a| {
+                    !(a.kind() == AssetKind::Policy
+                        && a.scope() == Scope::Project
+                        && a.effective_targets().contains(&Target::OpenCode))
+                })
+                .cloned()
+                .collect(),
+        )
+    }
+
     /// Generate Cursor command file content (static version)
     ///
     /// Uses the same format as Claude Code commands for consistency.
@@ -190,6 +221,10 @@ impl CompilerService {
                     continue;
                 }
 
+                // Apply this target's `overrides:` frontmatter
+                let asset = asset.for_target(adapter.target());
+                let asset = asset.as_ref();
+
                 match adapter.compile(asset) {
                     Ok(adapter_outputs) => outputs.extend(adapter_outputs),
                     Err(e) => {
@@ -222,8 +257,12 @@ impl CompilerService {
         }
 
         // Post-compile for each adapter (e.g., generate AGENTS.md)
+        let has_opencode = active_adapters
+            .iter()
+            .any(|a| a.target() == Target::OpenCode);
         for adapter in &active_adapters {
-            match adapter.post_compile(assets) {
+            let post_assets = Self::post_compile_assets(assets, adapter.target(), has_opencode);
+            match adapter.post_compile(&post_assets) {
                 Ok(post_outputs) => outputs.extend(post_outputs),
                 Err(e) => {
                     return Err(CompileError {
@@ -382,6 +421,28 @@ mod tests {
         assert!(content.contains("# Content"));
     }
 
+    #[test]
+    fn codex_leaves_shared_project_policies_to_opencode() {
+        let shared = create_policy_asset("shared", "Shared", "Both");
+        let codex_only =
+            create_policy_asset("codex-only", "Codex", "Codex").with_targets(vec![Target::Codex]);
+        let user = create_policy_asset("user", "User", "User").with_scope(Scope::User);
+        let assets = vec![shared, codex_only, user];
+
+        let codex = CompilerService::post_compile_assets(&assets, Target::Codex, true);
+        let ids: Vec<&str> = codex.iter().map(|a| a.id()).collect();
+        assert_eq!(ids, ["codex-only", "user"]);
+
+        assert_eq!(
+            CompilerService::post_compile_assets(&assets, Target::Codex, false).len(),
+            3
+        );
+        assert_eq!(
+            CompilerService::post_compile_assets(&assets, Target::OpenCode, true).len(),
+            3
+        );
+    }
+
     // === TDD 1.4: compile 核心逻辑 ===
 
     #[test]
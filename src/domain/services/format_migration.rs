@@ -0,0 +1,301 @@
+//! Format Migration Domain Service
+//!
+//! Rewrites promptpack sources from one [`FormatVersion`] to a newer one.
+//!
+//! Migrations are a registry of [`MigrationStep`]s, each upgrading by one version.
+//! A step rewrites asset frontmatter (the YAML between `---` fences) and the layer's
+//! `config.toml`. Rewrites are textual and keep comments and formatting intact.
+//!
+//! # Built-in steps
+//!
+//! None yet: [`FormatVersion::CURRENT`] is the first versioned format. A step is
+//! added together with the format version that breaks the schema.
+
+use toml_edit::{DocumentMut, Item, Table};
+
+use crate::domain::value_objects::FormatVersion;
+
+/// Error returned when a migration cannot be planned or applied
+#[derive(Debug, thiserror::Error, PartialEq, Eq)]
+pub enum FormatMigrationError {
+    #[error("cannot migrate from format {from} down to {to}")]
+    Downgrade {
+        from: FormatVersion,
+        to: FormatVersion,
+    },
+
+    #[error("format {to} is newer than this version of Calvin supports (latest: {latest})")]
+    Unsupported {
+        to: FormatVersion,
+        latest: FormatVersion,
+    },
+
+    #[error("no migration path from format {from} to {to}")]
+    NoPath {
+        from: FormatVersion,
+        to: FormatVersion,
+    },
+
+    #[error("config.toml cannot be parsed: {0}")]
+    InvalidConfig(String),
+}
+
+/// One migration step between two adjacent format versions
+pub struct MigrationStep {
+    from: FormatVersion,
+    to: FormatVersion,
+    summary: &'static str,
+    frontmatter: fn(&str) -> String,
+    config: fn(&mut DocumentMut),
+}
+
+impl MigrationStep {
+    /// Create a migration step
+    ///
+    /// `frontmatter` receives the YAML between the `---` fences and returns the
+    /// rewritten YAML; `config` edits the layer's `config.toml` in place.
+    pub fn new(
+        from: FormatVersion,
+        to: FormatVersion,
+        summary: &'static str,
+        frontmatter: fn(&str) -> String,
+        config: fn(&mut DocumentMut),
+    ) -> Self {
+        Self {
+            from,
+            to,
+            summary,
+            frontmatter,
+            config,
+        }
+    }
+
+    /// Version this step migrates from
+    pub fn from(&self) -> FormatVersion {
+        self.from
+    }
+
+    /// Version this step migrates to
+    pub fn to(&self) -> FormatVersion {
+        self.to
+    }
+
+    /// Human-readable summary of the changes
+    pub fn summary(&self) -> &'static str {
+        self.summary
+    }
+}
+
+/// Registry of migration steps
+pub struct FormatMigrator {
+    steps: Vec<MigrationStep>,
+    latest: FormatVersion,
+}
+
+impl Default for FormatMigrator {
+    fn default() -> Self {
+        Self::new(Vec::new())
+    }
+}
+
+impl FormatMigrator {
+    /// Create a migrator from an explicit list of steps, migrating up to
+    /// [`FormatVersion::CURRENT`]
+    pub fn new(steps: Vec<MigrationStep>) -> Self {
+        Self {
+            steps,
+            latest: FormatVersion::CURRENT,
+        }
+    }
+
+    /// Builder: allow migrating up to `latest` instead of [`FormatVersion::CURRENT`]
+    pub fn with_latest(mut self, latest: FormatVersion) -> Self {
+        self.latest = latest;
+        self
+    }
+
+    /// Plan the chain of steps migrating `from` to `to`
+    pub fn plan(
+        &self,
+        from: FormatVersion,
+        to: FormatVersion,
+    ) -> Result<MigrationPlan<'_>, FormatMigrationError> {
+        if to > self.latest {
+            return Err(FormatMigrationError::Unsupported {
+                to,
+                latest: self.latest,
+            });
+        }
+        if to < from {
+            return Err(FormatMigrationError::Downgrade { from, to });
+        }
+
+        let mut steps = Vec::new();
+        let mut current = from;
+        while current < to {
+            let step = self
+                .steps
+                .iter()
+                .find(|s| s.from == current && s.to <= to)
+                .ok_or(FormatMigrationError::NoPath { from, to })?;
+            steps.push(step);
+            current = step.to;
+        }
+
+        Ok(MigrationPlan { to, steps })
+    }
+}
+
+/// An ordered chain of migration steps
+pub struct MigrationPlan<'a> {
+    to: FormatVersion,
+    steps: Vec<&'a MigrationStep>,
+}
+
+impl MigrationPlan<'_> {
+    /// Steps in application order
+    pub fn steps(&self) -> &[&MigrationStep] {
+        &self.steps
+    }
+
+    /// Rewrite an asset source file (frontmatter only; the body is untouched)
+    pub fn migrate_source(&self, content: &str) -> String {
+        let Some((yaml_start, yaml_end)) = frontmatter_span(content) else {
+            return content.to_string();
+        };
+
+        let mut yaml = content[yaml_start..yaml_end].to_string();
+        for step in &self.steps {
+            yaml = (step.frontmatter)(&yaml);
+        }
+
+        format!("{}{}{}", &content[..yaml_start], yaml, &content[yaml_end..])
+    }
+
+    /// Rewrite a layer's `config.toml` and declare the target format version
+    ///
+    /// `content` may be empty (layer without a config file).
+    pub fn migrate_config(&self, content: &str) -> Result<String, FormatMigrationError> {
+        let mut doc: DocumentMut = content.parse().map_err(|e: toml_edit::TomlError| {
+            FormatMigrationError::InvalidConfig(e.to_string())
+        })?;
+
+        for step in &self.steps {
+            (step.config)(&mut doc);
+        }
+        set_format_version(&mut doc, self.to);
+
+        Ok(doc.to_string())
+    }
+}
+
+/// Byte range of the YAML between the opening and closing `---` fences
+fn frontmatter_span(content: &str) -> Option<(usize, usize)> {
+    let after_open = content
+        .strip_prefix("---\r\n")
+        .or_else(|| content.strip_prefix("---\n"))?;
+    let yaml_start = content.len() - after_open.len();
+
+    let mut offset = yaml_start;
+    for line in after_open.split_inclusive('\n') {
+        if line.trim_end() == "---" {
+            return Some((yaml_start, offset));
+        }
+        offset += line.len();
+    }
+    None
+}
+
+fn set_format_version(doc: &mut DocumentMut, version: FormatVersion) {
+    let root = doc.as_table_mut();
+    if !root.get("format").is_some_and(Item::is_table_like) {
+        let mut table = Table::new();
+        // Keep `[format]` at the top of the file, where `calvin init` puts it,
+        // below the file's header comment (the prefix of the first table).
+        table.set_position(0);
+        let only_tables = root
+            .iter()
+            .all(|(_, item)| item.is_table() || item.is_array_of_tables());
+        let first_table = root
+            .iter_mut()
+            .filter_map(|(_, item)| item.as_table_mut())
+            .min_by_key(|t| t.position());
+        if let Some(first) = first_table.filter(|_| only_tables) {
+            let header = first
+                .decor()
+                .prefix()
+                .and_then(|prefix| prefix.as_str())
+                .map(str::to_string);
+            if let Some(header) = header.filter(|h| h.trim_start().starts_with('#')) {
+                table.decor_mut().set_prefix(header);
+                first.decor_mut().set_prefix("\n");
+            }
+        }
+        root.insert("format", Item::Table(table));
+    }
+    if let Some(format) = root.get_mut("format").and_then(Item::as_table_like_mut) {
+        format.insert("version", toml_edit::value(version.to_string()));
+    }
+}
+
+/// A format 1.0 → 1.1 step exercising the registry before a real schema break exists
+///
+/// Renames `permissionMode` to `permission-mode` and moves a `[security] deny` list
+/// to `[security.deny] patterns`.
+#[cfg(test)]
+pub(crate) mod test_steps {
+    use super::*;
+
+    /// Migrator with the test step, allowing migration up to format 1.1
+    pub(crate) fn migrator() -> FormatMigrator {
+        FormatMigrator::new(vec![MigrationStep::new(
+            FormatVersion::new(1, 0),
+            FormatVersion::new(1, 1),
+            "Rename `permissionMode` to `permission-mode`; move `[security] deny` list to `[security.deny] patterns`",
+            frontmatter,
+            config,
+        )])
+        .with_latest(FormatVersion::new(1, 1))
+    }
+
+    /// Rename a top-level YAML key, unless the new key is already present
+    fn rename_top_level_key(yaml: &str, old: &str, new: &str) -> String {
+        let is_key = |line: &str, key: &str| {
+            line.strip_prefix(key)
+                .is_some_and(|rest| rest.trim_start().starts_with(':'))
+        };
+        if yaml.lines().any(|line| is_key(line, new)) {
+            return yaml.to_string();
+        }
+
+        yaml.split_inclusive('\n')
+            .map(|line| {
+                if is_key(line, old) {
+                    format!("{}{}", new, &line[old.len()..])
+                } else {
+                    line.to_string()
+                }
+            })
+            .collect()
+    }
+
+    fn frontmatter(yaml: &str) -> String {
+        rename_top_level_key(yaml, "permissionMode", "permission-mode")
+    }
+
+    fn config(doc: &mut DocumentMut) {
+        let Some(security) = doc.get_mut("security").and_then(Item::as_table_like_mut) else {
+            return;
+        };
+        let Some(patterns) = security.get("deny").and_then(Item::as_array).cloned() else {
+            return;
+        };
+
+        let mut deny = Table::new();
+        deny.insert("patterns", toml_edit::value(patterns));
+        security.insert("deny", Item::Table(deny));
+    }
+}
+
+#[cfg(test)]
+mod tests;
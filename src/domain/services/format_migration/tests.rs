@@ -0,0 +1,189 @@
+use super::*;
+
+fn v(major: u32, minor: u32) -> FormatVersion {
+    FormatVersion::new(major, minor)
+}
+
+// === Planning ===
+
+#[test]
+fn plan_chains_steps_up_to_target() {
+    let migrator = test_steps::migrator();
+    let plan = migrator.plan(v(1, 0), v(1, 1)).unwrap();
+
+    assert_eq!(plan.steps().len(), 1);
+    assert_eq!(plan.steps()[0].from(), v(1, 0));
+    assert_eq!(plan.steps()[0].to(), v(1, 1));
+}
+
+#[test]
+fn default_migrator_stops_at_current_format() {
+    let migrator = FormatMigrator::default();
+    let plan = migrator
+        .plan(FormatVersion::LEGACY, FormatVersion::CURRENT)
+        .unwrap();
+    assert!(plan.steps().is_empty());
+
+    let err = migrator.plan(v(1, 0), v(1, 1)).err().unwrap();
+    assert_eq!(
+        err,
+        FormatMigrationError::Unsupported {
+            to: v(1, 1),
+            latest: FormatVersion::CURRENT
+        }
+    );
+}
+
+#[test]
+fn plan_to_same_version_is_empty() {
+    let migrator = test_steps::migrator();
+    let plan = migrator.plan(v(1, 1), v(1, 1)).unwrap();
+    assert!(plan.steps().is_empty());
+}
+
+#[test]
+fn plan_rejects_downgrade() {
+    let migrator = test_steps::migrator();
+    let err = migrator.plan(v(1, 1), v(1, 0)).err().unwrap();
+    assert!(matches!(err, FormatMigrationError::Downgrade { .. }));
+}
+
+#[test]
+fn plan_rejects_unsupported_target() {
+    let migrator = test_steps::migrator();
+    let err = migrator.plan(v(1, 0), v(9, 0)).err().unwrap();
+    assert!(matches!(err, FormatMigrationError::Unsupported { .. }));
+}
+
+#[test]
+fn plan_reports_missing_path() {
+    let migrator = FormatMigrator::new(Vec::new()).with_latest(v(1, 1));
+    let err = migrator.plan(v(1, 0), v(1, 1)).err().unwrap();
+    assert_eq!(
+        err,
+        FormatMigrationError::NoPath {
+            from: v(1, 0),
+            to: v(1, 1)
+        }
+    );
+}
+
+// === Sources ===
+
+#[test]
+fn migrate_source_renames_legacy_permission_mode() {
+    let migrator = test_steps::migrator();
+    let plan = migrator.plan(v(1, 0), v(1, 1)).unwrap();
+    let source = "---\ndescription: Reviewer\nkind: agent\npermissionMode: plan # keep\n---\n\npermissionMode: in body\n";
+
+    let migrated = plan.migrate_source(source);
+
+    assert_eq!(
+        migrated,
+        "---\ndescription: Reviewer\nkind: agent\npermission-mode: plan # keep\n---\n\npermissionMode: in body\n"
+    );
+}
+
+#[test]
+fn migrate_source_keeps_existing_new_key() {
+    let migrator = test_steps::migrator();
+    let plan = migrator.plan(v(1, 0), v(1, 1)).unwrap();
+    let source = "---\ndescription: X\npermission-mode: plan\npermissionMode: default\n---\nBody\n";
+
+    assert_eq!(plan.migrate_source(source), source);
+}
+
+#[test]
+fn migrate_source_without_frontmatter_is_unchanged() {
+    let migrator = test_steps::migrator();
+    let plan = migrator.plan(v(1, 0), v(1, 1)).unwrap();
+    assert_eq!(
+        plan.migrate_source("# Just markdown\n"),
+        "# Just markdown\n"
+    );
+}
+
+// === Config ===
+
+#[test]
+fn migrate_config_moves_legacy_deny_list_and_bumps_version() {
+    let migrator = test_steps::migrator();
+    let plan = migrator.plan(v(1, 0), v(1, 1)).unwrap();
+    let config = r#"# Team config
+[format]
+version = "1.0"
+
+[security]
+mode = "strict" # enforce
+deny = ["*.secret"]
+"#;
+
+    let migrated = plan.migrate_config(config).unwrap();
+
+    assert!(migrated.contains("# Team config"), "{}", migrated);
+    assert!(
+        migrated.contains("mode = \"strict\" # enforce"),
+        "{}",
+        migrated
+    );
+    let parsed: ::toml::Table = ::toml::from_str(&migrated).unwrap();
+    assert_eq!(parsed["format"]["version"].as_str(), Some("1.1"));
+    assert_eq!(
+        parsed["security"]["deny"]["patterns"][0].as_str(),
+        Some("*.secret")
+    );
+}
+
+#[test]
+fn migrate_config_adds_format_section_when_missing() {
+    let migrator = test_steps::migrator();
+    let plan = migrator.plan(v(1, 0), v(1, 1)).unwrap();
+
+    let migrated = plan
+        .migrate_config("[targets]\nenabled = [\"cursor\"]\n")
+        .unwrap();
+
+    assert!(
+        migrated.starts_with("[format]\nversion = \"1.1\"\n"),
+        "{}",
+        migrated
+    );
+    assert!(migrated.contains("[targets]"));
+}
+
+#[test]
+fn migrate_config_keeps_header_comment_above_format_section() {
+    let migrator = FormatMigrator::default();
+    let plan = migrator
+        .plan(FormatVersion::LEGACY, FormatVersion::CURRENT)
+        .unwrap();
+
+    let migrated = plan
+        .migrate_config("# Team settings\n[targets]\nenabled = [\"cursor\"]\n")
+        .unwrap();
+
+    assert_eq!(
+        migrated,
+        "# Team settings\n[format]\nversion = \"1.0\"\n\n[targets]\nenabled = [\"cursor\"]\n"
+    );
+}
+
+#[test]
+fn migrate_config_is_idempotent() {
+    let migrator = test_steps::migrator();
+    let plan = migrator.plan(v(1, 0), v(1, 1)).unwrap();
+
+    let once = plan.migrate_config("[security]\ndeny = [\"a\"]\n").unwrap();
+    let twice = plan.migrate_config(&once).unwrap();
+    assert_eq!(once, twice);
+}
+
+#[test]
+fn migrate_config_rejects_invalid_toml() {
+    let migrator = test_steps::migrator();
+    let plan = migrator.plan(v(1, 0), v(1, 1)).unwrap();
+    assert!(matches!(
+        plan.migrate_config("[oops"),
+        Err(FormatMigrationError::InvalidConfig(_))
+    ));
+}
@@ -1,13 +1,17 @@
 //! Layer merger
 //!
 //! Merges assets from multiple layers according to priority rules:
-//! - Same asset ID: higher priority layer wins (replaces entirely)
+//! - Same asset ID: higher priority layer wins; by default it replaces the lower
+//!   asset entirely, `merge: append | prepend | frontmatter-only` combines them
 //! - Different asset IDs: all are kept
+//! - MCP servers are merged by name with the same priority rules
+//! - Partials are merged by include path with the same priority rules
 
-use std::collections::HashMap;
-use std::path::PathBuf;
+use std::collections::{BTreeMap, HashMap};
+use std::path::{Path, PathBuf};
 
-use crate::domain::entities::{Asset, Layer};
+use crate::domain::entities::{Asset, ContentOrigin, Layer, McpServer, Partial};
+use crate::domain::value_objects::MergeStrategy;
 
 #[derive(Debug, Clone, PartialEq)]
 pub struct MergedAsset {
@@ -16,6 +20,18 @@ pub struct MergedAsset {
     pub source_layer_path: PathBuf,
     pub source_file: PathBuf,
     pub overrides: Option<String>,
+    /// Layers whose content is part of the merged asset, lowest first
+    ///
+    /// Just the winning layer unless higher layers used a non-`replace` strategy.
+    pub contributing_layers: Vec<String>,
+}
+
+/// A partial and the layer file it was resolved from
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct MergedPartial {
+    pub partial: Partial,
+    pub source_layer: String,
+    pub source_file: PathBuf,
 }
 
 #[derive(Debug, Clone, PartialEq, Eq)]
@@ -23,41 +39,83 @@ pub struct OverrideInfo {
     pub asset_id: String,
     pub from_layer: String,
     pub by_layer: String,
+    /// How the higher layer's asset was merged onto the lower one
+    pub strategy: MergeStrategy,
+}
+
+impl OverrideInfo {
+    /// Whether the lower layer's asset was discarded (`merge: replace`)
+    pub fn is_replacement(&self) -> bool {
+        !self.strategy.keeps_lower()
+    }
 }
 
 #[derive(Debug, Clone, Default, PartialEq)]
 pub struct MergeResult {
     pub assets: HashMap<String, MergedAsset>,
     pub overrides: Vec<OverrideInfo>,
+    /// MCP servers, sorted by name (higher layer wins on the same name)
+    pub mcp_servers: Vec<McpServer>,
+    /// Partials by include path (higher layer wins on the same path)
+    pub partials: BTreeMap<String, MergedPartial>,
 }
 
 pub fn merge_layers(layers: &[Layer]) -> MergeResult {
     let mut merged: HashMap<String, MergedAsset> = HashMap::new();
     let mut overrides: Vec<OverrideInfo> = Vec::new();
+    let mut mcp_servers: BTreeMap<String, McpServer> = BTreeMap::new();
+    let mut partials: BTreeMap<String, MergedPartial> = BTreeMap::new();
 
     for layer in layers {
+        for server in &layer.mcp_servers {
+            mcp_servers.insert(server.name().to_string(), server.clone());
+        }
+
+        for partial in &layer.partials {
+            partials.insert(
+                partial.path().to_string(),
+                MergedPartial {
+                    partial: partial.clone(),
+                    source_layer: layer.name.clone(),
+                    source_file: layer.path.resolved().join(partial.path()),
+                },
+            );
+        }
+
         for asset in &layer.assets {
             let key = asset_key(asset);
-            let previous_source_layer = merged.get(&key).map(|e| e.source_layer.clone());
-            if let Some(from_layer) = previous_source_layer.clone() {
+            let source_layer_path = layer.path.resolved().clone();
+            let source_file = source_layer_path.join(asset.source_path());
+            let strategy = asset.merge();
+
+            let previous = merged.remove(&key);
+            let (merged_asset, mut contributing_layers) = match &previous {
+                Some(lower) if strategy.keeps_lower() => (
+                    merge_onto(asset, &source_file, lower),
+                    lower.contributing_layers.clone(),
+                ),
+                _ => (asset.clone(), Vec::new()),
+            };
+            contributing_layers.push(layer.name.clone());
+
+            if let Some(lower) = &previous {
                 overrides.push(OverrideInfo {
                     asset_id: key.clone(),
-                    from_layer,
+                    from_layer: lower.source_layer.clone(),
                     by_layer: layer.name.clone(),
+                    strategy,
                 });
             }
 
-            let source_layer_path = layer.path.resolved().clone();
-            let source_file = source_layer_path.join(asset.source_path());
-
             merged.insert(
-                key.clone(),
+                key,
                 MergedAsset {
-                    asset: asset.clone(),
+                    asset: merged_asset,
                     source_layer: layer.name.clone(),
                     source_layer_path,
                     source_file,
-                    overrides: previous_source_layer,
+                    overrides: previous.map(|lower| lower.source_layer),
+                    contributing_layers,
                 },
             );
         }
@@ -66,7 +124,62 @@ pub fn merge_layers(layers: &[Layer]) -> MergeResult {
     MergeResult {
         assets: merged,
         overrides,
+        mcp_servers: mcp_servers.into_values().collect(),
+        partials,
+    }
+}
+
+/// Combine `higher` (read from `higher_file`) with the merged asset below it
+///
+/// The result keeps `higher`'s frontmatter; the body follows `higher.merge()`.
+fn merge_onto(higher: &Asset, higher_file: &Path, lower: &MergedAsset) -> Asset {
+    let lower_part = (
+        lower.asset.content(),
+        origins(&lower.asset, &lower.source_file),
+    );
+    let higher_part = (higher.content(), origins(higher, higher_file));
+
+    let (content, origins) = match higher.merge() {
+        MergeStrategy::Replace => return higher.clone(),
+        MergeStrategy::FrontmatterOnly => (lower_part.0.to_string(), lower_part.1),
+        MergeStrategy::Append => concat(lower_part, higher_part),
+        MergeStrategy::Prepend => concat(higher_part, lower_part),
+    };
+    higher.clone().with_merged_content(content, origins)
+}
+
+fn origins(asset: &Asset, source_file: &Path) -> Vec<ContentOrigin> {
+    if !asset.content_origins().is_empty() {
+        return asset.content_origins().to_vec();
+    }
+    vec![ContentOrigin {
+        line: 1,
+        source_file: source_file.to_path_buf(),
+        source_line: asset.content_line(),
+    }]
+}
+
+/// Join two bodies with a blank line, shifting the second body's origins
+fn concat(
+    (first, first_origins): (&str, Vec<ContentOrigin>),
+    (second, second_origins): (&str, Vec<ContentOrigin>),
+) -> (String, Vec<ContentOrigin>) {
+    let first = first.trim_end_matches('\n');
+    if first.trim().is_empty() {
+        return (second.to_string(), second_origins);
     }
+    if second.trim().is_empty() {
+        return (format!("{first}\n"), first_origins);
+    }
+
+    // `second` starts after the first body's lines and one blank line
+    let offset = first.lines().count() + 1;
+    let mut origins = first_origins;
+    origins.extend(second_origins.into_iter().map(|origin| ContentOrigin {
+        line: origin.line + offset,
+        ..origin
+    }));
+    (format!("{first}\n\n{second}"), origins)
 }
 
 fn asset_key(asset: &Asset) -> String {
@@ -74,6 +187,7 @@ fn asset_key(asset: &Asset) -> String {
 
     match asset.kind() {
         AssetKind::Skill => format!("skill:{}", asset.id().to_lowercase()),
+        AssetKind::Hook => format!("hook:{}", asset.id().to_lowercase()),
         _ => asset.id().to_lowercase(),
     }
 }
@@ -118,3 +118,152 @@ fn merge_case_insensitive_ids_conflict() {
     );
     assert!(result.assets.contains_key("reviewer"));
 }
+
+#[test]
+fn merge_mcp_servers_higher_layer_wins_by_name() {
+    use crate::domain::entities::McpServer;
+
+    let user_layer =
+        Layer::new("user", layer_path("user"), LayerType::User).with_mcp_servers(vec![
+            McpServer::new("github", "user-cmd"),
+            McpServer::new("fs", "npx"),
+        ]);
+    let project_layer = Layer::new("project", layer_path("project"), LayerType::Project)
+        .with_mcp_servers(vec![McpServer::new("github", "npx")]);
+
+    let result = merge_layers(&[user_layer, project_layer]);
+
+    let names: Vec<&str> = result.mcp_servers.iter().map(|s| s.name()).collect();
+    assert_eq!(names, vec!["fs", "github"]);
+    assert_eq!(result.mcp_servers[1].command(), "npx");
+}
+
+#[test]
+fn merge_partials_higher_layer_wins_by_path() {
+    use crate::domain::entities::Partial;
+
+    let user_layer = Layer::new("user", layer_path("user"), LayerType::User).with_partials(vec![
+        Partial::new("partials/secrets.md", "user secrets"),
+        Partial::new("partials/testing.md", "user testing"),
+    ]);
+    let project_layer = Layer::new("project", layer_path("project"), LayerType::Project)
+        .with_partials(vec![Partial::new("partials/secrets.md", "project secrets")]);
+
+    let result = merge_layers(&[user_layer, project_layer]);
+
+    assert_eq!(result.partials.len(), 2);
+    let secrets = &result.partials["partials/secrets.md"];
+    assert_eq!(secrets.partial.content(), "project secrets");
+    assert_eq!(secrets.source_layer, "project");
+    assert_eq!(
+        secrets.source_file,
+        PathBuf::from("project/partials/secrets.md")
+    );
+    assert_eq!(result.partials["partials/testing.md"].source_layer, "user");
+}
+
+fn layer_with(name: &str, layer_type: LayerType, assets: Vec<Asset>) -> Layer {
+    Layer::new(name, layer_path(name), layer_type).with_assets(assets)
+}
+
+#[test]
+fn merge_append_combines_bodies_and_records_contributors() {
+    use crate::domain::value_objects::MergeStrategy;
+
+    let user = layer_with(
+        "user",
+        LayerType::User,
+        vec![create_asset("code-style", "- Use rustfmt\n").with_content_line(5)],
+    );
+    let project = layer_with(
+        "project",
+        LayerType::Project,
+        vec![Asset::new(
+            "code-style",
+            "actions/code-style.md",
+            "project desc",
+            "- No unwrap in src/\n",
+        )
+        .with_merge(MergeStrategy::Append)
+        .with_content_line(6)],
+    );
+
+    let result = merge_layers(&[user, project]);
+
+    let merged = &result.assets["code-style"];
+    assert_eq!(
+        merged.asset.content(),
+        "- Use rustfmt\n\n- No unwrap in src/\n"
+    );
+    assert_eq!(merged.asset.description(), "project desc");
+    assert_eq!(merged.contributing_layers, vec!["user", "project"]);
+    assert_eq!(merged.overrides, Some("user".to_string()));
+    assert_eq!(result.overrides[0].strategy, MergeStrategy::Append);
+    assert!(!result.overrides[0].is_replacement());
+
+    // Lines map back to the file they came from
+    assert_eq!(
+        merged.asset.source_location(1),
+        (std::path::Path::new("user/actions/code-style.md"), 5)
+    );
+    assert_eq!(
+        merged.asset.source_location(3),
+        (std::path::Path::new("project/actions/code-style.md"), 6)
+    );
+}
+
+#[test]
+fn merge_prepend_and_frontmatter_only() {
+    use crate::domain::value_objects::MergeStrategy;
+
+    let base = || layer_with("user", LayerType::User, vec![create_asset("style", "base")]);
+    let higher = |strategy| {
+        layer_with(
+            "project",
+            LayerType::Project,
+            vec![Asset::new("style", "actions/style.md", "new desc", "extra").with_merge(strategy)],
+        )
+    };
+
+    let prepended = merge_layers(&[base(), higher(MergeStrategy::Prepend)]);
+    assert_eq!(prepended.assets["style"].asset.content(), "extra\n\nbase");
+
+    let frontmatter_only = merge_layers(&[base(), higher(MergeStrategy::FrontmatterOnly)]);
+    let merged = &frontmatter_only.assets["style"].asset;
+    assert_eq!(merged.content(), "base");
+    assert_eq!(merged.description(), "new desc");
+}
+
+#[test]
+fn merge_replace_resets_contributors_and_append_without_base_is_plain() {
+    use crate::domain::value_objects::MergeStrategy;
+
+    let user = layer_with("user", LayerType::User, vec![create_asset("style", "user")]);
+    let custom = layer_with(
+        "custom",
+        LayerType::Custom,
+        vec![create_asset("style", "custom").with_merge(MergeStrategy::Append)],
+    );
+    let project = layer_with(
+        "project",
+        LayerType::Project,
+        vec![create_asset("style", "project")],
+    );
+
+    let chained = merge_layers(&[user.clone(), custom.clone()]);
+    assert_eq!(
+        chained.assets["style"].contributing_layers,
+        vec!["user", "custom"]
+    );
+
+    let replaced = merge_layers(&[user, custom.clone(), project]);
+    assert_eq!(replaced.assets["style"].asset.content(), "project");
+    assert_eq!(
+        replaced.assets["style"].contributing_layers,
+        vec!["project"]
+    );
+
+    let alone = merge_layers(&[custom]);
+    assert_eq!(alone.assets["style"].asset.content(), "custom");
+    assert!(alone.overrides.is_empty());
+}
@@ -6,6 +6,7 @@ use std::collections::HashSet;
 use std::path::{Path, PathBuf};
 
 use crate::domain::entities::{Layer, LayerPath, LayerType};
+use crate::domain::value_objects::RemoteLayer;
 
 #[derive(Debug, Clone, Default, PartialEq)]
 pub struct LayerResolution {
@@ -122,6 +123,15 @@ impl LayerResolver {
 
         // 2) Additional layers (middle priority)
         for (idx, layer_path) in self.additional_layers.iter().enumerate() {
+            // Remote sources must be fetched into the layer cache first
+            // (see `application::remote_layers`)
+            if RemoteLayer::from_path(layer_path).is_some() {
+                resolution.warnings.push(format!(
+                    "Remote layer not fetched: {}",
+                    layer_path.display()
+                ));
+                continue;
+            }
             let expanded = expand_home(layer_path);
             let name = format!("custom-{}", idx);
             if let Some(layer) = self.try_add_layer(
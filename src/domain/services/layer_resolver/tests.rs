@@ -37,6 +37,23 @@ fn resolve_all_layers_in_priority_order() {
     assert_eq!(resolution.layers[2].layer_type, LayerType::Project);
 }
 
+#[test]
+fn unfetched_remote_layers_are_skipped_with_warning() {
+    let dir = tempdir().unwrap();
+    let project_root = dir.path().join("project");
+    std::fs::create_dir_all(project_root.join(".promptpack")).unwrap();
+
+    let resolver = LayerResolver::new(project_root)
+        .with_additional_layers(vec![PathBuf::from("git+file:///srv/team.git#v1")]);
+
+    let resolution = resolver.resolve().unwrap();
+    assert_eq!(resolution.layers.len(), 1);
+    assert_eq!(
+        resolution.warnings,
+        vec!["Remote layer not fetched: git+file:///srv/team.git#v1".to_string()]
+    );
+}
+
 #[test]
 fn remote_mode_uses_only_project_layer() {
     let dir = tempdir().unwrap();
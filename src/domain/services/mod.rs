@@ -6,18 +6,29 @@
 mod compiler;
 mod compiler_service;
 mod differ;
+mod format_migration;
 mod layer_merger;
 mod layer_resolver;
 mod orphan_detector;
 mod planner;
+mod structured_merge;
+mod template;
 
 pub use compiler::{generate_comment_footer, generate_footer, CompilationResult, PathGenerator};
 pub use compiler_service::{CompileError, CompilerService};
 pub use differ::{DiffLine, DiffResult, DiffTag, Differ};
-pub use layer_merger::{merge_layers, MergeResult, MergedAsset, OverrideInfo};
+#[cfg(test)]
+pub(crate) use format_migration::test_steps as format_migration_test_steps;
+pub use format_migration::{FormatMigrationError, FormatMigrator, MigrationPlan, MigrationStep};
+pub use layer_merger::{merge_layers, MergeResult, MergedAsset, MergedPartial, OverrideInfo};
 pub use layer_resolver::{LayerResolution, LayerResolveError, LayerResolver};
 pub use orphan_detector::{
     extract_path_from_key, has_calvin_signature, OrphanDetectionResult, OrphanDetector, OrphanFile,
     CALVIN_SIGNATURES,
 };
 pub use planner::{ConflictReason, FileAction, PlannedFile, Planner, SyncPlan, TargetFileState};
+pub use structured_merge::{
+    extract_managed_regions, is_empty_document, managed_region, merge_document,
+    StructuredMergeError, CALVIN_TAG,
+};
+pub use template::{Include, Template, TemplateContext, TemplateError};
@@ -12,6 +12,9 @@ use std::path::PathBuf;
 pub enum FileAction {
     /// Write the file (new or safe update)
     Write,
+    /// Write structurally merged content: only Calvin's owned keys changed,
+    /// user keys in the existing file are preserved
+    Merge,
     /// Skip (already up-to-date)
     Skip,
     /// Conflict requiring resolution
@@ -53,9 +56,9 @@ impl PlannedFile {
         matches!(self.action, FileAction::Conflict(_))
     }
 
-    /// Check if this should be written
+    /// Check if this should be written (including merged writes)
     pub fn should_write(&self) -> bool {
-        matches!(self.action, FileAction::Write)
+        matches!(self.action, FileAction::Write | FileAction::Merge)
     }
 
     /// Check if this should be skipped
@@ -239,6 +242,40 @@ impl Planner {
             }
         }
     }
+
+    /// Plan sync for a structurally merged file
+    ///
+    /// Merged content already preserves everything outside Calvin's owned keys,
+    /// so there is nothing to conflict with: skip if unchanged, otherwise merge.
+    pub fn plan_merge(new_content_hash: &str, target_state: &TargetFileState) -> FileAction {
+        if target_state.current_hash.as_deref() == Some(new_content_hash) {
+            FileAction::Skip
+        } else {
+            FileAction::Merge
+        }
+    }
+
+    /// Plan sync for a file merged by managed region (e.g. `AGENTS.md`)
+    ///
+    /// Text outside the regions belongs to the user and never conflicts. The regions
+    /// themselves are Calvin's: if their current text no longer matches the hash
+    /// recorded in the lockfile, they were edited by hand.
+    pub fn plan_region(
+        new_content_hash: &str,
+        target_state: &TargetFileState,
+        current_region_hash: Option<&str>,
+        recorded_region_hash: Option<&str>,
+    ) -> FileAction {
+        if target_state.current_hash.as_deref() == Some(new_content_hash) {
+            return FileAction::Skip;
+        }
+        match (current_region_hash, recorded_region_hash) {
+            (Some(current), Some(recorded)) if current != recorded => {
+                FileAction::Conflict(ConflictReason::Modified)
+            }
+            _ => FileAction::Merge,
+        }
+    }
 }
 
 #[cfg(test)]
@@ -475,4 +512,65 @@ mod tests {
 
         assert_eq!(action, FileAction::Conflict(ConflictReason::Untracked));
     }
+
+    #[test]
+    fn plan_merge_skips_identical_content() {
+        let state = TargetFileState::exists_with_hash("sha256:same");
+        assert_eq!(Planner::plan_merge("sha256:same", &state), FileAction::Skip);
+    }
+
+    #[test]
+    fn plan_merge_never_conflicts() {
+        let untracked = TargetFileState::exists_with_hash("sha256:user");
+        assert_eq!(
+            Planner::plan_merge("sha256:new", &untracked),
+            FileAction::Merge
+        );
+        assert_eq!(
+            Planner::plan_merge("sha256:new", &TargetFileState::not_exists()),
+            FileAction::Merge
+        );
+    }
+
+    #[test]
+    fn plan_region_conflicts_only_on_region_edits() {
+        let state = TargetFileState::exists_with_hash("sha256:file");
+        assert_eq!(
+            Planner::plan_region("sha256:file", &state, Some("sha256:a"), Some("sha256:b")),
+            FileAction::Skip
+        );
+        assert_eq!(
+            Planner::plan_region("sha256:new", &state, Some("sha256:a"), Some("sha256:a")),
+            FileAction::Merge
+        );
+        assert_eq!(
+            Planner::plan_region(
+                "sha256:new",
+                &state,
+                Some("sha256:edited"),
+                Some("sha256:a")
+            ),
+            FileAction::Conflict(ConflictReason::Modified)
+        );
+        // Region missing from the file, or not tracked yet
+        assert_eq!(
+            Planner::plan_region("sha256:new", &state, None, Some("sha256:a")),
+            FileAction::Merge
+        );
+        assert_eq!(
+            Planner::plan_region("sha256:new", &state, Some("sha256:a"), None),
+            FileAction::Merge
+        );
+    }
+
+    #[test]
+    fn planned_merge_counts_as_write() {
+        let merge = PlannedFile::new(
+            PathBuf::from(".mcp.json"),
+            "{}".to_string(),
+            FileAction::Merge,
+        );
+        assert!(merge.should_write());
+        assert!(!merge.is_conflict());
+    }
 }
@@ -0,0 +1,513 @@
+//! Structured Merge Domain Service
+//!
+//! Merges Calvin-generated configuration documents into files that may also
+//! contain user-authored settings (e.g. `.claude/settings.json`, `opencode.json`,
+//! `~/.codex/config.toml`).
+//!
+//! Only the owned keys (see [`MergeSpec`](crate::domain::value_objects::MergeSpec))
+//! are touched. For each owned key:
+//! - present in the generated document → written into the existing document
+//!   (object members are replaced, array elements are appended if missing; JSON
+//!   object elements, matched by `slug` or, for hook groups tagged with
+//!   [`CALVIN_TAG`], by hook `command`, are replaced in place)
+//! - absent from the generated document → removed from the existing document,
+//!   pruning containers that become empty
+//!
+//! Everything else in the existing document is preserved. TOML documents keep
+//! their comments and formatting. YAML documents (e.g. a hand-written `.roomodes`)
+//! are merged like JSON and written back as YAML.
+//!
+//! Markdown documents (e.g. `CLAUDE.md`) are merged by managed region: each owned
+//! key names a region between `<!-- calvin:begin <key> -->` and `<!-- calvin:end -->`
+//! that replaces the existing region, is appended if missing, or is removed.
+//! Hand-written text outside the regions is never touched.
+
+use serde_json::Value;
+use std::fmt;
+
+use crate::domain::value_objects::{pointer_segments, DocumentFormat};
+
+/// Member marking a JSON array element as generated by Calvin (Claude Code hook
+/// groups); untagged elements are user-authored and never matched by an owned key
+pub const CALVIN_TAG: &str = "calvin";
+
+/// Error returned when a document cannot be merged structurally
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum StructuredMergeError {
+    /// The existing document cannot be parsed
+    InvalidExisting(String),
+    /// The generated document cannot be parsed
+    InvalidGenerated(String),
+    /// The existing document's root is not an object/table
+    NotAnObject,
+    /// An owned key's parent exists but is not an object/table/array
+    KeyConflict(String),
+}
+
+impl fmt::Display for StructuredMergeError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            StructuredMergeError::InvalidExisting(e) => {
+                write!(f, "existing file cannot be parsed: {}", e)
+            }
+            StructuredMergeError::InvalidGenerated(e) => {
+                write!(f, "generated content cannot be parsed: {}", e)
+            }
+            StructuredMergeError::NotAnObject => {
+                write!(f, "existing file is not an object")
+            }
+            StructuredMergeError::KeyConflict(key) => {
+                write!(f, "existing value at '{}' has an unexpected type", key)
+            }
+        }
+    }
+}
+
+impl std::error::Error for StructuredMergeError {}
+
+/// Merge the owned keys of a generated document into an existing one.
+///
+/// An empty (or whitespace-only) existing or generated document is treated as
+/// empty, so `merge_document(format, existing, "", keys)` removes `keys`.
+pub fn merge_document(
+    format: DocumentFormat,
+    existing: &str,
+    generated: &str,
+    owned_keys: &[String],
+) -> Result<String, StructuredMergeError> {
+    match format {
+        DocumentFormat::Json => json_merge::merge(existing, generated, owned_keys),
+        DocumentFormat::Yaml => json_merge::merge_yaml(existing, generated, owned_keys),
+        DocumentFormat::Toml => toml_merge::merge(existing, generated, owned_keys),
+        DocumentFormat::Markdown => markdown_merge::merge(existing, generated, owned_keys),
+    }
+}
+
+/// Wrap `body` in the markers of the managed region `owner`
+pub fn managed_region(owner: &str, body: &str) -> String {
+    format!(
+        "{}\n{}\n{}\n",
+        markdown_merge::begin_marker(owner),
+        body.trim(),
+        markdown_merge::END_MARKER
+    )
+}
+
+/// Text of the managed regions `owners` in a Markdown document, markers included
+///
+/// Regions are returned in `owners` order; missing (or unterminated) regions are
+/// skipped. Returns `None` if no region is present. Lockfile hashes of region-merged
+/// files cover only this text, so edits outside the regions do not count as changes.
+pub fn extract_managed_regions(content: &str, owners: &[String]) -> Option<String> {
+    let lines: Vec<&str> = content.lines().collect();
+    let regions: Vec<String> = owners
+        .iter()
+        .filter_map(|owner| markdown_merge::find(&lines, owner).ok().flatten())
+        .map(|(start, end)| lines[start..=end].join("\n"))
+        .collect();
+    if regions.is_empty() {
+        None
+    } else {
+        Some(format!("{}\n", regions.join("\n")))
+    }
+}
+
+/// Check whether a document has no keys left (e.g. after removing owned keys).
+pub fn is_empty_document(format: DocumentFormat, content: &str) -> bool {
+    match format {
+        DocumentFormat::Json => serde_json::from_str::<Value>(content)
+            .map(|v| v.as_object().is_some_and(|m| m.is_empty()))
+            .unwrap_or(false),
+        DocumentFormat::Yaml => serde_yaml_ng::from_str::<Value>(content)
+            .map(|v| v.is_null() || v.as_object().is_some_and(|m| m.is_empty()))
+            .unwrap_or(false),
+        DocumentFormat::Toml => content
+            .parse::<toml_edit::DocumentMut>()
+            .map(|doc| doc.as_table().is_empty())
+            .unwrap_or(false),
+        DocumentFormat::Markdown => content.trim().is_empty(),
+    }
+}
+
+mod json_merge {
+    use super::*;
+    use serde_json::Map;
+
+    pub(super) fn merge(
+        existing: &str,
+        generated: &str,
+        owned_keys: &[String],
+    ) -> Result<String, StructuredMergeError> {
+        let base = parse(existing).map_err(StructuredMergeError::InvalidExisting)?;
+        let overlay = parse(generated).map_err(StructuredMergeError::InvalidGenerated)?;
+        let merged = merge_values(base, &overlay, owned_keys)?;
+
+        let rendered = serde_json::to_string_pretty(&merged)
+            .map_err(|e| StructuredMergeError::InvalidGenerated(e.to_string()))?;
+        Ok(format!("{}\n", rendered))
+    }
+
+    /// Merge into a YAML document; the generated document may be YAML or JSON
+    pub(super) fn merge_yaml(
+        existing: &str,
+        generated: &str,
+        owned_keys: &[String],
+    ) -> Result<String, StructuredMergeError> {
+        let base = parse_yaml(existing).map_err(StructuredMergeError::InvalidExisting)?;
+        let overlay = parse_yaml(generated).map_err(StructuredMergeError::InvalidGenerated)?;
+        let merged = merge_values(base, &overlay, owned_keys)?;
+
+        serde_yaml_ng::to_string(&merged)
+            .map_err(|e| StructuredMergeError::InvalidGenerated(e.to_string()))
+    }
+
+    fn merge_values(
+        mut base: Value,
+        overlay: &Value,
+        owned_keys: &[String],
+    ) -> Result<Value, StructuredMergeError> {
+        if !base.is_object() {
+            return Err(StructuredMergeError::NotAnObject);
+        }
+
+        for key in owned_keys {
+            let segments = pointer_segments(key);
+            if segments.is_empty() {
+                continue;
+            }
+            match lookup(overlay, &segments) {
+                Some((value, is_element)) => set(&mut base, &segments, value, is_element)
+                    .map_err(|_| StructuredMergeError::KeyConflict(key.clone()))?,
+                None => {
+                    remove(&mut base, &segments);
+                }
+            }
+        }
+        Ok(base)
+    }
+
+    fn parse(content: &str) -> Result<Value, String> {
+        if content.trim().is_empty() {
+            return Ok(Value::Object(Map::new()));
+        }
+        serde_json::from_str(content).map_err(|e| e.to_string())
+    }
+
+    fn parse_yaml(content: &str) -> Result<Value, String> {
+        match serde_yaml_ng::from_str(content).map_err(|e| e.to_string())? {
+            Value::Null => Ok(Value::Object(Map::new())),
+            value => Ok(value),
+        }
+    }
+
+    /// Returns the owned value and whether it is an array element.
+    fn lookup(root: &Value, segments: &[String]) -> Option<(Value, bool)> {
+        let (last, parents) = segments.split_last()?;
+        let mut node = root;
+        for segment in parents {
+            node = node.as_object()?.get(segment)?;
+        }
+        match node {
+            Value::Object(map) => map.get(last).map(|v| (v.clone(), false)),
+            Value::Array(items) => items
+                .iter()
+                .find(|item| matches_element(item, last))
+                .map(|item| (item.clone(), true)),
+            _ => None,
+        }
+    }
+
+    /// Whether an array item is the element named `key`: a string equal to `key`,
+    /// an object whose `slug` is `key` (e.g. Roo Code `customModes`), or a Calvin-tagged
+    /// Claude Code hook group running the command `key`
+    fn matches_element(item: &Value, key: &str) -> bool {
+        match item {
+            Value::String(s) => s == key,
+            Value::Object(map) => {
+                let runs_key = || {
+                    map.get("hooks")
+                        .and_then(Value::as_array)
+                        .is_some_and(|hooks| {
+                            hooks.iter().any(|hook| {
+                                hook.get("command").and_then(Value::as_str) == Some(key)
+                            })
+                        })
+                };
+                map.get("slug").and_then(Value::as_str) == Some(key)
+                    || (map.contains_key(CALVIN_TAG) && runs_key())
+            }
+            _ => false,
+        }
+    }
+
+    fn set(
+        root: &mut Value,
+        segments: &[String],
+        value: Value,
+        is_element: bool,
+    ) -> Result<(), ()> {
+        let (last, parents) = segments.split_last().ok_or(())?;
+        let mut node = root;
+        for (index, segment) in parents.iter().enumerate() {
+            let container = if is_element && index + 1 == parents.len() {
+                Value::Array(Vec::new())
+            } else {
+                Value::Object(Map::new())
+            };
+            node = node
+                .as_object_mut()
+                .ok_or(())?
+                .entry(segment.clone())
+                .or_insert(container);
+        }
+        match node {
+            Value::Array(items) if is_element => {
+                match items.iter_mut().find(|item| matches_element(item, last)) {
+                    Some(item) => *item = value,
+                    None => items.push(value),
+                }
+                Ok(())
+            }
+            Value::Object(map) if !is_element => {
+                map.insert(last.clone(), value);
+                Ok(())
+            }
+            _ => Err(()),
+        }
+    }
+
+    /// Remove the owned key; returns true if something was removed.
+    fn remove(node: &mut Value, segments: &[String]) -> bool {
+        let Some((first, rest)) = segments.split_first() else {
+            return false;
+        };
+        if rest.is_empty() {
+            return match node {
+                Value::Object(map) => map.shift_remove(first).is_some(),
+                Value::Array(items) => {
+                    let before = items.len();
+                    items.retain(|v| !matches_element(v, first));
+                    items.len() != before
+                }
+                _ => false,
+            };
+        }
+
+        let Some(map) = node.as_object_mut() else {
+            return false;
+        };
+        let Some(child) = map.get_mut(first) else {
+            return false;
+        };
+        let removed = remove(child, rest);
+        let child_empty = match child {
+            Value::Object(m) => m.is_empty(),
+            Value::Array(a) => a.is_empty(),
+            _ => false,
+        };
+        if removed && child_empty {
+            map.shift_remove(first);
+        }
+        removed
+    }
+}
+
+mod toml_merge {
+    use super::*;
+    use toml_edit::{Array, DocumentMut, Item, Table, TableLike};
+
+    pub(super) fn merge(
+        existing: &str,
+        generated: &str,
+        owned_keys: &[String],
+    ) -> Result<String, StructuredMergeError> {
+        let mut base: DocumentMut = existing.parse().map_err(|e: toml_edit::TomlError| {
+            StructuredMergeError::InvalidExisting(e.to_string())
+        })?;
+        let overlay: DocumentMut = generated.parse().map_err(|e: toml_edit::TomlError| {
+            StructuredMergeError::InvalidGenerated(e.to_string())
+        })?;
+
+        for key in owned_keys {
+            let segments = pointer_segments(key);
+            if segments.is_empty() {
+                continue;
+            }
+            match lookup(overlay.as_table(), &segments) {
+                Some(Owned::Member(item)) => set_member(base.as_table_mut(), &segments, item)
+                    .map_err(|_| StructuredMergeError::KeyConflict(key.clone()))?,
+                Some(Owned::Element(value)) => set_element(base.as_table_mut(), &segments, &value)
+                    .map_err(|_| StructuredMergeError::KeyConflict(key.clone()))?,
+                None => {
+                    remove(base.as_table_mut(), &segments);
+                }
+            }
+        }
+
+        let mut rendered = base.to_string();
+        if !rendered.is_empty() && !rendered.ends_with('\n') {
+            rendered.push('\n');
+        }
+        Ok(rendered)
+    }
+
+    enum Owned {
+        Member(Item),
+        Element(String),
+    }
+
+    fn lookup(root: &Table, segments: &[String]) -> Option<Owned> {
+        let (last, parents) = segments.split_last()?;
+        let mut node: &dyn TableLike = root;
+        for (index, segment) in parents.iter().enumerate() {
+            let item = node.get(segment)?;
+            if index + 1 == parents.len() {
+                if let Some(array) = item.as_array() {
+                    return array
+                        .iter()
+                        .any(|v| v.as_str() == Some(last.as_str()))
+                        .then(|| Owned::Element(last.clone()));
+                }
+            }
+            node = item.as_table_like()?;
+        }
+        node.get(last).cloned().map(Owned::Member)
+    }
+
+    fn parent_table<'a>(
+        root: &'a mut Table,
+        parents: &[String],
+    ) -> Result<&'a mut dyn TableLike, ()> {
+        let mut node: &mut dyn TableLike = root;
+        for segment in parents {
+            let item = node.entry(segment).or_insert_with(|| {
+                let mut table = Table::new();
+                table.set_implicit(true);
+                Item::Table(table)
+            });
+            node = item.as_table_like_mut().ok_or(())?;
+        }
+        Ok(node)
+    }
+
+    fn set_member(root: &mut Table, segments: &[String], item: Item) -> Result<(), ()> {
+        let (last, parents) = segments.split_last().ok_or(())?;
+        parent_table(root, parents)?.insert(last, item);
+        Ok(())
+    }
+
+    fn set_element(root: &mut Table, segments: &[String], value: &str) -> Result<(), ()> {
+        let (array_key, parents) = segments[..segments.len() - 1].split_last().ok_or(())?;
+        let item = parent_table(root, parents)?
+            .entry(array_key)
+            .or_insert_with(|| Item::Value(Array::new().into()));
+        let array = item.as_array_mut().ok_or(())?;
+        if !array.iter().any(|v| v.as_str() == Some(value)) {
+            array.push(value);
+        }
+        Ok(())
+    }
+
+    /// Remove the owned key; returns true if something was removed.
+    fn remove(node: &mut dyn TableLike, segments: &[String]) -> bool {
+        let Some((first, rest)) = segments.split_first() else {
+            return false;
+        };
+        if rest.is_empty() {
+            return node.remove(first).is_some();
+        }
+
+        let Some(child) = node.get_mut(first) else {
+            return false;
+        };
+        let (removed, child_empty) = if rest.len() == 1 && child.is_array() {
+            let array = child.as_array_mut().expect("checked is_array");
+            let before = array.len();
+            array.retain(|v| v.as_str() != Some(rest[0].as_str()));
+            (array.len() != before, array.is_empty())
+        } else if let Some(table) = child.as_table_like_mut() {
+            let removed = remove(table, rest);
+            (removed, table.is_empty())
+        } else {
+            (false, false)
+        };
+        if removed && child_empty {
+            node.remove(first);
+        }
+        removed
+    }
+}
+
+mod markdown_merge {
+    use super::*;
+
+    pub(super) const END_MARKER: &str = "<!-- calvin:end -->";
+
+    pub(super) fn begin_marker(owner: &str) -> String {
+        format!("<!-- calvin:begin {} -->", owner)
+    }
+
+    pub(super) fn merge(
+        existing: &str,
+        generated: &str,
+        owned_keys: &[String],
+    ) -> Result<String, StructuredMergeError> {
+        let mut doc: Vec<String> = existing.lines().map(str::to_string).collect();
+        let overlay: Vec<&str> = generated.lines().collect();
+
+        for owner in owned_keys {
+            let region = find(&overlay, owner)
+                .map_err(StructuredMergeError::InvalidGenerated)?
+                .map(|(start, end)| overlay[start..=end].iter().map(|l| l.to_string()));
+            let current = find(&doc, owner).map_err(StructuredMergeError::InvalidExisting)?;
+
+            match (current, region) {
+                (Some((start, end)), Some(region)) => {
+                    doc.splice(start..=end, region);
+                }
+                (None, Some(region)) => {
+                    if doc.last().is_some_and(|l| !l.trim().is_empty()) {
+                        doc.push(String::new());
+                    }
+                    doc.extend(region);
+                }
+                (Some((start, end)), None) => {
+                    doc.drain(start..=end);
+                    // Drop the blank line that separated the region from the text before it
+                    let preceded_by_blank = start == 0 || doc[start - 1].trim().is_empty();
+                    if preceded_by_blank && doc.get(start).is_some_and(|l| l.trim().is_empty()) {
+                        doc.remove(start);
+                    }
+                }
+                (None, None) => {}
+            }
+        }
+
+        while doc.last().is_some_and(|l| l.trim().is_empty()) {
+            doc.pop();
+        }
+        if doc.is_empty() {
+            return Ok(String::new());
+        }
+        Ok(format!("{}\n", doc.join("\n")))
+    }
+
+    /// Line range of the region `owner` (markers included)
+    pub(super) fn find<S: AsRef<str>>(
+        lines: &[S],
+        owner: &str,
+    ) -> Result<Option<(usize, usize)>, String> {
+        let begin = begin_marker(owner);
+        let Some(start) = lines.iter().position(|l| l.as_ref().trim() == begin) else {
+            return Ok(None);
+        };
+        lines[start + 1..]
+            .iter()
+            .position(|l| l.as_ref().trim() == END_MARKER)
+            .map(|offset| Some((start, start + 1 + offset)))
+            .ok_or_else(|| format!("managed region '{}' has no end marker", owner))
+    }
+}
+
+#[cfg(test)]
+mod tests;
@@ -0,0 +1,443 @@
+use super::*;
+use crate::domain::value_objects::json_pointer;
+
+fn keys(pointers: &[&[&str]]) -> Vec<String> {
+    pointers.iter().map(|p| json_pointer(p)).collect()
+}
+
+fn json(content: &str) -> Value {
+    serde_json::from_str(content).unwrap()
+}
+
+// === JSON ===
+
+#[test]
+fn json_merge_into_empty_returns_owned_keys() {
+    let merged = merge_document(
+        DocumentFormat::Json,
+        "",
+        r#"{"mcpServers":{"github":{"command":"npx"}}}"#,
+        &keys(&[&["mcpServers", "github"]]),
+    )
+    .unwrap();
+
+    assert_eq!(json(&merged)["mcpServers"]["github"]["command"], "npx");
+    assert!(merged.ends_with('\n'));
+}
+
+#[test]
+fn json_merge_preserves_user_keys() {
+    let existing = r#"{"theme":"dark","mcpServers":{"mine":{"command":"x"}}}"#;
+    let merged = merge_document(
+        DocumentFormat::Json,
+        existing,
+        r#"{"mcpServers":{"github":{"command":"npx"}}}"#,
+        &keys(&[&["mcpServers", "github"]]),
+    )
+    .unwrap();
+    let value = json(&merged);
+
+    assert_eq!(value["theme"], "dark");
+    assert_eq!(value["mcpServers"]["mine"]["command"], "x");
+    assert_eq!(value["mcpServers"]["github"]["command"], "npx");
+}
+
+#[test]
+fn json_merge_replaces_owned_member_entirely() {
+    let existing = r#"{"mcpServers":{"github":{"command":"old","args":["a","b"]}}}"#;
+    let merged = merge_document(
+        DocumentFormat::Json,
+        existing,
+        r#"{"mcpServers":{"github":{"command":"npx","args":["c"]}}}"#,
+        &keys(&[&["mcpServers", "github"]]),
+    )
+    .unwrap();
+
+    assert_eq!(
+        json(&merged)["mcpServers"]["github"],
+        serde_json::json!({"command": "npx", "args": ["c"]})
+    );
+}
+
+#[test]
+fn json_merge_owns_array_elements_individually() {
+    let existing = r#"{"permissions":{"deny":["build/**",".env"]}}"#;
+    let merged = merge_document(
+        DocumentFormat::Json,
+        existing,
+        r#"{"permissions":{"deny":[".env","*.pem"]}}"#,
+        &keys(&[
+            &["permissions", "deny", ".env"],
+            &["permissions", "deny", "*.pem"],
+        ]),
+    )
+    .unwrap();
+
+    assert_eq!(
+        json(&merged)["permissions"]["deny"],
+        serde_json::json!(["build/**", ".env", "*.pem"])
+    );
+}
+
+#[test]
+fn json_merge_owns_object_elements_by_slug() {
+    let existing =
+        r#"{"customModes":[{"slug":"mine","name":"Mine"},{"slug":"reviewer","name":"Old"}]}"#;
+    let merged = merge_document(
+        DocumentFormat::Json,
+        existing,
+        r#"{"customModes":[{"slug":"reviewer","name":"Reviewer"},{"slug":"docs","name":"Docs"}]}"#,
+        &keys(&[&["customModes", "reviewer"], &["customModes", "docs"]]),
+    )
+    .unwrap();
+
+    assert_eq!(
+        json(&merged)["customModes"],
+        serde_json::json!([
+            {"slug": "mine", "name": "Mine"},
+            {"slug": "reviewer", "name": "Reviewer"},
+            {"slug": "docs", "name": "Docs"}
+        ])
+    );
+
+    let removed = merge_document(
+        DocumentFormat::Json,
+        &merged,
+        "",
+        &keys(&[&["customModes", "reviewer"], &["customModes", "docs"]]),
+    )
+    .unwrap();
+    assert_eq!(
+        json(&removed),
+        serde_json::json!({"customModes": [{"slug": "mine", "name": "Mine"}]})
+    );
+}
+
+#[test]
+fn json_merge_owns_only_tagged_hook_groups_by_command() {
+    // The user runs the same command in a group of their own
+    let existing = r#"{"hooks":{"Stop":[{"hooks":[{"type":"command","command":"say done"}]},{"matcher":"","hooks":[{"type":"command","command":"make lint"}]}]}}"#;
+    let merged = merge_document(
+        DocumentFormat::Json,
+        existing,
+        r#"{"hooks":{"Stop":[{"calvin":"lint","hooks":[{"type":"command","command":"make lint"}]}]}}"#,
+        &keys(&[&["hooks", "Stop", "make lint"]]),
+    )
+    .unwrap();
+
+    let user_groups = serde_json::json!([
+        {"hooks": [{"type": "command", "command": "say done"}]},
+        {"matcher": "", "hooks": [{"type": "command", "command": "make lint"}]}
+    ]);
+    let mut expected = user_groups.as_array().unwrap().clone();
+    expected.push(serde_json::json!(
+        {"calvin": "lint", "hooks": [{"type": "command", "command": "make lint"}]}
+    ));
+    assert_eq!(json(&merged)["hooks"]["Stop"], serde_json::json!(expected));
+
+    // Redeploys replace the tagged group in place
+    let updated = merge_document(
+        DocumentFormat::Json,
+        &merged,
+        r#"{"hooks":{"Stop":[{"calvin":"lint","hooks":[{"type":"command","command":"make lint","timeout":30}]}]}}"#,
+        &keys(&[&["hooks", "Stop", "make lint"]]),
+    )
+    .unwrap();
+    assert_eq!(json(&updated)["hooks"]["Stop"].as_array().unwrap().len(), 3);
+    assert_eq!(
+        json(&updated)["hooks"]["Stop"][2]["hooks"][0]["timeout"],
+        30
+    );
+
+    let removed = merge_document(
+        DocumentFormat::Json,
+        &updated,
+        "",
+        &keys(&[&["hooks", "Stop", "make lint"]]),
+    )
+    .unwrap();
+    assert_eq!(json(&removed)["hooks"]["Stop"], user_groups);
+}
+
+#[test]
+fn json_merge_removes_stale_owned_keys_and_prunes_empty_parents() {
+    let existing =
+        r#"{"theme":"dark","mcpServers":{"old":{"command":"x"}},"permissions":{"deny":["a"]}}"#;
+    let merged = merge_document(
+        DocumentFormat::Json,
+        existing,
+        "",
+        &keys(&[&["mcpServers", "old"], &["permissions", "deny", "a"]]),
+    )
+    .unwrap();
+
+    assert_eq!(json(&merged), serde_json::json!({"theme": "dark"}));
+}
+
+#[test]
+fn json_merge_is_idempotent() {
+    let generated = r#"{"permissions":{"deny":[".env"]}}"#;
+    let owned = keys(&[&["permissions", "deny", ".env"]]);
+    let once = merge_document(
+        DocumentFormat::Json,
+        r#"{"model":"opus"}"#,
+        generated,
+        &owned,
+    )
+    .unwrap();
+    let twice = merge_document(DocumentFormat::Json, &once, generated, &owned).unwrap();
+    assert_eq!(once, twice);
+}
+
+#[test]
+fn json_merge_rejects_invalid_existing() {
+    let err = merge_document(DocumentFormat::Json, "{ not json", "{}", &[]).unwrap_err();
+    assert!(matches!(err, StructuredMergeError::InvalidExisting(_)));
+}
+
+#[test]
+fn json_merge_rejects_non_object_root() {
+    let err = merge_document(DocumentFormat::Json, "[1, 2]", "{}", &[]).unwrap_err();
+    assert_eq!(err, StructuredMergeError::NotAnObject);
+}
+
+#[test]
+fn json_merge_reports_conflicting_parent() {
+    let err = merge_document(
+        DocumentFormat::Json,
+        r#"{"mcpServers":"nope"}"#,
+        r#"{"mcpServers":{"github":{}}}"#,
+        &keys(&[&["mcpServers", "github"]]),
+    )
+    .unwrap_err();
+    assert!(matches!(err, StructuredMergeError::KeyConflict(_)));
+}
+
+// === TOML ===
+
+#[test]
+fn toml_merge_preserves_comments_and_user_tables() {
+    let existing = r#"# my settings
+model = "o3" # preferred
+
+[mcp_servers.mine]
+command = "x"
+"#;
+    let generated = r#"
+[mcp_servers.github]
+command = "npx"
+args = ["-y"]
+"#;
+    let merged = merge_document(
+        DocumentFormat::Toml,
+        existing,
+        generated,
+        &keys(&[&["mcp_servers", "github"]]),
+    )
+    .unwrap();
+
+    assert!(merged.contains("# my settings"));
+    assert!(merged.contains("model = \"o3\" # preferred"));
+    let parsed: ::toml::Table = ::toml::from_str(&merged).unwrap();
+    assert_eq!(parsed["mcp_servers"]["mine"]["command"].as_str(), Some("x"));
+    assert_eq!(
+        parsed["mcp_servers"]["github"]["command"].as_str(),
+        Some("npx")
+    );
+}
+
+#[test]
+fn toml_merge_removes_stale_owned_table() {
+    let existing = r#"model = "o3"
+
+[mcp_servers.old]
+command = "x"
+"#;
+    let merged = merge_document(
+        DocumentFormat::Toml,
+        existing,
+        "",
+        &keys(&[&["mcp_servers", "old"]]),
+    )
+    .unwrap();
+
+    assert_eq!(merged.trim(), r#"model = "o3""#);
+}
+
+#[test]
+fn toml_merge_is_idempotent() {
+    let generated = "[mcp_servers.github]\ncommand = \"npx\"\n";
+    let owned = keys(&[&["mcp_servers", "github"]]);
+    let once = merge_document(DocumentFormat::Toml, "model = \"o3\"\n", generated, &owned).unwrap();
+    let twice = merge_document(DocumentFormat::Toml, &once, generated, &owned).unwrap();
+    assert_eq!(once, twice);
+}
+
+// === Markdown ===
+
+fn owners(names: &[&str]) -> Vec<String> {
+    names.iter().map(|n| n.to_string()).collect()
+}
+
+#[test]
+fn markdown_merge_appends_region_after_user_text() {
+    let merged = merge_document(
+        DocumentFormat::Markdown,
+        "# Notes\n\nHand-written.\n",
+        &managed_region("claude-code", "## Security\n\nNo secrets."),
+        &owners(&["claude-code"]),
+    )
+    .unwrap();
+
+    assert_eq!(
+        merged,
+        "# Notes\n\nHand-written.\n\n<!-- calvin:begin claude-code -->\n## Security\n\nNo secrets.\n<!-- calvin:end -->\n"
+    );
+}
+
+#[test]
+fn markdown_merge_replaces_region_in_place() {
+    let existing = "Top\n\n<!-- calvin:begin claude-code -->\nold\n<!-- calvin:end -->\n\nBottom\n";
+    let merged = merge_document(
+        DocumentFormat::Markdown,
+        existing,
+        &managed_region("claude-code", "new"),
+        &owners(&["claude-code"]),
+    )
+    .unwrap();
+
+    assert_eq!(
+        merged,
+        "Top\n\n<!-- calvin:begin claude-code -->\nnew\n<!-- calvin:end -->\n\nBottom\n"
+    );
+}
+
+#[test]
+fn markdown_merge_removes_region_and_keeps_user_text() {
+    let existing = "Top\n\n<!-- calvin:begin claude-code -->\nold\n<!-- calvin:end -->\n\nBottom\n";
+    let merged = merge_document(
+        DocumentFormat::Markdown,
+        existing,
+        "",
+        &owners(&["claude-code"]),
+    )
+    .unwrap();
+
+    assert_eq!(merged, "Top\n\nBottom\n");
+
+    let only_region = managed_region("claude-code", "old");
+    let merged = merge_document(
+        DocumentFormat::Markdown,
+        &only_region,
+        "",
+        &owners(&["claude-code"]),
+    )
+    .unwrap();
+    assert!(is_empty_document(DocumentFormat::Markdown, &merged));
+}
+
+#[test]
+fn markdown_merge_leaves_other_regions_alone() {
+    let existing = managed_region("opencode", "theirs");
+    let merged = merge_document(
+        DocumentFormat::Markdown,
+        &existing,
+        &managed_region("claude-code", "ours"),
+        &owners(&["claude-code"]),
+    )
+    .unwrap();
+
+    assert!(merged.starts_with(&existing));
+    assert!(merged.contains("ours"));
+}
+
+#[test]
+fn markdown_merge_rejects_unterminated_region() {
+    let err = merge_document(
+        DocumentFormat::Markdown,
+        "<!-- calvin:begin claude-code -->\nold\n",
+        &managed_region("claude-code", "new"),
+        &owners(&["claude-code"]),
+    )
+    .unwrap_err();
+
+    assert!(matches!(err, StructuredMergeError::InvalidExisting(_)));
+}
+
+#[test]
+fn markdown_merge_is_idempotent() {
+    let generated = managed_region("claude-code", "## Rules\n\nBe nice.");
+    let owned = owners(&["claude-code"]);
+    let once = merge_document(DocumentFormat::Markdown, "# Mine\n", &generated, &owned).unwrap();
+    let twice = merge_document(DocumentFormat::Markdown, &once, &generated, &owned).unwrap();
+    assert_eq!(once, twice);
+}
+
+#[test]
+fn extract_managed_regions_ignores_user_text() {
+    let region = managed_region("vscode", "## Agents");
+    let before = format!("# Mine\n\n{}", region);
+    let after = format!("# Mine, edited\n\n{}\nMore notes.\n", region);
+    let owned = owners(&["vscode", "opencode"]);
+
+    assert_eq!(extract_managed_regions(&before, &owned), Some(region));
+    assert_eq!(
+        extract_managed_regions(&before, &owned),
+        extract_managed_regions(&after, &owned)
+    );
+    assert_eq!(extract_managed_regions("# Mine\n", &owned), None);
+}
+
+// === YAML ===
+
+#[test]
+fn yaml_merge_owns_object_elements_by_slug_and_stays_yaml() {
+    let existing = "customModes:\n  - slug: mine\n    name: Mine\n";
+    let merged = merge_document(
+        DocumentFormat::Yaml,
+        existing,
+        r#"{"customModes":[{"slug":"reviewer","name":"Reviewer"}]}"#,
+        &keys(&[&["customModes", "reviewer"]]),
+    )
+    .unwrap();
+
+    let value: Value = serde_yaml_ng::from_str(&merged).unwrap();
+    assert_eq!(
+        value,
+        serde_json::json!({"customModes": [
+            {"slug": "mine", "name": "Mine"},
+            {"slug": "reviewer", "name": "Reviewer"}
+        ]})
+    );
+    assert!(
+        serde_json::from_str::<Value>(&merged).is_err(),
+        "{}",
+        merged
+    );
+
+    let removed = merge_document(
+        DocumentFormat::Yaml,
+        &merged,
+        "",
+        &keys(&[&["customModes", "reviewer"]]),
+    )
+    .unwrap();
+    assert_eq!(
+        serde_yaml_ng::from_str::<Value>(&removed).unwrap(),
+        serde_json::json!({"customModes": [{"slug": "mine", "name": "Mine"}]})
+    );
+}
+
+// === is_empty_document ===
+
+#[test]
+fn empty_documents_are_detected() {
+    assert!(is_empty_document(DocumentFormat::Json, "{}\n"));
+    assert!(!is_empty_document(DocumentFormat::Json, r#"{"a":1}"#));
+    assert!(is_empty_document(DocumentFormat::Toml, "\n"));
+    assert!(!is_empty_document(DocumentFormat::Toml, "a = 1\n"));
+    assert!(is_empty_document(DocumentFormat::Markdown, "\n"));
+    assert!(!is_empty_document(DocumentFormat::Markdown, "# Notes\n"));
+    assert!(is_empty_document(DocumentFormat::Yaml, "{}\n"));
+    assert!(!is_empty_document(DocumentFormat::Yaml, "a: 1\n"));
+}
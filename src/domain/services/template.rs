@@ -0,0 +1,971 @@
+//! Asset Body Templates
+//!
+//! A small, deterministic templating pass over asset bodies:
+//!
+//! - `{{ name }}` inserts a variable from `[vars]` or a built-in (`target`, `scope`)
+//! - `{% if target == "cursor" %}` … `{% elif … %}` … `{% else %}` … `{% endif %}`
+//! - `{% raw %}` … `{% endraw %}` keeps its content verbatim
+//! - `{{> partials/secrets.md }}` inserts a partial, itself rendered as a template
+//!
+//! A template is parsed and checked once per asset (every variable in every branch
+//! must be defined), then rendered once per target. `{{ … }}` whose content is not a
+//! plain name (e.g. `${{ secrets.TOKEN }}`) is left as-is. Markdown code (fenced
+//! blocks and inline code spans) is literal, so Vue, Jinja or Liquid examples are
+//! copied verbatim; only `{% raw %}` is still recognized there. Block tags on a line
+//! of their own don't leave blank lines behind.
+
+use std::collections::BTreeMap;
+use std::fmt;
+
+use crate::domain::value_objects::{Scope, Target};
+
+/// Built-in variables, always defined
+const BUILTINS: &[&str] = &["target", "scope"];
+
+/// A template syntax error or undefined variable, with its 1-based position
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct TemplateError {
+    pub line: usize,
+    pub column: usize,
+    pub message: String,
+}
+
+impl fmt::Display for TemplateError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(
+            f,
+            "Line {}, column {}: {}",
+            self.line, self.column, self.message
+        )
+    }
+}
+
+impl std::error::Error for TemplateError {}
+
+/// Values available while rendering for one target
+#[derive(Debug, Clone, Copy)]
+pub struct TemplateContext<'a> {
+    pub vars: &'a BTreeMap<String, String>,
+    pub target: Target,
+    pub scope: Scope,
+    /// Parsed partials by include path; includes of missing partials render empty
+    pub partials: &'a BTreeMap<String, Template>,
+}
+
+/// An `{{> path }}` include and where it appears
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Include {
+    /// Partial path relative to the layer root (e.g. `partials/secrets.md`)
+    pub path: String,
+    pub line: usize,
+    pub column: usize,
+}
+
+/// A parsed asset body template
+#[derive(Debug, Clone, PartialEq)]
+pub struct Template {
+    nodes: Vec<Node>,
+}
+
+#[derive(Debug, Clone, PartialEq)]
+enum Node {
+    Text(String),
+    Var(Name),
+    /// A partial include; `standalone` when the tag sat alone on its line
+    Include {
+        include: Include,
+        standalone: bool,
+    },
+    If {
+        branches: Vec<(Condition, Vec<Node>)>,
+        otherwise: Vec<Node>,
+    },
+}
+
+/// A variable reference and where it appears
+#[derive(Debug, Clone, PartialEq)]
+struct Name {
+    name: String,
+    line: usize,
+    column: usize,
+}
+
+#[derive(Debug, Clone, PartialEq)]
+enum Condition {
+    /// `target == "cursor"` / `target != "cursor"`
+    Target { target: Target, negate: bool },
+    /// `scope == "user"` / `scope != "user"`
+    Scope { scope: Scope, negate: bool },
+    /// `name == "value"` / `name != "value"`
+    Equals {
+        var: Name,
+        value: String,
+        negate: bool,
+    },
+    /// `name` (true when the variable is non-empty)
+    Set(Name),
+}
+
+enum Token {
+    Text(String),
+    Var(Name),
+    Include {
+        include: Include,
+        standalone: bool,
+    },
+    Tag {
+        keyword: String,
+        args: String,
+        line: usize,
+        column: usize,
+    },
+}
+
+impl Template {
+    /// Whether `text` contains anything the template engine would interpret
+    ///
+    /// Template syntax inside Markdown code is literal and doesn't count.
+    pub fn contains_syntax(text: &str) -> bool {
+        let code = code_ranges(text);
+        let mut start = 0;
+        for &(code_start, code_end) in code.iter().chain([(text.len(), text.len())].iter()) {
+            let outside = &text[start..code_start];
+            if outside.contains("{{") || outside.contains("{%") {
+                return true;
+            }
+            if text[code_start..code_end].contains("{% raw %}") {
+                return true;
+            }
+            start = code_end;
+        }
+        false
+    }
+
+    /// Parse a template
+    pub fn parse(text: &str) -> Result<Self, TemplateError> {
+        let tokens = tokenize(text)?;
+        let mut tokens = tokens.into_iter();
+        let (nodes, end) = parse_nodes(&mut tokens)?;
+        if let Some(end) = end {
+            return Err(end.unexpected());
+        }
+        Ok(Self { nodes })
+    }
+
+    /// Check that every referenced variable is a built-in or defined in `vars`
+    ///
+    /// All branches are checked, so a template that renders for one target renders
+    /// for all of them.
+    pub fn check_vars(&self, vars: &BTreeMap<String, String>) -> Result<(), TemplateError> {
+        check_nodes(&self.nodes, vars)
+    }
+
+    /// Partials included directly by this template, in all branches
+    pub fn includes(&self) -> Vec<&Include> {
+        let mut includes = Vec::new();
+        collect_includes(&self.nodes, &mut includes);
+        includes
+    }
+
+    /// Render the template for one target
+    ///
+    /// Variables must have been checked with [`Template::check_vars`]; unknown ones
+    /// render as empty strings.
+    pub fn render(&self, ctx: &TemplateContext<'_>) -> String {
+        let mut out = String::new();
+        render_nodes(&self.nodes, ctx, &mut out);
+        out
+    }
+}
+
+/// A block tag that ended a run of nodes
+struct BlockEnd {
+    keyword: String,
+    args: String,
+    line: usize,
+    column: usize,
+}
+
+impl BlockEnd {
+    fn unexpected(&self) -> TemplateError {
+        TemplateError {
+            line: self.line,
+            column: self.column,
+            message: format!("`{{% {} %}}` without a matching `{{% if %}}`", self.keyword),
+        }
+    }
+}
+
+fn parse_nodes(
+    tokens: &mut impl Iterator<Item = Token>,
+) -> Result<(Vec<Node>, Option<BlockEnd>), TemplateError> {
+    let mut nodes = Vec::new();
+    while let Some(token) = tokens.next() {
+        match token {
+            Token::Text(text) => nodes.push(Node::Text(text)),
+            Token::Var(name) => nodes.push(Node::Var(name)),
+            Token::Include {
+                include,
+                standalone,
+            } => nodes.push(Node::Include {
+                include,
+                standalone,
+            }),
+            Token::Tag {
+                keyword,
+                args,
+                line,
+                column,
+            } => {
+                if keyword == "if" {
+                    nodes.push(parse_if(tokens, &args, line, column)?);
+                } else {
+                    return Ok((
+                        nodes,
+                        Some(BlockEnd {
+                            keyword,
+                            args,
+                            line,
+                            column,
+                        }),
+                    ));
+                }
+            }
+        }
+    }
+    Ok((nodes, None))
+}
+
+fn parse_if(
+    tokens: &mut impl Iterator<Item = Token>,
+    args: &str,
+    line: usize,
+    column: usize,
+) -> Result<Node, TemplateError> {
+    let mut branches = Vec::new();
+    let mut condition = parse_condition(args, line, column)?;
+    loop {
+        let (body, end) = parse_nodes(tokens)?;
+        let Some(end) = end else {
+            return Err(TemplateError {
+                line,
+                column,
+                message: "`{% if %}` is never closed; add `{% endif %}`".to_string(),
+            });
+        };
+        branches.push((condition, body));
+        match end.keyword.as_str() {
+            "elif" => condition = parse_condition(&end.args, end.line, end.column)?,
+            "else" => {
+                let (otherwise, close) = parse_nodes(tokens)?;
+                return match close {
+                    Some(close) if close.keyword == "endif" => Ok(Node::If {
+                        branches,
+                        otherwise,
+                    }),
+                    Some(close) => Err(close.unexpected()),
+                    None => Err(TemplateError {
+                        line,
+                        column,
+                        message: "`{% if %}` is never closed; add `{% endif %}`".to_string(),
+                    }),
+                };
+            }
+            "endif" => {
+                return Ok(Node::If {
+                    branches,
+                    otherwise: Vec::new(),
+                })
+            }
+            _ => return Err(end.unexpected()),
+        }
+    }
+}
+
+fn parse_condition(args: &str, line: usize, column: usize) -> Result<Condition, TemplateError> {
+    let error = |message: String| TemplateError {
+        line,
+        column,
+        message,
+    };
+
+    let (lhs, op, rhs) = if let Some((lhs, rhs)) = args.split_once("==") {
+        (lhs.trim(), Some(false), rhs.trim())
+    } else if let Some((lhs, rhs)) = args.split_once("!=") {
+        (lhs.trim(), Some(true), rhs.trim())
+    } else {
+        (args.trim(), None, "")
+    };
+
+    if !is_name(lhs) {
+        return Err(error(format!(
+            "invalid condition `{}`; expected `name == \"value\"`, `name != \"value\"` or `name`",
+            args.trim()
+        )));
+    }
+    let var = Name {
+        name: lhs.to_string(),
+        line,
+        column,
+    };
+    let Some(negate) = op else {
+        return Ok(Condition::Set(var));
+    };
+
+    let value = unquote(rhs).ok_or_else(|| {
+        error(format!(
+            "expected a quoted string after `{}`, found `{}`",
+            if negate { "!=" } else { "==" },
+            rhs
+        ))
+    })?;
+
+    match lhs {
+        "target" => match Target::from_str_with_suggestion(value) {
+            Ok(target) if !target.is_all() => Ok(Condition::Target { target, negate }),
+            Ok(_) => Err(error(
+                "`target` is always a single target; `all` never matches".to_string(),
+            )),
+            Err(e) => Err(error(e.to_string())),
+        },
+        "scope" => match value {
+            "project" => Ok(Condition::Scope {
+                scope: Scope::Project,
+                negate,
+            }),
+            "user" => Ok(Condition::Scope {
+                scope: Scope::User,
+                negate,
+            }),
+            other => Err(error(format!(
+                "invalid scope '{}'. Valid scopes: project, user",
+                other
+            ))),
+        },
+        _ => Ok(Condition::Equals {
+            var,
+            value: value.to_string(),
+            negate,
+        }),
+    }
+}
+
+fn unquote(s: &str) -> Option<&str> {
+    ['"', '\'']
+        .iter()
+        .find_map(|q| s.strip_prefix(*q)?.strip_suffix(*q))
+}
+
+fn is_name(s: &str) -> bool {
+    let mut chars = s.chars();
+    chars
+        .next()
+        .is_some_and(|c| c.is_ascii_alphabetic() || c == '_')
+        && chars.all(|c| c.is_ascii_alphanumeric() || c == '_' || c == '-')
+}
+
+fn check_nodes(nodes: &[Node], vars: &BTreeMap<String, String>) -> Result<(), TemplateError> {
+    for node in nodes {
+        match node {
+            Node::Text(_) | Node::Include { .. } => {}
+            Node::Var(name) => check_name(name, vars)?,
+            Node::If {
+                branches,
+                otherwise,
+            } => {
+                for (condition, body) in branches {
+                    match condition {
+                        Condition::Equals { var, .. } | Condition::Set(var) => {
+                            check_name(var, vars)?
+                        }
+                        Condition::Target { .. } | Condition::Scope { .. } => {}
+                    }
+                    check_nodes(body, vars)?;
+                }
+                check_nodes(otherwise, vars)?;
+            }
+        }
+    }
+    Ok(())
+}
+
+fn collect_includes<'a>(nodes: &'a [Node], out: &mut Vec<&'a Include>) {
+    for node in nodes {
+        match node {
+            Node::Text(_) | Node::Var(_) => {}
+            Node::Include { include, .. } => out.push(include),
+            Node::If {
+                branches,
+                otherwise,
+            } => {
+                for (_, body) in branches {
+                    collect_includes(body, out);
+                }
+                collect_includes(otherwise, out);
+            }
+        }
+    }
+}
+
+fn check_name(name: &Name, vars: &BTreeMap<String, String>) -> Result<(), TemplateError> {
+    if BUILTINS.contains(&name.name.as_str()) || vars.contains_key(&name.name) {
+        return Ok(());
+    }
+    Err(TemplateError {
+        line: name.line,
+        column: name.column,
+        message: format!("undefined variable `{}`", name.name),
+    })
+}
+
+fn lookup<'a>(name: &str, ctx: &'a TemplateContext<'_>) -> std::borrow::Cow<'a, str> {
+    match name {
+        "target" => ctx.target.id().into(),
+        "scope" => ctx.scope.to_string().into(),
+        _ => ctx
+            .vars
+            .get(name)
+            .map(String::as_str)
+            .unwrap_or_default()
+            .into(),
+    }
+}
+
+fn render_nodes(nodes: &[Node], ctx: &TemplateContext<'_>, out: &mut String) {
+    for node in nodes {
+        match node {
+            Node::Text(text) => out.push_str(text),
+            Node::Var(name) => out.push_str(&lookup(&name.name, ctx)),
+            Node::Include {
+                include,
+                standalone,
+            } => {
+                if let Some(partial) = ctx.partials.get(&include.path) {
+                    let start = out.len();
+                    render_nodes(&partial.nodes, ctx, out);
+                    if *standalone && out.len() > start && !out.ends_with('\n') {
+                        out.push('\n');
+                    }
+                }
+            }
+            Node::If {
+                branches,
+                otherwise,
+            } => {
+                let body = branches
+                    .iter()
+                    .find(|(condition, _)| evaluate(condition, ctx))
+                    .map(|(_, body)| body)
+                    .unwrap_or(otherwise);
+                render_nodes(body, ctx, out);
+            }
+        }
+    }
+}
+
+fn evaluate(condition: &Condition, ctx: &TemplateContext<'_>) -> bool {
+    match condition {
+        Condition::Target { target, negate } => (ctx.target == *target) != *negate,
+        Condition::Scope { scope, negate } => (ctx.scope == *scope) != *negate,
+        Condition::Equals { var, value, negate } => (lookup(&var.name, ctx) == *value) != *negate,
+        Condition::Set(var) => !lookup(&var.name, ctx).is_empty(),
+    }
+}
+
+/// 1-based line and column (in characters) of a byte offset
+fn position(text: &str, offset: usize) -> (usize, usize) {
+    let before = &text[..offset];
+    let line = before.matches('\n').count() + 1;
+    let line_start = before.rfind('\n').map_or(0, |i| i + 1);
+    (line, before[line_start..].chars().count() + 1)
+}
+
+/// Byte range to drop around a block tag that sits alone on its line
+///
+/// Returns `(start, end)` covering the tag plus its line's indentation and newline,
+/// or the tag itself when it shares the line with other content.
+fn standalone_span(text: &str, start: usize, end: usize) -> (usize, usize) {
+    let line_start = text[..start].rfind('\n').map_or(0, |i| i + 1);
+    let line_end = text[end..].find('\n').map_or(text.len(), |i| end + i + 1);
+    if text[line_start..start].trim().is_empty() && text[end..line_end].trim().is_empty() {
+        (line_start, line_end)
+    } else {
+        (start, end)
+    }
+}
+
+/// Byte ranges of Markdown code: fenced code blocks and inline code spans
+///
+/// A fence is a line starting (after up to three spaces) with three or more
+/// backticks or tildes, closed by a line with at least as many of the same
+/// character; an unclosed fence runs to the end of the text. An inline span is a
+/// run of backticks closed by a run of the same length before the next blank line.
+fn code_ranges(text: &str) -> Vec<(usize, usize)> {
+    let mut ranges = Vec::new();
+    let mut fence: Option<(usize, char, usize)> = None;
+    let mut prose_start = 0;
+    let mut line_start = 0;
+
+    while line_start < text.len() {
+        let line_end = text[line_start..]
+            .find('\n')
+            .map_or(text.len(), |i| line_start + i + 1);
+        let line = &text[line_start..line_end];
+        let marker = fence_marker(line);
+        match fence {
+            None => {
+                if let Some((ch, len)) = marker {
+                    inline_code_ranges(text, prose_start, line_start, &mut ranges);
+                    fence = Some((line_start, ch, len));
+                }
+            }
+            Some((start, ch, len)) => {
+                if marker.is_some_and(|(c, l)| c == ch && l >= len && is_closing_fence(line)) {
+                    ranges.push((start, line_end));
+                    fence = None;
+                    prose_start = line_end;
+                }
+            }
+        }
+        line_start = line_end;
+    }
+
+    match fence {
+        Some((start, _, _)) => ranges.push((start, text.len())),
+        None => inline_code_ranges(text, prose_start, text.len(), &mut ranges),
+    }
+    ranges.sort_unstable();
+    ranges
+}
+
+/// The fence character and length if `line` opens or closes a code fence
+fn fence_marker(line: &str) -> Option<(char, usize)> {
+    let trimmed = line.trim_start_matches(' ');
+    if line.len() - trimmed.len() > 3 {
+        return None;
+    }
+    let ch = trimmed.chars().next().filter(|c| *c == '`' || *c == '~')?;
+    let len = trimmed.chars().take_while(|c| *c == ch).count();
+    (len >= 3).then_some((ch, len))
+}
+
+/// Whether a fence line has nothing after its marker (closing fences take no info string)
+fn is_closing_fence(line: &str) -> bool {
+    line.trim().chars().all(|c| c == '`' || c == '~')
+}
+
+/// Collect inline code spans in `text[start..end]` (prose outside fences)
+fn inline_code_ranges(text: &str, start: usize, end: usize, ranges: &mut Vec<(usize, usize)>) {
+    let prose = &text[start..end];
+    let mut cursor = 0;
+    while let Some(open) = prose[cursor..].find('`').map(|i| cursor + i) {
+        let run = prose[open..].bytes().take_while(|b| *b == b'`').count();
+        let body_start = open + run;
+        let limit = prose[body_start..]
+            .find("\n\n")
+            .map_or(prose.len(), |i| body_start + i);
+        let mut search = body_start;
+        let mut close = None;
+        while let Some(found) = prose[search..limit].find('`').map(|i| search + i) {
+            let len = prose[found..limit]
+                .bytes()
+                .take_while(|b| *b == b'`')
+                .count();
+            if len == run {
+                close = Some(found + len);
+                break;
+            }
+            search = found + len;
+        }
+        match close {
+            Some(close) => {
+                ranges.push((start + open, start + close));
+                cursor = close;
+            }
+            None => cursor = body_start,
+        }
+    }
+}
+
+fn tokenize(text: &str) -> Result<Vec<Token>, TemplateError> {
+    let mut tokens = Vec::new();
+    let mut text_start = 0;
+    let mut cursor = 0;
+    let code = code_ranges(text);
+
+    while let Some(found) = text[cursor..].find('{').map(|i| cursor + i) {
+        let rest = &text[found..];
+        if let Some(&(_, code_end)) = code.iter().find(|(s, e)| (*s..*e).contains(&found)) {
+            if !is_raw_tag(rest) {
+                cursor = code_end;
+                continue;
+            }
+        }
+        if rest.starts_with("{{") {
+            let Some(close) = rest.find("}}") else {
+                break;
+            };
+            let inner = rest[2..close].trim();
+            if let Some(path) = inner.strip_prefix('>') {
+                let (line, column) = position(text, found);
+                let path = path.trim().trim_start_matches("./");
+                if path.is_empty() {
+                    return Err(TemplateError {
+                        line,
+                        column,
+                        message: "`{{> }}` needs a partial path, e.g. `{{> partials/secrets.md }}`"
+                            .to_string(),
+                    });
+                }
+                let end = found + close + 2;
+                let (span_start, span_end) = standalone_span(text, found, end);
+                push_text(&mut tokens, &text[text_start..span_start.max(text_start)]);
+                tokens.push(Token::Include {
+                    include: Include {
+                        path: path.to_string(),
+                        line,
+                        column,
+                    },
+                    standalone: span_end != end || span_start != found,
+                });
+                text_start = span_end;
+                cursor = span_end;
+            } else if is_name(inner) {
+                push_text(&mut tokens, &text[text_start..found]);
+                let (line, column) = position(text, found);
+                tokens.push(Token::Var(Name {
+                    name: inner.to_string(),
+                    line,
+                    column,
+                }));
+                text_start = found + close + 2;
+                cursor = text_start;
+            } else {
+                cursor = found + 2;
+            }
+        } else if rest.starts_with("{%") {
+            let (line, column) = position(text, found);
+            let close = rest.find("%}").ok_or_else(|| TemplateError {
+                line,
+                column,
+                message: "unclosed tag; add `%}`".to_string(),
+            })?;
+            let end = found + close + 2;
+            let inner = rest[2..close].trim();
+            let (keyword, args) = inner
+                .split_once(char::is_whitespace)
+                .map_or((inner, ""), |(k, a)| (k, a.trim()));
+
+            match keyword {
+                "if" | "elif" | "else" | "endif" | "raw" => {}
+                "endraw" => {
+                    return Err(TemplateError {
+                        line,
+                        column,
+                        message: "`{% endraw %}` without a matching `{% raw %}`".to_string(),
+                    })
+                }
+                _ => {
+                    return Err(TemplateError {
+                        line,
+                        column,
+                        message: format!(
+                            "unknown tag `{{% {} %}}`; wrap literal `{{%` in `{{% raw %}}` … `{{% endraw %}}`",
+                            inner
+                        ),
+                    })
+                }
+            }
+            if matches!(keyword, "if" | "elif") == args.is_empty() {
+                return Err(TemplateError {
+                    line,
+                    column,
+                    message: if args.is_empty() {
+                        format!("`{{% {} %}}` needs a condition", keyword)
+                    } else {
+                        format!("`{{% {} %}}` takes no arguments", keyword)
+                    },
+                });
+            }
+
+            let (span_start, span_end) = standalone_span(text, found, end);
+            push_text(&mut tokens, &text[text_start..span_start.max(text_start)]);
+            cursor = span_end;
+            text_start = span_end;
+
+            if keyword == "raw" {
+                let (raw_end, after) =
+                    find_endraw(text, span_end).ok_or_else(|| TemplateError {
+                        line,
+                        column,
+                        message: "`{% raw %}` is never closed; add `{% endraw %}`".to_string(),
+                    })?;
+                push_text(&mut tokens, &text[span_end..raw_end]);
+                cursor = after;
+                text_start = after;
+            } else {
+                tokens.push(Token::Tag {
+                    keyword: keyword.to_string(),
+                    args: args.to_string(),
+                    line,
+                    column,
+                });
+            }
+        } else {
+            cursor = found + 1;
+        }
+    }
+
+    push_text(&mut tokens, &text[text_start..]);
+    Ok(tokens)
+}
+
+/// Whether `rest` starts with a `{% raw %}` tag
+fn is_raw_tag(rest: &str) -> bool {
+    rest.strip_prefix("{%")
+        .and_then(|r| r.find("%}").map(|close| r[..close].trim() == "raw"))
+        .unwrap_or(false)
+}
+
+/// Find the `{% endraw %}` closing a raw block starting at `from`
+///
+/// Returns the end of the raw content and the offset just past the closing tag.
+fn find_endraw(text: &str, from: usize) -> Option<(usize, usize)> {
+    let mut cursor = from;
+    while let Some(found) = text[cursor..].find("{%").map(|i| cursor + i) {
+        let close = text[found..].find("%}")?;
+        let end = found + close + 2;
+        if text[found + 2..found + close].trim() == "endraw" {
+            let (span_start, span_end) = standalone_span(text, found, end);
+            return Some((span_start.max(from), span_end));
+        }
+        cursor = end;
+    }
+    None
+}
+
+fn push_text(tokens: &mut Vec<Token>, text: &str) {
+    if !text.is_empty() {
+        tokens.push(Token::Text(text.to_string()));
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn vars(pairs: &[(&str, &str)]) -> BTreeMap<String, String> {
+        pairs
+            .iter()
+            .map(|(k, v)| (k.to_string(), v.to_string()))
+            .collect()
+    }
+
+    fn render(text: &str, vars: &BTreeMap<String, String>, target: Target) -> String {
+        let template = Template::parse(text).unwrap();
+        template.check_vars(vars).unwrap();
+        template.render(&TemplateContext {
+            vars,
+            target,
+            scope: Scope::Project,
+            partials: &BTreeMap::new(),
+        })
+    }
+
+    #[test]
+    fn renders_variables_and_builtins() {
+        let vars = vars(&[("project", "calvin")]);
+        assert_eq!(
+            render(
+                "{{ project }} for {{target}} ({{ scope }})",
+                &vars,
+                Target::Cursor
+            ),
+            "calvin for cursor (project)"
+        );
+    }
+
+    #[test]
+    fn renders_target_conditionals() {
+        let text = "Intro\n{% if target == \"cursor\" %}\nCursor only\n{% elif target == \"vscode\" %}\nCopilot only\n{% else %}\nEveryone else\n{% endif %}\nOutro\n";
+        let vars = BTreeMap::new();
+        assert_eq!(
+            render(text, &vars, Target::Cursor),
+            "Intro\nCursor only\nOutro\n"
+        );
+        assert_eq!(
+            render(text, &vars, Target::VSCode),
+            "Intro\nCopilot only\nOutro\n"
+        );
+        assert_eq!(
+            render(text, &vars, Target::Codex),
+            "Intro\nEveryone else\nOutro\n"
+        );
+    }
+
+    #[test]
+    fn inline_conditionals_keep_surrounding_text() {
+        let vars = vars(&[("lang", "rust")]);
+        assert_eq!(
+            render(
+                "Use {% if lang == 'rust' %}cargo{% else %}make{% endif %}.",
+                &vars,
+                Target::Cursor
+            ),
+            "Use cargo."
+        );
+        assert_eq!(
+            render(
+                "A{% if target != \"cursor\" %}B{% endif %}C",
+                &vars,
+                Target::Cursor
+            ),
+            "AC"
+        );
+    }
+
+    #[test]
+    fn leaves_non_variable_braces_alone() {
+        let vars = BTreeMap::new();
+        assert_eq!(
+            render(
+                "token: ${{ secrets.TOKEN }} and {{ }} and { x }",
+                &vars,
+                Target::Cursor
+            ),
+            "token: ${{ secrets.TOKEN }} and {{ }} and { x }"
+        );
+    }
+
+    #[test]
+    fn markdown_code_is_literal() {
+        let vars = vars(&[("project", "calvin")]);
+        let text = "# {{ project }}\n\n```vue\n<p>{{ message }}</p>\n{% for x in y %}\n```\n\nUse `{{ name }}` or ``{% if a %}``.\n~~~~\n{{ other }}\n~~~\n{{ still_code }}\n~~~~\nAfter {{ project }}.\n";
+        assert_eq!(
+            render(text, &vars, Target::Cursor),
+            "# calvin\n\n```vue\n<p>{{ message }}</p>\n{% for x in y %}\n```\n\nUse `{{ name }}` or ``{% if a %}``.\n~~~~\n{{ other }}\n~~~\n{{ still_code }}\n~~~~\nAfter calvin.\n"
+        );
+    }
+
+    #[test]
+    fn unmatched_backticks_do_not_hide_variables() {
+        let vars = vars(&[("project", "calvin")]);
+        assert_eq!(
+            render("A ` tick\n\n{{ project }} `x`", &vars, Target::Cursor),
+            "A ` tick\n\ncalvin `x`"
+        );
+    }
+
+    #[test]
+    fn raw_blocks_are_verbatim() {
+        let vars = BTreeMap::new();
+        assert_eq!(
+            render(
+                "```html\n{% raw %}\n<p>{{ message }}</p>{% if x %}\n{% endraw %}\n```",
+                &vars,
+                Target::Cursor
+            ),
+            "```html\n<p>{{ message }}</p>{% if x %}\n```"
+        );
+    }
+
+    #[test]
+    fn undefined_variable_reports_position() {
+        let template = Template::parse("Line one\n  see {{ projct }}").unwrap();
+        let err = template.check_vars(&vars(&[("project", "x")])).unwrap_err();
+        assert_eq!((err.line, err.column), (2, 7));
+        assert_eq!(err.message, "undefined variable `projct`");
+    }
+
+    #[test]
+    fn undefined_variables_in_untaken_branches_are_reported() {
+        let template =
+            Template::parse("{% if target == \"codex\" %}{{ missing }}{% endif %}").unwrap();
+        assert!(template.check_vars(&BTreeMap::new()).is_err());
+    }
+
+    #[test]
+    fn syntax_errors_report_position() {
+        let cases = [
+            (
+                "a\n{% if target == \"cursor\" %}\nb",
+                (2, 1),
+                "never closed",
+            ),
+            ("{% endif %}", (1, 1), "without a matching"),
+            ("x {% for a in b %}", (1, 3), "unknown tag"),
+            (
+                "{% if target == \"cursr\" %}{% endif %}",
+                (1, 1),
+                "Did you mean 'cursor'",
+            ),
+            ("{% if lang == rust %}{% endif %}", (1, 1), "quoted string"),
+            ("{% raw %}never closed", (1, 1), "never closed"),
+            ("{% else %}", (1, 1), "without a matching"),
+            ("{% if %}{% endif %}", (1, 1), "needs a condition"),
+        ];
+        for (text, (line, column), needle) in cases {
+            let err = Template::parse(text).unwrap_err();
+            assert_eq!((err.line, err.column), (line, column), "{}", text);
+            assert!(err.message.contains(needle), "{}: {}", text, err.message);
+        }
+    }
+
+    #[test]
+    fn renders_includes_as_templates() {
+        let partials = BTreeMap::from([(
+            "partials/secrets.md".to_string(),
+            Template::parse("Never commit secrets to {{ project }}.").unwrap(),
+        )]);
+        let template = Template::parse(
+            "# Rules\n  {{> ./partials/secrets.md }}\nInline: {{>partials/secrets.md}}",
+        )
+        .unwrap();
+
+        let includes: Vec<_> = template
+            .includes()
+            .iter()
+            .map(|i| (i.path.as_str(), i.line, i.column))
+            .collect();
+        assert_eq!(
+            includes,
+            vec![("partials/secrets.md", 2, 3), ("partials/secrets.md", 3, 9)]
+        );
+
+        let vars = vars(&[("project", "calvin")]);
+        let rendered = template.render(&TemplateContext {
+            vars: &vars,
+            target: Target::Cursor,
+            scope: Scope::Project,
+            partials: &partials,
+        });
+        assert_eq!(
+            rendered,
+            "# Rules\nNever commit secrets to calvin.\nInline: Never commit secrets to calvin."
+        );
+    }
+
+    #[test]
+    fn include_without_path_is_an_error() {
+        let err = Template::parse("a\n{{> }}").unwrap_err();
+        assert_eq!((err.line, err.column), (2, 1));
+        assert!(
+            err.message.contains("needs a partial path"),
+            "{}",
+            err.message
+        );
+    }
+
+    #[test]
+    fn contains_syntax_detects_tags() {
+        assert!(Template::contains_syntax("{{ x }}"));
+        assert!(Template::contains_syntax("{% if x %}"));
+        assert!(!Template::contains_syntax("plain { text }"));
+        assert!(!Template::contains_syntax(
+            "```vue\n<p>{{ message }}</p>\n```\nSee `{% if %}`."
+        ));
+        assert!(Template::contains_syntax(
+            "```\n{% raw %}{{ x }}{% endraw %}\n```"
+        ));
+    }
+}
@@ -0,0 +1,57 @@
+//! Activation value object - when a policy is loaded into the assistant's context
+//!
+//! The `activation:` frontmatter field is target-neutral; adapters map it onto
+//! their own rule metadata (Cursor `alwaysApply`/`globs`, Windsurf `trigger`, ...):
+//!
+//! - `always`: loaded into every request
+//! - `glob`: loaded when a file matching `apply` is in context
+//! - `model-decision`: the model decides from the policy's description
+//! - `manual`: loaded only when the user mentions it
+
+use serde::{Deserialize, Serialize};
+use std::fmt;
+
+/// How a policy is activated
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Serialize, Deserialize)]
+#[serde(rename_all = "kebab-case")]
+pub enum Activation {
+    Always,
+    Glob,
+    ModelDecision,
+    Manual,
+}
+
+impl Activation {
+    /// The frontmatter value for this activation
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            Activation::Always => "always",
+            Activation::Glob => "glob",
+            Activation::ModelDecision => "model-decision",
+            Activation::Manual => "manual",
+        }
+    }
+}
+
+impl fmt::Display for Activation {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(self.as_str())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn deserializes_kebab_case_values() {
+        let activation: Activation = serde_yaml_ng::from_str("model-decision").unwrap();
+        assert_eq!(activation, Activation::ModelDecision);
+        assert_eq!(activation.to_string(), "model-decision");
+    }
+
+    #[test]
+    fn rejects_unknown_value() {
+        assert!(serde_yaml_ng::from_str::<Activation>("auto").is_err());
+    }
+}
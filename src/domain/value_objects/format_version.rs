@@ -0,0 +1,128 @@
+//! Source Format Version Value Object
+//!
+//! Each `.promptpack` layer may declare the schema its sources are written in:
+//!
+//! ```toml
+//! [format]
+//! version = "1.0"
+//! ```
+//!
+//! Calvin compiles the current format and older formats that are still supported
+//! (deprecated); packs declaring a newer format require a newer Calvin.
+
+use std::fmt;
+
+/// A `major.minor` source format version
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct FormatVersion {
+    major: u32,
+    minor: u32,
+}
+
+/// How this Calvin build supports a declared format version
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum FormatSupport {
+    /// The current format
+    Current,
+    /// Still compiled, but should be migrated with `calvin migrate --format`
+    Deprecated,
+    /// Newer than this Calvin build understands
+    TooNew,
+    /// Older than the oldest format this Calvin build can compile
+    TooOld,
+}
+
+impl FormatVersion {
+    /// The format written by `calvin init` and produced by `calvin migrate`
+    pub const CURRENT: FormatVersion = FormatVersion::new(1, 0);
+
+    /// The oldest format that still compiles
+    pub const OLDEST_SUPPORTED: FormatVersion = FormatVersion::new(1, 0);
+
+    /// The format of layers that don't declare `[format] version` (pre-versioning packs)
+    pub const LEGACY: FormatVersion = FormatVersion::new(1, 0);
+
+    /// Create a format version
+    pub const fn new(major: u32, minor: u32) -> Self {
+        Self { major, minor }
+    }
+
+    /// Parse `"1"`, `"1.0"` or `"1.1"` style versions
+    pub fn parse(s: &str) -> Result<Self, String> {
+        let trimmed = s.trim();
+        let (major, minor) = trimmed.split_once('.').unwrap_or((trimmed, "0"));
+        match (major.parse(), minor.parse()) {
+            (Ok(major), Ok(minor)) => Ok(Self::new(major, minor)),
+            _ => Err(format!(
+                "invalid format version '{}' (expected e.g. \"{}\")",
+                s,
+                Self::CURRENT
+            )),
+        }
+    }
+
+    /// How this Calvin build supports this version
+    pub fn support(&self) -> FormatSupport {
+        self.support_between(Self::OLDEST_SUPPORTED, Self::CURRENT)
+    }
+
+    fn support_between(&self, oldest: FormatVersion, current: FormatVersion) -> FormatSupport {
+        if *self > current {
+            FormatSupport::TooNew
+        } else if *self == current {
+            FormatSupport::Current
+        } else if *self >= oldest {
+            FormatSupport::Deprecated
+        } else {
+            FormatSupport::TooOld
+        }
+    }
+}
+
+impl fmt::Display for FormatVersion {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}.{}", self.major, self.minor)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn parse_accepts_major_and_major_minor() {
+        assert_eq!(FormatVersion::parse("1.1"), Ok(FormatVersion::new(1, 1)));
+        assert_eq!(FormatVersion::parse(" 2 "), Ok(FormatVersion::new(2, 0)));
+        assert!(FormatVersion::parse("latest").is_err());
+        assert!(FormatVersion::parse("1.x").is_err());
+    }
+
+    #[test]
+    fn versions_order_numerically() {
+        assert!(FormatVersion::new(1, 10) > FormatVersion::new(1, 9));
+        assert!(FormatVersion::new(2, 0) > FormatVersion::new(1, 10));
+    }
+
+    #[test]
+    fn support_classifies_versions() {
+        assert_eq!(FormatVersion::CURRENT.support(), FormatSupport::Current);
+        assert_eq!(FormatVersion::LEGACY.support(), FormatSupport::Current);
+        assert_eq!(FormatVersion::new(2, 0).support(), FormatSupport::TooNew);
+        assert_eq!(FormatVersion::new(0, 9).support(), FormatSupport::TooOld);
+    }
+
+    #[test]
+    fn support_reports_versions_between_oldest_and_current_as_deprecated() {
+        let (oldest, current) = (FormatVersion::new(1, 0), FormatVersion::new(1, 2));
+        let support = |minor| FormatVersion::new(1, minor).support_between(oldest, current);
+        assert_eq!(support(0), FormatSupport::Deprecated);
+        assert_eq!(support(1), FormatSupport::Deprecated);
+        assert_eq!(support(2), FormatSupport::Current);
+        assert_eq!(support(3), FormatSupport::TooNew);
+    }
+
+    #[test]
+    fn display_is_major_dot_minor() {
+        assert_eq!(FormatVersion::new(1, 0).to_string(), "1.0");
+    }
+}
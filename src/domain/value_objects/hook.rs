@@ -0,0 +1,92 @@
+//! Hook Value Objects
+//!
+//! A hook asset (`.promptpack/hooks/`) runs a shell command on a Claude Code
+//! lifecycle event, optionally filtered by a tool-name matcher.
+
+use serde::{Deserialize, Serialize};
+use std::fmt;
+
+/// Claude Code lifecycle event a hook runs on
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, PartialOrd, Ord, Serialize, Deserialize)]
+pub enum HookEvent {
+    PreToolUse,
+    PostToolUse,
+    Notification,
+    UserPromptSubmit,
+    Stop,
+    SubagentStop,
+    PreCompact,
+    SessionStart,
+    SessionEnd,
+}
+
+impl HookEvent {
+    /// Name of the event in Claude Code settings
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            HookEvent::PreToolUse => "PreToolUse",
+            HookEvent::PostToolUse => "PostToolUse",
+            HookEvent::Notification => "Notification",
+            HookEvent::UserPromptSubmit => "UserPromptSubmit",
+            HookEvent::Stop => "Stop",
+            HookEvent::SubagentStop => "SubagentStop",
+            HookEvent::PreCompact => "PreCompact",
+            HookEvent::SessionStart => "SessionStart",
+            HookEvent::SessionEnd => "SessionEnd",
+        }
+    }
+
+    /// Whether Claude Code filters this event with a `matcher`
+    ///
+    /// Tool events match tool names; `PreCompact` and `SessionStart` match their trigger.
+    pub fn supports_matcher(&self) -> bool {
+        matches!(
+            self,
+            HookEvent::PreToolUse
+                | HookEvent::PostToolUse
+                | HookEvent::PreCompact
+                | HookEvent::SessionStart
+        )
+    }
+}
+
+impl fmt::Display for HookEvent {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(self.as_str())
+    }
+}
+
+/// What a hook asset runs, and when
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct HookSpec {
+    pub event: HookEvent,
+    /// Tool-name pattern (e.g. `Edit|Write`); `None` matches everything
+    pub matcher: Option<String>,
+    pub command: String,
+    /// Timeout in seconds (Claude Code's default when `None`)
+    pub timeout: Option<u32>,
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn event_deserializes_from_settings_name() {
+        let event: HookEvent = serde_yaml_ng::from_str("PreToolUse").unwrap();
+        assert_eq!(event, HookEvent::PreToolUse);
+        assert_eq!(event.to_string(), "PreToolUse");
+    }
+
+    #[test]
+    fn event_rejects_unknown_name() {
+        assert!(serde_yaml_ng::from_str::<HookEvent>("pre_tool_use").is_err());
+    }
+
+    #[test]
+    fn only_tool_and_trigger_events_support_matcher() {
+        assert!(HookEvent::PostToolUse.supports_matcher());
+        assert!(HookEvent::SessionStart.supports_matcher());
+        assert!(!HookEvent::Stop.supports_matcher());
+    }
+}
@@ -0,0 +1,164 @@
+//! Merge specification for shared configuration outputs
+//!
+//! Some outputs (MCP configs, settings files) live in files that also hold
+//! user-authored settings. A `MergeSpec` lists the keys Calvin owns in such a
+//! file so deploy can merge them structurally instead of replacing the file.
+//!
+//! Owned keys are JSON Pointers (RFC 6901). When a pointer's parent is an
+//! array, the last segment names an array element by its string value, so
+//! `/permissions/deny/.env` owns only the `.env` entry of the deny list. In JSON,
+//! object elements are named by their `slug` (`/customModes/reviewer`), and Claude
+//! Code hook groups by the command they run (`/hooks/Stop/make lint`).
+//!
+//! Markdown documents have no keys; there an owned key names a managed region
+//! delimited by `<!-- calvin:begin <key> -->` and `<!-- calvin:end -->`.
+
+use std::path::Path;
+
+/// Structured document format of a mergeable output
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum DocumentFormat {
+    Json,
+    Yaml,
+    Toml,
+    Markdown,
+}
+
+impl DocumentFormat {
+    /// Infer the format from a file extension (`.toml` → TOML, `.md` → Markdown,
+    /// `.yaml`/`.yml` → YAML, everything else → JSON)
+    pub fn from_path(path: &Path) -> Self {
+        match path.extension().and_then(|e| e.to_str()) {
+            Some("toml") => DocumentFormat::Toml,
+            Some("md") => DocumentFormat::Markdown,
+            Some("yaml" | "yml") => DocumentFormat::Yaml,
+            _ => DocumentFormat::Json,
+        }
+    }
+
+    /// Infer the format of an existing file from its path and content
+    ///
+    /// Files without an extension (Roo Code's `.roomodes`) may be written as YAML
+    /// or JSON; they are YAML unless the content is empty or a JSON object.
+    pub fn detect(path: &Path, existing: &str) -> Self {
+        let content = existing.trim_start();
+        if path.extension().is_none() && !content.is_empty() && !content.starts_with('{') {
+            DocumentFormat::Yaml
+        } else {
+            DocumentFormat::from_path(path)
+        }
+    }
+}
+
+/// The keys Calvin owns inside a shared configuration file
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct MergeSpec {
+    owned_keys: Vec<String>,
+    always: bool,
+}
+
+impl MergeSpec {
+    /// Create a merge spec owning the given key pointers
+    pub fn new(owned_keys: Vec<String>) -> Self {
+        Self {
+            owned_keys,
+            always: false,
+        }
+    }
+
+    /// Builder: merge even without `deploy --merge`
+    ///
+    /// Used for files Calvin must never replace wholesale (security baselines).
+    pub fn always(mut self) -> Self {
+        self.always = true;
+        self
+    }
+
+    /// Get the owned key pointers
+    pub fn owned_keys(&self) -> &[String] {
+        &self.owned_keys
+    }
+
+    /// Whether merging is applied regardless of `--merge`
+    pub fn is_always(&self) -> bool {
+        self.always
+    }
+}
+
+/// Build an owned-key pointer from unescaped segments
+pub fn json_pointer(segments: &[&str]) -> String {
+    segments
+        .iter()
+        .map(|s| format!("/{}", s.replace('~', "~0").replace('/', "~1")))
+        .collect()
+}
+
+/// Split an owned-key pointer into unescaped segments
+pub fn pointer_segments(pointer: &str) -> Vec<String> {
+    pointer
+        .split('/')
+        .skip(1)
+        .map(|s| s.replace("~1", "/").replace("~0", "~"))
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn format_from_path() {
+        assert_eq!(
+            DocumentFormat::from_path(Path::new(".codex/config.toml")),
+            DocumentFormat::Toml
+        );
+        assert_eq!(
+            DocumentFormat::from_path(Path::new(".mcp.json")),
+            DocumentFormat::Json
+        );
+        assert_eq!(
+            DocumentFormat::from_path(Path::new("CLAUDE.md")),
+            DocumentFormat::Markdown
+        );
+        assert_eq!(
+            DocumentFormat::from_path(Path::new("modes.yaml")),
+            DocumentFormat::Yaml
+        );
+    }
+
+    #[test]
+    fn detect_reads_extensionless_files() {
+        let roomodes = Path::new(".roomodes");
+        assert_eq!(DocumentFormat::detect(roomodes, ""), DocumentFormat::Json);
+        assert_eq!(
+            DocumentFormat::detect(roomodes, "\n{ \"customModes\": [] }"),
+            DocumentFormat::Json
+        );
+        assert_eq!(
+            DocumentFormat::detect(roomodes, "customModes:\n  - slug: docs\n"),
+            DocumentFormat::Yaml
+        );
+        assert_eq!(
+            DocumentFormat::detect(Path::new(".mcp.json"), "// comment\n{}"),
+            DocumentFormat::Json
+        );
+    }
+
+    #[test]
+    fn json_pointer_escapes_segments() {
+        assert_eq!(
+            json_pointer(&["permissions", "deny", "secrets/**"]),
+            "/permissions/deny/secrets~1**"
+        );
+        assert_eq!(json_pointer(&["a~b"]), "/a~0b");
+    }
+
+    #[test]
+    fn pointer_segments_roundtrip() {
+        let pointer = json_pointer(&["permissions", "deny", "~/secrets/**"]);
+        assert_eq!(
+            pointer_segments(&pointer),
+            vec!["permissions", "deny", "~/secrets/**"]
+        );
+    }
+}
@@ -0,0 +1,66 @@
+//! Merge strategy value object - how an asset combines with a lower layer's asset
+//!
+//! When a higher layer defines an asset with the same ID as a lower layer, the
+//! `merge:` frontmatter field decides what the merged asset looks like:
+//!
+//! - `replace` (default): the higher asset replaces the lower one entirely
+//! - `append`: higher frontmatter, lower body followed by higher body
+//! - `prepend`: higher frontmatter, higher body followed by lower body
+//! - `frontmatter-only`: higher frontmatter, lower body
+
+use serde::{Deserialize, Serialize};
+
+/// How an asset is merged onto the same asset from a lower layer
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Serialize, Deserialize, Default)]
+#[serde(rename_all = "kebab-case")]
+pub enum MergeStrategy {
+    #[default]
+    Replace,
+    Append,
+    Prepend,
+    FrontmatterOnly,
+}
+
+impl MergeStrategy {
+    /// The frontmatter value for this strategy
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            MergeStrategy::Replace => "replace",
+            MergeStrategy::Append => "append",
+            MergeStrategy::Prepend => "prepend",
+            MergeStrategy::FrontmatterOnly => "frontmatter-only",
+        }
+    }
+
+    /// Whether the lower layer's asset contributes to the result
+    pub fn keeps_lower(&self) -> bool {
+        !matches!(self, MergeStrategy::Replace)
+    }
+}
+
+impl std::fmt::Display for MergeStrategy {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str(self.as_str())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn deserializes_kebab_case_values() {
+        let strategy: MergeStrategy = serde_yaml_ng::from_str("frontmatter-only").unwrap();
+        assert_eq!(strategy, MergeStrategy::FrontmatterOnly);
+        assert_eq!(strategy.to_string(), "frontmatter-only");
+        assert!(serde_yaml_ng::from_str::<MergeStrategy>("merge").is_err());
+    }
+
+    #[test]
+    fn only_replace_discards_the_lower_asset() {
+        assert!(!MergeStrategy::Replace.keeps_lower());
+        assert!(MergeStrategy::Append.keeps_lower());
+        assert!(MergeStrategy::Prepend.keeps_lower());
+        assert!(MergeStrategy::FrontmatterOnly.keeps_lower());
+    }
+}
@@ -3,22 +3,36 @@
 //! Immutable value types that represent domain concepts.
 //! These are defined in the domain layer but can be re-exported for legacy code.
 
+mod activation;
 mod config_warning;
 mod deploy_target;
+mod format_version;
 mod hash;
+mod hook;
 mod ignore_patterns;
 mod lockfile_namespace;
+mod merge_spec;
+mod merge_strategy;
 mod path;
+mod remote_layer;
 mod scope;
 mod security_mode;
 mod target;
+mod tool_version;
 
+pub use activation::Activation;
 pub use config_warning::ConfigWarning;
 pub use deploy_target::DeployTarget;
+pub use format_version::{FormatSupport, FormatVersion};
 pub use hash::ContentHash;
+pub use hook::{HookEvent, HookSpec};
 pub use ignore_patterns::{IgnoreError, IgnorePatterns};
 pub use lockfile_namespace::{lockfile_key, parse_lockfile_key, LockfileNamespace};
+pub use merge_spec::{json_pointer, pointer_segments, DocumentFormat, MergeSpec};
+pub use merge_strategy::MergeStrategy;
 pub use path::{PathError, SafePath};
+pub use remote_layer::RemoteLayer;
 pub use scope::Scope;
 pub use security_mode::SecurityMode;
-pub use target::{Target, TargetParseError};
+pub use target::{CustomTargetName, Target, TargetParseError};
+pub use tool_version::ToolVersion;
@@ -0,0 +1,130 @@
+//! Remote layer value object - a layer fetched instead of read in place
+//!
+//! Entries of `sources.additional_layers` (and `--layer`) are normally local
+//! directories. Two forms are fetched into the layer cache instead:
+//!
+//! - `git+<url>[#<ref>]` - a git repository at a branch, tag or commit
+//!   (e.g. `git+file:///srv/packs/team.git#v1.2`, `git+https://…/pack.git`)
+//! - a local tarball (`.tar`, `.tar.gz`, `.tgz`), optionally as a `file://` URL
+
+use std::fmt;
+use std::path::{Path, PathBuf};
+
+/// A layer source that must be fetched before it can be loaded
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum RemoteLayer {
+    /// A git repository (`reference` defaults to the remote `HEAD`)
+    Git {
+        url: String,
+        reference: Option<String>,
+    },
+    /// A tarball on the local filesystem
+    Tarball { path: PathBuf },
+}
+
+impl RemoteLayer {
+    /// Parse a layer entry; `None` for plain local directories
+    pub fn parse(spec: &str) -> Option<Self> {
+        let spec = spec.trim();
+
+        if let Some(rest) = spec.strip_prefix("git+") {
+            let (url, reference) = match rest.split_once('#') {
+                Some((url, reference)) if !reference.is_empty() => {
+                    (url, Some(reference.to_string()))
+                }
+                Some((url, _)) => (url, None),
+                None => (rest, None),
+            };
+            if url.is_empty() {
+                return None;
+            }
+            return Some(RemoteLayer::Git {
+                url: url.to_string(),
+                reference,
+            });
+        }
+
+        const TARBALL_EXTENSIONS: &[&str] = &[".tar", ".tar.gz", ".tgz"];
+        if TARBALL_EXTENSIONS.iter().any(|ext| spec.ends_with(ext)) {
+            let path = spec.strip_prefix("file://").unwrap_or(spec);
+            return Some(RemoteLayer::Tarball {
+                path: PathBuf::from(path),
+            });
+        }
+
+        None
+    }
+
+    /// Parse a configured layer path (see [`RemoteLayer::parse`])
+    pub fn from_path(path: &Path) -> Option<Self> {
+        path.to_str().and_then(Self::parse)
+    }
+}
+
+impl fmt::Display for RemoteLayer {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            RemoteLayer::Git {
+                url,
+                reference: Some(reference),
+            } => write!(f, "git+{}#{}", url, reference),
+            RemoteLayer::Git {
+                url,
+                reference: None,
+            } => write!(f, "git+{}", url),
+            RemoteLayer::Tarball { path } => write!(f, "{}", path.display()),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn parses_git_sources_with_and_without_ref() {
+        assert_eq!(
+            RemoteLayer::parse("git+file:///srv/team.git#v1.2"),
+            Some(RemoteLayer::Git {
+                url: "file:///srv/team.git".to_string(),
+                reference: Some("v1.2".to_string()),
+            })
+        );
+        assert_eq!(
+            RemoteLayer::parse("git+https://example.com/pack.git"),
+            Some(RemoteLayer::Git {
+                url: "https://example.com/pack.git".to_string(),
+                reference: None,
+            })
+        );
+        assert_eq!(RemoteLayer::parse("git+#main"), None);
+    }
+
+    #[test]
+    fn parses_tarballs_by_extension() {
+        assert_eq!(
+            RemoteLayer::parse("file:///tmp/pack.tar.gz"),
+            Some(RemoteLayer::Tarball {
+                path: PathBuf::from("/tmp/pack.tar.gz"),
+            })
+        );
+        assert_eq!(
+            RemoteLayer::parse("~/packs/team.tgz"),
+            Some(RemoteLayer::Tarball {
+                path: PathBuf::from("~/packs/team.tgz"),
+            })
+        );
+    }
+
+    #[test]
+    fn local_directories_are_not_remote() {
+        assert_eq!(RemoteLayer::parse("~/team/.promptpack"), None);
+        assert_eq!(RemoteLayer::from_path(Path::new("/srv/pack")), None);
+    }
+
+    #[test]
+    fn display_round_trips_git_specs() {
+        let spec = "git+file:///srv/team.git#v1.2";
+        assert_eq!(RemoteLayer::parse(spec).unwrap().to_string(), spec);
+    }
+}
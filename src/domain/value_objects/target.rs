@@ -1,40 +1,87 @@
 //! Target value object - defines which AI platform to compile for
 
-use serde::{Deserialize, Serialize};
+use std::collections::BTreeSet;
+use std::str::FromStr;
+use std::sync::Mutex;
+
+use serde::{Deserialize, Deserializer, Serialize, Serializer};
 
 /// Target platform for compilation
-#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Serialize, Deserialize, clap::ValueEnum)]
-#[serde(rename_all = "kebab-case")]
+///
+/// Serialized by its [`id`](Target::id); any other kebab-case name parses as a
+/// custom target, which callers check against `[[targets.custom]]` with
+/// [`Target::check_declared`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
 pub enum Target {
     /// Claude Code (Anthropic)
-    #[serde(alias = "claude")]
     ClaudeCode,
     /// Cursor IDE
     Cursor,
     /// VS Code with GitHub Copilot
-    #[serde(alias = "vscode")]
     VSCode,
     /// Google Antigravity/Gemini
     Antigravity,
     /// OpenAI Codex CLI
     Codex,
     /// OpenCode (SST) terminal agent
-    #[serde(rename = "opencode", alias = "open-code")]
-    #[value(name = "opencode", alias = "open-code")]
     OpenCode,
+    /// Google Gemini CLI
+    GeminiCli,
+    /// Windsurf IDE (Codeium)
+    Windsurf,
+    /// Cline VS Code extension (Roo Code via `[targets.cline] flavor`)
+    Cline,
+    /// A declarative target from `[[targets.custom]]` in config.toml
+    Custom(CustomTargetName),
     /// All platforms (meta-target, expands to all specific targets)
     All,
 }
 
+/// Name of a `[[targets.custom]]` declaration
+///
+/// Names are interned so that `Target` stays `Copy`; a config declares a handful
+/// of them, so the leaked strings are bounded.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, PartialOrd, Ord)]
+pub struct CustomTargetName(&'static str);
+
+impl CustomTargetName {
+    pub fn new(name: &str) -> Self {
+        static NAMES: Mutex<BTreeSet<&'static str>> = Mutex::new(BTreeSet::new());
+
+        let mut names = NAMES.lock().unwrap_or_else(|e| e.into_inner());
+        if let Some(interned) = names.get(name) {
+            return Self(interned);
+        }
+        let interned: &'static str = Box::leak(name.to_owned().into_boxed_str());
+        names.insert(interned);
+        Self(interned)
+    }
+
+    pub fn as_str(&self) -> &'static str {
+        self.0
+    }
+
+    /// Custom target names are kebab-case (a-z, 0-9, -)
+    pub fn is_valid(name: &str) -> bool {
+        !name.is_empty()
+            && name
+                .chars()
+                .all(|c| c.is_ascii_lowercase() || c.is_ascii_digit() || c == '-')
+    }
+}
+
 impl Target {
-    /// All concrete targets (excluding `All`)
-    pub const ALL_CONCRETE: [Target; 6] = [
+    /// All built-in concrete targets (excluding `All`; custom targets come from config)
+    pub const ALL_CONCRETE: [Target; 9] = [
         Target::ClaudeCode,
         Target::Cursor,
         Target::VSCode,
         Target::Antigravity,
         Target::Codex,
         Target::OpenCode,
+        Target::GeminiCli,
+        Target::Windsurf,
+        Target::Cline,
     ];
 
     /// Returns true if this is the `All` meta-target
@@ -50,7 +97,17 @@ impl Target {
         )
     }
 
-    /// Expand `All` to concrete targets, or return self if already concrete
+    /// Returns true if this target is declared in `[[targets.custom]]`
+    pub fn is_custom(&self) -> bool {
+        matches!(self, Target::Custom(_))
+    }
+
+    /// Create a custom target from its declared name
+    pub fn custom(name: &str) -> Target {
+        Target::Custom(CustomTargetName::new(name))
+    }
+
+    /// Expand `All` to built-in concrete targets, or return self if already concrete
     pub fn expand(&self) -> Vec<Target> {
         if self.is_all() {
             Self::ALL_CONCRETE.to_vec()
@@ -68,7 +125,11 @@ impl Target {
             Target::Antigravity => ".gemini",
             Target::Codex => ".codex",
             Target::OpenCode => ".opencode",
-            Target::All => ".all", // Should not be used directly
+            Target::GeminiCli => ".gemini",
+            Target::Windsurf => ".windsurf",
+            Target::Cline => ".clinerules",
+            Target::Custom(_) => ".custom", // Paths come from config
+            Target::All => ".all",          // Should not be used directly
         }
     }
 
@@ -81,9 +142,43 @@ impl Target {
             Target::Antigravity => "Antigravity",
             Target::Codex => "Codex",
             Target::OpenCode => "OpenCode",
+            Target::GeminiCli => "Gemini CLI",
+            Target::Windsurf => "Windsurf",
+            Target::Cline => "Cline",
+            Target::Custom(name) => name.as_str(),
             Target::All => "All",
         }
     }
+
+    /// Get the canonical name used in config files and `calvin.lock` (e.g. `claude-code`)
+    pub fn id(&self) -> &'static str {
+        match self {
+            Target::ClaudeCode => "claude-code",
+            Target::Cursor => "cursor",
+            Target::VSCode => "vscode",
+            Target::Antigravity => "antigravity",
+            Target::Codex => "codex",
+            Target::OpenCode => "opencode",
+            Target::GeminiCli => "gemini-cli",
+            Target::Windsurf => "windsurf",
+            Target::Cline => "cline",
+            Target::Custom(name) => name.as_str(),
+            Target::All => "all",
+        }
+    }
+
+    /// Check a custom target against the names declared in `[[targets.custom]]`
+    ///
+    /// Built-in targets always pass; an undeclared name is reported like a typo.
+    pub fn check_declared(&self, declared: &[&str]) -> Result<(), TargetParseError> {
+        match self {
+            Target::Custom(name) if !declared.contains(&name.as_str()) => Err(TargetParseError {
+                invalid: name.as_str().to_string(),
+                suggestion: Self::suggest_target(name.as_str(), declared),
+            }),
+            _ => Ok(()),
+        }
+    }
 }
 
 impl std::fmt::Display for Target {
@@ -105,6 +200,10 @@ impl Target {
         "codex",
         "opencode",
         "open-code", // alias for opencode
+        "gemini-cli",
+        "gemini", // alias for gemini-cli
+        "windsurf",
+        "cline",
         "all",
     ];
 
@@ -116,10 +215,15 @@ impl Target {
         "antigravity",
         "codex",
         "opencode",
+        "gemini-cli",
+        "windsurf",
+        "cline",
         "all",
     ];
 
-    /// Parse a target name with helpful error message
+    /// Parse a built-in target name with helpful error message
+    ///
+    /// Custom targets are rejected here; use `str::parse` to accept them.
     ///
     /// Accepts various aliases:
     /// - `claude` or `claude-code` → ClaudeCode
@@ -132,9 +236,12 @@ impl Target {
             "antigravity" => Ok(Target::Antigravity),
             "codex" => Ok(Target::Codex),
             "opencode" | "open-code" | "open_code" => Ok(Target::OpenCode),
+            "gemini-cli" | "gemini" | "gemini_cli" => Ok(Target::GeminiCli),
+            "windsurf" => Ok(Target::Windsurf),
+            "cline" => Ok(Target::Cline),
             "all" => Ok(Target::All),
             _ => {
-                let suggestion = Self::suggest_target(s);
+                let suggestion = Self::suggest_target(s, &[]);
                 Err(TargetParseError {
                     invalid: s.to_string(),
                     suggestion,
@@ -143,8 +250,8 @@ impl Target {
         }
     }
 
-    /// Suggest a valid target name based on typo
-    fn suggest_target(input: &str) -> Option<String> {
+    /// Suggest a valid target name (built-in or declared custom) based on typo
+    fn suggest_target(input: &str, custom: &[&str]) -> Option<String> {
         let input_lower = input.to_lowercase();
 
         // Common typos and shortcuts
@@ -155,9 +262,11 @@ impl Target {
             ("vsc", "vscode"),
             ("anti", "antigravity"),
             ("gravity", "antigravity"),
-            ("gemini", "antigravity"),
+            ("gemini cli", "gemini-cli"),
             ("open code", "opencode"),
             ("open", "opencode"),
+            ("roo", "cline"),
+            ("roo code", "cline"),
         ];
 
         for (typo, correct) in aliases {
@@ -168,7 +277,7 @@ impl Target {
 
         // Levenshtein distance for other typos
         let mut best: Option<(&str, usize)> = None;
-        for valid in Self::CANONICAL_NAMES {
+        for valid in Self::CANONICAL_NAMES.iter().chain(custom) {
             let dist = levenshtein(&input_lower, valid);
             match best {
                 None => best = Some((valid, dist)),
@@ -184,6 +293,35 @@ impl Target {
     }
 }
 
+/// Parses built-in names and aliases; any other kebab-case name is a custom target
+impl FromStr for Target {
+    type Err = TargetParseError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        Target::from_str_with_suggestion(s).or_else(|err| {
+            let name = s.trim();
+            if CustomTargetName::is_valid(name) {
+                Ok(Target::custom(name))
+            } else {
+                Err(err)
+            }
+        })
+    }
+}
+
+impl Serialize for Target {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        serializer.serialize_str(self.id())
+    }
+}
+
+impl<'de> Deserialize<'de> for Target {
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        let name = String::deserialize(deserializer)?;
+        name.parse().map_err(serde::de::Error::custom)
+    }
+}
+
 /// Error when parsing an invalid target name
 #[derive(Debug, Clone)]
 pub struct TargetParseError {
@@ -235,8 +373,8 @@ mod tests {
     use super::*;
 
     #[test]
-    fn target_all_concrete_has_6_targets() {
-        assert_eq!(Target::ALL_CONCRETE.len(), 6);
+    fn target_all_concrete_has_9_targets() {
+        assert_eq!(Target::ALL_CONCRETE.len(), 9);
     }
 
     #[test]
@@ -248,7 +386,7 @@ mod tests {
     #[test]
     fn target_expand_all() {
         let expanded = Target::All.expand();
-        assert_eq!(expanded.len(), 6);
+        assert_eq!(expanded.len(), 9);
     }
 
     #[test]
@@ -270,6 +408,16 @@ mod tests {
         assert_eq!(Target::Cursor.display_name(), "Cursor");
     }
 
+    #[test]
+    fn target_ids_parse_back() {
+        for target in Target::ALL_CONCRETE {
+            assert_eq!(
+                Target::from_str_with_suggestion(target.id()).unwrap(),
+                target
+            );
+        }
+    }
+
     #[test]
     fn target_serde_kebab_case() {
         let json = "\"claude-code\"";
@@ -314,12 +462,73 @@ mod tests {
             Target::from_str_with_suggestion("opencode").unwrap(),
             Target::OpenCode
         );
+        assert_eq!(
+            Target::from_str_with_suggestion("gemini-cli").unwrap(),
+            Target::GeminiCli
+        );
+        assert_eq!(
+            Target::from_str_with_suggestion("gemini").unwrap(),
+            Target::GeminiCli
+        );
+        assert_eq!(
+            Target::from_str_with_suggestion("windsurf").unwrap(),
+            Target::Windsurf
+        );
+        assert_eq!(
+            Target::from_str_with_suggestion("cline").unwrap(),
+            Target::Cline
+        );
         assert_eq!(
             Target::from_str_with_suggestion("all").unwrap(),
             Target::All
         );
     }
 
+    #[test]
+    fn target_parse_accepts_custom_names() {
+        let target: Target = "aider".parse().unwrap();
+        assert_eq!(target, Target::custom("aider"));
+        assert_eq!(target.id(), "aider");
+        assert_ne!(target, Target::custom("zed"));
+        assert_eq!("claude".parse::<Target>().unwrap(), Target::ClaudeCode);
+        assert!("Not A Target".parse::<Target>().is_err());
+        assert!(Target::from_str_with_suggestion("aider").is_err());
+    }
+
+    #[test]
+    fn target_serde_roundtrips_custom_names() {
+        let target: Target = serde_json::from_str("\"aider\"").unwrap();
+        assert_eq!(target, Target::custom("aider"));
+        assert_eq!(serde_json::to_string(&target).unwrap(), "\"aider\"");
+        assert_eq!(
+            serde_json::to_string(&Target::VSCode).unwrap(),
+            "\"vscode\""
+        );
+    }
+
+    #[test]
+    fn check_declared_rejects_undeclared_custom_targets() {
+        assert!(Target::Cursor.check_declared(&[]).is_ok());
+        assert!(Target::custom("aider").check_declared(&["aider"]).is_ok());
+
+        let err = Target::custom("aidr")
+            .check_declared(&["aider", "zed"])
+            .unwrap_err();
+        assert_eq!(err.invalid, "aidr");
+        assert_eq!(err.suggestion, Some("aider".to_string()));
+
+        let err = Target::custom("cursr").check_declared(&[]).unwrap_err();
+        assert_eq!(err.suggestion, Some("cursor".to_string()));
+    }
+
+    #[test]
+    fn target_serde_gemini_cli() {
+        let target: Target = serde_json::from_str("\"gemini-cli\"").unwrap();
+        assert_eq!(target, Target::GeminiCli);
+        let target: Target = serde_json::from_str("\"gemini\"").unwrap();
+        assert_eq!(target, Target::GeminiCli);
+    }
+
     #[test]
     fn target_from_str_with_suggestion_claude_alias() {
         // "claude" is now a valid alias for "claude-code"
@@ -356,6 +565,9 @@ mod tests {
         assert!(Target::OpenCode.supports_skills());
         assert!(!Target::VSCode.supports_skills());
         assert!(!Target::Antigravity.supports_skills());
+        assert!(!Target::GeminiCli.supports_skills());
+        assert!(!Target::Windsurf.supports_skills());
+        assert!(!Target::Cline.supports_skills());
     }
 
     #[test]
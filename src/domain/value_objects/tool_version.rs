@@ -0,0 +1,129 @@
+//! Tool Version Value Object
+//!
+//! Version of an installed AI coding tool (Claude Code, Cursor, VS Code, ...),
+//! as reported by `<binary> --version`. Adapters declare the range of tool versions
+//! they were written and tested against (TD-18).
+
+use std::fmt;
+
+/// A `major.minor.patch` tool version
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct ToolVersion {
+    major: u32,
+    minor: u32,
+    patch: u32,
+}
+
+impl ToolVersion {
+    /// Create a tool version
+    pub const fn new(major: u32, minor: u32, patch: u32) -> Self {
+        Self {
+            major,
+            minor,
+            patch,
+        }
+    }
+
+    pub fn major(&self) -> u32 {
+        self.major
+    }
+
+    pub fn minor(&self) -> u32 {
+        self.minor
+    }
+
+    pub fn patch(&self) -> u32 {
+        self.patch
+    }
+
+    /// Parse `"1.2.3"`, `"1.2"` or `"v1.2.3"` (pre-release/build suffixes are ignored)
+    pub fn parse(s: &str) -> Option<Self> {
+        let trimmed = s.trim().trim_start_matches('v');
+        let core = trimmed.split(['-', '+']).next().unwrap_or(trimmed);
+
+        let mut parts = core.split('.');
+        let major = parts.next()?.parse().ok()?;
+        let minor = parts.next()?.parse().ok()?;
+        let patch = match parts.next() {
+            Some(patch) => patch.parse().ok()?,
+            None => 0,
+        };
+        if parts.next().is_some() {
+            return None;
+        }
+        Some(Self::new(major, minor, patch))
+    }
+
+    /// Find the first version number in free-form `--version` output
+    ///
+    /// Handles e.g. `"1.0.30 (Claude Code)"`, `"codex-cli 0.46.0"` and the multi-line
+    /// output of `code --version`.
+    pub fn find_in(output: &str) -> Option<Self> {
+        output
+            .split(|c: char| c.is_whitespace() || c == '(' || c == ')' || c == ',')
+            .find_map(|token| {
+                let token = token.trim_start_matches(|c: char| !c.is_ascii_digit());
+                token.contains('.').then(|| Self::parse(token)).flatten()
+            })
+    }
+
+    /// Whether this version is a newer major/minor release than `tested`
+    ///
+    /// Patch releases of a tested minor version are considered compatible.
+    pub fn is_newer_release_than(&self, tested: &ToolVersion) -> bool {
+        (self.major, self.minor) > (tested.major, tested.minor)
+    }
+}
+
+impl fmt::Display for ToolVersion {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}.{}.{}", self.major, self.minor, self.patch)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn parse_accepts_common_forms() {
+        assert_eq!(ToolVersion::parse("1.2.3"), Some(ToolVersion::new(1, 2, 3)));
+        assert_eq!(
+            ToolVersion::parse("v0.46"),
+            Some(ToolVersion::new(0, 46, 0))
+        );
+        assert_eq!(
+            ToolVersion::parse("2.0.1-beta.2"),
+            Some(ToolVersion::new(2, 0, 1))
+        );
+        assert_eq!(ToolVersion::parse("1"), None);
+        assert_eq!(ToolVersion::parse("1.2.3.4"), None);
+        assert_eq!(ToolVersion::parse("latest"), None);
+    }
+
+    #[test]
+    fn find_in_extracts_version_from_tool_output() {
+        assert_eq!(
+            ToolVersion::find_in("1.0.30 (Claude Code)\n"),
+            Some(ToolVersion::new(1, 0, 30))
+        );
+        assert_eq!(
+            ToolVersion::find_in("codex-cli 0.46.0"),
+            Some(ToolVersion::new(0, 46, 0))
+        );
+        assert_eq!(
+            ToolVersion::find_in("1.105.1\n7d842fb85a0275a4a8e4d7e040d2625abbf7f084\nx64\n"),
+            Some(ToolVersion::new(1, 105, 1))
+        );
+        assert_eq!(ToolVersion::find_in("no version here"), None);
+    }
+
+    #[test]
+    fn newer_release_ignores_patch_versions() {
+        let tested = ToolVersion::new(1, 4, 0);
+        assert!(!ToolVersion::new(1, 4, 9).is_newer_release_than(&tested));
+        assert!(ToolVersion::new(1, 5, 0).is_newer_release_than(&tested));
+        assert!(ToolVersion::new(2, 0, 0).is_newer_release_than(&tested));
+        assert!(!ToolVersion::new(1, 3, 0).is_newer_release_than(&tested));
+    }
+}
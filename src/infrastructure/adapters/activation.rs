@@ -0,0 +1,91 @@
+//! Policy activation helpers shared by target adapters
+
+use crate::domain::entities::{Asset, AssetKind};
+use crate::domain::ports::target_adapter::{AdapterDiagnostic, DiagnosticSeverity};
+use crate::domain::value_objects::{Activation, Target};
+
+/// Windsurf-style `trigger` value (also read by Antigravity rules)
+pub(crate) fn trigger(activation: Activation) -> &'static str {
+    match activation {
+        Activation::Always => "always_on",
+        Activation::Glob => "glob",
+        Activation::ModelDecision => "model_decision",
+        Activation::Manual => "manual",
+    }
+}
+
+/// Downgrade diagnostic for a policy whose `activation` the output cannot express
+///
+/// Only an explicit `activation` is reported; the default derived from `apply` is
+/// never a downgrade. `applied_as` names how the output actually behaves.
+pub(crate) fn activation_downgrade(
+    asset: &Asset,
+    target: Target,
+    supported: &[Activation],
+    applied_as: Activation,
+) -> Vec<AdapterDiagnostic> {
+    let Some(activation) = asset.activation() else {
+        return Vec::new();
+    };
+    if asset.kind() != AssetKind::Policy || supported.contains(&activation) {
+        return Vec::new();
+    }
+
+    vec![AdapterDiagnostic {
+        severity: DiagnosticSeverity::Warning,
+        message: format!(
+            "Policy '{}' activation '{}' is not supported on {}; applied as '{}'",
+            asset.id(),
+            activation,
+            target.display_name(),
+            applied_as
+        ),
+    }]
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn policy(activation: Option<Activation>) -> Asset {
+        Asset::new("style", "policies/style.md", "Style", "x")
+            .with_kind(AssetKind::Policy)
+            .with_activation(activation)
+    }
+
+    #[test]
+    fn unsupported_activation_is_reported() {
+        let diags = activation_downgrade(
+            &policy(Some(Activation::Manual)),
+            Target::Codex,
+            &[Activation::Always],
+            Activation::Always,
+        );
+
+        assert_eq!(diags.len(), 1);
+        assert_eq!(
+            diags[0].message,
+            "Policy 'style' activation 'manual' is not supported on Codex; applied as 'always'"
+        );
+    }
+
+    #[test]
+    fn supported_or_derived_activation_is_not_reported() {
+        let supported = [Activation::Always, Activation::Glob];
+
+        assert!(activation_downgrade(
+            &policy(Some(Activation::Always)),
+            Target::Cline,
+            &supported,
+            Activation::Always
+        )
+        .is_empty());
+        assert!(activation_downgrade(
+            &policy(None).with_apply("**/*.rs"),
+            Target::Codex,
+            &[Activation::Always],
+            Activation::Always
+        )
+        .is_empty());
+    }
+}
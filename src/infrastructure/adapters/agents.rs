@@ -2,8 +2,9 @@
 //!
 //! Reduces duplication across adapters by centralizing agent output generation.
 
-use crate::domain::entities::Asset;
+use crate::domain::entities::{Asset, AssetKind};
 use crate::domain::ports::target_adapter::{AdapterDiagnostic, AdapterError, DiagnosticSeverity};
+use crate::domain::value_objects::Target;
 
 /// Serializable agent frontmatter for YAML output
 #[derive(serde::Serialize)]
@@ -53,8 +54,8 @@ pub(crate) fn generate_agent_md(asset: &Asset, footer: &str) -> Result<String, A
 
     let mut out = String::new();
     out.push_str("---\n");
-    out.push_str(yaml.trim_end());
-    out.push_str("\n---\n\n");
+    out.push_str(&yaml);
+    out.push_str("---\n\n");
     out.push_str(asset.content().trim());
     out.push_str("\n\n");
     out.push_str(footer);
@@ -62,6 +63,58 @@ pub(crate) fn generate_agent_md(asset: &Asset, footer: &str) -> Result<String, A
     Ok(out)
 }
 
+/// Downgrade diagnostic for an agent on a target without full agent support
+///
+/// `compiled_as` names the fallback output (`None` when the agent is skipped). Agent
+/// fields the agent sets but the output does not keep (`supported`) are reported as
+/// ignored. Non-agents, and agents losing nothing, get no diagnostic.
+pub(crate) fn agent_downgrade(
+    asset: &Asset,
+    target: Target,
+    compiled_as: Option<&str>,
+    supported: &[&str],
+) -> Vec<AdapterDiagnostic> {
+    if asset.kind() != AssetKind::Agent {
+        return Vec::new();
+    }
+
+    let message = match compiled_as {
+        None => format!(
+            "Agent '{}' skipped on {}: no native agent format",
+            asset.id(),
+            target.display_name()
+        ),
+        Some(output) => {
+            let set = [
+                ("tools", !asset.agent_tools().is_empty()),
+                ("model", asset.agent_model().is_some()),
+                ("permissionMode", asset.agent_permission_mode().is_some()),
+                ("skills", !asset.agent_skills().is_empty()),
+            ];
+            let ignored: Vec<&str> = set
+                .iter()
+                .filter(|(field, is_set)| *is_set && !supported.contains(field))
+                .map(|(field, _)| *field)
+                .collect();
+            if ignored.is_empty() {
+                return Vec::new();
+            }
+            format!(
+                "Agent '{}' compiled as {} on {}; ignores {}",
+                asset.id(),
+                output,
+                target.display_name(),
+                ignored.join(", ")
+            )
+        }
+    };
+
+    vec![AdapterDiagnostic {
+        severity: DiagnosticSeverity::Warning,
+        message,
+    }]
+}
+
 /// Validates agent-specific frontmatter fields.
 /// TODO: Wire this into compilation when we add diagnostics collection to compile().
 /// Currently unused but kept for future asset-level validation support.
@@ -130,7 +183,6 @@ pub(crate) fn validate_agent_fields(asset: &Asset) -> Vec<AdapterDiagnostic> {
 #[cfg(test)]
 mod tests {
     use super::*;
-    use crate::domain::entities::AssetKind;
     use crate::domain::value_objects::Scope;
 
     fn create_agent_asset(id: &str, description: &str, content: &str) -> Asset {
@@ -287,4 +339,41 @@ mod tests {
 
         assert!(diags.is_empty());
     }
+
+    #[test]
+    fn agent_downgrade_reports_skipped_agent() {
+        let asset = create_agent_asset("reviewer", "Code reviewer", "x");
+
+        let diags = agent_downgrade(&asset, Target::Antigravity, None, &[]);
+
+        assert_eq!(diags.len(), 1);
+        assert_eq!(diags[0].severity, DiagnosticSeverity::Warning);
+        assert_eq!(
+            diags[0].message,
+            "Agent 'reviewer' skipped on Antigravity: no native agent format"
+        );
+    }
+
+    #[test]
+    fn agent_downgrade_lists_only_set_unsupported_fields() {
+        let asset = create_agent_asset("reviewer", "Code reviewer", "x")
+            .with_agent_tools(vec!["Read".to_string()])
+            .with_agent_model(Some("sonnet".to_string()));
+
+        let diags = agent_downgrade(&asset, Target::Codex, Some("a prompt"), &["model"]);
+
+        assert_eq!(diags.len(), 1);
+        assert!(diags[0]
+            .message
+            .ends_with("compiled as a prompt on Codex; ignores tools"));
+    }
+
+    #[test]
+    fn agent_downgrade_is_empty_when_nothing_is_lost() {
+        let agent = create_agent_asset("simple", "Simple", "x");
+        let action = Asset::new("a", "actions/a.md", "Action", "x").with_kind(AssetKind::Action);
+
+        assert!(agent_downgrade(&agent, Target::Codex, Some("a prompt"), &[]).is_empty());
+        assert!(agent_downgrade(&action, Target::Antigravity, None, &[]).is_empty());
+    }
 }
@@ -4,6 +4,10 @@
 //! - `.agent/rules/<id>.md` - Rules (Policy)
 //! - `.agent/workflows/<id>.md` - Workflows (Action/Agent)
 //!
+//! Antigravity has no agent format: agents are compiled as workflows, with a downgrade
+//! diagnostic for the agent fields a workflow cannot keep. Skills are skipped, unless `[targets.antigravity] skills = "fallback"` compiles
+//! them as workflows, with their files copied to `.agent/skills/<id>/`.
+//!
 //! Path matrix (from platform.md):
 //! - Project scope: `.agent/rules/` or `.agent/workflows/`
 //! - User scope: `~/.gemini/antigravity/global_rules/` or `~/.gemini/antigravity/global_workflows/`
@@ -11,21 +15,56 @@
 //! Improvement over legacy adapter:
 //! - Distinguishes between rules (Policy) and workflows (Action/Agent)
 //! - Supports globs via apply field
+//! - Rules carry a Windsurf-style `trigger` (`always_on`, `glob`, `model_decision`,
+//!   `manual`) derived from the policy's `activation`
 
 use std::path::PathBuf;
 
-use crate::domain::entities::{Asset, AssetKind, OutputFile};
+use super::activation;
+use super::agents::agent_downgrade;
+use super::frontmatter::FrontmatterBuilder;
+use super::skills;
+use crate::config::SkillFallbackMode;
+use crate::domain::entities::{Asset, AssetKind, BinaryOutputFile, OutputFile};
 use crate::domain::ports::target_adapter::{
     AdapterDiagnostic, AdapterError, DiagnosticSeverity, TargetAdapter,
 };
+use crate::domain::ports::tool_probe::{DetectedTool, ToolProbe};
 use crate::domain::value_objects::{Scope, Target};
 
 /// Antigravity adapter
-pub struct AntigravityAdapter;
+pub struct AntigravityAdapter {
+    skill_mode: SkillFallbackMode,
+}
 
 impl AntigravityAdapter {
     pub fn new() -> Self {
-        Self
+        Self {
+            skill_mode: SkillFallbackMode::default(),
+        }
+    }
+
+    /// Builder: compile skills as workflows (`[targets.antigravity] skills`)
+    pub fn with_skill_mode(mut self, mode: SkillFallbackMode) -> Self {
+        self.skill_mode = mode;
+        self
+    }
+
+    /// Create an adapter from the `[targets.antigravity]` config table
+    pub fn from_config(config: &crate::config::Config) -> Self {
+        Self::new().with_skill_mode(config.targets.antigravity.skills)
+    }
+
+    fn is_skill_fallback(&self, asset: &Asset) -> bool {
+        self.skill_mode == SkillFallbackMode::Fallback && asset.kind() == AssetKind::Skill
+    }
+
+    /// Directory that receives a fallback skill's supplementals, per skill id
+    fn skill_files_dir(&self, scope: Scope) -> PathBuf {
+        match scope {
+            Scope::User => PathBuf::from("~/.gemini/antigravity/skills"),
+            Scope::Project => PathBuf::from(".agent/skills"),
+        }
     }
 
     /// Get the output directory based on asset kind and scope
@@ -39,10 +78,13 @@ impl AntigravityAdapter {
             (AssetKind::Action | AssetKind::Agent, Scope::User) => {
                 PathBuf::from("~/.gemini/antigravity/global_workflows")
             }
-            // Skills are not supported on Antigravity, but we still provide a deterministic
-            // path for internal callers (compile() returns early for skills).
-            (AssetKind::Skill, Scope::Project) => PathBuf::from(".agent/workflows"),
-            (AssetKind::Skill, Scope::User) => {
+            // Skills become workflows with the fallback. Hooks are not supported on
+            // Antigravity, but we still provide a deterministic path for internal callers
+            // (compile() returns early for them).
+            (AssetKind::Skill | AssetKind::Hook, Scope::Project) => {
+                PathBuf::from(".agent/workflows")
+            }
+            (AssetKind::Skill | AssetKind::Hook, Scope::User) => {
                 PathBuf::from("~/.gemini/antigravity/global_workflows")
             }
         }
@@ -50,19 +92,17 @@ impl AntigravityAdapter {
 
     /// Generate frontmatter
     fn generate_frontmatter(&self, asset: &Asset) -> String {
-        let mut fm = String::from("---\n");
-        fm.push_str(&format!("description: {}\n", asset.description()));
-
-        // Add globs if apply pattern is specified
-        if let Some(apply) = asset.apply() {
-            fm.push_str(&format!("globs: \"{}\"\n", apply));
-        }
-
-        // Preserve extra frontmatter fields (e.g., version, version_date, changelog)
-        fm.push_str(&super::format_extra_frontmatter(asset.extra_frontmatter()));
-
-        fm.push_str("---\n");
-        fm
+        let trigger = (asset.kind() == AssetKind::Policy)
+            .then(|| activation::trigger(asset.effective_activation()));
+
+        FrontmatterBuilder::new()
+            .field_opt("trigger", trigger)
+            .field("description", asset.description())
+            // Add globs if the policy is glob-activated
+            .field_opt("globs", asset.activation_globs())
+            // Preserve extra frontmatter fields (e.g., version, version_date, changelog)
+            .extra(asset.extra_frontmatter())
+            .render()
     }
 }
 
@@ -77,10 +117,22 @@ impl TargetAdapter for AntigravityAdapter {
         Target::Antigravity
     }
 
+    /// v2: rules carry a `trigger` derived from `activation`
+    fn output_format_version(&self) -> u32 {
+        2
+    }
+
+    fn detect_version(&self, probe: &dyn ToolProbe) -> Option<DetectedTool> {
+        DetectedTool::probe(probe, "antigravity", ".gemini/antigravity")
+    }
+
     fn compile(&self, asset: &Asset) -> Result<Vec<OutputFile>, AdapterError> {
-        // Skills and Agents are not supported on Antigravity.
-        // Antigravity has no sub-agent delegation system like Claude Code.
-        if matches!(asset.kind(), AssetKind::Skill | AssetKind::Agent) {
+        let skill_fallback = self.is_skill_fallback(asset);
+
+        // Skills (without the fallback) and Hooks are not supported on Antigravity.
+        // Agents become workflows: Antigravity has no sub-agent delegation system like
+        // Claude Code (see downgrades()).
+        if !skill_fallback && matches!(asset.kind(), AssetKind::Skill | AssetKind::Hook) {
             return Ok(Vec::new());
         }
 
@@ -89,15 +141,48 @@ impl TargetAdapter for AntigravityAdapter {
         let output_dir = self.output_dir(asset.kind(), asset.scope());
         let path = output_dir.join(format!("{}.md", asset.id()));
 
+        let files_dir = self.skill_files_dir(asset.scope()).join(asset.id());
+        let body = if skill_fallback {
+            skills::skill_fallback_body(asset, &files_dir)
+        } else {
+            asset.content().trim().to_string()
+        };
+
         let frontmatter = self.generate_frontmatter(asset);
         let footer = self.footer(&asset.source_path_normalized());
-        let content = format!("{}\n{}\n\n{}", frontmatter, asset.content().trim(), footer);
+        let content = format!("{}\n{}\n\n{}", frontmatter, body, footer);
 
         outputs.push(OutputFile::new(path, content, self.target()));
 
+        if skill_fallback {
+            outputs.extend(
+                skills::compile_supplementals(asset, &files_dir, self.target(), "skill")?.outputs,
+            );
+        }
+
         Ok(outputs)
     }
 
+    fn compile_binary(&self, asset: &Asset) -> Result<Vec<BinaryOutputFile>, AdapterError> {
+        if !self.is_skill_fallback(asset) {
+            return Ok(Vec::new());
+        }
+
+        let files_dir = self.skill_files_dir(asset.scope()).join(asset.id());
+        Ok(
+            skills::compile_supplementals(asset, &files_dir, self.target(), "skill")?
+                .binary_outputs,
+        )
+    }
+
+    fn downgrades(&self, asset: &Asset) -> Vec<AdapterDiagnostic> {
+        let mut diagnostics = agent_downgrade(asset, self.target(), Some("a workflow"), &[]);
+        if self.is_skill_fallback(asset) {
+            diagnostics.extend(skills::skill_downgrade(asset, self.target(), "a workflow"));
+        }
+        diagnostics
+    }
+
     fn validate(&self, output: &OutputFile) -> Vec<AdapterDiagnostic> {
         let mut diagnostics = Vec::new();
 
@@ -123,6 +208,7 @@ impl TargetAdapter for AntigravityAdapter {
 #[cfg(test)]
 mod tests {
     use super::*;
+    use crate::domain::value_objects::Activation;
     use std::collections::HashMap;
 
     fn create_policy_asset(id: &str, description: &str, content: &str) -> Asset {
@@ -211,7 +297,35 @@ mod tests {
 
         let outputs = adapter.compile(&asset).unwrap();
 
-        assert!(outputs[0].content().contains("globs: \"**/*.rs\""));
+        assert!(outputs[0]
+            .content()
+            .starts_with("---\ntrigger: glob\ndescription: Rust rules\nglobs: '**/*.rs'\n"));
+    }
+
+    #[test]
+    fn compile_policy_activation_sets_trigger() {
+        let adapter = AntigravityAdapter::new();
+        let asset = create_policy_asset("rust-rules", "Rust rules", "# Rust")
+            .with_apply("**/*.rs")
+            .with_activation(Some(Activation::ModelDecision));
+
+        let outputs = adapter.compile(&asset).unwrap();
+
+        assert!(outputs[0]
+            .content()
+            .starts_with("---\ntrigger: model_decision\ndescription: Rust rules\n---\n"));
+    }
+
+    #[test]
+    fn compile_action_has_no_trigger() {
+        let adapter = AntigravityAdapter::new();
+        let asset = create_action_asset("build", "Build", "# Build");
+
+        let outputs = adapter.compile(&asset).unwrap();
+
+        assert!(outputs[0]
+            .content()
+            .starts_with("---\ndescription: Build\n"));
     }
 
     #[test]
@@ -248,12 +362,74 @@ mod tests {
     }
 
     #[test]
-    fn compile_agent_returns_empty() {
+    fn skill_fallback_compiles_workflow_and_files() {
+        let adapter = AntigravityAdapter::new().with_skill_mode(SkillFallbackMode::Fallback);
+        let mut supplementals = HashMap::new();
+        supplementals.insert(PathBuf::from("reference.md"), "# Ref".to_string());
+        let asset = create_skill_asset("my-skill", "My skill", "# Instructions")
+            .with_supplementals(supplementals)
+            .with_scope(Scope::User);
+
+        let outputs = adapter.compile(&asset).unwrap();
+
+        assert_eq!(outputs.len(), 2);
+        assert_eq!(
+            outputs[0].path(),
+            &PathBuf::from("~/.gemini/antigravity/global_workflows/my-skill.md")
+        );
+        assert!(outputs[0]
+            .content()
+            .starts_with("---\ndescription: My skill\n---\n\n# Instructions"));
+        assert!(outputs[0]
+            .content()
+            .contains("- `~/.gemini/antigravity/skills/my-skill/reference.md`"));
+        assert_eq!(
+            outputs[1].path(),
+            &PathBuf::from("~/.gemini/antigravity/skills/my-skill/reference.md")
+        );
+        assert!(adapter.downgrades(&asset)[0]
+            .message
+            .contains("compiled as a workflow on Antigravity"));
+    }
+
+    #[test]
+    fn from_config_reads_skill_mode() {
+        let config: crate::config::Config =
+            toml::from_str("[targets.antigravity]\nskills = \"fallback\"\n").unwrap();
+
+        let adapter = AntigravityAdapter::from_config(&config);
+
+        assert_eq!(adapter.skill_mode, SkillFallbackMode::Fallback);
+    }
+
+    #[test]
+    fn compile_agent_as_workflow() {
         let adapter = AntigravityAdapter::new();
         let asset = create_agent_asset("reviewer", "Code reviewer", "You are a reviewer.");
 
         let outputs = adapter.compile(&asset).unwrap();
-        assert!(outputs.is_empty());
+        assert_eq!(outputs.len(), 1);
+        assert_eq!(
+            outputs[0].path(),
+            &PathBuf::from(".agent/workflows/reviewer.md")
+        );
+        assert!(outputs[0].content().contains("description: Code reviewer"));
+        assert!(outputs[0].content().contains("You are a reviewer."));
+        assert!(adapter.downgrades(&asset).is_empty());
+    }
+
+    #[test]
+    fn agent_fields_dropped_by_workflow_are_reported() {
+        let adapter = AntigravityAdapter::new();
+        let asset = create_agent_asset("reviewer", "Code reviewer", "You are a reviewer.")
+            .with_agent_tools(vec!["Read".to_string()]);
+
+        let diags = adapter.downgrades(&asset);
+        assert_eq!(diags.len(), 1);
+        assert_eq!(
+            diags[0].message,
+            "Agent 'reviewer' compiled as a workflow on Antigravity; ignores tools"
+        );
     }
 
     // === TDD: Validate Tests ===
@@ -304,9 +480,9 @@ mod tests {
     }
 
     #[test]
-    fn adapter_version_is_one() {
+    fn adapter_output_format_version_is_two() {
         let adapter = AntigravityAdapter::new();
-        assert_eq!(adapter.version(), 1);
+        assert_eq!(adapter.output_format_version(), 2);
     }
 
     #[test]
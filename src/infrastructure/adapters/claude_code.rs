@@ -2,30 +2,66 @@
 //!
 //! Generates output for Claude Code (Anthropic):
 //! - `.claude/commands/<id>.md` - Slash commands (Actions, Policies)
+//! - `CLAUDE.md` - Policies, in a managed section (`policies = "memory"`)
+//! - `.claude/rules/<id>.md` - Policies, path-scoped by `apply` (`policies = "rules"`)
 //! - `.claude/agents/<id>.md` - Agent definitions
 //! - `.claude/skills/<id>/SKILL.md` - Skills with supplemental files
+//! - `.claude/settings.json` - Security baseline (`permissions.deny`) and Hooks (`hooks`)
+//! - `.claude/hooks/<id>/` - Hook script supplementals
+//! - `.mcp.json` - MCP servers (project scope only)
 //!
 //! Path matrix (from platform.md):
 //! - Commands: `.claude/commands/` (Project), `~/.claude/commands/` (User)
 //! - Agents: `.claude/agents/` (Project), `~/.claude/agents/` (User)
 //! - Skills: `.claude/skills/` (Project), `~/.claude/skills/` (User)
-
+//! - Memory: `CLAUDE.md` (Project), `~/.claude/CLAUDE.md` (User)
+//! - Rules: `.claude/rules/` (Project), `~/.claude/rules/` (User)
+//! - Settings: `.claude/settings.json` (Project), `~/.claude/settings.json` (User)
+//! - Hook scripts: `.claude/hooks/` (Project), `~/.claude/hooks/` (User)
+//! - MCP: `.mcp.json` (Project); user-level servers live in `~/.claude.json`, which
+//!   Claude Code owns, so they are not generated
+
+use std::collections::{BTreeMap, HashMap};
 use std::path::PathBuf;
 
+use super::activation::activation_downgrade;
 use super::agents;
+use super::frontmatter::FrontmatterBuilder;
+use super::managed_region_output;
+use super::mcp;
 use super::skills;
-use crate::domain::entities::{Asset, AssetKind, BinaryOutputFile, OutputFile};
+use crate::config::ClaudePolicyMode;
+use crate::domain::entities::{Asset, AssetKind, BinaryOutputFile, McpServer, OutputFile};
 use crate::domain::ports::target_adapter::{
     AdapterDiagnostic, AdapterError, DiagnosticSeverity, TargetAdapter,
 };
-use crate::domain::value_objects::{Scope, Target};
+use crate::domain::ports::tool_probe::{DetectedTool, ToolProbe};
+use crate::domain::services::CALVIN_TAG;
+use crate::domain::value_objects::{
+    json_pointer, Activation, HookEvent, MergeSpec, Scope, Target, ToolVersion,
+};
 
 /// Claude Code adapter
-pub struct ClaudeCodeAdapter;
+pub struct ClaudeCodeAdapter {
+    policy_mode: ClaudePolicyMode,
+}
 
 impl ClaudeCodeAdapter {
     pub fn new() -> Self {
-        Self
+        Self {
+            policy_mode: ClaudePolicyMode::default(),
+        }
+    }
+
+    /// Builder: choose where policies are compiled to (`[targets.claude-code] policies`)
+    pub fn with_policy_mode(mut self, mode: ClaudePolicyMode) -> Self {
+        self.policy_mode = mode;
+        self
+    }
+
+    /// Create an adapter from the `[targets.claude-code]` config table
+    pub fn from_config(config: &crate::config::Config) -> Self {
+        Self::new().with_policy_mode(config.targets.claude_code.policies)
     }
 
     /// Get the commands directory based on scope
@@ -44,6 +80,21 @@ impl ClaudeCodeAdapter {
         }
     }
 
+    /// Get the hook scripts directory based on scope
+    fn hooks_dir(&self, scope: Scope) -> PathBuf {
+        match scope {
+            Scope::User => PathBuf::from("~/.claude/hooks"),
+            Scope::Project => PathBuf::from(".claude/hooks"),
+        }
+    }
+
+    fn settings_path(&self, scope: Scope) -> PathBuf {
+        match scope {
+            Scope::User => PathBuf::from("~").join(SETTINGS_PATH),
+            Scope::Project => PathBuf::from(SETTINGS_PATH),
+        }
+    }
+
     fn agents_dir(&self, scope: Scope) -> PathBuf {
         match scope {
             Scope::User => PathBuf::from("~/.claude/agents"),
@@ -51,6 +102,155 @@ impl ClaudeCodeAdapter {
         }
     }
 
+    fn rules_dir(&self, scope: Scope) -> PathBuf {
+        match scope {
+            Scope::User => PathBuf::from("~/.claude/rules"),
+            Scope::Project => PathBuf::from(".claude/rules"),
+        }
+    }
+
+    fn memory_path(&self, scope: Scope) -> PathBuf {
+        match scope {
+            Scope::User => PathBuf::from("~/.claude/CLAUDE.md"),
+            Scope::Project => PathBuf::from(MEMORY_PATH),
+        }
+    }
+
+    /// Compile a policy into `.claude/rules/<id>.md`
+    ///
+    /// `apply` globs become the `paths` frontmatter of glob-activated policies; rules
+    /// without it always load.
+    fn compile_rule(&self, asset: &Asset) -> Vec<OutputFile> {
+        let rule_path = self
+            .rules_dir(asset.scope())
+            .join(format!("{}.md", asset.id()));
+        let footer = self.footer(&asset.source_path_normalized());
+
+        let mut content = String::new();
+        let globs: Vec<&str> = asset
+            .activation_globs()
+            .map(|apply| {
+                apply
+                    .split(',')
+                    .map(str::trim)
+                    .filter(|g| !g.is_empty())
+                    .collect()
+            })
+            .unwrap_or_default();
+        if !globs.is_empty() {
+            content.push_str(&FrontmatterBuilder::new().field("paths", globs).render());
+            content.push('\n');
+        }
+        content.push_str(asset.content().trim());
+        content.push_str("\n\n");
+        content.push_str(&footer);
+
+        vec![OutputFile::new(rule_path, content, Target::ClaudeCode)]
+    }
+
+    /// Render the policies of `scope` into the managed section of `CLAUDE.md`
+    ///
+    /// The file is shared with the user, so only the section is owned by Calvin.
+    fn compile_memory(&self, assets: &[Asset], scope: Scope) -> Option<OutputFile> {
+        let policies: Vec<&Asset> = assets
+            .iter()
+            .filter(|a| a.kind() == AssetKind::Policy && a.scope() == scope)
+            .collect();
+        if policies.is_empty() {
+            return None;
+        }
+
+        let sections: Vec<String> = policies
+            .iter()
+            .map(|policy| {
+                let body = policy.content().trim();
+                if policy.description().trim().is_empty() {
+                    body.to_string()
+                } else {
+                    format!("## {}\n\n{}", policy.description().trim(), body)
+                }
+            })
+            .collect();
+        Some(managed_region_output(
+            self.memory_path(scope),
+            &sections.join("\n\n"),
+            Target::ClaudeCode,
+        ))
+    }
+
+    /// Render the hooks of `scope` into the `hooks` block of `settings.json`
+    ///
+    /// Each hook becomes its own matcher group, tagged with the hook id. The settings
+    /// file is shared with the user and the security baseline, so Calvin owns only its
+    /// tagged groups, named by command; user groups running the same command are kept.
+    fn compile_hooks(
+        &self,
+        assets: &[Asset],
+        scope: Scope,
+    ) -> Result<Option<OutputFile>, AdapterError> {
+        let mut groups: BTreeMap<HookEvent, Vec<serde_json::Value>> = BTreeMap::new();
+        let mut owned_keys = Vec::new();
+        let mut owners: HashMap<(HookEvent, &str), &str> = HashMap::new();
+
+        for asset in assets.iter().filter(|a| a.scope() == scope) {
+            let Some(hook) = asset.hook() else {
+                continue;
+            };
+            if let Some(other) = owners.insert((hook.event, &hook.command), asset.id()) {
+                return Err(AdapterError::CompilationFailed {
+                    message: format!(
+                        "Hooks '{}' and '{}' both run `{}` on {}",
+                        other,
+                        asset.id(),
+                        hook.command,
+                        hook.event
+                    ),
+                });
+            }
+
+            let mut handler = serde_json::json!({
+                "type": "command",
+                "command": hook.command,
+            });
+            if let Some(timeout) = hook.timeout {
+                handler["timeout"] = timeout.into();
+            }
+            let mut group = serde_json::Map::new();
+            group.insert(CALVIN_TAG.into(), asset.id().into());
+            if let Some(matcher) = &hook.matcher {
+                group.insert("matcher".into(), matcher.as_str().into());
+            }
+            group.insert("hooks".into(), serde_json::json!([handler]));
+
+            groups.entry(hook.event).or_default().push(group.into());
+            owned_keys.push(json_pointer(&["hooks", hook.event.as_str(), &hook.command]));
+        }
+
+        if groups.is_empty() {
+            return Ok(None);
+        }
+
+        let hooks: serde_json::Map<String, serde_json::Value> = groups
+            .into_iter()
+            .map(|(event, groups)| (event.to_string(), groups.into()))
+            .collect();
+        let settings = serde_json::json!({ "hooks": hooks });
+        let content = serde_json::to_string_pretty(&settings).map_err(|e| {
+            AdapterError::CompilationFailed {
+                message: format!("Failed to serialize hooks: {}", e),
+            }
+        })?;
+
+        Ok(Some(
+            OutputFile::new(
+                self.settings_path(scope),
+                format!("{}\n", content),
+                Target::ClaudeCode,
+            )
+            .with_merge(MergeSpec::new(owned_keys).always()),
+        ))
+    }
+
     fn compile_agent(&self, asset: &Asset) -> Result<Vec<OutputFile>, AdapterError> {
         let agents_dir = self.agents_dir(asset.scope());
         let agent_path = agents_dir.join(format!("{}.md", asset.id()));
@@ -73,6 +273,15 @@ impl ClaudeCodeAdapter {
     }
 }
 
+/// Project-relative path of the Claude Code settings file.
+pub const SETTINGS_PATH: &str = ".claude/settings.json";
+
+/// Project-level MCP server configuration
+pub const MCP_PATH: &str = ".mcp.json";
+
+/// Project memory file that holds the policies section in `memory` mode
+pub const MEMORY_PATH: &str = "CLAUDE.md";
+
 impl Default for ClaudeCodeAdapter {
     fn default() -> Self {
         Self::new()
@@ -84,6 +293,18 @@ impl TargetAdapter for ClaudeCodeAdapter {
         Target::ClaudeCode
     }
 
+    fn min_ide_version(&self) -> Option<ToolVersion> {
+        Some(ToolVersion::new(1, 0, 0))
+    }
+
+    fn max_tested_version(&self) -> Option<ToolVersion> {
+        Some(ToolVersion::new(2, 0, 0))
+    }
+
+    fn detect_version(&self, probe: &dyn ToolProbe) -> Option<DetectedTool> {
+        DetectedTool::probe(probe, "claude", ".claude")
+    }
+
     fn compile(&self, asset: &Asset) -> Result<Vec<OutputFile>, AdapterError> {
         if asset.kind() == AssetKind::Skill {
             let footer = self.footer(&asset.source_path_normalized());
@@ -100,6 +321,22 @@ impl TargetAdapter for ClaudeCodeAdapter {
             return self.compile_agent(asset);
         }
 
+        if asset.kind() == AssetKind::Hook {
+            // The hook itself goes to settings.json (see post_compile()); only its
+            // scripts are files.
+            let dir = self.hooks_dir(asset.scope()).join(asset.id());
+            let result = skills::compile_supplementals(asset, &dir, Target::ClaudeCode, "hook")?;
+            return Ok(result.outputs);
+        }
+
+        if asset.kind() == AssetKind::Policy {
+            match self.policy_mode {
+                ClaudePolicyMode::Commands => {}
+                ClaudePolicyMode::Memory => return Ok(Vec::new()), // Aggregated in post_compile()
+                ClaudePolicyMode::Rules => return Ok(self.compile_rule(asset)),
+            }
+        }
+
         let mut outputs = Vec::new();
 
         // Generate command file for all asset types
@@ -134,6 +371,38 @@ impl TargetAdapter for ClaudeCodeAdapter {
         Ok(outputs)
     }
 
+    fn downgrades(&self, asset: &Asset) -> Vec<AdapterDiagnostic> {
+        // Commands only run when invoked; memory and rules are loaded up front.
+        let (supported, applied_as): (&[Activation], _) = match self.policy_mode {
+            ClaudePolicyMode::Commands => (&[Activation::Manual], Activation::Manual),
+            ClaudePolicyMode::Memory => (&[Activation::Always], Activation::Always),
+            ClaudePolicyMode::Rules => {
+                (&[Activation::Always, Activation::Glob], Activation::Always)
+            }
+        };
+        let mut diagnostics = activation_downgrade(asset, self.target(), supported, applied_as);
+
+        // CLAUDE.md is always loaded, so an `apply` glob (without an explicit
+        // `activation`, which is reported above) is lost too.
+        if self.policy_mode == ClaudePolicyMode::Memory
+            && asset.kind() == AssetKind::Policy
+            && asset.activation().is_none()
+        {
+            if let Some(globs) = asset.activation_globs() {
+                diagnostics.push(AdapterDiagnostic {
+                    severity: DiagnosticSeverity::Warning,
+                    message: format!(
+                        "Policy '{}' apply '{}' is ignored in CLAUDE.md; applied as 'always' \
+                         (use [targets.claude-code] policies = \"rules\" for path-scoped rules)",
+                        asset.id(),
+                        globs
+                    ),
+                });
+            }
+        }
+        diagnostics
+    }
+
     fn validate(&self, output: &OutputFile) -> Vec<AdapterDiagnostic> {
         let mut diagnostics = Vec::new();
 
@@ -165,6 +434,12 @@ impl TargetAdapter for ClaudeCodeAdapter {
             });
         }
 
+        // Hook scripts and settings run shell commands: `$VAR` is not a placeholder
+        let path = output.path().to_string_lossy();
+        if path.contains(".claude/hooks/") || path.ends_with(SETTINGS_PATH) {
+            return diagnostics;
+        }
+
         // Check for undocumented named placeholders
         let content = output.content();
         let chars: Vec<char> = content.chars().collect();
@@ -204,7 +479,75 @@ impl TargetAdapter for ClaudeCodeAdapter {
         diagnostics
     }
 
+    fn post_compile(&self, assets: &[Asset]) -> Result<Vec<OutputFile>, AdapterError> {
+        let mut outputs = Vec::new();
+
+        for scope in [Scope::Project, Scope::User] {
+            if self.policy_mode == ClaudePolicyMode::Memory {
+                outputs.extend(self.compile_memory(assets, scope));
+            }
+            outputs.extend(self.compile_hooks(assets, scope)?);
+        }
+
+        Ok(outputs)
+    }
+
+    fn security_baseline(
+        &self,
+        config: &crate::config::Config,
+    ) -> Result<Vec<OutputFile>, AdapterError> {
+        let deny = crate::domain::policies::effective_claude_deny_patterns(config);
+        // Settings files are shared with the user: only the deny entries are Calvin's.
+        let owned_keys = deny
+            .iter()
+            .map(|pattern| json_pointer(&["permissions", "deny", pattern]))
+            .collect();
+
+        // `allow_naked = true` without custom patterns: nothing to enforce.
+        if deny.is_empty() {
+            return Ok(Vec::new());
+        }
+
+        let settings = serde_json::json!({
+            "permissions": {
+                "deny": deny,
+            }
+        });
+        let content = serde_json::to_string_pretty(&settings).map_err(|e| {
+            AdapterError::CompilationFailed {
+                message: format!("Failed to serialize {}: {}", SETTINGS_PATH, e),
+            }
+        })?;
+
+        Ok(vec![OutputFile::new(
+            SETTINGS_PATH,
+            format!("{}\n", content),
+            Target::ClaudeCode,
+        )
+        .with_merge(MergeSpec::new(owned_keys).always())])
+    }
+
+    fn compile_mcp(
+        &self,
+        servers: &[McpServer],
+        scope: Scope,
+    ) -> Result<Vec<OutputFile>, AdapterError> {
+        if servers.is_empty() || scope == Scope::User {
+            return Ok(Vec::new());
+        }
+
+        let content = mcp::render_servers_json("mcpServers", servers, mcp::stdio_server)?;
+        Ok(vec![OutputFile::new(MCP_PATH, content, Target::ClaudeCode)
+            .with_merge(mcp::merge_spec("mcpServers", servers))])
+    }
+
     fn compile_binary(&self, asset: &Asset) -> Result<Vec<BinaryOutputFile>, AdapterError> {
+        if asset.kind() == AssetKind::Hook {
+            let dir = self.hooks_dir(asset.scope()).join(asset.id());
+            let result = skills::compile_supplementals(asset, &dir, Target::ClaudeCode, "hook")?;
+            return Ok(result.binary_outputs);
+        }
+
         if asset.kind() != AssetKind::Skill {
             return Ok(vec![]);
         }
@@ -255,6 +598,17 @@ mod tests {
             .with_kind(AssetKind::Agent)
     }
 
+    fn create_hook_asset(id: &str, event: HookEvent, command: &str) -> Asset {
+        Asset::new(id, format!("hooks/{}.md", id), "", "")
+            .with_kind(AssetKind::Hook)
+            .with_hook(crate::domain::value_objects::HookSpec {
+                event,
+                matcher: None,
+                command: command.to_string(),
+                timeout: None,
+            })
+    }
+
     // === TDD: Compile Tests ===
 
     #[test]
@@ -320,6 +674,89 @@ mod tests {
         );
     }
 
+    #[test]
+    fn memory_mode_collects_policies_into_claude_md_section() {
+        let adapter = ClaudeCodeAdapter::new().with_policy_mode(ClaudePolicyMode::Memory);
+        let assets = vec![
+            create_policy_asset("security", "Security rules", "Never commit secrets."),
+            create_policy_asset("style", "Style", "Use rustfmt.").with_scope(Scope::User),
+            create_action_asset("review", "Review", "Review the diff."),
+        ];
+
+        assert!(adapter.compile(&assets[0]).unwrap().is_empty());
+        assert_eq!(adapter.compile(&assets[2]).unwrap().len(), 1);
+
+        let outputs = adapter.post_compile(&assets).unwrap();
+        assert_eq!(outputs.len(), 2);
+        assert_eq!(outputs[0].path(), &PathBuf::from("CLAUDE.md"));
+        assert_eq!(
+            outputs[0].content(),
+            "<!-- calvin:begin claude-code -->\n## Security rules\n\nNever commit secrets.\n<!-- calvin:end -->\n"
+        );
+        assert_eq!(
+            outputs[0].merge_spec().unwrap().owned_keys(),
+            &["claude-code".to_string()]
+        );
+        assert!(outputs[0].merge_spec().unwrap().is_always());
+        assert_eq!(outputs[1].path(), &PathBuf::from("~/.claude/CLAUDE.md"));
+    }
+
+    #[test]
+    fn memory_mode_reports_apply_globs() {
+        let adapter = ClaudeCodeAdapter::new().with_policy_mode(ClaudePolicyMode::Memory);
+        let scoped = create_policy_asset("rust", "Rust", "Use clippy.").with_apply("src/**/*.rs");
+        let always = create_policy_asset("security", "Security", "No secrets.");
+
+        let diags = adapter.downgrades(&scoped);
+        assert_eq!(diags.len(), 1);
+        assert_eq!(
+            diags[0].message,
+            "Policy 'rust' apply 'src/**/*.rs' is ignored in CLAUDE.md; applied as 'always' \
+             (use [targets.claude-code] policies = \"rules\" for path-scoped rules)"
+        );
+        assert!(adapter.downgrades(&always).is_empty());
+
+        let rules = ClaudeCodeAdapter::new().with_policy_mode(ClaudePolicyMode::Rules);
+        assert!(rules.downgrades(&scoped).is_empty());
+    }
+
+    #[test]
+    fn rules_mode_maps_apply_to_paths() {
+        let adapter = ClaudeCodeAdapter::new().with_policy_mode(ClaudePolicyMode::Rules);
+        let scoped =
+            create_policy_asset("rust", "Rust", "Use clippy.").with_apply("src/**/*.rs, *.toml");
+        let global =
+            create_policy_asset("security", "Security", "No secrets.").with_scope(Scope::User);
+
+        let outputs = adapter.compile(&scoped).unwrap();
+        assert_eq!(outputs[0].path(), &PathBuf::from(".claude/rules/rust.md"));
+        assert!(outputs[0]
+            .content()
+            .starts_with("---\npaths:\n- src/**/*.rs\n- '*.toml'\n---\n\nUse clippy."));
+
+        let outputs = adapter.compile(&global).unwrap();
+        assert_eq!(
+            outputs[0].path(),
+            &PathBuf::from("~/.claude/rules/security.md")
+        );
+        assert!(outputs[0].content().starts_with("No secrets."));
+        assert!(adapter.post_compile(&[scoped, global]).unwrap().is_empty());
+    }
+
+    #[test]
+    fn from_config_reads_policy_mode() {
+        let config: crate::config::Config =
+            toml::from_str("[targets.claude-code]\npolicies = \"rules\"\n").unwrap();
+        let adapter = ClaudeCodeAdapter::from_config(&config);
+        let asset = create_policy_asset("security", "Security", "No secrets.");
+
+        let outputs = adapter.compile(&asset).unwrap();
+        assert_eq!(
+            outputs[0].path(),
+            &PathBuf::from(".claude/rules/security.md")
+        );
+    }
+
     #[test]
     fn compile_user_scope_uses_home_path() {
         let adapter = ClaudeCodeAdapter::new();
@@ -478,6 +915,54 @@ mod tests {
         );
     }
 
+    // === TDD: Security Baseline ===
+
+    #[test]
+    fn security_baseline_generates_settings_with_deny_list() {
+        let adapter = ClaudeCodeAdapter::new();
+        let config = crate::config::Config::default();
+
+        let baseline = adapter.security_baseline(&config).unwrap();
+
+        assert_eq!(baseline.len(), 1);
+        assert_eq!(baseline[0].path(), &PathBuf::from(".claude/settings.json"));
+
+        let parsed: serde_json::Value = serde_json::from_str(baseline[0].content()).unwrap();
+        let deny: Vec<&str> = parsed["permissions"]["deny"]
+            .as_array()
+            .unwrap()
+            .iter()
+            .filter_map(|v| v.as_str())
+            .collect();
+        assert!(deny.contains(&".env"));
+        assert!(deny.contains(&".git/"));
+    }
+
+    #[test]
+    fn security_baseline_includes_custom_patterns_and_exclusions() {
+        let adapter = ClaudeCodeAdapter::new();
+        let mut config = crate::config::Config::default();
+        config.security.deny.patterns = vec!["secrets/**".to_string()];
+        config.security.deny.exclude = vec![".env.example".to_string()];
+
+        let baseline = adapter.security_baseline(&config).unwrap();
+        let content = baseline[0].content();
+
+        assert!(content.contains("\"secrets/**\""));
+        assert!(!content.contains("\".env.*\""));
+    }
+
+    #[test]
+    fn security_baseline_allow_naked_without_patterns_is_empty() {
+        let adapter = ClaudeCodeAdapter::new();
+        let mut config = crate::config::Config::default();
+        config.security.allow_naked = true;
+
+        let baseline = adapter.security_baseline(&config).unwrap();
+
+        assert!(baseline.is_empty());
+    }
+
     // === TDD: Trait Implementation ===
 
     #[test]
@@ -487,9 +972,9 @@ mod tests {
     }
 
     #[test]
-    fn adapter_version_is_one() {
+    fn adapter_output_format_version_is_one() {
         let adapter = ClaudeCodeAdapter::new();
-        assert_eq!(adapter.version(), 1);
+        assert_eq!(adapter.output_format_version(), 1);
     }
 
     // === TDD: Binary Outputs ===
@@ -697,4 +1182,114 @@ mod tests {
             .iter()
             .all(|d| !d.message.contains("YAML frontmatter")));
     }
+
+    // === TDD: MCP ===
+
+    #[test]
+    fn compile_mcp_generates_project_mcp_json() {
+        let adapter = ClaudeCodeAdapter::new();
+        let servers = vec![McpServer::new("github", "npx").with_args(vec!["-y".into()])];
+
+        let outputs = adapter.compile_mcp(&servers, Scope::Project).unwrap();
+
+        assert_eq!(outputs.len(), 1);
+        assert_eq!(outputs[0].path(), &PathBuf::from(".mcp.json"));
+        let value: serde_json::Value = serde_json::from_str(outputs[0].content()).unwrap();
+        assert_eq!(value["mcpServers"]["github"]["command"], "npx");
+        assert_eq!(value["mcpServers"]["github"]["args"][0], "-y");
+    }
+
+    #[test]
+    fn compile_mcp_user_scope_is_empty() {
+        let adapter = ClaudeCodeAdapter::new();
+        let servers = vec![McpServer::new("github", "npx")];
+
+        assert!(adapter
+            .compile_mcp(&servers, Scope::User)
+            .unwrap()
+            .is_empty());
+    }
+
+    // === TDD: Hook Tests ===
+
+    #[test]
+    fn hooks_compile_into_settings_hooks_block() {
+        let adapter = ClaudeCodeAdapter::new();
+        let format = create_hook_asset("format", HookEvent::PostToolUse, "cargo fmt").with_hook(
+            crate::domain::value_objects::HookSpec {
+                event: HookEvent::PostToolUse,
+                matcher: Some("Edit|Write".to_string()),
+                command: "cargo fmt".to_string(),
+                timeout: Some(30),
+            },
+        );
+        let assets = vec![
+            format,
+            create_hook_asset("lint", HookEvent::Stop, "make lint"),
+        ];
+
+        assert!(adapter.compile(&assets[0]).unwrap().is_empty());
+        let outputs = adapter.post_compile(&assets).unwrap();
+
+        assert_eq!(outputs.len(), 1);
+        assert_eq!(outputs[0].path(), &PathBuf::from(SETTINGS_PATH));
+        let value: serde_json::Value = serde_json::from_str(outputs[0].content()).unwrap();
+        let group = &value["hooks"]["PostToolUse"][0];
+        assert_eq!(group["calvin"], "format");
+        assert_eq!(group["matcher"], "Edit|Write");
+        assert_eq!(group["hooks"][0]["type"], "command");
+        assert_eq!(group["hooks"][0]["command"], "cargo fmt");
+        assert_eq!(group["hooks"][0]["timeout"], 30);
+        assert!(value["hooks"]["Stop"][0].get("matcher").is_none());
+        assert_eq!(
+            outputs[0].merge_spec().unwrap().owned_keys(),
+            ["/hooks/PostToolUse/cargo fmt", "/hooks/Stop/make lint"]
+        );
+    }
+
+    #[test]
+    fn hooks_user_scope_uses_home_settings() {
+        let adapter = ClaudeCodeAdapter::new();
+        let assets =
+            vec![create_hook_asset("lint", HookEvent::Stop, "make lint").with_scope(Scope::User)];
+
+        let outputs = adapter.post_compile(&assets).unwrap();
+
+        assert_eq!(outputs.len(), 1);
+        assert_eq!(outputs[0].path(), &PathBuf::from("~/.claude/settings.json"));
+    }
+
+    #[test]
+    fn hooks_with_same_command_and_event_conflict() {
+        let adapter = ClaudeCodeAdapter::new();
+        let assets = vec![
+            create_hook_asset("a", HookEvent::Stop, "make lint"),
+            create_hook_asset("b", HookEvent::Stop, "make lint"),
+        ];
+
+        let err = adapter.post_compile(&assets).unwrap_err();
+        assert!(err.to_string().contains("both run `make lint` on Stop"));
+    }
+
+    #[test]
+    fn hook_supplementals_deploy_under_hooks_dir() {
+        let adapter = ClaudeCodeAdapter::new();
+        let mut supplementals = HashMap::new();
+        supplementals.insert(PathBuf::from("fmt.sh"), "cargo fmt\n".to_string());
+        let asset = create_hook_asset(
+            "format",
+            HookEvent::PostToolUse,
+            ".claude/hooks/format/fmt.sh",
+        )
+        .with_supplementals(supplementals);
+
+        let outputs = adapter.compile(&asset).unwrap();
+
+        assert_eq!(outputs.len(), 1);
+        assert_eq!(
+            outputs[0].path(),
+            &PathBuf::from(".claude/hooks/format/fmt.sh")
+        );
+        assert!(adapter.validate(&outputs[0]).is_empty());
+    }
 }
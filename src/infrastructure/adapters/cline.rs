@@ -0,0 +1,454 @@
+//! Cline Adapter
+//!
+//! Generates output for Cline and, with `[targets.cline] flavor = "roo"`, Roo Code:
+//! - `.clinerules/<id>.md` - Rules (Policies)
+//! - `.clinerules/workflows/<id>.md` - Workflows (Actions, and Agents in the Cline flavor)
+//! - `~/Documents/Cline/Rules/<id>.md` - User rules
+//! - `~/Documents/Cline/Workflows/<id>.md` - User workflows
+//! - `.roomodes` - Roo Code custom modes (project Agents, Roo flavor)
+//!
+//! `.roomodes` is shared with hand-written modes, so Calvin owns one `customModes`
+//! entry per agent (matched by `slug`) instead of the whole file. Tool groups are
+//! derived from the agent's `tools`; Roo Code picks models per API configuration
+//! profile, so `model` is not emitted.
+
+use std::path::PathBuf;
+
+use super::activation::activation_downgrade;
+use super::agents::agent_downgrade;
+use super::frontmatter::FrontmatterBuilder;
+use crate::config::ClineFlavor;
+use crate::domain::entities::{Asset, AssetKind, OutputFile};
+use crate::domain::ports::target_adapter::{
+    AdapterDiagnostic, AdapterError, DiagnosticSeverity, TargetAdapter,
+};
+use crate::domain::ports::tool_probe::{DetectedTool, ToolProbe};
+use crate::domain::value_objects::{json_pointer, Activation, MergeSpec, Scope, Target};
+
+/// Roo Code custom modes file (project scope)
+const ROOMODES_PATH: &str = ".roomodes";
+
+/// Roo Code tool groups, in the order they are emitted
+const TOOL_GROUPS: [&str; 5] = ["read", "edit", "browser", "command", "mcp"];
+
+/// Serializable Roo Code custom mode (one `customModes` entry)
+#[derive(serde::Serialize)]
+#[serde(rename_all = "camelCase")]
+struct RooMode<'a> {
+    slug: &'a str,
+    name: &'a str,
+    role_definition: &'a str,
+    when_to_use: &'a str,
+    groups: Vec<&'static str>,
+}
+
+/// Cline adapter
+pub struct ClineAdapter {
+    flavor: ClineFlavor,
+}
+
+impl ClineAdapter {
+    pub fn new() -> Self {
+        Self {
+            flavor: ClineFlavor::default(),
+        }
+    }
+
+    /// Builder: choose the Cline-family extension (`[targets.cline] flavor`)
+    pub fn with_flavor(mut self, flavor: ClineFlavor) -> Self {
+        self.flavor = flavor;
+        self
+    }
+
+    /// Create an adapter from the `[targets.cline]` config table
+    pub fn from_config(config: &crate::config::Config) -> Self {
+        Self::new().with_flavor(config.targets.cline.flavor)
+    }
+
+    fn rules_dir(&self, scope: Scope) -> PathBuf {
+        match scope {
+            Scope::User => PathBuf::from("~/Documents/Cline/Rules"),
+            Scope::Project => PathBuf::from(".clinerules"),
+        }
+    }
+
+    fn workflows_dir(&self, scope: Scope) -> PathBuf {
+        match scope {
+            Scope::User => PathBuf::from("~/Documents/Cline/Workflows"),
+            Scope::Project => PathBuf::from(".clinerules/workflows"),
+        }
+    }
+
+    /// Whether an agent is compiled into `.roomodes` rather than a workflow
+    fn is_roo_mode(&self, asset: &Asset) -> bool {
+        self.flavor == ClineFlavor::Roo
+            && asset.kind() == AssetKind::Agent
+            && asset.scope() == Scope::Project
+    }
+
+    /// Compile a policy into a rule; `apply` globs of glob-activated policies become the
+    /// `paths` frontmatter
+    fn compile_rule(&self, asset: &Asset) -> Vec<OutputFile> {
+        let path = self
+            .rules_dir(asset.scope())
+            .join(format!("{}.md", asset.id()));
+
+        let mut content = String::new();
+        let globs: Vec<&str> = asset
+            .activation_globs()
+            .map(|apply| {
+                apply
+                    .split(',')
+                    .map(str::trim)
+                    .filter(|g| !g.is_empty())
+                    .collect()
+            })
+            .unwrap_or_default();
+        if !globs.is_empty() {
+            content.push_str(&FrontmatterBuilder::new().field("paths", globs).render());
+            content.push('\n');
+        }
+        content.push_str(asset.content().trim());
+        content.push_str("\n\n");
+        content.push_str(&self.footer(&asset.source_path_normalized()));
+
+        vec![OutputFile::new(path, content, self.target())]
+    }
+
+    fn compile_workflow(&self, asset: &Asset) -> Vec<OutputFile> {
+        let path = self
+            .workflows_dir(asset.scope())
+            .join(format!("{}.md", asset.id()));
+        let content = format!(
+            "{}\n\n{}",
+            asset.content().trim(),
+            self.footer(&asset.source_path_normalized())
+        );
+        vec![OutputFile::new(path, content, self.target())]
+    }
+
+    /// Roo Code tool groups for an agent's `tools`
+    ///
+    /// Agents without `tools` inherit every tool, so they get every group.
+    fn tool_groups(tools: &[String]) -> Vec<&'static str> {
+        if tools.is_empty() {
+            return TOOL_GROUPS.to_vec();
+        }
+        let group_of = |tool: &str| match tool {
+            "Read" | "Grep" | "Glob" | "LS" | "NotebookRead" => Some("read"),
+            "Edit" | "Write" | "MultiEdit" | "NotebookEdit" => Some("edit"),
+            "WebFetch" | "WebSearch" => Some("browser"),
+            "Bash" => Some("command"),
+            t if t.starts_with("mcp__") => Some("mcp"),
+            _ => None,
+        };
+        let used: Vec<&str> = tools.iter().filter_map(|t| group_of(t)).collect();
+        TOOL_GROUPS
+            .into_iter()
+            .filter(|group| used.contains(group))
+            .collect()
+    }
+
+    /// Render the Roo flavor's project agents into `.roomodes`
+    fn compile_roomodes(&self, assets: &[Asset]) -> Result<Option<OutputFile>, AdapterError> {
+        let agents: Vec<&Asset> = assets
+            .iter()
+            .filter(|a| self.is_roo_mode(a) && a.effective_targets().contains(&self.target()))
+            .collect();
+        if agents.is_empty() {
+            return Ok(None);
+        }
+
+        let modes: Vec<RooMode> = agents
+            .iter()
+            .map(|agent| RooMode {
+                slug: agent.id(),
+                name: agent.agent_name().unwrap_or_else(|| agent.id()),
+                role_definition: agent.content().trim(),
+                when_to_use: agent.description(),
+                groups: Self::tool_groups(agent.agent_tools()),
+            })
+            .collect();
+        let document = serde_json::json!({ "customModes": modes });
+        let rendered = serde_json::to_string_pretty(&document).map_err(|e| {
+            AdapterError::CompilationFailed {
+                message: format!("Failed to render {}: {}", ROOMODES_PATH, e),
+            }
+        })?;
+
+        let owned_keys = agents
+            .iter()
+            .map(|agent| json_pointer(&["customModes", agent.id()]))
+            .collect();
+        Ok(Some(
+            OutputFile::new(ROOMODES_PATH, format!("{}\n", rendered), self.target())
+                .with_merge(MergeSpec::new(owned_keys).always()),
+        ))
+    }
+}
+
+impl Default for ClineAdapter {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl TargetAdapter for ClineAdapter {
+    fn target(&self) -> Target {
+        Target::Cline
+    }
+
+    fn detect_version(&self, probe: &dyn ToolProbe) -> Option<DetectedTool> {
+        DetectedTool::probe(probe, "cline", "Documents/Cline")
+    }
+
+    fn compile(&self, asset: &Asset) -> Result<Vec<OutputFile>, AdapterError> {
+        if self.is_roo_mode(asset) {
+            return Ok(Vec::new()); // Aggregated in post_compile()
+        }
+        Ok(match asset.kind() {
+            AssetKind::Policy => self.compile_rule(asset),
+            // Cline has no agents; they become workflows like actions.
+            AssetKind::Action | AssetKind::Agent => self.compile_workflow(asset),
+            AssetKind::Skill | AssetKind::Hook => Vec::new(), // Not supported on Cline
+        })
+    }
+
+    fn downgrades(&self, asset: &Asset) -> Vec<AdapterDiagnostic> {
+        let mut diagnostics = if self.is_roo_mode(asset) {
+            agent_downgrade(asset, self.target(), Some("a custom mode"), &["tools"])
+        } else {
+            agent_downgrade(asset, self.target(), Some("a workflow"), &[])
+        };
+        diagnostics.extend(activation_downgrade(
+            asset,
+            self.target(),
+            &[Activation::Always, Activation::Glob],
+            Activation::Always,
+        ));
+        diagnostics
+    }
+
+    fn validate(&self, output: &OutputFile) -> Vec<AdapterDiagnostic> {
+        let mut diagnostics = Vec::new();
+
+        if output.path() != &PathBuf::from(ROOMODES_PATH) {
+            return diagnostics;
+        }
+        let Ok(document) = serde_json::from_str::<serde_json::Value>(output.content()) else {
+            diagnostics.push(AdapterDiagnostic {
+                severity: DiagnosticSeverity::Error,
+                message: format!("{} is not valid JSON", ROOMODES_PATH),
+            });
+            return diagnostics;
+        };
+        let modes = document["customModes"]
+            .as_array()
+            .cloned()
+            .unwrap_or_default();
+        for slug in modes.iter().filter_map(|mode| mode["slug"].as_str()) {
+            if !slug.chars().all(|c| c.is_ascii_alphanumeric() || c == '-') {
+                diagnostics.push(AdapterDiagnostic {
+                    severity: DiagnosticSeverity::Error,
+                    message: format!(
+                        "Roo Code mode slug '{}' may only contain letters, numbers and dashes",
+                        slug
+                    ),
+                });
+            }
+        }
+
+        diagnostics
+    }
+
+    fn post_compile(&self, assets: &[Asset]) -> Result<Vec<OutputFile>, AdapterError> {
+        Ok(self.compile_roomodes(assets)?.into_iter().collect())
+    }
+
+    fn security_baseline(
+        &self,
+        _config: &crate::config::Config,
+    ) -> Result<Vec<OutputFile>, AdapterError> {
+        // Cline approval settings live in the VS Code extension state
+        Ok(Vec::new())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn create_policy_asset(id: &str, description: &str, content: &str) -> Asset {
+        Asset::new(id, format!("policies/{}.md", id), description, content)
+            .with_kind(AssetKind::Policy)
+    }
+
+    fn create_action_asset(id: &str, description: &str, content: &str) -> Asset {
+        Asset::new(id, format!("actions/{}.md", id), description, content)
+            .with_kind(AssetKind::Action)
+    }
+
+    fn create_agent_asset(id: &str, description: &str, content: &str) -> Asset {
+        Asset::new(id, format!("agents/{}.md", id), description, content)
+            .with_kind(AssetKind::Agent)
+    }
+
+    fn roo() -> ClineAdapter {
+        ClineAdapter::new().with_flavor(ClineFlavor::Roo)
+    }
+
+    // === TDD: Compile Tests ===
+
+    #[test]
+    fn compile_policy_to_clinerules() {
+        let adapter = ClineAdapter::new();
+        let asset = create_policy_asset("style", "Code style", "Use rustfmt.");
+
+        let outputs = adapter.compile(&asset).unwrap();
+
+        assert_eq!(outputs.len(), 1);
+        assert_eq!(outputs[0].path(), &PathBuf::from(".clinerules/style.md"));
+        assert!(outputs[0].content().starts_with("Use rustfmt."));
+        assert!(outputs[0].content().contains("Generated by Calvin"));
+    }
+
+    #[test]
+    fn compile_policy_with_apply_uses_paths_frontmatter() {
+        let adapter = ClineAdapter::new();
+        let asset = create_policy_asset("rust", "Rust", "Run clippy.").with_apply("**/*.rs");
+
+        let outputs = adapter.compile(&asset).unwrap();
+
+        assert!(outputs[0]
+            .content()
+            .starts_with("---\npaths:\n- '**/*.rs'\n---\n\nRun clippy."));
+    }
+
+    #[test]
+    fn compile_action_to_workflow() {
+        let adapter = ClineAdapter::new();
+        let asset = create_action_asset("deploy", "Deploy", "Run deploy.");
+
+        let outputs = adapter.compile(&asset).unwrap();
+
+        assert_eq!(
+            outputs[0].path(),
+            &PathBuf::from(".clinerules/workflows/deploy.md")
+        );
+    }
+
+    #[test]
+    fn compile_user_scope_uses_documents_dir() {
+        let adapter = ClineAdapter::new();
+        let policy = create_policy_asset("style", "Style", "x").with_scope(Scope::User);
+        let action = create_action_asset("deploy", "Deploy", "x").with_scope(Scope::User);
+
+        assert_eq!(
+            adapter.compile(&policy).unwrap()[0].path(),
+            &PathBuf::from("~/Documents/Cline/Rules/style.md")
+        );
+        assert_eq!(
+            adapter.compile(&action).unwrap()[0].path(),
+            &PathBuf::from("~/Documents/Cline/Workflows/deploy.md")
+        );
+    }
+
+    #[test]
+    fn cline_flavor_compiles_agents_to_workflows() {
+        let adapter = ClineAdapter::new();
+        let asset = create_agent_asset("reviewer", "Reviews code", "You review.");
+
+        let outputs = adapter.compile(&asset).unwrap();
+
+        assert_eq!(
+            outputs[0].path(),
+            &PathBuf::from(".clinerules/workflows/reviewer.md")
+        );
+        assert!(adapter.post_compile(&[asset]).unwrap().is_empty());
+    }
+
+    #[test]
+    fn roo_flavor_compiles_agents_to_roomodes() {
+        let adapter = roo();
+        let asset = create_agent_asset("reviewer", "Reviews code", "You review.")
+            .with_agent_name(Some("Code Reviewer".to_string()))
+            .with_agent_tools(vec!["Read".to_string(), "Grep".to_string()])
+            .with_agent_model(Some("sonnet".to_string()));
+
+        assert!(adapter.compile(&asset).unwrap().is_empty());
+        let outputs = adapter.post_compile(&[asset]).unwrap();
+
+        assert_eq!(outputs.len(), 1);
+        assert_eq!(outputs[0].path(), &PathBuf::from(".roomodes"));
+        let document: serde_json::Value = serde_json::from_str(outputs[0].content()).unwrap();
+        assert_eq!(
+            document,
+            serde_json::json!({"customModes": [{
+                "slug": "reviewer",
+                "name": "Code Reviewer",
+                "roleDefinition": "You review.",
+                "whenToUse": "Reviews code",
+                "groups": ["read"]
+            }]})
+        );
+        let merge = outputs[0].merge_spec().unwrap();
+        assert_eq!(merge.owned_keys(), ["/customModes/reviewer"]);
+        assert!(merge.is_always());
+    }
+
+    #[test]
+    fn roo_flavor_keeps_user_agents_as_workflows() {
+        let adapter = roo();
+        let asset = create_agent_asset("reviewer", "Reviews", "x").with_scope(Scope::User);
+
+        let outputs = adapter.compile(&asset).unwrap();
+
+        assert_eq!(
+            outputs[0].path(),
+            &PathBuf::from("~/Documents/Cline/Workflows/reviewer.md")
+        );
+    }
+
+    #[test]
+    fn tool_groups_from_tools() {
+        assert_eq!(ClineAdapter::tool_groups(&[]), TOOL_GROUPS.to_vec());
+        let tools: Vec<String> = ["Bash", "Edit", "Read", "mcp__github__search", "Task"]
+            .iter()
+            .map(|t| t.to_string())
+            .collect();
+        assert_eq!(
+            ClineAdapter::tool_groups(&tools),
+            ["read", "edit", "command", "mcp"]
+        );
+    }
+
+    // === TDD: Validate Tests ===
+
+    #[test]
+    fn validate_rejects_invalid_mode_slug() {
+        let adapter = roo();
+        let output = OutputFile::new(
+            ".roomodes",
+            r#"{"customModes":[{"slug":"code_review"}]}"#,
+            Target::Cline,
+        );
+
+        let diags = adapter.validate(&output);
+
+        assert_eq!(diags.len(), 1);
+        assert!(diags[0].message.contains("code_review"));
+    }
+
+    // === TDD: Trait Implementation ===
+
+    #[test]
+    fn adapter_from_config_reads_flavor() {
+        let config: crate::config::Config =
+            toml::from_str("[targets.cline]\nflavor = \"roo\"\n").unwrap();
+
+        let adapter = ClineAdapter::from_config(&config);
+
+        assert_eq!(adapter.flavor, ClineFlavor::Roo);
+        assert_eq!(adapter.target(), Target::Cline);
+    }
+}
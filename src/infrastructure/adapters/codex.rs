@@ -1,24 +1,49 @@
 //! OpenAI Codex CLI Adapter
 //!
-//! Generates output for Codex CLI prompts:
-//! - `.codex/prompts/<id>.md` - Project-level prompts
+//! Generates output for Codex CLI:
+//! - `.codex/prompts/<id>.md` - Project-level prompts (Actions/Agents)
 //! - `~/.codex/prompts/<id>.md` - User-level prompts
+//! - `AGENTS.md` / `~/.codex/AGENTS.md` - Policies, aggregated into the `codex`
+//!   managed region
+//! - `.codex/config.toml` / `~/.codex/config.toml` - MCP servers (`[mcp_servers]`) and
+//!   `sandbox_mode` / `approval_policy` from `security.mode`
 //!
 //! Path matrix (from platform.md):
-//! - Project scope: `.codex/prompts/`
-//! - User scope: `~/.codex/prompts/`
+//! - Project scope: `.codex/prompts/`, `AGENTS.md`
+//! - User scope: `~/.codex/prompts/`, `~/.codex/AGENTS.md`
 //!
 //! Improvement over legacy adapter:
 //! - Only Action/Agent include $ARGUMENTS placeholder (Policy does not)
 
 use std::path::PathBuf;
 
+use super::activation::activation_downgrade;
+use super::agents::agent_downgrade;
+use super::frontmatter::FrontmatterBuilder;
+use super::mcp;
+use super::policies_region_output;
 use super::skills;
-use crate::domain::entities::{Asset, AssetKind, BinaryOutputFile, OutputFile};
+use crate::config::SecurityMode;
+use crate::domain::entities::{Asset, AssetKind, BinaryOutputFile, McpServer, OutputFile};
 use crate::domain::ports::target_adapter::{
     AdapterDiagnostic, AdapterError, DiagnosticSeverity, TargetAdapter,
 };
-use crate::domain::value_objects::{Scope, Target};
+use crate::domain::ports::tool_probe::{DetectedTool, ToolProbe};
+use crate::domain::value_objects::{
+    json_pointer, Activation, MergeSpec, Scope, Target, ToolVersion,
+};
+
+/// Codex configuration file (project-relative; `~/.codex/config.toml` for home deploys)
+const CONFIG_PATH: &str = ".codex/config.toml";
+
+/// `sandbox_mode` and `approval_policy` values for a security mode
+fn sandbox_settings(mode: SecurityMode) -> (&'static str, &'static str) {
+    match mode {
+        SecurityMode::Yolo => ("danger-full-access", "never"),
+        SecurityMode::Balanced => ("workspace-write", "on-request"),
+        SecurityMode::Strict => ("read-only", "untrusted"),
+    }
+}
 
 /// Codex adapter
 pub struct CodexAdapter;
@@ -36,6 +61,13 @@ impl CodexAdapter {
         }
     }
 
+    fn agents_md_path(&self, scope: Scope) -> PathBuf {
+        match scope {
+            Scope::User => PathBuf::from("~/.codex/AGENTS.md"),
+            Scope::Project => PathBuf::from("AGENTS.md"),
+        }
+    }
+
     fn skills_dir(&self, scope: Scope) -> PathBuf {
         match scope {
             Scope::User => PathBuf::from("~/.codex/skills"),
@@ -45,26 +77,18 @@ impl CodexAdapter {
 
     /// Generate YAML frontmatter for Codex prompts
     fn generate_frontmatter(&self, asset: &Asset) -> String {
-        let mut fm = String::from("---\n");
-        fm.push_str(&format!("description: {}\n", asset.description()));
+        let fm = FrontmatterBuilder::new().field("description", asset.description());
 
         // Only include argument-hint for Action/Agent (not Policy)
-        match asset.kind() {
-            AssetKind::Action | AssetKind::Agent => {
-                fm.push_str("argument-hint: <arguments>\n");
-            }
-            AssetKind::Policy => {
-                // Policy prompts don't need arguments
+        let fm = match asset.kind() {
+            AssetKind::Action | AssetKind::Agent => fm.field("argument-hint", "<arguments>"),
+            AssetKind::Policy | AssetKind::Skill | AssetKind::Hook => {
+                // Policies go to AGENTS.md and skills use SKILL.md, not prompts frontmatter.
+                fm
             }
-            AssetKind::Skill => {
-                // Skills use SKILL.md format, not Codex prompts frontmatter.
-            }
-        }
-
-        fm.push_str(&super::format_extra_frontmatter(asset.extra_frontmatter()));
+        };
 
-        fm.push_str("---\n");
-        fm
+        fm.extra(asset.extra_frontmatter()).render()
     }
 
     fn compile_skill(&self, asset: &Asset) -> Result<Vec<OutputFile>, AdapterError> {
@@ -91,10 +115,30 @@ impl TargetAdapter for CodexAdapter {
         Target::Codex
     }
 
+    fn min_ide_version(&self) -> Option<ToolVersion> {
+        Some(ToolVersion::new(0, 20, 0))
+    }
+
+    fn max_tested_version(&self) -> Option<ToolVersion> {
+        Some(ToolVersion::new(0, 46, 0))
+    }
+
+    /// v2: policies are written to `AGENTS.md` instead of `.codex/prompts/`
+    fn output_format_version(&self) -> u32 {
+        2
+    }
+
+    fn detect_version(&self, probe: &dyn ToolProbe) -> Option<DetectedTool> {
+        DetectedTool::probe(probe, "codex", ".codex")
+    }
+
     fn compile(&self, asset: &Asset) -> Result<Vec<OutputFile>, AdapterError> {
-        // Skills are compiled to `.codex/skills/<id>/SKILL.md` (implemented separately).
-        if asset.kind() == AssetKind::Skill {
-            return self.compile_skill(asset);
+        match asset.kind() {
+            // Skills are compiled to `.codex/skills/<id>/SKILL.md` (implemented separately).
+            AssetKind::Skill => return self.compile_skill(asset),
+            AssetKind::Policy => return Ok(Vec::new()), // Aggregated in post_compile()
+            AssetKind::Hook => return Ok(Vec::new()),   // Not supported on Codex
+            AssetKind::Action | AssetKind::Agent => {}
         }
 
         let mut outputs = Vec::new();
@@ -115,10 +159,8 @@ impl TargetAdapter for CodexAdapter {
                     footer
                 )
             }
-            AssetKind::Policy => {
-                format!("{}\n{}\n\n{}", frontmatter, asset.content().trim(), footer)
-            }
-            AssetKind::Skill => String::new(), // unreachable (guarded above)
+            // unreachable (guarded above)
+            AssetKind::Policy | AssetKind::Skill | AssetKind::Hook => String::new(),
         };
 
         outputs.push(OutputFile::new(path, content, self.target()));
@@ -126,6 +168,18 @@ impl TargetAdapter for CodexAdapter {
         Ok(outputs)
     }
 
+    fn downgrades(&self, asset: &Asset) -> Vec<AdapterDiagnostic> {
+        let mut diagnostics = agent_downgrade(asset, self.target(), Some("a prompt"), &[]);
+        // Policies share one always-loaded file.
+        diagnostics.extend(activation_downgrade(
+            asset,
+            self.target(),
+            &[Activation::Always],
+            Activation::Always,
+        ));
+        diagnostics
+    }
+
     fn validate(&self, output: &OutputFile) -> Vec<AdapterDiagnostic> {
         let mut diagnostics = Vec::new();
 
@@ -176,12 +230,79 @@ impl TargetAdapter for CodexAdapter {
         diagnostics
     }
 
+    fn post_compile(&self, assets: &[Asset]) -> Result<Vec<OutputFile>, AdapterError> {
+        Ok([Scope::Project, Scope::User]
+            .into_iter()
+            .filter_map(|scope| {
+                policies_region_output(self.agents_md_path(scope), assets, scope, self.target())
+            })
+            .collect())
+    }
+
     fn security_baseline(
         &self,
-        _config: &crate::config::Config,
+        config: &crate::config::Config,
+    ) -> Result<Vec<OutputFile>, AdapterError> {
+        // `config.toml` is shared with the user: only the two policy keys are Calvin's.
+        let (sandbox_mode, approval_policy) = sandbox_settings(config.security.mode);
+        let content = format!(
+            "# Generated by Calvin. Source: security.mode. DO NOT EDIT.\n\n\
+             sandbox_mode = \"{}\"\napproval_policy = \"{}\"\n",
+            sandbox_mode, approval_policy
+        );
+        let owned_keys = vec![
+            json_pointer(&["sandbox_mode"]),
+            json_pointer(&["approval_policy"]),
+        ];
+
+        Ok(vec![OutputFile::new(CONFIG_PATH, content, Target::Codex)
+            .with_merge(MergeSpec::new(owned_keys).always())])
+    }
+
+    fn compile_mcp(
+        &self,
+        servers: &[McpServer],
+        scope: Scope,
     ) -> Result<Vec<OutputFile>, AdapterError> {
-        // Codex doesn't have project-level security config
-        Ok(Vec::new())
+        if servers.is_empty() {
+            return Ok(Vec::new());
+        }
+
+        let mut table = toml::Table::new();
+        for server in servers {
+            let mut entry = toml::Table::new();
+            entry.insert("command".into(), server.command().into());
+            entry.insert(
+                "args".into(),
+                toml::Value::Array(server.args().iter().map(|a| a.as_str().into()).collect()),
+            );
+            if !server.env().is_empty() {
+                let env: toml::Table = server
+                    .env()
+                    .iter()
+                    .map(|(k, v)| (k.clone(), v.as_str().into()))
+                    .collect();
+                entry.insert("env".into(), toml::Value::Table(env));
+            }
+            table.insert(server.name().to_string(), toml::Value::Table(entry));
+        }
+
+        let mut root = toml::Table::new();
+        root.insert("mcp_servers".into(), toml::Value::Table(table));
+        let body = toml::to_string(&root).map_err(|e| AdapterError::CompilationFailed {
+            message: format!("Failed to render Codex MCP config: {}", e),
+        })?;
+
+        let path = match scope {
+            Scope::User => PathBuf::from("~").join(CONFIG_PATH),
+            Scope::Project => PathBuf::from(CONFIG_PATH),
+        };
+        let content = format!(
+            "# Generated by Calvin. Source: mcp/. DO NOT EDIT.\n\n{}",
+            body
+        );
+        Ok(vec![OutputFile::new(path, content, Target::Codex)
+            .with_merge(mcp::merge_spec("mcp_servers", servers))])
     }
 
     fn compile_binary(&self, asset: &Asset) -> Result<Vec<BinaryOutputFile>, AdapterError> {
@@ -238,14 +359,45 @@ mod tests {
     }
 
     #[test]
-    fn compile_policy_no_arguments() {
+    fn compile_policy_is_aggregated_in_post_compile() {
         let adapter = CodexAdapter::new();
         let asset = create_policy_asset("code-style", "Code style", "# Style");
 
         let outputs = adapter.compile(&asset).unwrap();
 
-        assert!(!outputs[0].content().contains("$ARGUMENTS"));
-        assert!(!outputs[0].content().contains("argument-hint"));
+        assert!(outputs.is_empty());
+    }
+
+    #[test]
+    fn post_compile_writes_policies_to_agents_md_region() {
+        let adapter = CodexAdapter::new();
+        let assets = vec![
+            create_policy_asset("code-style", "Code style", "Use rustfmt."),
+            create_policy_asset("security", "Security", "No secrets.").with_scope(Scope::User),
+            create_action_asset("test", "desc", "content"),
+        ];
+
+        let outputs = adapter.post_compile(&assets).unwrap();
+
+        assert_eq!(outputs.len(), 2);
+        assert_eq!(outputs[0].path(), &PathBuf::from("AGENTS.md"));
+        assert!(outputs[0]
+            .content()
+            .starts_with("<!-- calvin:begin codex -->\n## Code style"));
+        assert!(outputs[0].content().contains("Use rustfmt."));
+        assert!(!outputs[0].content().contains("No secrets."));
+        assert_eq!(outputs[0].merge_spec().unwrap().owned_keys(), ["codex"]);
+
+        assert_eq!(outputs[1].path(), &PathBuf::from("~/.codex/AGENTS.md"));
+        assert!(outputs[1].content().contains("No secrets."));
+    }
+
+    #[test]
+    fn post_compile_without_policies_is_empty() {
+        let adapter = CodexAdapter::new();
+        let assets = vec![create_action_asset("test", "desc", "content")];
+
+        assert!(adapter.post_compile(&assets).unwrap().is_empty());
     }
 
     #[test]
@@ -345,13 +497,38 @@ mod tests {
     // === TDD: Security Baseline ===
 
     #[test]
-    fn security_baseline_returns_empty() {
+    fn security_baseline_sets_sandbox_and_approval_policy() {
         let adapter = CodexAdapter::new();
         let config = crate::config::Config::default();
 
         let baseline = adapter.security_baseline(&config).unwrap();
 
-        assert!(baseline.is_empty());
+        assert_eq!(baseline.len(), 1);
+        assert_eq!(baseline[0].path(), &PathBuf::from(".codex/config.toml"));
+        let parsed: toml::Table = toml::from_str(baseline[0].content()).unwrap();
+        assert_eq!(parsed["sandbox_mode"].as_str(), Some("workspace-write"));
+        assert_eq!(parsed["approval_policy"].as_str(), Some("on-request"));
+
+        let spec = baseline[0].merge_spec().unwrap();
+        assert!(spec.is_always());
+        assert_eq!(spec.owned_keys(), ["/sandbox_mode", "/approval_policy"]);
+    }
+
+    #[test]
+    fn security_baseline_follows_security_mode() {
+        let adapter = CodexAdapter::new();
+        let mut config = crate::config::Config::default();
+
+        for (mode, sandbox, approval) in [
+            (SecurityMode::Yolo, "danger-full-access", "never"),
+            (SecurityMode::Strict, "read-only", "untrusted"),
+        ] {
+            config.security.mode = mode;
+            let baseline = adapter.security_baseline(&config).unwrap();
+            let parsed: toml::Table = toml::from_str(baseline[0].content()).unwrap();
+            assert_eq!(parsed["sandbox_mode"].as_str(), Some(sandbox));
+            assert_eq!(parsed["approval_policy"].as_str(), Some(approval));
+        }
     }
 
     // === TDD: Trait Implementation ===
@@ -363,9 +540,9 @@ mod tests {
     }
 
     #[test]
-    fn adapter_version_is_one() {
+    fn adapter_output_format_version_is_two() {
         let adapter = CodexAdapter::new();
-        assert_eq!(adapter.version(), 1);
+        assert_eq!(adapter.output_format_version(), 2);
     }
 
     // === TDD: Skills ===
@@ -422,4 +599,28 @@ mod tests {
 
         assert!(outputs.is_empty());
     }
+
+    // === TDD: MCP ===
+
+    #[test]
+    fn compile_mcp_generates_mcp_servers_table() {
+        let adapter = CodexAdapter::new();
+        let servers = vec![McpServer::new("github", "npx")
+            .with_args(vec!["-y".into()])
+            .with_env(std::collections::BTreeMap::from([(
+                "TOKEN".to_string(),
+                "x".to_string(),
+            )]))];
+
+        let outputs = adapter.compile_mcp(&servers, Scope::User).unwrap();
+
+        assert_eq!(outputs[0].path(), &PathBuf::from("~/.codex/config.toml"));
+        let content = outputs[0].content();
+        assert!(content.starts_with("# Generated by Calvin"));
+        let parsed: toml::Table = toml::from_str(content).unwrap();
+        let github = &parsed["mcp_servers"]["github"];
+        assert_eq!(github["command"].as_str(), Some("npx"));
+        assert_eq!(github["args"][0].as_str(), Some("-y"));
+        assert_eq!(github["env"]["TOKEN"].as_str(), Some("x"));
+    }
 }
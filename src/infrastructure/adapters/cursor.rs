@@ -2,6 +2,7 @@
 //!
 //! Generates output for Cursor IDE:
 //! - `.cursor/rules/<id>/RULE.md` - Rules with frontmatter
+//! - `.cursor/mcp.json` / `~/.cursor/mcp.json` - MCP servers
 //!
 //! Path matrix (from platform.md):
 //! - Project scope: `.cursor/rules/`
@@ -12,12 +13,16 @@
 
 use std::path::PathBuf;
 
+use super::agents::agent_downgrade;
+use super::frontmatter::FrontmatterBuilder;
+use super::mcp;
 use super::skills;
-use crate::domain::entities::{Asset, AssetKind, BinaryOutputFile, OutputFile};
+use crate::domain::entities::{Asset, AssetKind, BinaryOutputFile, McpServer, OutputFile};
 use crate::domain::ports::target_adapter::{
     AdapterDiagnostic, AdapterError, DiagnosticSeverity, TargetAdapter,
 };
-use crate::domain::value_objects::{Scope, Target};
+use crate::domain::ports::tool_probe::{DetectedTool, ToolProbe};
+use crate::domain::value_objects::{Activation, Scope, Target, ToolVersion};
 
 /// Cursor adapter
 pub struct CursorAdapter;
@@ -44,28 +49,22 @@ impl CursorAdapter {
     }
 
     /// Generate Cursor RULE.md frontmatter
+    ///
+    /// Cursor infers the rule type from the field combination:
+    /// - Always: `alwaysApply: true`
+    /// - Auto Attached: `globs`
+    /// - Agent Requested: `description` only
+    /// - Manual: neither description nor globs
     fn generate_rule_frontmatter(&self, asset: &Asset) -> String {
-        let mut fm = String::from("---\n");
-
-        fm.push_str(&format!("description: {}\n", asset.description()));
-
-        // Add globs if apply pattern is specified
-        if let Some(apply) = asset.apply() {
-            fm.push_str(&format!("globs: \"{}\"\n", apply));
-        }
-
-        // alwaysApply: true only for policies without apply pattern
-        // Actions/Agents should NOT be alwaysApply
-        if asset.kind() == AssetKind::Policy && asset.apply().is_none() {
-            fm.push_str("alwaysApply: true\n");
-        } else {
-            fm.push_str("alwaysApply: false\n");
-        }
-
-        fm.push_str(&super::format_extra_frontmatter(asset.extra_frontmatter()));
-
-        fm.push_str("---\n");
-        fm
+        let activation = asset.effective_activation();
+        let description = (activation != Activation::Manual).then_some(asset.description());
+
+        FrontmatterBuilder::new()
+            .field_opt("description", description)
+            .field_opt("globs", asset.activation_globs())
+            .field("alwaysApply", activation == Activation::Always)
+            .extra(asset.extra_frontmatter())
+            .render()
     }
 
     fn compile_skill(&self, asset: &Asset) -> Result<Vec<OutputFile>, AdapterError> {
@@ -92,6 +91,18 @@ impl TargetAdapter for CursorAdapter {
         Target::Cursor
     }
 
+    fn min_ide_version(&self) -> Option<ToolVersion> {
+        Some(ToolVersion::new(0, 45, 0))
+    }
+
+    fn max_tested_version(&self) -> Option<ToolVersion> {
+        Some(ToolVersion::new(1, 7, 0))
+    }
+
+    fn detect_version(&self, probe: &dyn ToolProbe) -> Option<DetectedTool> {
+        DetectedTool::probe(probe, "cursor", ".cursor")
+    }
+
     fn compile(&self, asset: &Asset) -> Result<Vec<OutputFile>, AdapterError> {
         let mut outputs = Vec::new();
 
@@ -116,11 +127,19 @@ impl TargetAdapter for CursorAdapter {
             AssetKind::Skill => {
                 outputs.extend(self.compile_skill(asset)?);
             }
+            AssetKind::Hook => {
+                // Cursor hooks have their own format; not supported
+            }
         }
 
         Ok(outputs)
     }
 
+    fn downgrades(&self, asset: &Asset) -> Vec<AdapterDiagnostic> {
+        // Agents run as commands (Claude Code's, or Cursor's own without Claude Code).
+        agent_downgrade(asset, self.target(), Some("a command"), &[])
+    }
+
     fn validate(&self, output: &OutputFile) -> Vec<AdapterDiagnostic> {
         let mut diagnostics = Vec::new();
 
@@ -156,6 +175,24 @@ impl TargetAdapter for CursorAdapter {
         diagnostics
     }
 
+    fn compile_mcp(
+        &self,
+        servers: &[McpServer],
+        scope: Scope,
+    ) -> Result<Vec<OutputFile>, AdapterError> {
+        if servers.is_empty() {
+            return Ok(Vec::new());
+        }
+
+        let path = match scope {
+            Scope::User => "~/.cursor/mcp.json",
+            Scope::Project => ".cursor/mcp.json",
+        };
+        let content = mcp::render_servers_json("mcpServers", servers, mcp::stdio_server)?;
+        Ok(vec![OutputFile::new(path, content, Target::Cursor)
+            .with_merge(mcp::merge_spec("mcpServers", servers))])
+    }
+
     fn compile_binary(&self, asset: &Asset) -> Result<Vec<BinaryOutputFile>, AdapterError> {
         if asset.kind() != AssetKind::Skill {
             return Ok(vec![]);
@@ -238,7 +275,7 @@ mod tests {
 
         let outputs = adapter.compile(&asset).unwrap();
 
-        assert!(outputs[0].content().contains("globs: \"*.rs\""));
+        assert!(outputs[0].content().contains("globs: '*.rs'"));
         assert!(outputs[0].content().contains("alwaysApply: false"));
     }
 
@@ -352,9 +389,9 @@ mod tests {
     }
 
     #[test]
-    fn adapter_version_is_one() {
+    fn adapter_output_format_version_is_one() {
         let adapter = CursorAdapter::new();
-        assert_eq!(adapter.version(), 1);
+        assert_eq!(adapter.output_format_version(), 1);
     }
 
     // === TDD: Frontmatter Generation ===
@@ -371,6 +408,34 @@ mod tests {
         assert!(fm.contains("description: Test description"));
     }
 
+    #[test]
+    fn frontmatter_maps_activation_to_rule_type() {
+        let adapter = CursorAdapter::new();
+        let policy = |activation| {
+            create_policy_asset("style", "Style rules", "")
+                .with_apply("*.rs")
+                .with_activation(Some(activation))
+        };
+
+        assert_eq!(
+            adapter.generate_rule_frontmatter(&policy(Activation::Always)),
+            "---\ndescription: Style rules\nalwaysApply: true\n---\n"
+        );
+        assert_eq!(
+            adapter.generate_rule_frontmatter(&policy(Activation::Glob)),
+            "---\ndescription: Style rules\nglobs: '*.rs'\nalwaysApply: false\n---\n"
+        );
+        assert_eq!(
+            adapter.generate_rule_frontmatter(&policy(Activation::ModelDecision)),
+            "---\ndescription: Style rules\nalwaysApply: false\n---\n"
+        );
+        assert_eq!(
+            adapter.generate_rule_frontmatter(&policy(Activation::Manual)),
+            "---\nalwaysApply: false\n---\n"
+        );
+        assert!(adapter.downgrades(&policy(Activation::Manual)).is_empty());
+    }
+
     // === TDD: Binary Outputs ===
 
     #[test]
@@ -412,4 +477,19 @@ mod tests {
 
         assert!(outputs.is_empty());
     }
+
+    // === TDD: MCP ===
+
+    #[test]
+    fn compile_mcp_uses_scope_specific_path() {
+        let adapter = CursorAdapter::new();
+        let servers = vec![McpServer::new("github", "npx")];
+
+        let project = adapter.compile_mcp(&servers, Scope::Project).unwrap();
+        let user = adapter.compile_mcp(&servers, Scope::User).unwrap();
+
+        assert_eq!(project[0].path(), &PathBuf::from(".cursor/mcp.json"));
+        assert_eq!(user[0].path(), &PathBuf::from("~/.cursor/mcp.json"));
+        assert!(project[0].content().contains("\"mcpServers\""));
+    }
 }
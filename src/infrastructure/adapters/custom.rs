@@ -0,0 +1,384 @@
+//! Custom Target Adapter
+//!
+//! Compiles the declarative targets of `[[targets.custom]]` (e.g. Aider's
+//! `CONVENTIONS.md` or Zed's `.rules`) without a dedicated adapter:
+//! - `paths.<kind>` - one file per asset, from a path template (`{id}`, `{scope_root}`)
+//! - `aggregate` - one file collecting assets of some kinds, in a managed region
+//!   named after the custom target
+//! - `frontmatter` - output frontmatter keys mapped from asset fields
+//!
+//! Each declaration gets its own adapter and `Target::Custom(name)`, so it is enabled,
+//! selected in asset `targets:` and recorded in the lockfile under its own name.
+
+use std::path::{Component, PathBuf};
+
+use crate::config::{CustomAssetKind, CustomTargetConfig};
+use crate::domain::entities::{Asset, AssetKind, OutputFile};
+use crate::domain::ports::target_adapter::{AdapterDiagnostic, AdapterError, TargetAdapter};
+use crate::domain::services::managed_region;
+use crate::domain::value_objects::{MergeSpec, Scope, Target};
+
+use super::asset_sections;
+
+/// Adapter running one `[[targets.custom]]` declaration
+pub struct CustomAdapter {
+    config: CustomTargetConfig,
+}
+
+impl CustomAdapter {
+    pub fn new(config: CustomTargetConfig) -> Self {
+        Self { config }
+    }
+
+    /// Create the adapter for the declaration named `name`; `None` if it is not declared
+    pub fn from_config(config: &crate::config::Config, name: &str) -> Option<Self> {
+        config
+            .targets
+            .custom
+            .iter()
+            .find(|target| target.name == name)
+            .map(|target| Self::new(target.clone()))
+    }
+
+    fn custom_kind(kind: AssetKind) -> Option<CustomAssetKind> {
+        match kind {
+            AssetKind::Policy => Some(CustomAssetKind::Policy),
+            AssetKind::Action => Some(CustomAssetKind::Action),
+            AssetKind::Agent => Some(CustomAssetKind::Agent),
+            AssetKind::Skill => None, // Skills are directories; not supported
+            AssetKind::Hook => None,  // Hooks are settings, not files
+        }
+    }
+
+    /// Expand `{scope_root}` and `{id}` in a path template
+    ///
+    /// Templates are checked when the config loads; the expanded path is checked
+    /// again so an asset id cannot lead it out of the scope root.
+    fn expand(&self, template: &str, id: &str, scope: Scope) -> Result<PathBuf, AdapterError> {
+        let root = match scope {
+            Scope::Project => ".",
+            Scope::User => "~",
+        };
+        let path = template.replace("{scope_root}", root).replace("{id}", id);
+        let path = PathBuf::from(path.strip_prefix("./").unwrap_or(&path));
+
+        let relative = match scope {
+            Scope::Project => path.as_path(),
+            Scope::User => path.strip_prefix("~").unwrap_or(&path),
+        };
+        if !relative
+            .components()
+            .all(|c| matches!(c, Component::Normal(_) | Component::CurDir))
+        {
+            return Err(AdapterError::CompilationFailed {
+                message: format!(
+                    "Custom target '{}' path '{}' leaves the {} root",
+                    self.config.name,
+                    path.display(),
+                    scope
+                ),
+            });
+        }
+        Ok(path)
+    }
+
+    /// Output frontmatter block (empty if the target maps no fields)
+    fn frontmatter(&self, asset: &Asset) -> Result<String, AdapterError> {
+        let mut fields = serde_yaml_ng::Mapping::new();
+        for (key, field) in &self.config.frontmatter {
+            let value: serde_yaml_ng::Value = match field.as_str() {
+                "id" => asset.id().into(),
+                "name" => asset.agent_name().unwrap_or_else(|| asset.id()).into(),
+                "description" => asset.description().into(),
+                "kind" => match asset.kind() {
+                    AssetKind::Policy => "policy",
+                    AssetKind::Action => "action",
+                    AssetKind::Agent => "agent",
+                    AssetKind::Skill => "skill",
+                    AssetKind::Hook => "hook",
+                }
+                .into(),
+                "scope" => asset.scope().to_string().into(),
+                "apply" => match asset.apply() {
+                    Some(apply) => apply.into(),
+                    None => continue,
+                },
+                "model" => match asset.agent_model() {
+                    Some(model) => model.into(),
+                    None => continue,
+                },
+                "tools" if !asset.agent_tools().is_empty() => asset.agent_tools().to_vec().into(),
+                _ => continue,
+            };
+            fields.insert(key.as_str().into(), value);
+        }
+        if fields.is_empty() {
+            return Ok(String::new());
+        }
+
+        let yaml =
+            serde_yaml_ng::to_string(&fields).map_err(|e| AdapterError::CompilationFailed {
+                message: format!(
+                    "Failed to serialize '{}' frontmatter for '{}': {}",
+                    self.config.name,
+                    asset.id(),
+                    e
+                ),
+            })?;
+        Ok(format!("---\n{}---\n\n", yaml))
+    }
+
+    fn aggregates(&self, kind: CustomAssetKind) -> bool {
+        self.config
+            .aggregate
+            .as_ref()
+            .is_some_and(|aggregate| aggregate.kinds.contains(&kind))
+    }
+}
+
+impl TargetAdapter for CustomAdapter {
+    fn target(&self) -> Target {
+        Target::custom(&self.config.name)
+    }
+
+    fn compile(&self, asset: &Asset) -> Result<Vec<OutputFile>, AdapterError> {
+        let Some(kind) = Self::custom_kind(asset.kind()) else {
+            return Ok(Vec::new());
+        };
+        // Aggregated kinds are collected in post_compile()
+        if self.aggregates(kind) {
+            return Ok(Vec::new());
+        }
+        let template = match kind {
+            CustomAssetKind::Policy => &self.config.paths.policy,
+            CustomAssetKind::Action => &self.config.paths.action,
+            CustomAssetKind::Agent => &self.config.paths.agent,
+        };
+        let Some(template) = template
+            .as_ref()
+            .and_then(|t| t.for_scope(asset.scope().is_user()))
+        else {
+            return Ok(Vec::new());
+        };
+
+        let content = format!(
+            "{}{}\n\n{}",
+            self.frontmatter(asset)?,
+            asset.content().trim(),
+            self.footer(&asset.source_path_normalized())
+        );
+        Ok(vec![OutputFile::new(
+            self.expand(template, asset.id(), asset.scope())?,
+            content,
+            self.target(),
+        )])
+    }
+
+    fn validate(&self, _output: &OutputFile) -> Vec<AdapterDiagnostic> {
+        Vec::new()
+    }
+
+    fn post_compile(&self, assets: &[Asset]) -> Result<Vec<OutputFile>, AdapterError> {
+        let Some(aggregate) = &self.config.aggregate else {
+            return Ok(Vec::new());
+        };
+        let name = &self.config.name;
+
+        let mut outputs = Vec::new();
+        for scope in [Scope::Project, Scope::User] {
+            let Some(template) = aggregate.path.for_scope(scope.is_user()) else {
+                continue;
+            };
+            let collected: Vec<&Asset> = assets
+                .iter()
+                .filter(|a| {
+                    a.scope() == scope
+                        && a.effective_targets().contains(&self.target())
+                        && Self::custom_kind(a.kind())
+                            .is_some_and(|kind| aggregate.kinds.contains(&kind))
+                })
+                .collect();
+            if collected.is_empty() {
+                continue;
+            }
+
+            // The region is named after the custom target, so several targets
+            // (built-in or custom) can share one file.
+            outputs.push(
+                OutputFile::new(
+                    self.expand(template, "", scope)?,
+                    managed_region(name, &asset_sections(&collected)),
+                    self.target(),
+                )
+                .with_merge(MergeSpec::new(vec![name.clone()]).always()),
+            );
+        }
+        Ok(outputs)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn config(toml: &str) -> crate::config::Config {
+        toml::from_str(toml).unwrap()
+    }
+
+    fn adapter(toml: &str, name: &str) -> CustomAdapter {
+        CustomAdapter::from_config(&config(toml), name).unwrap()
+    }
+
+    fn create_policy_asset(id: &str, description: &str, content: &str) -> Asset {
+        Asset::new(id, format!("policies/{}.md", id), description, content)
+            .with_kind(AssetKind::Policy)
+    }
+
+    fn create_action_asset(id: &str, description: &str, content: &str) -> Asset {
+        Asset::new(id, format!("actions/{}.md", id), description, content)
+            .with_kind(AssetKind::Action)
+    }
+
+    const ZED: &str = r#"
+[[targets.custom]]
+name = "zed"
+paths.policy = "{scope_root}/.zed/rules/{id}.md"
+paths.action = { project = ".zed/prompts/{id}.md" }
+frontmatter = { description = "description", globs = "apply" }
+"#;
+
+    const AIDER: &str = r#"
+[[targets.custom]]
+name = "aider"
+aggregate = { path = "{scope_root}/CONVENTIONS.md" }
+"#;
+
+    // === TDD: Config Tests ===
+
+    #[test]
+    fn from_config_without_custom_targets_is_none() {
+        assert!(CustomAdapter::from_config(&crate::config::Config::default(), "zed").is_none());
+        assert!(CustomAdapter::from_config(&config(ZED), "aider").is_none());
+    }
+
+    #[test]
+    fn config_rejects_undeclared_enabled_targets() {
+        let toml = format!("{}\n[targets]\nenabled = [\"zed\", \"aidr\"]\n", ZED);
+        let err = toml::from_str::<crate::config::Config>(&toml).unwrap_err();
+        assert!(err.to_string().contains("invalid target 'aidr'"), "{}", err);
+
+        let toml = format!("[targets]\nenabled = [\"zed\"]\n{}", ZED);
+        let config = config(&toml);
+        assert_eq!(config.enabled_targets(), vec![Target::custom("zed")]);
+    }
+
+    #[test]
+    fn config_rejects_invalid_declarations() {
+        let cases = [
+            ("name = \"cursor\"\npaths.policy = \"{id}.md\"", "built-in"),
+            ("name = \"Zed\"\npaths.policy = \"{id}.md\"", "kebab-case"),
+            ("name = \"zed\"\npaths.policy = \".rules\"", "{id}"),
+            ("name = \"zed\"", "no paths"),
+            (
+                "name = \"zed\"\npaths.policy = \"../escaped/{id}.md\"",
+                "cannot contain '..'",
+            ),
+            (
+                "name = \"zed\"\npaths.policy = \"/tmp/escaped/{id}.md\"",
+                "must be relative",
+            ),
+            (
+                "name = \"zed\"\npaths.policy = \"{scope_root}/../{id}.md\"",
+                "cannot contain '..'",
+            ),
+            (
+                "name = \"zed\"\naggregate = { path = \"~/CONVENTIONS.md\" }",
+                "must be relative",
+            ),
+            (
+                "name = \"zed\"\npaths.policy = \"{id}.md\"\nfrontmatter = { x = \"body\" }",
+                "unknown frontmatter field",
+            ),
+        ];
+        for (body, expected) in cases {
+            let err =
+                toml::from_str::<crate::config::Config>(&format!("[[targets.custom]]\n{}\n", body))
+                    .unwrap_err();
+            assert!(err.to_string().contains(expected), "{}: {}", body, err);
+        }
+    }
+
+    // === TDD: Compile Tests ===
+
+    #[test]
+    fn compile_policy_expands_path_template_and_frontmatter() {
+        let adapter = adapter(ZED, "zed");
+        let asset = create_policy_asset("rust", "Rust rules", "Run clippy.").with_apply("**/*.rs");
+
+        let outputs = adapter.compile(&asset).unwrap();
+
+        assert_eq!(outputs.len(), 1);
+        assert_eq!(outputs[0].path(), &PathBuf::from(".zed/rules/rust.md"));
+        assert_eq!(outputs[0].target(), Target::custom("zed"));
+        assert!(outputs[0].content().starts_with(
+            "---\ndescription: Rust rules\nglobs: '**/*.rs'\n---\n\nRun clippy.\n\n<!-- Generated by Calvin"
+        ));
+    }
+
+    #[test]
+    fn compile_respects_per_scope_templates() {
+        let adapter = adapter(ZED, "zed");
+        let user_policy = create_policy_asset("style", "Style", "x").with_scope(Scope::User);
+        let user_action = create_action_asset("deploy", "Deploy", "x").with_scope(Scope::User);
+
+        assert_eq!(
+            adapter.compile(&user_policy).unwrap()[0].path(),
+            &PathBuf::from("~/.zed/rules/style.md")
+        );
+        assert!(adapter.compile(&user_action).unwrap().is_empty());
+    }
+
+    #[test]
+    fn aggregate_collects_policies_into_named_region() {
+        let adapter = adapter(AIDER, "aider");
+        let aider = vec![Target::custom("aider")];
+        let assets = vec![
+            create_policy_asset("style", "Style", "Use rustfmt.").with_targets(aider.clone()),
+            create_action_asset("deploy", "Deploy", "Run deploy.").with_targets(aider),
+            create_policy_asset("other", "Other", "Not for aider."),
+        ];
+
+        assert!(adapter.compile(&assets[0]).unwrap().is_empty());
+        let outputs = adapter.post_compile(&assets).unwrap();
+
+        assert_eq!(outputs.len(), 1);
+        assert_eq!(outputs[0].path(), &PathBuf::from("CONVENTIONS.md"));
+        assert!(outputs[0]
+            .content()
+            .starts_with("<!-- calvin:begin aider -->\n## Style"));
+        assert!(!outputs[0].content().contains("Run deploy."));
+        assert!(!outputs[0].content().contains("Not for aider."));
+        assert_eq!(outputs[0].merge_spec().unwrap().owned_keys(), ["aider"]);
+    }
+
+    #[test]
+    fn compile_rejects_ids_that_leave_the_scope_root() {
+        let adapter = adapter(ZED, "zed");
+        let asset = create_policy_asset("../../../escaped", "Escape", "x");
+
+        let err = adapter.compile(&asset).unwrap_err();
+        assert!(
+            err.to_string().contains("leaves the project root"),
+            "{}",
+            err
+        );
+    }
+
+    #[test]
+    fn skills_are_not_compiled() {
+        let adapter = adapter(ZED, "zed");
+        let skill = Asset::new("s", "skills/s/SKILL.md", "Skill", "x").with_kind(AssetKind::Skill);
+
+        assert!(adapter.compile(&skill).unwrap().is_empty());
+    }
+}
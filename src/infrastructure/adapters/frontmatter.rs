@@ -0,0 +1,118 @@
+//! YAML frontmatter builder shared by target adapters
+//!
+//! Values are serialized with serde, so a description containing `: `, `#`, quotes
+//! or newlines still yields valid YAML. Keys keep the order they were added in; the
+//! source asset's unrecognized keys (`extra`) follow in sorted order.
+
+use std::collections::HashMap;
+
+use serde_yaml_ng::{Mapping, Value};
+
+/// Ordered YAML frontmatter of a generated file
+#[derive(Debug, Clone, Default)]
+pub(crate) struct FrontmatterBuilder {
+    fields: Mapping,
+}
+
+impl FrontmatterBuilder {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Add a key (replacing an earlier value of the same key)
+    pub fn field(mut self, key: &str, value: impl Into<Value>) -> Self {
+        self.fields.insert(key.into(), value.into());
+        self
+    }
+
+    /// Add a key only if it has a value
+    pub fn field_opt<V: Into<Value>>(self, key: &str, value: Option<V>) -> Self {
+        match value {
+            Some(value) => self.field(key, value),
+            None => self,
+        }
+    }
+
+    /// Pass through the source asset's unrecognized keys, sorted by name
+    ///
+    /// Keys the adapter already set win, so the block never has duplicate keys.
+    pub fn extra(mut self, extra: &HashMap<String, Value>) -> Self {
+        let mut keys: Vec<&String> = extra.keys().collect();
+        keys.sort();
+        for key in keys {
+            let key_value = Value::from(key.as_str());
+            if !self.fields.contains_key(&key_value) {
+                self.fields.insert(key_value, extra[key].clone());
+            }
+        }
+        self
+    }
+
+    /// YAML lines without delimiters (empty if there are no keys)
+    pub fn to_yaml(&self) -> String {
+        if self.fields.is_empty() {
+            return String::new();
+        }
+        serde_yaml_ng::to_string(&self.fields).expect("a YAML mapping always serializes")
+    }
+
+    /// `---`-delimited block, ending with a newline
+    pub fn render(&self) -> String {
+        format!("---\n{}---\n", self.to_yaml())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn render_keeps_key_order() {
+        let fm = FrontmatterBuilder::new()
+            .field("trigger", "glob")
+            .field("description", "Rust")
+            .field("alwaysApply", false);
+
+        assert_eq!(
+            fm.render(),
+            "---\ntrigger: glob\ndescription: Rust\nalwaysApply: false\n---\n"
+        );
+    }
+
+    #[test]
+    fn render_quotes_unsafe_values() {
+        let description = "Rules: \"quoted\" # not a comment\nsecond line";
+        let fm = FrontmatterBuilder::new()
+            .field("description", description)
+            .field("globs", "**/*.rs");
+
+        let yaml = fm.to_yaml();
+        let parsed: Mapping = serde_yaml_ng::from_str(&yaml).unwrap();
+        assert_eq!(parsed["description"], Value::from(description));
+        assert_eq!(parsed["globs"], Value::from("**/*.rs"));
+    }
+
+    #[test]
+    fn extra_is_sorted_and_never_overrides_adapter_keys() {
+        let mut extra = HashMap::new();
+        extra.insert("version".to_string(), Value::from("1.0"));
+        extra.insert("description".to_string(), Value::from("ignored"));
+        extra.insert("author".to_string(), Value::from("me"));
+
+        let fm = FrontmatterBuilder::new()
+            .field("description", "Kept")
+            .extra(&extra);
+
+        assert_eq!(
+            fm.to_yaml(),
+            "description: Kept\nauthor: me\nversion: '1.0'\n"
+        );
+    }
+
+    #[test]
+    fn empty_builder_renders_empty_block() {
+        let fm = FrontmatterBuilder::new().field_opt::<&str>("model", None);
+
+        assert_eq!(fm.render(), "---\n---\n");
+    }
+}
@@ -0,0 +1,330 @@
+//! Google Gemini CLI Adapter
+//!
+//! Generates output for Gemini CLI:
+//! - `GEMINI.md` - Project context (Policies, aggregated into the `gemini-cli` managed region)
+//! - `~/.gemini/GEMINI.md` - User context (same region)
+//! - `.gemini/commands/<id>.toml` - Custom commands (Actions/Agents)
+//! - `~/.gemini/commands/<id>.toml` - User-level custom commands
+//! - `.gemini/settings.json` / `~/.gemini/settings.json` - MCP servers (`mcpServers`),
+//!   merged into the user's settings
+//!
+//! Commands are TOML files with `description` and `prompt`; Calvin's `$ARGUMENTS`
+//! placeholder is rewritten to Gemini's `{{args}}`.
+
+use std::path::PathBuf;
+
+use super::activation::activation_downgrade;
+use super::agents::agent_downgrade;
+use super::mcp;
+use super::policies_region_output;
+use crate::domain::entities::{Asset, AssetKind, McpServer, OutputFile};
+use crate::domain::ports::target_adapter::{
+    AdapterDiagnostic, AdapterError, DiagnosticSeverity, TargetAdapter,
+};
+use crate::domain::ports::tool_probe::{DetectedTool, ToolProbe};
+use crate::domain::value_objects::{Activation, Scope, Target};
+
+/// Gemini CLI adapter
+pub struct GeminiCliAdapter;
+
+impl GeminiCliAdapter {
+    pub fn new() -> Self {
+        Self
+    }
+
+    fn context_path(&self, scope: Scope) -> PathBuf {
+        match scope {
+            Scope::User => PathBuf::from("~/.gemini/GEMINI.md"),
+            Scope::Project => PathBuf::from("GEMINI.md"),
+        }
+    }
+
+    fn settings_path(&self, scope: Scope) -> PathBuf {
+        match scope {
+            Scope::User => PathBuf::from("~/.gemini/settings.json"),
+            Scope::Project => PathBuf::from(".gemini/settings.json"),
+        }
+    }
+
+    fn commands_dir(&self, scope: Scope) -> PathBuf {
+        match scope {
+            Scope::User => PathBuf::from("~/.gemini/commands"),
+            Scope::Project => PathBuf::from(".gemini/commands"),
+        }
+    }
+
+    /// Render a custom command as TOML (`description` + `prompt`)
+    fn compile_command(&self, asset: &Asset) -> Result<Vec<OutputFile>, AdapterError> {
+        let mut command = toml::Table::new();
+        command.insert("description".into(), asset.description().into());
+        command.insert(
+            "prompt".into(),
+            asset
+                .content()
+                .trim()
+                .replace("$ARGUMENTS", "{{args}}")
+                .into(),
+        );
+        let body =
+            toml::to_string_pretty(&command).map_err(|e| AdapterError::CompilationFailed {
+                message: format!("Failed to render Gemini command '{}': {}", asset.id(), e),
+            })?;
+
+        let path = self
+            .commands_dir(asset.scope())
+            .join(format!("{}.toml", asset.id()));
+        let content = format!(
+            "# Generated by Calvin. Source: {}. DO NOT EDIT.\n\n{}",
+            asset.source_path_normalized(),
+            body
+        );
+
+        Ok(vec![OutputFile::new(path, content, self.target())])
+    }
+}
+
+impl Default for GeminiCliAdapter {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl TargetAdapter for GeminiCliAdapter {
+    fn target(&self) -> Target {
+        Target::GeminiCli
+    }
+
+    fn detect_version(&self, probe: &dyn ToolProbe) -> Option<DetectedTool> {
+        DetectedTool::probe(probe, "gemini", ".gemini")
+    }
+
+    fn compile(&self, asset: &Asset) -> Result<Vec<OutputFile>, AdapterError> {
+        match asset.kind() {
+            // Agents have no native equivalent; they become commands like actions.
+            AssetKind::Action | AssetKind::Agent => self.compile_command(asset),
+            AssetKind::Policy => Ok(Vec::new()), // Aggregated in post_compile()
+            AssetKind::Skill | AssetKind::Hook => Ok(Vec::new()), // Not supported on Gemini CLI
+        }
+    }
+
+    fn compile_mcp(
+        &self,
+        servers: &[McpServer],
+        scope: Scope,
+    ) -> Result<Vec<OutputFile>, AdapterError> {
+        if servers.is_empty() {
+            return Ok(Vec::new());
+        }
+
+        // `settings.json` holds all of the user's Gemini settings: only server entries
+        // are Calvin's, so it is always merged.
+        let content = mcp::render_servers_json("mcpServers", servers, mcp::stdio_server)?;
+        Ok(vec![OutputFile::new(
+            self.settings_path(scope),
+            content,
+            self.target(),
+        )
+        .with_merge(mcp::merge_spec("mcpServers", servers).always())])
+    }
+
+    fn downgrades(&self, asset: &Asset) -> Vec<AdapterDiagnostic> {
+        let mut diagnostics = agent_downgrade(asset, self.target(), Some("a command"), &[]);
+        // Policies share one always-loaded file.
+        diagnostics.extend(activation_downgrade(
+            asset,
+            self.target(),
+            &[Activation::Always],
+            Activation::Always,
+        ));
+        diagnostics
+    }
+
+    fn validate(&self, output: &OutputFile) -> Vec<AdapterDiagnostic> {
+        let mut diagnostics = Vec::new();
+
+        if output.path().extension().is_some_and(|ext| ext == "toml") {
+            match output.content().parse::<toml::Table>() {
+                Ok(command) if !command.contains_key("prompt") => {
+                    diagnostics.push(AdapterDiagnostic {
+                        severity: DiagnosticSeverity::Error,
+                        message: "Gemini command is missing 'prompt'".to_string(),
+                    });
+                }
+                Ok(_) => {}
+                Err(e) => diagnostics.push(AdapterDiagnostic {
+                    severity: DiagnosticSeverity::Error,
+                    message: format!("Gemini command is not valid TOML: {}", e),
+                }),
+            }
+        }
+
+        diagnostics
+    }
+
+    fn post_compile(&self, assets: &[Asset]) -> Result<Vec<OutputFile>, AdapterError> {
+        Ok([Scope::Project, Scope::User]
+            .into_iter()
+            .filter_map(|scope| {
+                policies_region_output(self.context_path(scope), assets, scope, self.target())
+            })
+            .collect())
+    }
+
+    fn security_baseline(
+        &self,
+        _config: &crate::config::Config,
+    ) -> Result<Vec<OutputFile>, AdapterError> {
+        // Gemini CLI sandboxing is configured per user, not per project
+        Ok(Vec::new())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn create_action_asset(id: &str, description: &str, content: &str) -> Asset {
+        Asset::new(id, format!("actions/{}.md", id), description, content)
+            .with_kind(AssetKind::Action)
+    }
+
+    fn create_policy_asset(id: &str, description: &str, content: &str) -> Asset {
+        Asset::new(id, format!("policies/{}.md", id), description, content)
+            .with_kind(AssetKind::Policy)
+    }
+
+    // === TDD: Compile Tests ===
+
+    #[test]
+    fn compile_action_to_toml_command() {
+        let adapter = GeminiCliAdapter::new();
+        let asset = create_action_asset(
+            "gen-tests",
+            "Generate \"unit\" tests",
+            "Test $ARGUMENTS\nthoroughly.",
+        );
+
+        let outputs = adapter.compile(&asset).unwrap();
+
+        assert_eq!(outputs.len(), 1);
+        assert_eq!(
+            outputs[0].path(),
+            &PathBuf::from(".gemini/commands/gen-tests.toml")
+        );
+        let content = outputs[0].content();
+        assert!(content.starts_with("# Generated by Calvin"));
+        let command: toml::Table = toml::from_str(content).unwrap();
+        assert_eq!(
+            command["description"].as_str(),
+            Some("Generate \"unit\" tests")
+        );
+        assert_eq!(
+            command["prompt"].as_str(),
+            Some("Test {{args}}\nthoroughly.")
+        );
+    }
+
+    #[test]
+    fn compile_user_scope_uses_home() {
+        let adapter = GeminiCliAdapter::new();
+        let asset = create_action_asset("test", "desc", "content").with_scope(Scope::User);
+
+        let outputs = adapter.compile(&asset).unwrap();
+
+        assert_eq!(
+            outputs[0].path(),
+            &PathBuf::from("~/.gemini/commands/test.toml")
+        );
+    }
+
+    #[test]
+    fn compile_policy_is_aggregated_in_post_compile() {
+        let adapter = GeminiCliAdapter::new();
+        let asset = create_policy_asset("style", "Style", "Use rustfmt.");
+
+        assert!(adapter.compile(&asset).unwrap().is_empty());
+    }
+
+    #[test]
+    fn post_compile_writes_policies_to_gemini_md_region() {
+        let adapter = GeminiCliAdapter::new();
+        let assets = vec![
+            create_policy_asset("style", "Style", "Use rustfmt."),
+            create_policy_asset("global", "Global", "Be concise.").with_scope(Scope::User),
+        ];
+
+        let outputs = adapter.post_compile(&assets).unwrap();
+
+        assert_eq!(outputs.len(), 2);
+        assert_eq!(outputs[0].path(), &PathBuf::from("GEMINI.md"));
+        assert!(outputs[0]
+            .content()
+            .starts_with("<!-- calvin:begin gemini-cli -->\n## Style"));
+        assert_eq!(
+            outputs[0].merge_spec().unwrap().owned_keys(),
+            ["gemini-cli"]
+        );
+        assert_eq!(outputs[1].path(), &PathBuf::from("~/.gemini/GEMINI.md"));
+        assert!(outputs[1].content().contains("Be concise."));
+    }
+
+    // === TDD: Validate Tests ===
+
+    #[test]
+    fn validate_compiled_command_ok() {
+        let adapter = GeminiCliAdapter::new();
+        let asset = create_action_asset("test", "desc", "content");
+        let outputs = adapter.compile(&asset).unwrap();
+
+        assert!(adapter.validate(&outputs[0]).is_empty());
+    }
+
+    #[test]
+    fn validate_command_without_prompt_errors() {
+        let adapter = GeminiCliAdapter::new();
+        let output = OutputFile::new(
+            ".gemini/commands/test.toml",
+            "description = \"x\"\n",
+            Target::GeminiCli,
+        );
+
+        let diags = adapter.validate(&output);
+
+        assert_eq!(diags.len(), 1);
+        assert!(diags[0].message.contains("prompt"));
+    }
+
+    // === TDD: Trait Implementation ===
+
+    #[test]
+    fn adapter_target_is_gemini_cli() {
+        let adapter = GeminiCliAdapter::new();
+        assert_eq!(adapter.target(), Target::GeminiCli);
+    }
+
+    #[test]
+    fn compile_mcp_merges_servers_into_settings() {
+        let adapter = GeminiCliAdapter::new();
+        let servers = vec![McpServer::new("github", "npx").with_args(vec!["-y".into()])];
+
+        let project = adapter.compile_mcp(&servers, Scope::Project).unwrap();
+        let user = adapter.compile_mcp(&servers, Scope::User).unwrap();
+
+        assert_eq!(project[0].path(), &PathBuf::from(".gemini/settings.json"));
+        assert_eq!(user[0].path(), &PathBuf::from("~/.gemini/settings.json"));
+        let parsed: serde_json::Value = serde_json::from_str(project[0].content()).unwrap();
+        assert_eq!(parsed["mcpServers"]["github"]["command"], "npx");
+        let spec = project[0].merge_spec().unwrap();
+        assert!(spec.is_always());
+        assert_eq!(spec.owned_keys(), ["/mcpServers/github"]);
+        assert!(adapter.compile_mcp(&[], Scope::Project).unwrap().is_empty());
+    }
+
+    #[test]
+    fn security_baseline_returns_empty() {
+        let adapter = GeminiCliAdapter::new();
+        let config = crate::config::Config::default();
+
+        assert!(adapter.security_baseline(&config).unwrap().is_empty());
+    }
+}
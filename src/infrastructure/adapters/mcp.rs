@@ -0,0 +1,96 @@
+//! Shared MCP configuration rendering
+//!
+//! Most platforms use the same stdio server shape
+//! (`{"command": ..., "args": [...], "env": {...}}`) under a platform-specific key.
+
+use serde_json::{json, Map, Value};
+
+use crate::domain::entities::McpServer;
+use crate::domain::ports::target_adapter::AdapterError;
+use crate::domain::value_objects::{json_pointer, MergeSpec};
+
+/// Standard stdio server entry: `command`, `args` and (non-empty) `env`.
+pub(crate) fn stdio_server(server: &McpServer) -> Value {
+    let mut entry = Map::new();
+    entry.insert("command".to_string(), json!(server.command()));
+    entry.insert("args".to_string(), json!(server.args()));
+    if !server.env().is_empty() {
+        entry.insert("env".to_string(), json!(server.env()));
+    }
+    Value::Object(entry)
+}
+
+/// Render `{"<key>": {"<name>": <entry>, ...}}` as pretty JSON with a trailing newline.
+pub(crate) fn render_servers_json(
+    key: &str,
+    servers: &[McpServer],
+    entry: impl Fn(&McpServer) -> Value,
+) -> Result<String, AdapterError> {
+    let entries: Map<String, Value> = servers
+        .iter()
+        .map(|server| (server.name().to_string(), entry(server)))
+        .collect();
+
+    let mut root = Map::new();
+    root.insert(key.to_string(), Value::Object(entries));
+    render_json(&Value::Object(root))
+}
+
+/// Merge spec owning one `/<key>/<name>` entry per server.
+///
+/// Used by `deploy --merge` so shared config files keep user-defined servers.
+pub(crate) fn merge_spec(key: &str, servers: &[McpServer]) -> MergeSpec {
+    MergeSpec::new(
+        servers
+            .iter()
+            .map(|server| json_pointer(&[key, server.name()]))
+            .collect(),
+    )
+}
+
+/// Pretty-print JSON with a trailing newline.
+pub(crate) fn render_json(value: &Value) -> Result<String, AdapterError> {
+    serde_json::to_string_pretty(value)
+        .map(|s| format!("{}\n", s))
+        .map_err(|e| AdapterError::CompilationFailed {
+            message: format!("Failed to render MCP config: {}", e),
+        })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::collections::BTreeMap;
+
+    #[test]
+    fn stdio_server_omits_empty_env() {
+        let server = McpServer::new("fs", "npx").with_args(vec!["-y".into()]);
+        assert_eq!(
+            stdio_server(&server),
+            json!({"command": "npx", "args": ["-y"]})
+        );
+    }
+
+    #[test]
+    fn render_servers_json_nests_under_key() {
+        let server = McpServer::new("github", "npx").with_env(BTreeMap::from([(
+            "TOKEN".to_string(),
+            "${TOKEN}".to_string(),
+        )]));
+
+        let rendered = render_servers_json("mcpServers", &[server], stdio_server).unwrap();
+        let value: Value = serde_json::from_str(&rendered).unwrap();
+
+        assert_eq!(value["mcpServers"]["github"]["command"], "npx");
+        assert_eq!(value["mcpServers"]["github"]["env"]["TOKEN"], "${TOKEN}");
+        assert!(rendered.ends_with('\n'));
+    }
+
+    #[test]
+    fn merge_spec_owns_each_server_entry() {
+        let servers = vec![McpServer::new("github", "npx"), McpServer::new("fs", "npx")];
+        let spec = merge_spec("mcpServers", &servers);
+        assert_eq!(spec.owned_keys(), ["/mcpServers/github", "/mcpServers/fs"]);
+        assert!(!spec.is_always());
+    }
+}
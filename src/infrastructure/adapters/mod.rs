@@ -3,95 +3,142 @@
 //! These adapters implement the TargetAdapter port from the domain layer.
 //! They transform domain Assets into platform-specific OutputFiles.
 
+mod activation;
 mod agents;
 pub mod antigravity;
 pub mod claude_code;
+pub mod cline;
 pub mod codex;
 pub mod cursor;
+pub mod custom;
+mod frontmatter;
+pub mod gemini_cli;
+mod mcp;
 pub mod opencode;
 mod skills;
 pub mod vscode;
+pub mod windsurf;
 
 pub use antigravity::AntigravityAdapter;
 pub use claude_code::ClaudeCodeAdapter;
+pub use cline::ClineAdapter;
 pub use codex::CodexAdapter;
 pub use cursor::CursorAdapter;
+pub use custom::CustomAdapter;
+pub use gemini_cli::GeminiCliAdapter;
 pub use opencode::OpenCodeAdapter;
 pub use vscode::VSCodeAdapter;
+pub use windsurf::WindsurfAdapter;
 
+use frontmatter::FrontmatterBuilder;
+
+use crate::config::Config;
+use crate::domain::entities::{Asset, AssetKind, OutputFile};
 use crate::domain::ports::TargetAdapter;
-use crate::domain::value_objects::Target;
+use crate::domain::services::managed_region;
+use crate::domain::value_objects::{MergeSpec, Scope, Target};
 use std::collections::HashMap;
+use std::path::PathBuf;
 
-/// Format extra frontmatter fields as YAML string.
+/// Output that owns only the managed region of `target` in a shared Markdown file
 ///
-/// Returns empty string if no extra fields, otherwise returns each field on its own line.
-pub fn format_extra_frontmatter(extra: &HashMap<String, serde_yaml_ng::Value>) -> String {
-    if extra.is_empty() {
-        return String::new();
-    }
+/// Hand-written text and other targets' regions in the file are preserved, so several
+/// adapters can contribute to one file (e.g. `AGENTS.md`).
+pub(crate) fn managed_region_output(
+    path: impl Into<PathBuf>,
+    body: &str,
+    target: Target,
+) -> OutputFile {
+    OutputFile::new(path, managed_region(target.id(), body), target)
+        .with_merge(MergeSpec::new(vec![target.id().to_string()]).always())
+}
 
-    let mut result = String::new();
-    let mut keys: Vec<_> = extra.keys().collect();
-    keys.sort();
+/// Managed region listing the `scope` policies of `target` (e.g. in `AGENTS.md`)
+///
+/// Each policy becomes a `## <description>` section. Returns `None` when the target
+/// has no policies in this scope.
+pub(crate) fn policies_region_output(
+    path: impl Into<PathBuf>,
+    assets: &[Asset],
+    scope: Scope,
+    target: Target,
+) -> Option<OutputFile> {
+    let policies: Vec<&Asset> = assets
+        .iter()
+        .filter(|a| {
+            a.kind() == AssetKind::Policy
+                && a.scope() == scope
+                && a.effective_targets().contains(&target)
+        })
+        .collect();
 
-    for key in keys {
-        if let Some(value) = extra.get(key) {
-            let value_str = format_yaml_value(value);
-            result.push_str(&format!("{}: {}\n", key, value_str));
-        }
+    if policies.is_empty() {
+        return None;
     }
-    result
+
+    Some(managed_region_output(
+        path,
+        &asset_sections(&policies),
+        target,
+    ))
 }
 
-fn format_yaml_value(value: &serde_yaml_ng::Value) -> String {
-    match value {
-        serde_yaml_ng::Value::Null => "null".to_string(),
-        serde_yaml_ng::Value::Bool(b) => b.to_string(),
-        serde_yaml_ng::Value::Number(n) => n.to_string(),
-        serde_yaml_ng::Value::String(s) => {
-            if s.contains(':') || s.contains('#') || s.starts_with(' ') || s.ends_with(' ') {
-                format!("\"{}\"", s.replace('\"', "\\\""))
-            } else {
-                s.clone()
-            }
-        }
-        serde_yaml_ng::Value::Sequence(seq) => {
-            let items: Vec<String> = seq.iter().map(format_yaml_value).collect();
-            format!("[{}]", items.join(", "))
-        }
-        serde_yaml_ng::Value::Mapping(map) => {
-            let pairs: Vec<String> = map
-                .iter()
-                .map(|(k, v)| format!("{}: {}", format_yaml_value(k), format_yaml_value(v)))
-                .collect();
-            format!("{{{}}}", pairs.join(", "))
-        }
-        serde_yaml_ng::Value::Tagged(tagged) => format_yaml_value(&tagged.value),
-    }
+/// Aggregated assets as `## <description>` sections separated by rules
+pub(crate) fn asset_sections(assets: &[&Asset]) -> String {
+    assets
+        .iter()
+        .map(|asset| {
+            format!(
+                "## {} (from: .promptpack/{})\n\n{}",
+                asset.description(),
+                asset.source_path_normalized(),
+                asset.content().trim()
+            )
+        })
+        .collect::<Vec<_>>()
+        .join("\n\n---\n\n")
+}
+
+/// Format extra frontmatter fields as YAML string.
+///
+/// Returns empty string if no extra fields, otherwise returns each field on its own line.
+pub fn format_extra_frontmatter(extra: &HashMap<String, serde_yaml_ng::Value>) -> String {
+    FrontmatterBuilder::new().extra(extra).to_yaml()
 }
 
 /// Get all available adapters
 pub fn all_adapters() -> Vec<Box<dyn TargetAdapter>> {
-    vec![
-        Box::new(ClaudeCodeAdapter::new()),
-        Box::new(CursorAdapter::new()),
-        Box::new(VSCodeAdapter::new()),
-        Box::new(AntigravityAdapter::new()),
-        Box::new(CodexAdapter::new()),
-        Box::new(OpenCodeAdapter::new()),
-    ]
+    all_adapters_for_config(&Config::default())
+}
+
+/// Get all available adapters, configured by their `[targets.<name>]` tables
+pub fn all_adapters_for_config(config: &Config) -> Vec<Box<dyn TargetAdapter>> {
+    Target::ALL_CONCRETE
+        .into_iter()
+        .chain(config.custom_targets())
+        .filter_map(|target| get_adapter_for_config(target, config))
+        .collect()
 }
 
 /// Get adapter for a specific target
 pub fn get_adapter(target: Target) -> Option<Box<dyn TargetAdapter>> {
+    get_adapter_for_config(target, &Config::default())
+}
+
+/// Get adapter for a specific target, configured by its `[targets.<name>]` table
+pub fn get_adapter_for_config(target: Target, config: &Config) -> Option<Box<dyn TargetAdapter>> {
     match target {
-        Target::ClaudeCode => Some(Box::new(ClaudeCodeAdapter::new())),
+        Target::ClaudeCode => Some(Box::new(ClaudeCodeAdapter::from_config(config))),
         Target::Cursor => Some(Box::new(CursorAdapter::new())),
-        Target::VSCode => Some(Box::new(VSCodeAdapter::new())),
-        Target::Antigravity => Some(Box::new(AntigravityAdapter::new())),
+        Target::VSCode => Some(Box::new(VSCodeAdapter::from_config(config))),
+        Target::Antigravity => Some(Box::new(AntigravityAdapter::from_config(config))),
         Target::Codex => Some(Box::new(CodexAdapter::new())),
         Target::OpenCode => Some(Box::new(OpenCodeAdapter::new())),
+        Target::GeminiCli => Some(Box::new(GeminiCliAdapter::new())),
+        Target::Windsurf => Some(Box::new(WindsurfAdapter::new())),
+        Target::Cline => Some(Box::new(ClineAdapter::from_config(config))),
+        Target::Custom(name) => CustomAdapter::from_config(config, name.as_str())
+            .map(|adapter| Box::new(adapter) as Box<dyn TargetAdapter>),
         Target::All => None, // Use all_adapters() instead
     }
 }
@@ -103,7 +150,18 @@ mod tests {
     #[test]
     fn all_adapters_returns_expected_count() {
         let adapters = all_adapters();
-        assert_eq!(adapters.len(), 6); // All concrete adapters
+        assert_eq!(adapters.len(), 9); // All concrete adapters
+    }
+
+    #[test]
+    fn adapter_tested_ranges_are_ordered() {
+        for adapter in all_adapters() {
+            if let (Some(min), Some(max)) =
+                (adapter.min_ide_version(), adapter.max_tested_version())
+            {
+                assert!(min <= max, "{:?}: {} > {}", adapter.target(), min, max);
+            }
+        }
     }
 
     #[test]
@@ -148,6 +206,27 @@ mod tests {
         assert_eq!(adapter.unwrap().target(), Target::OpenCode);
     }
 
+    #[test]
+    fn get_adapter_returns_gemini_cli() {
+        let adapter = get_adapter(Target::GeminiCli);
+        assert!(adapter.is_some());
+        assert_eq!(adapter.unwrap().target(), Target::GeminiCli);
+    }
+
+    #[test]
+    fn get_adapter_returns_cline() {
+        let adapter = get_adapter(Target::Cline);
+        assert!(adapter.is_some());
+        assert_eq!(adapter.unwrap().target(), Target::Cline);
+    }
+
+    #[test]
+    fn get_adapter_returns_windsurf() {
+        let adapter = get_adapter(Target::Windsurf);
+        assert!(adapter.is_some());
+        assert_eq!(adapter.unwrap().target(), Target::Windsurf);
+    }
+
     #[test]
     fn get_adapter_all_returns_none() {
         assert!(get_adapter(Target::All).is_none());
@@ -162,4 +241,26 @@ mod tests {
             assert!(targets.contains(&t), "Missing adapter for {:?}", t);
         }
     }
+
+    #[test]
+    fn get_adapter_for_config_returns_custom_only_when_declared() {
+        let aider = Target::custom("aider");
+        assert!(get_adapter(aider).is_none());
+
+        let config: crate::config::Config = toml::from_str(
+            "[[targets.custom]]\nname = \"aider\"\naggregate.path = \"CONVENTIONS.md\"\n\n\
+             [[targets.custom]]\nname = \"zed\"\npaths.policy = \".zed/{id}.md\"\n",
+        )
+        .unwrap();
+        let adapter = get_adapter_for_config(aider, &config);
+        assert_eq!(adapter.unwrap().target(), aider);
+        assert!(get_adapter_for_config(Target::custom("cody"), &config).is_none());
+
+        let targets: Vec<Target> = all_adapters_for_config(&config)
+            .iter()
+            .map(|a| a.target())
+            .collect();
+        assert!(targets.contains(&aider));
+        assert!(targets.contains(&Target::custom("zed")));
+    }
 }
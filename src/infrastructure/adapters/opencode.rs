@@ -4,17 +4,24 @@
 //! - `.opencode/agent/<id>.md` - Agents
 //! - `.opencode/command/<id>.md` - Commands (Actions)
 //! - `.opencode/skill/<id>/SKILL.md` - Skills (plus supplementals)
-//! - `AGENTS.md` - Project rules/instructions (Policies, aggregated)
-//! - `~/.config/opencode/AGENTS.md` - User rules/instructions (Policies, aggregated)
+//! - `AGENTS.md` - Project rules/instructions (Policies, aggregated into the `opencode`
+//!   managed region)
+//! - `~/.config/opencode/AGENTS.md` - User rules/instructions (same region)
+//! - `opencode.json` / `~/.config/opencode/opencode.json` - MCP servers (`mcp` block)
 
 use std::path::PathBuf;
 
+use super::activation::activation_downgrade;
+use super::agents::agent_downgrade;
+use super::mcp;
+use super::policies_region_output;
 use super::skills;
-use crate::domain::entities::{Asset, AssetKind, BinaryOutputFile, OutputFile};
+use crate::domain::entities::{Asset, AssetKind, BinaryOutputFile, McpServer, OutputFile};
 use crate::domain::ports::target_adapter::{
     AdapterDiagnostic, AdapterError, DiagnosticSeverity, TargetAdapter,
 };
-use crate::domain::value_objects::{Scope, Target};
+use crate::domain::ports::tool_probe::{DetectedTool, ToolProbe};
+use crate::domain::value_objects::{Activation, Scope, Target, ToolVersion};
 
 /// OpenCode adapter
 pub struct OpenCodeAdapter;
@@ -168,8 +175,8 @@ impl OpenCodeAdapter {
 
         let mut out = String::new();
         out.push_str("---\n");
-        out.push_str(yaml.trim_end());
-        out.push_str("\n---\n\n");
+        out.push_str(&yaml);
+        out.push_str("---\n\n");
         out.push_str(asset.content().trim());
         out.push_str("\n\n");
         out.push_str(&footer);
@@ -219,8 +226,8 @@ impl OpenCodeAdapter {
 
         let mut out = String::new();
         out.push_str("---\n");
-        out.push_str(yaml.trim_end());
-        out.push_str("\n---\n\n");
+        out.push_str(&yaml);
+        out.push_str("---\n\n");
         out.push_str(asset.content().trim());
         out.push_str("\n\n");
         out.push_str(&footer);
@@ -245,35 +252,7 @@ impl OpenCodeAdapter {
 
     fn compile_policies_to_agents_md(&self, assets: &[Asset], scope: Scope) -> Option<OutputFile> {
         let agents_md_path = self.agents_md_path(scope)?;
-
-        let policies: Vec<&Asset> = assets
-            .iter()
-            .filter(|a| {
-                a.kind() == AssetKind::Policy
-                    && a.scope() == scope
-                    && a.effective_targets().contains(&self.target())
-            })
-            .collect();
-
-        if policies.is_empty() {
-            return None;
-        }
-
-        let mut content = String::new();
-        content.push_str("# Project Guidelines\n\n");
-        content.push_str("<!-- Generated by Calvin. DO NOT EDIT. -->\n\n");
-
-        for policy in policies {
-            content.push_str(&format!(
-                "## {} (from: .promptpack/{})\n\n",
-                policy.description(),
-                policy.source_path_normalized()
-            ));
-            content.push_str(policy.content().trim());
-            content.push_str("\n\n---\n\n");
-        }
-
-        Some(OutputFile::new(agents_md_path, content, self.target()))
+        policies_region_output(agents_md_path, assets, scope, self.target())
     }
 }
 
@@ -288,15 +267,46 @@ impl TargetAdapter for OpenCodeAdapter {
         Target::OpenCode
     }
 
+    /// v2: `AGENTS.md` is written as a managed region instead of a whole file
+    fn output_format_version(&self) -> u32 {
+        2
+    }
+
+    fn max_tested_version(&self) -> Option<ToolVersion> {
+        Some(ToolVersion::new(0, 15, 0))
+    }
+
+    fn detect_version(&self, probe: &dyn ToolProbe) -> Option<DetectedTool> {
+        DetectedTool::probe(probe, "opencode", ".config/opencode")
+    }
+
     fn compile(&self, asset: &Asset) -> Result<Vec<OutputFile>, AdapterError> {
         match asset.kind() {
             AssetKind::Agent => self.compile_agent(asset),
             AssetKind::Action => self.compile_command(asset),
             AssetKind::Policy => Ok(Vec::new()), // Aggregated in post_compile()
             AssetKind::Skill => self.compile_skill(asset),
+            AssetKind::Hook => Ok(Vec::new()), // Not supported on OpenCode
         }
     }
 
+    fn downgrades(&self, asset: &Asset) -> Vec<AdapterDiagnostic> {
+        let mut diagnostics = agent_downgrade(
+            asset,
+            self.target(),
+            Some("an agent"),
+            &["tools", "model", "permissionMode"],
+        );
+        // Policies share one always-loaded file.
+        diagnostics.extend(activation_downgrade(
+            asset,
+            self.target(),
+            &[Activation::Always],
+            Activation::Always,
+        ));
+        diagnostics
+    }
+
     fn validate(&self, output: &OutputFile) -> Vec<AdapterDiagnostic> {
         let mut diagnostics = Vec::new();
 
@@ -331,6 +341,47 @@ impl TargetAdapter for OpenCodeAdapter {
         Ok(outputs)
     }
 
+    fn compile_mcp(
+        &self,
+        servers: &[McpServer],
+        scope: Scope,
+    ) -> Result<Vec<OutputFile>, AdapterError> {
+        if servers.is_empty() {
+            return Ok(Vec::new());
+        }
+
+        // OpenCode local servers take the full command line as an array.
+        let entries: serde_json::Map<String, serde_json::Value> = servers
+            .iter()
+            .map(|server| {
+                let command: Vec<&str> = std::iter::once(server.command())
+                    .chain(server.args().iter().map(String::as_str))
+                    .collect();
+                let mut entry = serde_json::json!({
+                    "type": "local",
+                    "command": command,
+                    "enabled": true,
+                });
+                if !server.env().is_empty() {
+                    entry["environment"] = serde_json::json!(server.env());
+                }
+                (server.name().to_string(), entry)
+            })
+            .collect();
+
+        let path = match scope {
+            Scope::User => "~/.config/opencode/opencode.json",
+            Scope::Project => "opencode.json",
+        };
+        let content = mcp::render_json(&serde_json::json!({
+            "$schema": "https://opencode.ai/config.json",
+            "mcp": entries,
+        }))?;
+        // `$schema` stays with the user's file when merging; only server entries are owned.
+        Ok(vec![OutputFile::new(path, content, Target::OpenCode)
+            .with_merge(mcp::merge_spec("mcp", servers))])
+    }
+
     fn compile_binary(&self, asset: &Asset) -> Result<Vec<BinaryOutputFile>, AdapterError> {
         if asset.kind() != AssetKind::Skill {
             return Ok(vec![]);
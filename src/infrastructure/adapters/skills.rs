@@ -2,9 +2,9 @@
 //!
 //! Reduces duplication across adapters by centralizing skill output generation.
 
-use std::path::{Component, PathBuf};
+use std::path::{Component, Path, PathBuf};
 
-use crate::domain::entities::{Asset, BinaryOutputFile, OutputFile};
+use crate::domain::entities::{Asset, AssetKind, BinaryOutputFile, OutputFile};
 use crate::domain::ports::target_adapter::{AdapterDiagnostic, AdapterError, DiagnosticSeverity};
 use crate::domain::value_objects::Target;
 
@@ -34,8 +34,8 @@ pub(crate) fn generate_skill_md(asset: &Asset, footer: &str) -> Result<String, A
 
     let mut out = String::new();
     out.push_str("---\n");
-    out.push_str(yaml.trim_end());
-    out.push_str("\n---\n\n");
+    out.push_str(&yaml);
+    out.push_str("---\n\n");
     out.push_str(asset.content().trim());
     out.push_str("\n\n");
     out.push_str(footer);
@@ -59,7 +59,6 @@ pub(crate) fn compile_skill_outputs(
     footer: &str,
 ) -> Result<SkillCompileResult, AdapterError> {
     let mut outputs = Vec::new();
-    let mut binary_outputs = Vec::new();
 
     let skill_dir = skills_dir.join(asset.id());
 
@@ -69,41 +68,50 @@ pub(crate) fn compile_skill_outputs(
         target,
     ));
 
+    let supplementals = compile_supplementals(asset, &skill_dir, target, "skill")?;
+    outputs.extend(supplementals.outputs);
+
+    Ok(SkillCompileResult {
+        outputs,
+        binary_outputs: supplementals.binary_outputs,
+    })
+}
+
+/// Supplemental files of a skill or hook, deployed under `dir`
+///
+/// `kind` names the asset kind in error messages.
+pub(crate) fn compile_supplementals(
+    asset: &Asset,
+    dir: &Path,
+    target: Target,
+    kind: &str,
+) -> Result<SkillCompileResult, AdapterError> {
+    let mut outputs = Vec::new();
+    let mut binary_outputs = Vec::new();
+
     // Handle text supplementals
     for (rel_path, content) in asset.supplementals() {
-        let is_escaping = rel_path.has_root()
-            || rel_path
-                .components()
-                .any(|c| matches!(c, Component::ParentDir | Component::Prefix(_)));
-
-        if is_escaping {
+        if is_escaping(rel_path) {
             return Err(AdapterError::CompilationFailed {
                 message: format!(
-                    "Invalid supplemental path for skill '{}': {}",
+                    "Invalid supplemental path for {} '{}': {}",
+                    kind,
                     asset.id(),
                     rel_path.display()
                 ),
             });
         }
 
-        outputs.push(OutputFile::new(
-            skill_dir.join(rel_path),
-            content.clone(),
-            target,
-        ));
+        outputs.push(OutputFile::new(dir.join(rel_path), content.clone(), target));
     }
 
     // Handle binary supplementals
     for (rel_path, content) in asset.binary_supplementals() {
-        let is_escaping = rel_path.has_root()
-            || rel_path
-                .components()
-                .any(|c| matches!(c, Component::ParentDir | Component::Prefix(_)));
-
-        if is_escaping {
+        if is_escaping(rel_path) {
             return Err(AdapterError::CompilationFailed {
                 message: format!(
-                    "Invalid binary supplemental path for skill '{}': {}",
+                    "Invalid binary supplemental path for {} '{}': {}",
+                    kind,
                     asset.id(),
                     rel_path.display()
                 ),
@@ -111,7 +119,7 @@ pub(crate) fn compile_skill_outputs(
         }
 
         binary_outputs.push(BinaryOutputFile::new(
-            skill_dir.join(rel_path),
+            dir.join(rel_path),
             content.clone(),
             target,
         ));
@@ -123,6 +131,69 @@ pub(crate) fn compile_skill_outputs(
     })
 }
 
+/// Body of a skill compiled for a target without native skills
+///
+/// The skill's instructions, followed by an index of its text supplementals under
+/// `files_dir`, where the target copies them (see [`compile_supplementals`]).
+pub(crate) fn skill_fallback_body(asset: &Asset, files_dir: &Path) -> String {
+    let mut body = asset.content().trim().to_string();
+
+    let mut files: Vec<&PathBuf> = asset.supplementals().keys().collect();
+    if !files.is_empty() {
+        files.sort();
+        let dir = files_dir.to_string_lossy().replace('\\', "/");
+        body.push_str("\n\n## Skill files\n\n");
+        body.push_str(&format!(
+            "Read these files from `{}/` when the steps above refer to them:\n\n",
+            dir
+        ));
+        for file in files {
+            body.push_str(&format!(
+                "- `{}/{}`\n",
+                dir,
+                file.to_string_lossy().replace('\\', "/")
+            ));
+        }
+    }
+
+    body
+}
+
+/// Downgrade diagnostic for a skill compiled through a target's fallback
+///
+/// `compiled_as` names the output the skill became. Non-skills get no diagnostic.
+pub(crate) fn skill_downgrade(
+    asset: &Asset,
+    target: Target,
+    compiled_as: &str,
+) -> Vec<AdapterDiagnostic> {
+    if asset.kind() != AssetKind::Skill {
+        return Vec::new();
+    }
+
+    let mut message = format!(
+        "Skill '{}' compiled as {} on {}: no native skill support",
+        asset.id(),
+        compiled_as,
+        target.display_name()
+    );
+    if !asset.allowed_tools().is_empty() {
+        message.push_str("; ignores allowed-tools");
+    }
+
+    vec![AdapterDiagnostic {
+        severity: DiagnosticSeverity::Warning,
+        message,
+    }]
+}
+
+fn is_escaping(rel_path: &Path) -> bool {
+    rel_path.has_root()
+        || rel_path
+            .components()
+            .any(|c| matches!(c, Component::ParentDir | Component::Prefix(_)))
+}
+
 pub(crate) fn validate_skill_allowed_tools(output: &OutputFile) -> Vec<AdapterDiagnostic> {
     let extracted = match crate::parser::extract_frontmatter(output.content(), output.path()) {
         Ok(extracted) => extracted,
@@ -151,7 +222,6 @@ pub(crate) fn validate_skill_allowed_tools(output: &OutputFile) -> Vec<AdapterDi
 #[cfg(test)]
 mod tests {
     use super::*;
-    use crate::domain::entities::AssetKind;
     use crate::domain::ports::target_adapter::DiagnosticSeverity;
     use crate::domain::value_objects::Scope;
     use std::collections::HashMap;
@@ -347,6 +417,49 @@ mod tests {
         assert!(matches!(err, AdapterError::CompilationFailed { .. }));
     }
 
+    #[test]
+    fn skill_fallback_body_indexes_text_supplementals() {
+        let mut supplementals = HashMap::new();
+        supplementals.insert(PathBuf::from("scripts/check.sh"), "exit 0".to_string());
+        supplementals.insert(PathBuf::from("reference.md"), "# Ref".to_string());
+        let asset = create_skill_asset("draft-commit", "Draft", "# Steps\n\nDo it.\n")
+            .with_supplementals(supplementals);
+
+        let body = skill_fallback_body(&asset, Path::new(".github/skills/draft-commit"));
+
+        assert_eq!(
+            body,
+            "# Steps\n\nDo it.\n\n## Skill files\n\n\
+             Read these files from `.github/skills/draft-commit/` when the steps above refer to them:\n\n\
+             - `.github/skills/draft-commit/reference.md`\n\
+             - `.github/skills/draft-commit/scripts/check.sh`\n"
+        );
+    }
+
+    #[test]
+    fn skill_fallback_body_without_supplementals_is_the_content() {
+        let asset = create_skill_asset("draft-commit", "Draft", "Do it.\n");
+
+        assert_eq!(
+            skill_fallback_body(&asset, Path::new(".agent/skills/draft-commit")),
+            "Do it."
+        );
+    }
+
+    #[test]
+    fn skill_downgrade_reports_ignored_allowed_tools() {
+        let asset = create_skill_asset("draft-commit", "Draft", "Do it.")
+            .with_allowed_tools(vec!["git".to_string()]);
+
+        let diags = skill_downgrade(&asset, Target::VSCode, "an instruction file");
+
+        assert_eq!(diags.len(), 1);
+        assert_eq!(
+            diags[0].message,
+            "Skill 'draft-commit' compiled as an instruction file on VS Code: no native skill support; ignores allowed-tools"
+        );
+    }
+
     #[test]
     fn validate_skill_allowed_tools_warns_on_dangerous_tool() {
         let output = OutputFile::new(
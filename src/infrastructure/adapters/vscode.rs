@@ -3,6 +3,14 @@
 //! Generates output for VS Code with GitHub Copilot:
 //! - `.github/instructions/<id>.instructions.md` - Instruction files (project scope)
 //! - `~/.vscode/instructions/<id>.instructions.md` - Instruction files (user scope)
+//! - `.github/copilot-instructions.md` - Always-active project policies, in the `vscode`
+//!   managed region (with `[targets.vscode] policies = "merged"`)
+//! - `.github/prompts/<id>.prompt.md` - Prompt files from actions (`~/.vscode/prompts/` for user scope)
+//! - `.github/agents/<id>.agent.md` - Custom agents (`~/.vscode/agents/` for user scope)
+//! - `.github/skills/<id>/` - Supplementals of skills compiled as instruction files
+//!   (with `[targets.vscode] skills = "fallback"`; `~/.vscode/skills/` for user scope)
+//! - `.vscode/mcp.json` - MCP servers (project scope only)
+//! - `AGENTS.md` - Index of all assets, in the `vscode` managed region (project scope only)
 //!
 //! Path matrix (from platform.md):
 //! - Project scope: `.github/instructions/`
@@ -10,18 +18,66 @@
 
 use std::path::PathBuf;
 
-use crate::domain::entities::{Asset, AssetKind, OutputFile};
+use super::activation::activation_downgrade;
+use super::agents::agent_downgrade;
+use super::frontmatter::FrontmatterBuilder;
+use super::mcp;
+use super::skills;
+use super::{asset_sections, managed_region_output};
+use crate::config::{SkillFallbackMode, VSCodePolicyMode};
+use crate::domain::entities::{Asset, AssetKind, BinaryOutputFile, McpServer, OutputFile};
 use crate::domain::ports::target_adapter::{
     AdapterDiagnostic, AdapterError, DiagnosticSeverity, TargetAdapter,
 };
-use crate::domain::value_objects::{Scope, Target};
+use crate::domain::ports::tool_probe::{DetectedTool, ToolProbe};
+use crate::domain::value_objects::{Activation, Scope, Target, ToolVersion};
 
 /// VS Code adapter
-pub struct VSCodeAdapter;
+pub struct VSCodeAdapter {
+    policy_mode: VSCodePolicyMode,
+    skill_mode: SkillFallbackMode,
+}
 
 impl VSCodeAdapter {
     pub fn new() -> Self {
-        Self
+        Self {
+            policy_mode: VSCodePolicyMode::default(),
+            skill_mode: SkillFallbackMode::default(),
+        }
+    }
+
+    /// Builder: choose where un-scoped project policies go (`[targets.vscode] policies`)
+    pub fn with_policy_mode(mut self, mode: VSCodePolicyMode) -> Self {
+        self.policy_mode = mode;
+        self
+    }
+
+    /// Builder: compile skills as instruction files (`[targets.vscode] skills`)
+    pub fn with_skill_mode(mut self, mode: SkillFallbackMode) -> Self {
+        self.skill_mode = mode;
+        self
+    }
+
+    /// Create an adapter from the `[targets.vscode]` config table
+    pub fn from_config(config: &crate::config::Config) -> Self {
+        Self::new()
+            .with_policy_mode(config.targets.vscode.policies)
+            .with_skill_mode(config.targets.vscode.skills)
+    }
+
+    fn is_skill_fallback(&self, asset: &Asset) -> bool {
+        self.skill_mode == SkillFallbackMode::Fallback && asset.kind() == AssetKind::Skill
+    }
+
+    /// Whether a policy is aggregated into `copilot-instructions.md`
+    ///
+    /// Only always-active project policies: Copilot applies that file to every
+    /// request, and it has no user-scope counterpart.
+    fn is_merged(&self, asset: &Asset) -> bool {
+        self.policy_mode == VSCodePolicyMode::Merged
+            && asset.kind() == AssetKind::Policy
+            && asset.scope() == Scope::Project
+            && asset.effective_activation() == Activation::Always
     }
 
     /// Get the instructions directory based on scope
@@ -32,20 +88,132 @@ impl VSCodeAdapter {
         }
     }
 
-    /// Generate applyTo frontmatter for instruction files
-    fn generate_instruction_frontmatter(&self, asset: &Asset) -> String {
-        let mut fm = String::from("---\n");
+    /// Get the prompt files directory based on scope
+    fn prompts_dir(&self, scope: Scope) -> PathBuf {
+        match scope {
+            Scope::User => PathBuf::from("~/.vscode/prompts"),
+            Scope::Project => PathBuf::from(".github/prompts"),
+        }
+    }
 
-        fm.push_str(&format!("description: {}\n", asset.description()));
+    /// Directory that receives a fallback skill's supplementals, per skill id
+    fn skill_files_dir(&self, scope: Scope) -> PathBuf {
+        match scope {
+            Scope::User => PathBuf::from("~/.vscode/skills"),
+            Scope::Project => PathBuf::from(".github/skills"),
+        }
+    }
 
-        if let Some(apply) = asset.apply() {
-            fm.push_str(&format!("applyTo: \"{}\"\n", apply));
+    /// Get the custom agents directory based on scope
+    fn agents_dir(&self, scope: Scope) -> PathBuf {
+        match scope {
+            Scope::User => PathBuf::from("~/.vscode/agents"),
+            Scope::Project => PathBuf::from(".github/agents"),
+        }
+    }
+
+    /// Compile an agent into a Copilot custom agent (`<id>.agent.md`)
+    ///
+    /// `tools` and `model` name Copilot tools and models, so they are usually set through
+    /// `overrides: { vscode: { ... } }`. `permissionMode` and `skills` have no Copilot
+    /// equivalent and are dropped (see downgrades()).
+    fn compile_agent(&self, asset: &Asset) -> OutputFile {
+        let tools = asset.agent_tools();
+        let fm = FrontmatterBuilder::new()
+            .field_opt("name", asset.agent_name())
+            .field("description", asset.description())
+            .field_opt("tools", (!tools.is_empty()).then(|| tools.to_vec()))
+            .field_opt("model", asset.agent_model())
+            .extra(asset.extra_frontmatter())
+            .render();
+
+        let path = self
+            .agents_dir(asset.scope())
+            .join(format!("{}.agent.md", asset.id()));
+        let footer = self.footer(&asset.source_path_normalized());
+        let content = format!("{}\n{}\n\n{}", fm, asset.content().trim(), footer);
+        OutputFile::new(path, content, self.target())
+    }
+
+    /// Compile an action into a Copilot prompt file (`<id>.prompt.md`)
+    ///
+    /// Prompts run in agent mode; `tools` lists Copilot tool names, so it is usually
+    /// set through `overrides: { vscode: { tools: [...] } }`.
+    fn compile_prompt(&self, asset: &Asset) -> OutputFile {
+        let tools = asset.agent_tools();
+        let fm = FrontmatterBuilder::new()
+            .field("description", asset.description())
+            .field("mode", "agent")
+            .field_opt("tools", (!tools.is_empty()).then(|| tools.to_vec()))
+            .extra(asset.extra_frontmatter())
+            .render();
+
+        let path = self
+            .prompts_dir(asset.scope())
+            .join(format!("{}.prompt.md", asset.id()));
+        let footer = self.footer(&asset.source_path_normalized());
+        let content = format!("{}\n{}\n\n{}", fm, asset.content().trim(), footer);
+        OutputFile::new(path, content, self.target())
+    }
+
+    /// Compile a skill into a manually attached instruction file
+    ///
+    /// Copilot has no `SKILL.md` support: the instructions list the skill's files,
+    /// which are copied to `.github/skills/<id>/`.
+    fn compile_skill(&self, asset: &Asset) -> Result<Vec<OutputFile>, AdapterError> {
+        let files_dir = self.skill_files_dir(asset.scope()).join(asset.id());
+        let fm = FrontmatterBuilder::new()
+            .field("description", asset.description())
+            .extra(asset.extra_frontmatter())
+            .render();
+
+        let path = self
+            .instructions_dir(asset.scope())
+            .join(format!("{}.instructions.md", asset.id()));
+        let footer = self.footer(&asset.source_path_normalized());
+        let body = skills::skill_fallback_body(asset, &files_dir);
+        let content = format!("{}\n{}\n\n{}", fm, body, footer);
+
+        let mut outputs = vec![OutputFile::new(path, content, self.target())];
+        outputs.extend(
+            skills::compile_supplementals(asset, &files_dir, self.target(), "skill")?.outputs,
+        );
+        Ok(outputs)
+    }
+
+    /// Render the merged policies into the `vscode` region of `copilot-instructions.md`
+    fn compile_copilot_instructions(&self, assets: &[Asset]) -> Option<OutputFile> {
+        let policies: Vec<&Asset> = assets
+            .iter()
+            .filter(|a| self.is_merged(a) && a.effective_targets().contains(&self.target()))
+            .collect();
+        if policies.is_empty() {
+            return None;
         }
 
-        fm.push_str(&super::format_extra_frontmatter(asset.extra_frontmatter()));
+        Some(managed_region_output(
+            ".github/copilot-instructions.md",
+            &asset_sections(&policies),
+            self.target(),
+        ))
+    }
 
-        fm.push_str("---\n");
-        fm
+    /// Generate applyTo frontmatter for instruction files
+    ///
+    /// Copilot only attaches instruction files automatically through `applyTo`:
+    /// always-active policies match `**`, and files without it are manual-only.
+    fn generate_instruction_frontmatter(&self, asset: &Asset) -> String {
+        let apply_to = match asset.effective_activation() {
+            Activation::Always => Some("**"),
+            Activation::Glob => asset.apply(),
+            Activation::ModelDecision | Activation::Manual => None,
+        };
+
+        FrontmatterBuilder::new()
+            .field("description", asset.description())
+            .field_opt("applyTo", apply_to)
+            .extra(asset.extra_frontmatter())
+            .render()
     }
 }
 
@@ -60,15 +228,46 @@ impl TargetAdapter for VSCodeAdapter {
         Target::VSCode
     }
 
+    /// v2: `AGENTS.md` is written as a managed region instead of a whole file
+    /// v3: agents are written to `.github/agents/<id>.agent.md` instead of instructions
+    /// v4: actions are written to `.github/prompts/<id>.prompt.md` instead of instructions
+    /// v5: always-active policies get `applyTo: '**'` (from `activation`)
+    fn output_format_version(&self) -> u32 {
+        5
+    }
+
+    fn min_ide_version(&self) -> Option<ToolVersion> {
+        Some(ToolVersion::new(1, 99, 0))
+    }
+
+    fn max_tested_version(&self) -> Option<ToolVersion> {
+        Some(ToolVersion::new(1, 105, 0))
+    }
+
+    fn detect_version(&self, probe: &dyn ToolProbe) -> Option<DetectedTool> {
+        DetectedTool::probe(probe, "code", ".vscode")
+    }
+
     fn compile(&self, asset: &Asset) -> Result<Vec<OutputFile>, AdapterError> {
-        // Skills are not supported on VS Code / Copilot.
-        if asset.kind() == AssetKind::Skill {
+        if self.is_skill_fallback(asset) {
+            return self.compile_skill(asset);
+        }
+
+        // Skills (without the fallback) and hooks are not supported on VS Code / Copilot.
+        if matches!(asset.kind(), AssetKind::Skill | AssetKind::Hook) {
             return Ok(Vec::new());
         }
 
+        match asset.kind() {
+            AssetKind::Agent => return Ok(vec![self.compile_agent(asset)]),
+            AssetKind::Action => return Ok(vec![self.compile_prompt(asset)]),
+            _ if self.is_merged(asset) => return Ok(Vec::new()), // Aggregated in post_compile()
+            _ => {}
+        }
+
         let mut outputs = Vec::new();
 
-        // All assets generate individual .instructions.md files
+        // Policies generate individual .instructions.md files
         let instructions_dir = self.instructions_dir(asset.scope());
         let path = instructions_dir.join(format!("{}.instructions.md", asset.id()));
 
@@ -81,6 +280,30 @@ impl TargetAdapter for VSCodeAdapter {
         Ok(outputs)
     }
 
+    fn downgrades(&self, asset: &Asset) -> Vec<AdapterDiagnostic> {
+        let mut diagnostics = agent_downgrade(
+            asset,
+            self.target(),
+            Some("a custom agent"),
+            &["tools", "model"],
+        );
+        // Copilot has no description-based attachment for instruction files.
+        diagnostics.extend(activation_downgrade(
+            asset,
+            self.target(),
+            &[Activation::Always, Activation::Glob, Activation::Manual],
+            Activation::Manual,
+        ));
+        if self.is_skill_fallback(asset) {
+            diagnostics.extend(skills::skill_downgrade(
+                asset,
+                self.target(),
+                "an instruction file",
+            ));
+        }
+        diagnostics
+    }
+
     fn validate(&self, output: &OutputFile) -> Vec<AdapterDiagnostic> {
         let mut diagnostics = Vec::new();
 
@@ -110,17 +333,57 @@ impl TargetAdapter for VSCodeAdapter {
         Ok(Vec::new())
     }
 
+    fn compile_mcp(
+        &self,
+        servers: &[McpServer],
+        scope: Scope,
+    ) -> Result<Vec<OutputFile>, AdapterError> {
+        // User-level MCP servers live in the VS Code profile, not in a dotfile.
+        if servers.is_empty() || scope == Scope::User {
+            return Ok(Vec::new());
+        }
+
+        let content = mcp::render_servers_json("servers", servers, |server| {
+            let mut entry = mcp::stdio_server(server);
+            if let Some(map) = entry.as_object_mut() {
+                map.insert("type".to_string(), serde_json::json!("stdio"));
+            }
+            entry
+        })?;
+        Ok(vec![OutputFile::new(
+            ".vscode/mcp.json",
+            content,
+            Target::VSCode,
+        )
+        .with_merge(mcp::merge_spec("servers", servers))])
+    }
+
+    fn compile_binary(&self, asset: &Asset) -> Result<Vec<BinaryOutputFile>, AdapterError> {
+        if !self.is_skill_fallback(asset) {
+            return Ok(Vec::new());
+        }
+
+        let files_dir = self.skill_files_dir(asset.scope()).join(asset.id());
+        Ok(
+            skills::compile_supplementals(asset, &files_dir, self.target(), "skill")?
+                .binary_outputs,
+        )
+    }
+
     fn post_compile(&self, assets: &[Asset]) -> Result<Vec<OutputFile>, AdapterError> {
         use crate::domain::value_objects::Scope;
 
-        let mut outputs = Vec::new();
+        let mut outputs: Vec<OutputFile> = self
+            .compile_copilot_instructions(assets)
+            .into_iter()
+            .collect();
 
         // Only generate AGENTS.md for project-scope deployments
         // AGENTS.md is a project-level index file that should be in the project root
         // For home scope deployments, we skip it to avoid creating ~/AGENTS.md
-        let has_project_scope_assets = assets
-            .iter()
-            .any(|a| a.scope() == Scope::Project && a.kind() != AssetKind::Skill);
+        let has_project_scope_assets = assets.iter().any(|a| {
+            a.scope() == Scope::Project && !matches!(a.kind(), AssetKind::Skill | AssetKind::Hook)
+        });
 
         if has_project_scope_assets {
             let project_assets: Vec<_> = assets
@@ -129,18 +392,20 @@ impl TargetAdapter for VSCodeAdapter {
                 .cloned()
                 .collect();
             let agents_content = generate_agents_md(&project_assets);
-            outputs.push(OutputFile::new("AGENTS.md", agents_content, self.target()));
+            outputs.push(managed_region_output(
+                "AGENTS.md",
+                &agents_content,
+                self.target(),
+            ));
         }
 
         Ok(outputs)
     }
 }
 
-/// Generate AGENTS.md summary index from all assets
+/// Generate the AGENTS.md index of all assets (the body of the `vscode` region)
 fn generate_agents_md(assets: &[Asset]) -> String {
-    let mut content = String::from("# AGENTS.md\n\n");
-    content.push_str("<!-- Generated by Calvin. DO NOT EDIT. -->\n\n");
-    content.push_str("This file provides an index of available AI agents and workflows.\n\n");
+    let mut content = String::from("Index of available AI agents and workflows.\n\n");
 
     // Group by kind
     let policies: Vec<_> = assets
@@ -242,7 +507,7 @@ mod tests {
     }
 
     #[test]
-    fn compile_action_generates_instruction() {
+    fn compile_action_generates_prompt_file() {
         let adapter = VSCodeAdapter::new();
         let asset = create_action_asset("gen-tests", "Generate tests", "# Generate");
 
@@ -251,7 +516,89 @@ mod tests {
         assert_eq!(outputs.len(), 1);
         assert_eq!(
             outputs[0].path(),
-            &PathBuf::from(".github/instructions/gen-tests.instructions.md")
+            &PathBuf::from(".github/prompts/gen-tests.prompt.md")
+        );
+        assert!(outputs[0]
+            .content()
+            .starts_with("---\ndescription: Generate tests\nmode: agent\n---\n\n# Generate"));
+    }
+
+    #[test]
+    fn compile_action_user_scope_lists_tools() {
+        let adapter = VSCodeAdapter::new();
+        let asset = create_action_asset("gen-tests", "Generate tests", "# Generate")
+            .with_scope(Scope::User)
+            .with_agent_tools(vec!["codebase".to_string(), "runTests".to_string()]);
+
+        let outputs = adapter.compile(&asset).unwrap();
+
+        assert_eq!(
+            outputs[0].path(),
+            &PathBuf::from("~/.vscode/prompts/gen-tests.prompt.md")
+        );
+        assert!(outputs[0]
+            .content()
+            .contains("mode: agent\ntools:\n- codebase\n- runTests\n---"));
+    }
+
+    #[test]
+    fn merged_mode_aggregates_unscoped_project_policies() {
+        let adapter = VSCodeAdapter::new().with_policy_mode(VSCodePolicyMode::Merged);
+        let assets = vec![
+            create_policy_asset("security", "Security", "Never commit secrets."),
+            create_policy_asset("rust", "Rust", "Run clippy.").with_apply("**/*.rs"),
+            create_policy_asset("style", "Style", "Use rustfmt.").with_scope(Scope::User),
+        ];
+
+        assert!(adapter.compile(&assets[0]).unwrap().is_empty());
+        assert_eq!(
+            adapter.compile(&assets[1]).unwrap()[0].path(),
+            &PathBuf::from(".github/instructions/rust.instructions.md")
+        );
+        assert_eq!(
+            adapter.compile(&assets[2]).unwrap()[0].path(),
+            &PathBuf::from("~/.vscode/instructions/style.instructions.md")
+        );
+
+        let outputs = adapter.post_compile(&assets).unwrap();
+        let merged = outputs
+            .iter()
+            .find(|o| o.path() == &PathBuf::from(".github/copilot-instructions.md"))
+            .expect("copilot-instructions.md should be generated");
+        assert!(merged.content().starts_with("<!-- calvin:begin vscode -->"));
+        assert!(merged.content().contains("Never commit secrets."));
+        assert!(!merged.content().contains("Run clippy."));
+        assert!(!merged.content().contains("Use rustfmt."));
+        assert_eq!(merged.merge_spec().unwrap().owned_keys(), ["vscode"]);
+    }
+
+    #[test]
+    fn instructions_mode_writes_no_copilot_instructions() {
+        let adapter = VSCodeAdapter::new();
+        let assets = vec![create_policy_asset("security", "Security", "x")];
+
+        let outputs = adapter.post_compile(&assets).unwrap();
+
+        assert!(outputs
+            .iter()
+            .all(|o| o.path() != &PathBuf::from(".github/copilot-instructions.md")));
+    }
+
+    #[test]
+    fn from_config_reads_policy_mode() {
+        let config: crate::config::Config =
+            toml::from_str("[targets.vscode]\npolicies = \"merged\"\n").unwrap();
+
+        let adapter = VSCodeAdapter::from_config(&config);
+
+        assert_eq!(adapter.policy_mode, VSCodePolicyMode::Merged);
+        assert_eq!(adapter.skill_mode, SkillFallbackMode::Skip);
+
+        let config: crate::config::Config =
+            toml::from_str("[targets.vscode]\nskills = \"fallback\"\n").unwrap();
+        assert_eq!(
+            VSCodeAdapter::from_config(&config).skill_mode,
+            SkillFallbackMode::Fallback
         );
     }
 
@@ -264,6 +611,47 @@ mod tests {
         assert!(outputs.is_empty());
     }
 
+    #[test]
+    fn skill_fallback_compiles_instruction_and_files() {
+        let adapter = VSCodeAdapter::new().with_skill_mode(SkillFallbackMode::Fallback);
+        let mut supplementals = HashMap::new();
+        supplementals.insert(PathBuf::from("reference.md"), "# Ref".to_string());
+        let mut binaries = HashMap::new();
+        binaries.insert(PathBuf::from("logo.png"), vec![0x89, 0x50]);
+        let asset = create_skill_asset("my-skill", "My skill", "# Instructions")
+            .with_supplementals(supplementals)
+            .with_binary_supplementals(binaries);
+
+        let outputs = adapter.compile(&asset).unwrap();
+
+        assert_eq!(outputs.len(), 2);
+        assert_eq!(
+            outputs[0].path(),
+            &PathBuf::from(".github/instructions/my-skill.instructions.md")
+        );
+        let content = outputs[0].content();
+        assert!(content.starts_with("---\ndescription: My skill\n---\n\n# Instructions"));
+        assert!(content.contains("- `.github/skills/my-skill/reference.md`"));
+        assert!(!content.contains("applyTo"));
+        assert_eq!(
+            outputs[1].path(),
+            &PathBuf::from(".github/skills/my-skill/reference.md")
+        );
+
+        let binary = adapter.compile_binary(&asset).unwrap();
+        assert_eq!(
+            binary[0].path(),
+            &PathBuf::from(".github/skills/my-skill/logo.png")
+        );
+
+        let diags = adapter.downgrades(&asset);
+        assert_eq!(diags.len(), 1);
+        assert!(diags[0]
+            .message
+            .contains("compiled as an instruction file on VS Code"));
+        assert!(VSCodeAdapter::new().downgrades(&asset).is_empty());
+    }
+
     #[test]
     fn compile_with_apply_includes_applyto() {
         let adapter = VSCodeAdapter::new();
@@ -271,7 +659,7 @@ mod tests {
 
         let outputs = adapter.compile(&asset).unwrap();
 
-        assert!(outputs[0].content().contains("applyTo: \"**/*.rs\""));
+        assert!(outputs[0].content().contains("applyTo: '**/*.rs'"));
     }
 
     #[test]
@@ -367,9 +755,9 @@ mod tests {
     }
 
     #[test]
-    fn adapter_version_is_one() {
+    fn adapter_output_format_version_is_five() {
         let adapter = VSCodeAdapter::new();
-        assert_eq!(adapter.version(), 1);
+        assert_eq!(adapter.output_format_version(), 5);
     }
 
     // === TDD: Frontmatter ===
@@ -393,7 +781,41 @@ mod tests {
 
         let fm = adapter.generate_instruction_frontmatter(&asset);
 
-        assert!(fm.contains("applyTo: \"*.ts\""));
+        assert!(fm.contains("applyTo: '*.ts'"));
+    }
+
+    #[test]
+    fn frontmatter_maps_activation_to_apply_to() {
+        let adapter = VSCodeAdapter::new();
+        let policy = |activation| {
+            create_policy_asset("test", "desc", "")
+                .with_apply("*.ts")
+                .with_activation(Some(activation))
+        };
+
+        assert_eq!(
+            adapter.generate_instruction_frontmatter(&create_policy_asset("test", "desc", "")),
+            "---\ndescription: desc\napplyTo: '**'\n---\n"
+        );
+        assert_eq!(
+            adapter.generate_instruction_frontmatter(&policy(Activation::Manual)),
+            "---\ndescription: desc\n---\n"
+        );
+        assert!(adapter.downgrades(&policy(Activation::Manual)).is_empty());
+    }
+
+    #[test]
+    fn model_decision_policy_is_downgraded_to_manual() {
+        let adapter = VSCodeAdapter::new();
+        let asset = create_policy_asset("test", "desc", "")
+            .with_activation(Some(Activation::ModelDecision));
+
+        let fm = adapter.generate_instruction_frontmatter(&asset);
+        let diags = adapter.downgrades(&asset);
+
+        assert!(!fm.contains("applyTo"));
+        assert_eq!(diags.len(), 1);
+        assert!(diags[0].message.contains("applied as 'manual'"));
     }
 
     // === TDD: Agent Tests ===
@@ -426,22 +848,51 @@ mod tests {
     }
 
     #[test]
-    fn compile_agent_generates_instruction_file() {
+    fn compile_agent_generates_custom_agent_file() {
         let adapter = VSCodeAdapter::new();
         let asset = create_agent_asset("reviewer", "Code review agent", "# Review code");
 
         let outputs = adapter.compile(&asset).unwrap();
 
-        // Assert output path is `.github/instructions/<id>.instructions.md`
         assert_eq!(outputs.len(), 1);
         assert_eq!(
             outputs[0].path(),
-            &PathBuf::from(".github/instructions/reviewer.instructions.md")
+            &PathBuf::from(".github/agents/reviewer.agent.md")
+        );
+        assert!(outputs[0]
+            .content()
+            .starts_with("---\ndescription: Code review agent\n---\n\n# Review code"));
+        assert!(adapter.downgrades(&asset).is_empty());
+    }
+
+    #[test]
+    fn compile_agent_user_scope_and_dropped_fields() {
+        let adapter = VSCodeAdapter::new();
+        let asset = create_agent_asset("reviewer", "Code review agent", "# Review code")
+            .with_scope(Scope::User)
+            .with_agent_tools(vec!["codebase".to_string(), "search".to_string()])
+            .with_agent_model(Some("GPT-4.1".to_string()))
+            .with_agent_permission_mode(Some("plan".to_string()));
+
+        let outputs = adapter.compile(&asset).unwrap();
+
+        assert_eq!(
+            outputs[0].path(),
+            &PathBuf::from("~/.vscode/agents/reviewer.agent.md")
+        );
+        let content = outputs[0].content();
+        assert!(
+            content.contains("tools:\n- codebase\n- search\n"),
+            "{}",
+            content
         );
-        // Assert content includes agent description
+        assert!(content.contains("model: GPT-4.1\n"), "{}", content);
+        let diags = adapter.downgrades(&asset);
+        assert_eq!(diags.len(), 1);
         assert!(
-            outputs[0].content().contains("Code review agent"),
-            "Output should contain the agent description"
+            diags[0].message.ends_with("ignores permissionMode"),
+            "{}",
+            diags[0].message
         );
     }
 
@@ -477,4 +928,23 @@ mod tests {
             "AGENTS.md should contain the agent description"
         );
     }
+
+    // === TDD: MCP ===
+
+    #[test]
+    fn compile_mcp_generates_vscode_servers_block() {
+        let adapter = VSCodeAdapter::new();
+        let servers = vec![McpServer::new("github", "npx")];
+
+        let outputs = adapter.compile_mcp(&servers, Scope::Project).unwrap();
+
+        assert_eq!(outputs[0].path(), &PathBuf::from(".vscode/mcp.json"));
+        let value: serde_json::Value = serde_json::from_str(outputs[0].content()).unwrap();
+        assert_eq!(value["servers"]["github"]["type"], "stdio");
+        assert_eq!(value["servers"]["github"]["command"], "npx");
+        assert!(adapter
+            .compile_mcp(&servers, Scope::User)
+            .unwrap()
+            .is_empty());
+    }
 }
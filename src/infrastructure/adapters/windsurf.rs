@@ -0,0 +1,408 @@
+//! Windsurf Adapter
+//!
+//! Generates output for Windsurf (Cascade):
+//! - `.windsurf/rules/<id>.md` - Rules (Policies, and Agents as `model_decision` rules)
+//! - `.windsurf/workflows/<id>.md` - Workflows (Actions)
+//! - `~/.codeium/windsurf/memories/global_rules.md` - User policies, aggregated into the
+//!   `windsurf` managed region
+//! - `~/.codeium/windsurf/global_workflows/<id>.md` - User workflows (Actions/Agents)
+//!
+//! Rule triggers:
+//! - policy → `trigger` from `activation` (`always_on`, `glob` with `globs` from `apply`,
+//!   `model_decision` or `manual`); without `activation`, `glob` if `apply` is set and
+//!   `always_on` otherwise
+//! - agent → `trigger: model_decision` (Cascade reads the description to decide)
+//!
+//! `global_rules.md` is always on, so user-scope policies with another `activation`
+//! are reported as downgrades.
+//!
+//! Windsurf truncates rule files beyond 6,000 characters and workflows beyond 12,000;
+//! `validate` warns before that happens.
+
+use std::path::{Path, PathBuf};
+
+use super::activation::{self, activation_downgrade};
+use super::agents::agent_downgrade;
+use super::frontmatter::FrontmatterBuilder;
+use super::policies_region_output;
+use crate::domain::entities::{Asset, AssetKind, OutputFile};
+use crate::domain::ports::target_adapter::{
+    AdapterDiagnostic, AdapterError, DiagnosticSeverity, TargetAdapter,
+};
+use crate::domain::ports::tool_probe::{DetectedTool, ToolProbe};
+use crate::domain::value_objects::{Activation, Scope, Target};
+
+/// Maximum characters Windsurf reads from a rule file (including `global_rules.md`)
+pub const RULE_CHAR_LIMIT: usize = 6_000;
+
+/// Maximum characters Windsurf reads from a workflow file
+const WORKFLOW_CHAR_LIMIT: usize = 12_000;
+
+const GLOBAL_RULES_PATH: &str = "~/.codeium/windsurf/memories/global_rules.md";
+
+/// Windsurf adapter
+pub struct WindsurfAdapter;
+
+impl WindsurfAdapter {
+    pub fn new() -> Self {
+        Self
+    }
+
+    fn workflows_dir(&self, scope: Scope) -> PathBuf {
+        match scope {
+            Scope::User => PathBuf::from("~/.codeium/windsurf/global_workflows"),
+            Scope::Project => PathBuf::from(".windsurf/workflows"),
+        }
+    }
+
+    /// Rule frontmatter: `trigger` derived from the asset kind and policy activation
+    fn rule_frontmatter(&self, asset: &Asset) -> String {
+        let fm = match asset.kind() {
+            AssetKind::Policy => FrontmatterBuilder::new()
+                .field("trigger", activation::trigger(asset.effective_activation()))
+                .field_opt("globs", asset.activation_globs()),
+            _ => FrontmatterBuilder::new().field("trigger", "model_decision"),
+        };
+        fm.field("description", asset.description())
+            .extra(asset.extra_frontmatter())
+            .render()
+    }
+
+    fn compile_rule(&self, asset: &Asset) -> Vec<OutputFile> {
+        let path = PathBuf::from(".windsurf/rules").join(format!("{}.md", asset.id()));
+        let content = format!(
+            "{}\n{}\n\n{}",
+            self.rule_frontmatter(asset),
+            asset.content().trim(),
+            self.footer(&asset.source_path_normalized())
+        );
+        vec![OutputFile::new(path, content, self.target())]
+    }
+
+    fn compile_workflow(&self, asset: &Asset) -> Vec<OutputFile> {
+        let path = self
+            .workflows_dir(asset.scope())
+            .join(format!("{}.md", asset.id()));
+        let fm = FrontmatterBuilder::new()
+            .field("description", asset.description())
+            .extra(asset.extra_frontmatter());
+        let content = format!(
+            "{}\n{}\n\n{}",
+            fm.render(),
+            asset.content().trim(),
+            self.footer(&asset.source_path_normalized())
+        );
+        vec![OutputFile::new(path, content, self.target())]
+    }
+
+    /// Character limit Windsurf applies to a generated file, if any
+    fn char_limit(path: &Path) -> Option<usize> {
+        let in_dir = |name: &str| {
+            path.parent()
+                .and_then(|p| p.file_name())
+                .is_some_and(|n| n == name)
+        };
+        if in_dir("workflows") || in_dir("global_workflows") {
+            Some(WORKFLOW_CHAR_LIMIT)
+        } else if in_dir("rules") || path == Path::new(GLOBAL_RULES_PATH) {
+            Some(RULE_CHAR_LIMIT)
+        } else {
+            None
+        }
+    }
+}
+
+impl Default for WindsurfAdapter {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl TargetAdapter for WindsurfAdapter {
+    fn target(&self) -> Target {
+        Target::Windsurf
+    }
+
+    fn detect_version(&self, probe: &dyn ToolProbe) -> Option<DetectedTool> {
+        DetectedTool::probe(probe, "windsurf", ".codeium/windsurf")
+    }
+
+    fn compile(&self, asset: &Asset) -> Result<Vec<OutputFile>, AdapterError> {
+        Ok(match (asset.kind(), asset.scope()) {
+            // User-scope policies share `global_rules.md` (see post_compile()).
+            (AssetKind::Policy, Scope::User) => Vec::new(),
+            (AssetKind::Policy, Scope::Project) => self.compile_rule(asset),
+            // Windsurf has no global model-decision rules: user agents become workflows.
+            (AssetKind::Agent, Scope::Project) => self.compile_rule(asset),
+            (AssetKind::Agent, Scope::User) | (AssetKind::Action, _) => {
+                self.compile_workflow(asset)
+            }
+            (AssetKind::Skill | AssetKind::Hook, _) => Vec::new(), // Not supported on Windsurf
+        })
+    }
+
+    fn downgrades(&self, asset: &Asset) -> Vec<AdapterDiagnostic> {
+        let compiled_as = match asset.scope() {
+            Scope::Project => "a model-decision rule",
+            Scope::User => "a workflow",
+        };
+        let mut diagnostics = agent_downgrade(asset, self.target(), Some(compiled_as), &[]);
+        if asset.scope() == Scope::User {
+            diagnostics.extend(activation_downgrade(
+                asset,
+                self.target(),
+                &[Activation::Always],
+                Activation::Always,
+            ));
+        }
+        diagnostics
+    }
+
+    fn validate(&self, output: &OutputFile) -> Vec<AdapterDiagnostic> {
+        let mut diagnostics = Vec::new();
+
+        if output.content().trim().is_empty() {
+            diagnostics.push(AdapterDiagnostic {
+                severity: DiagnosticSeverity::Warning,
+                message: "Generated output is empty".to_string(),
+            });
+        }
+
+        if let Some(limit) = Self::char_limit(output.path()) {
+            let chars = output.content().chars().count();
+            if chars > limit {
+                diagnostics.push(AdapterDiagnostic {
+                    severity: DiagnosticSeverity::Warning,
+                    message: format!(
+                        "{} characters exceeds Windsurf's {} character limit; the rest will be ignored",
+                        chars, limit
+                    ),
+                });
+            }
+        }
+
+        diagnostics
+    }
+
+    fn post_compile(&self, assets: &[Asset]) -> Result<Vec<OutputFile>, AdapterError> {
+        Ok(
+            policies_region_output(GLOBAL_RULES_PATH, assets, Scope::User, self.target())
+                .into_iter()
+                .collect(),
+        )
+    }
+
+    fn security_baseline(
+        &self,
+        _config: &crate::config::Config,
+    ) -> Result<Vec<OutputFile>, AdapterError> {
+        // Windsurf auto-execution settings are per user, not per project
+        Ok(Vec::new())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn create_policy_asset(id: &str, description: &str, content: &str) -> Asset {
+        Asset::new(id, format!("policies/{}.md", id), description, content)
+            .with_kind(AssetKind::Policy)
+    }
+
+    fn create_action_asset(id: &str, description: &str, content: &str) -> Asset {
+        Asset::new(id, format!("actions/{}.md", id), description, content)
+            .with_kind(AssetKind::Action)
+    }
+
+    fn create_agent_asset(id: &str, description: &str, content: &str) -> Asset {
+        Asset::new(id, format!("agents/{}.md", id), description, content)
+            .with_kind(AssetKind::Agent)
+    }
+
+    // === TDD: Compile Tests ===
+
+    #[test]
+    fn compile_policy_without_apply_is_always_on() {
+        let adapter = WindsurfAdapter::new();
+        let asset = create_policy_asset("style", "Code style", "Use rustfmt.");
+
+        let outputs = adapter.compile(&asset).unwrap();
+
+        assert_eq!(outputs.len(), 1);
+        assert_eq!(
+            outputs[0].path(),
+            &PathBuf::from(".windsurf/rules/style.md")
+        );
+        assert!(outputs[0]
+            .content()
+            .starts_with("---\ntrigger: always_on\ndescription: Code style\n---\n"));
+        assert!(outputs[0].content().contains("Generated by Calvin"));
+    }
+
+    #[test]
+    fn compile_policy_with_apply_is_glob() {
+        let adapter = WindsurfAdapter::new();
+        let asset = create_policy_asset("rust", "Rust", "Run clippy.").with_apply("**/*.rs");
+
+        let outputs = adapter.compile(&asset).unwrap();
+
+        assert!(outputs[0]
+            .content()
+            .starts_with("---\ntrigger: glob\nglobs: '**/*.rs'\n"));
+    }
+
+    #[test]
+    fn compile_policy_activation_sets_trigger() {
+        let adapter = WindsurfAdapter::new();
+        let policy = |activation| {
+            create_policy_asset("rust", "Rust", "Run clippy.")
+                .with_apply("**/*.rs")
+                .with_activation(Some(activation))
+        };
+
+        let manual = adapter.compile(&policy(Activation::Manual)).unwrap();
+        assert!(manual[0]
+            .content()
+            .starts_with("---\ntrigger: manual\ndescription: Rust\n---\n"));
+
+        let model = adapter.compile(&policy(Activation::ModelDecision)).unwrap();
+        assert!(model[0]
+            .content()
+            .starts_with("---\ntrigger: model_decision\ndescription: Rust\n---\n"));
+        assert!(adapter.downgrades(&policy(Activation::Manual)).is_empty());
+    }
+
+    #[test]
+    fn user_policy_activation_is_downgraded_to_always_on() {
+        let adapter = WindsurfAdapter::new();
+        let asset = create_policy_asset("global", "Global", "Be concise.")
+            .with_scope(Scope::User)
+            .with_activation(Some(Activation::Manual));
+
+        let diags = adapter.downgrades(&asset);
+
+        assert_eq!(diags.len(), 1);
+        assert!(diags[0].message.contains("applied as 'always'"));
+    }
+
+    #[test]
+    fn compile_agent_is_model_decision_rule() {
+        let adapter = WindsurfAdapter::new();
+        let asset = create_agent_asset("reviewer", "Review pull requests", "You review.");
+
+        let outputs = adapter.compile(&asset).unwrap();
+
+        assert_eq!(
+            outputs[0].path(),
+            &PathBuf::from(".windsurf/rules/reviewer.md")
+        );
+        assert!(outputs[0]
+            .content()
+            .starts_with("---\ntrigger: model_decision\ndescription: Review pull requests\n"));
+    }
+
+    #[test]
+    fn compile_action_to_workflow() {
+        let adapter = WindsurfAdapter::new();
+        let asset = create_action_asset("deploy", "Deploy the app", "Run deploy.");
+
+        let outputs = adapter.compile(&asset).unwrap();
+
+        assert_eq!(
+            outputs[0].path(),
+            &PathBuf::from(".windsurf/workflows/deploy.md")
+        );
+        assert!(outputs[0]
+            .content()
+            .starts_with("---\ndescription: Deploy the app\n---\n\nRun deploy."));
+    }
+
+    #[test]
+    fn compile_user_scope_action_to_global_workflows() {
+        let adapter = WindsurfAdapter::new();
+        let asset = create_action_asset("deploy", "Deploy", "Run.").with_scope(Scope::User);
+
+        let outputs = adapter.compile(&asset).unwrap();
+
+        assert_eq!(
+            outputs[0].path(),
+            &PathBuf::from("~/.codeium/windsurf/global_workflows/deploy.md")
+        );
+    }
+
+    #[test]
+    fn user_policies_go_to_global_rules_region() {
+        let adapter = WindsurfAdapter::new();
+        let assets = vec![
+            create_policy_asset("global", "Global", "Be concise.").with_scope(Scope::User),
+            create_policy_asset("style", "Style", "Use rustfmt."),
+        ];
+
+        assert!(adapter.compile(&assets[0]).unwrap().is_empty());
+        let outputs = adapter.post_compile(&assets).unwrap();
+
+        assert_eq!(outputs.len(), 1);
+        assert_eq!(outputs[0].path(), &PathBuf::from(GLOBAL_RULES_PATH));
+        assert!(outputs[0]
+            .content()
+            .starts_with("<!-- calvin:begin windsurf -->"));
+        assert!(outputs[0].content().contains("Be concise."));
+        assert!(!outputs[0].content().contains("Use rustfmt."));
+    }
+
+    // === TDD: Validate Tests ===
+
+    #[test]
+    fn validate_rule_over_limit_warns() {
+        let adapter = WindsurfAdapter::new();
+        let output = OutputFile::new(
+            ".windsurf/rules/big.md",
+            "x".repeat(RULE_CHAR_LIMIT + 1),
+            Target::Windsurf,
+        );
+
+        let diags = adapter.validate(&output);
+
+        assert_eq!(diags.len(), 1);
+        assert!(diags[0].message.contains("6000 character limit"));
+    }
+
+    #[test]
+    fn validate_workflow_uses_larger_limit() {
+        let adapter = WindsurfAdapter::new();
+        let within = OutputFile::new(
+            ".windsurf/workflows/big.md",
+            "x".repeat(RULE_CHAR_LIMIT + 1),
+            Target::Windsurf,
+        );
+        let over = OutputFile::new(
+            "~/.codeium/windsurf/global_workflows/big.md",
+            "x".repeat(WORKFLOW_CHAR_LIMIT + 1),
+            Target::Windsurf,
+        );
+
+        assert!(adapter.validate(&within).is_empty());
+        assert_eq!(adapter.validate(&over).len(), 1);
+    }
+
+    #[test]
+    fn validate_global_rules_over_limit_warns() {
+        let adapter = WindsurfAdapter::new();
+        let output = OutputFile::new(
+            GLOBAL_RULES_PATH,
+            "x".repeat(RULE_CHAR_LIMIT + 1),
+            Target::Windsurf,
+        );
+
+        assert_eq!(adapter.validate(&output).len(), 1);
+    }
+
+    // === TDD: Trait Implementation ===
+
+    #[test]
+    fn adapter_target_is_windsurf() {
+        let adapter = WindsurfAdapter::new();
+        assert_eq!(adapter.target(), Target::Windsurf);
+    }
+}
@@ -3,30 +3,41 @@
 //! Implements the FileSystem port for remote operations via SSH.
 
 use crate::domain::ports::file_system::{FileSystem, FsError, FsResult};
+use crate::infrastructure::sync::{RemoteSession, SshOptions};
 use std::collections::HashMap;
 use std::path::{Path, PathBuf};
 use std::sync::Mutex;
 
 /// Remote file system implementation using SSH
 ///
-/// Provides file operations on remote hosts via SSH commands.
+/// Provides file operations on remote hosts over one shared SSH session.
 /// Caches the remote $HOME directory for efficiency.
 pub struct RemoteFs {
     /// SSH destination (user@host or host)
     destination: String,
+    /// Shell that runs every command
+    session: RemoteSession,
     /// Cached remote $HOME value
     cached_home: Mutex<Option<String>>,
 }
 
 impl RemoteFs {
-    /// Create a new RemoteFs for the given SSH destination
-    pub fn new(destination: impl Into<String>) -> Self {
+    /// Create a new RemoteFs for the given SSH destination and connection options
+    pub fn new(destination: impl Into<String>, ssh: &SshOptions) -> Self {
+        let destination = destination.into();
         Self {
-            destination: destination.into(),
+            session: RemoteSession::ssh(&destination, ssh),
+            destination,
             cached_home: Mutex::new(None),
         }
     }
 
+    /// Use another session (e.g. a local shell in tests)
+    pub fn with_session(mut self, session: RemoteSession) -> Self {
+        self.session = session;
+        self
+    }
+
     /// Get the SSH destination
     pub fn destination(&self) -> &str {
         &self.destination
@@ -43,8 +54,7 @@ impl RemoteFs {
         }
 
         // Fetch from remote via `echo $HOME`
-        if let Ok(home) = self.run_command("echo $HOME", None) {
-            let home = home.trim().to_string();
+        if let Ok(home) = self.run_command("echo $HOME") {
             if !home.is_empty() {
                 let mut cache = self.cached_home.lock().unwrap();
                 *cache = Some(home.clone());
@@ -55,42 +65,15 @@ impl RemoteFs {
         None
     }
 
-    /// Run a command on the remote host via SSH
-    fn run_command(&self, command: &str, input: Option<&str>) -> FsResult<String> {
-        self.run_command_bytes(command, input.map(|s| s.as_bytes()))
-    }
-
-    /// Run a command on the remote host via SSH with binary input
-    fn run_command_bytes(&self, command: &str, input: Option<&[u8]>) -> FsResult<String> {
-        use std::io::Write;
-        use std::process::{Command, Stdio};
-
-        let mut child = Command::new("ssh")
-            .arg(&self.destination)
-            .arg(command)
-            .stdin(if input.is_some() {
-                Stdio::piped()
-            } else {
-                Stdio::null()
-            })
-            .stdout(Stdio::piped())
-            .stderr(Stdio::piped())
-            .spawn()?;
-
-        if let Some(inp) = input {
-            if let Some(mut stdin) = child.stdin.take() {
-                stdin.write_all(inp)?;
-            }
-        }
-
-        let output = child.wait_with_output()?;
+    /// Run a command on the remote host over the session
+    fn run_command(&self, command: &str) -> FsResult<String> {
+        let output = self.session.run(command)?;
 
-        if !output.status.success() {
-            let stderr = String::from_utf8_lossy(&output.stderr);
-            return Err(FsError::Other(format!("SSH error: {}", stderr)));
+        if !output.success() {
+            return Err(FsError::Other(format!("SSH error: {}", output.text())));
         }
 
-        Ok(String::from_utf8_lossy(&output.stdout).trim().to_string())
+        Ok(output.text())
     }
 
     /// Quote a path for safe use in shell commands
@@ -98,7 +81,7 @@ impl RemoteFs {
         format!("'{}'", path.to_string_lossy().replace('\'', "'\\''"))
     }
 
-    /// Batch check multiple files for existence and SHA-256 hash in a single SSH call
+    /// Batch check multiple files for existence and SHA-256 hash in a single session command
     ///
     /// Returns a map of path -> (exists, hash_if_exists)
     /// Hash format: `sha256:<64 hex digits>` (same as lockfile format)
@@ -110,11 +93,11 @@ impl RemoteFs {
             return Ok(HashMap::new());
         }
 
-        // Build a script that checks all files in one SSH call
+        // Build a script that checks all files in one command
         // Output format: one line per file with either:
         //   0 (not exists)
         //   1 <sha256hash> (exists with hash)
-        let mut script = String::from("#!/bin/sh\n");
+        let mut script = String::new();
         for path in paths {
             let p = Self::quote_path(path);
             // Check existence, then compute hash if exists
@@ -125,7 +108,7 @@ impl RemoteFs {
             ));
         }
 
-        let output = self.run_command("sh", Some(&script))?;
+        let output = self.run_command(&script)?;
 
         let mut result = HashMap::new();
         let lines: Vec<&str> = output.lines().collect();
@@ -160,57 +143,40 @@ impl RemoteFs {
 
 impl FileSystem for RemoteFs {
     fn read(&self, path: &Path) -> FsResult<String> {
-        self.run_command(&format!("cat {}", Self::quote_path(path)), None)
+        self.run_command(&format!("cat {}", Self::quote_path(path)))
     }
 
     fn write(&self, path: &Path, content: &str) -> FsResult<()> {
-        let p = Self::quote_path(path);
-        let tmp = format!("{}.tmp", p);
-
-        // Ensure parent directory exists
-        if let Some(parent) = path.parent() {
-            self.create_dir_all(parent)?;
-        }
-
-        // Write to temp file then atomically rename
-        self.run_command(&format!("cat > {}", tmp), Some(content))?;
-        self.run_command(&format!("mv -f {} {}", tmp, p), None)?;
-        Ok(())
+        self.write_binary(path, content.as_bytes())
     }
 
     fn write_binary(&self, path: &Path, content: &[u8]) -> FsResult<()> {
-        let p = Self::quote_path(path);
-        let tmp = format!("{}.tmp", p);
+        // Creates the parent directory, writes a temp file and renames it into place
+        let output = self.session.write_file(&path.to_string_lossy(), content)?;
 
-        // Ensure parent directory exists
-        if let Some(parent) = path.parent() {
-            self.create_dir_all(parent)?;
+        if !output.success() {
+            return Err(FsError::Other(format!("SSH error: {}", output.text())));
         }
-
-        // Write binary content to temp file via stdin, then atomically rename
-        // SSH passes stdin through as binary, so this works for any content
-        self.run_command_bytes(&format!("cat > {}", tmp), Some(content))?;
-        self.run_command(&format!("mv -f {} {}", tmp, p), None)?;
         Ok(())
     }
 
     fn exists(&self, path: &Path) -> bool {
-        self.run_command(&format!("test -e {}", Self::quote_path(path)), None)
+        self.run_command(&format!("test -e {}", Self::quote_path(path)))
             .is_ok()
     }
 
     fn remove(&self, path: &Path) -> FsResult<()> {
         let p = Self::quote_path(path);
-        if self.run_command(&format!("rm -f {}", p), None).is_ok() {
+        if self.run_command(&format!("rm -f {}", p)).is_ok() {
             return Ok(());
         }
         // Best-effort: allow removing empty directories without introducing `rm -rf`.
-        self.run_command(&format!("rmdir {}", p), None)?;
+        self.run_command(&format!("rmdir {}", p))?;
         Ok(())
     }
 
     fn create_dir_all(&self, path: &Path) -> FsResult<()> {
-        self.run_command(&format!("mkdir -p {}", Self::quote_path(path)), None)?;
+        self.run_command(&format!("mkdir -p {}", Self::quote_path(path)))?;
         Ok(())
     }
 
@@ -221,7 +187,7 @@ impl FileSystem for RemoteFs {
             "sha256sum {} 2>/dev/null || shasum -a 256 {} 2>/dev/null",
             p, p
         );
-        let out = self.run_command(&cmd, None)?;
+        let out = self.run_command(&cmd)?;
         let hash_hex = out.split_whitespace().next().unwrap_or("");
         Ok(format!("sha256:{}", hash_hex))
     }
@@ -278,21 +244,77 @@ mod tests {
 
     #[test]
     fn remote_fs_new_stores_destination() {
-        let fs = RemoteFs::new("user@host");
+        let fs = RemoteFs::new("user@host", &SshOptions::default());
         assert_eq!(fs.destination(), "user@host");
     }
 
+    #[test]
+    fn remote_fs_new_passes_ssh_options_to_session() {
+        let options = SshOptions {
+            port: Some(2222),
+            identity_file: Some(PathBuf::from("/keys/fleet")),
+        };
+        let fs = RemoteFs::new("user@host", &options);
+        assert_eq!(
+            fs.session.args(),
+            ["-p", "2222", "-i", "/keys/fleet", "user@host", "sh"]
+        );
+    }
+
     #[test]
     fn remote_fs_expand_home_non_tilde() {
-        let fs = RemoteFs::new("user@host");
+        let fs = RemoteFs::new("user@host", &SshOptions::default());
         let path = Path::new("/absolute/path");
         assert_eq!(fs.expand_home(path), PathBuf::from("/absolute/path"));
     }
 
+    #[cfg(unix)]
+    fn local_fs() -> RemoteFs {
+        RemoteFs::new("localhost", &SshOptions::default())
+            .with_session(RemoteSession::with_command("sh", Vec::new()))
+    }
+
+    #[cfg(unix)]
+    #[test]
+    fn remote_fs_batch_check_files_over_session() {
+        let dir = tempfile::tempdir().unwrap();
+        let present = dir.path().join("present.md");
+        let missing = dir.path().join("missing.md");
+        let fs = local_fs();
+        fs.write(&present, "hello").unwrap();
+
+        let checks = fs
+            .batch_check_files(&[present.clone(), missing.clone()])
+            .unwrap();
+        assert_eq!(
+            checks[&present],
+            (
+                true,
+                Some(crate::domain::value_objects::ContentHash::from_content("hello").to_string())
+            )
+        );
+        assert_eq!(checks[&missing], (false, None));
+    }
+
+    #[cfg(unix)]
+    #[test]
+    fn remote_fs_write_binary_over_session() {
+        let dir = tempfile::tempdir().unwrap();
+        let path = dir.path().join("nested/test_binary.bin");
+        let content = [0x89, b'P', b'N', b'G', 0x0D, 0x0A, 0x00, b'%', b'\'', b'\\'];
+
+        let fs = local_fs();
+        fs.write_binary(&path, &content).unwrap();
+        assert!(fs.exists(&path));
+        assert_eq!(std::fs::read(&path).unwrap(), content);
+        fs.remove(&path).unwrap();
+        assert!(!fs.exists(&path));
+    }
+
     // Note: Tests that require actual SSH connections are not included here.
     // Those should be integration tests or require mocking.
 
-    /// Test that write_binary uses binary stdin correctly
+    /// Test that write_binary round-trips binary content over SSH
     ///
     /// This test is ignored by default because it requires SSH access to localhost.
     /// Run with: cargo test --lib remote_fs_write_binary_localhost -- --ignored
@@ -306,7 +328,7 @@ mod tests {
         use crate::domain::ports::file_system::FileSystem;
         use tempfile::tempdir;
 
-        let fs = RemoteFs::new("localhost");
+        let fs = RemoteFs::new("localhost", &SshOptions::default());
 
         // Create a temp directory to write to
         let dir = tempdir().unwrap();
@@ -1,3 +1,5 @@
 pub mod fs_loader;
+pub mod remote;
 
 pub use fs_loader::FsLayerLoader;
+pub use remote::CachedLayerFetcher;
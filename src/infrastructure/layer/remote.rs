@@ -0,0 +1,465 @@
+//! Remote Layer Fetcher
+//!
+//! Implements the LayerFetcher port with the `git` and `tar` command line tools.
+//! Fetched trees are stored in a content-addressed cache (`~/.calvin/cache/layers/<hash>`),
+//! so a pinned layer that is already cached is used without contacting its source.
+//! Symlinks are removed from fetched trees; a layer only contributes regular files.
+
+use std::path::{Path, PathBuf};
+use std::process::{Command, Stdio};
+
+use crate::domain::entities::LayerPin;
+use crate::domain::ports::{FetchedLayer, LayerFetchError, LayerFetcher};
+use crate::domain::value_objects::{ContentHash, RemoteLayer};
+use crate::infrastructure::fs::calvin_home_dir;
+
+/// Fetches remote layers into a content-addressed cache directory
+#[derive(Debug, Clone)]
+pub struct CachedLayerFetcher {
+    cache_dir: PathBuf,
+}
+
+impl CachedLayerFetcher {
+    pub fn new(cache_dir: impl Into<PathBuf>) -> Self {
+        Self {
+            cache_dir: cache_dir.into(),
+        }
+    }
+
+    /// `~/.calvin/cache/layers`
+    pub fn default_cache_dir() -> PathBuf {
+        calvin_home_dir()
+            .map(|h| h.join(".calvin/cache/layers"))
+            .unwrap_or_else(|| PathBuf::from("~/.calvin/cache/layers"))
+    }
+
+    fn cached_path(&self, hash: &str) -> PathBuf {
+        self.cache_dir.join(ContentHash::new(hash).hex())
+    }
+}
+
+impl Default for CachedLayerFetcher {
+    fn default() -> Self {
+        Self::new(Self::default_cache_dir())
+    }
+}
+
+impl LayerFetcher for CachedLayerFetcher {
+    fn fetch(
+        &self,
+        layer: &RemoteLayer,
+        pin: Option<&LayerPin>,
+    ) -> Result<FetchedLayer, LayerFetchError> {
+        if let Some(pin) = pin {
+            let cached = self.cached_path(pin.hash());
+            if cached.is_dir() {
+                // Trees cached by older versions may still contain links
+                remove_symlinks(&cached).map_err(|e| LayerFetchError::Io(e.to_string()))?;
+                return Ok(FetchedLayer {
+                    path: cached,
+                    pin: pin.clone(),
+                });
+            }
+        }
+
+        let spec = layer.to_string();
+        let io_error = |e: std::io::Error| LayerFetchError::Io(e.to_string());
+
+        // Stage inside the cache so the final rename stays on one filesystem
+        std::fs::create_dir_all(&self.cache_dir).map_err(io_error)?;
+        let staging = tempfile::Builder::new()
+            .prefix(".fetch-")
+            .tempdir_in(&self.cache_dir)
+            .map_err(io_error)?;
+        let tree = staging.path().join("tree");
+
+        let commit = match layer {
+            RemoteLayer::Git { url, reference } => {
+                let commit = checkout_git(
+                    url,
+                    reference.as_deref(),
+                    pin.and_then(LayerPin::commit),
+                    &tree,
+                )
+                .map_err(|message| LayerFetchError::Fetch {
+                    layer: spec.clone(),
+                    message,
+                })?;
+                Some(commit)
+            }
+            RemoteLayer::Tarball { path } => {
+                extract_tarball(&expand_home(path), &tree).map_err(|message| {
+                    LayerFetchError::Fetch {
+                        layer: spec.clone(),
+                        message,
+                    }
+                })?;
+                None
+            }
+        };
+
+        remove_symlinks(&tree).map_err(io_error)?;
+        let hash = hash_tree(&tree).map_err(io_error)?;
+        if let Some(pin) = pin {
+            if pin.hash() != hash {
+                return Err(LayerFetchError::HashMismatch {
+                    layer: spec,
+                    expected: pin.hash().to_string(),
+                    actual: hash,
+                });
+            }
+        }
+
+        let path = self.cached_path(&hash);
+        if !path.is_dir() {
+            std::fs::rename(&tree, &path).map_err(io_error)?;
+        }
+
+        Ok(FetchedLayer {
+            path,
+            pin: LayerPin::new(commit, hash),
+        })
+    }
+}
+
+/// Clone `url` into `dest` and check out `commit` (or `reference`, or `HEAD`)
+///
+/// Returns the checked out commit. The `.git` directory is removed afterwards.
+fn checkout_git(
+    url: &str,
+    reference: Option<&str>,
+    commit: Option<&str>,
+    dest: &Path,
+) -> Result<String, String> {
+    let dest_str = dest.to_string_lossy();
+    git(
+        None,
+        &["clone", "--quiet", "--no-checkout", "--", url, &dest_str],
+    )?;
+
+    let revision = match (commit, reference) {
+        (Some(commit), _) => {
+            rev_parse(dest, commit).ok_or_else(|| format!("pinned commit {} not found", commit))?
+        }
+        (None, Some(reference)) => rev_parse(dest, reference)
+            .or_else(|| rev_parse(dest, &format!("origin/{}", reference)))
+            .ok_or_else(|| format!("ref `{}` not found", reference))?,
+        (None, None) => {
+            rev_parse(dest, "HEAD").ok_or_else(|| "repository has no commits".to_string())?
+        }
+    };
+
+    git(
+        Some(dest),
+        &["checkout", "--quiet", "--detach", revision.as_str()],
+    )?;
+    std::fs::remove_dir_all(dest.join(".git")).map_err(|e| e.to_string())?;
+    Ok(revision)
+}
+
+fn rev_parse(repo: &Path, revision: &str) -> Option<String> {
+    git(
+        Some(repo),
+        &[
+            "rev-parse",
+            "--verify",
+            "--quiet",
+            &format!("{}^{{commit}}", revision),
+        ],
+    )
+    .ok()
+    .map(|out| out.trim().to_string())
+    .filter(|out| !out.is_empty())
+}
+
+fn git(repo: Option<&Path>, args: &[&str]) -> Result<String, String> {
+    let mut cmd = Command::new("git");
+    if let Some(repo) = repo {
+        cmd.arg("-C").arg(repo);
+    }
+    let output = cmd
+        .args(args)
+        .env("GIT_TERMINAL_PROMPT", "0")
+        .stdin(Stdio::null())
+        .output()
+        .map_err(|e| format!("failed to run git: {}", e))?;
+    if output.status.success() {
+        Ok(String::from_utf8_lossy(&output.stdout).into_owned())
+    } else {
+        Err(String::from_utf8_lossy(&output.stderr).trim().to_string())
+    }
+}
+
+fn extract_tarball(archive: &Path, dest: &Path) -> Result<(), String> {
+    if !archive.is_file() {
+        return Err(format!("tarball not found: {}", archive.display()));
+    }
+    std::fs::create_dir_all(dest).map_err(|e| e.to_string())?;
+    let output = Command::new("tar")
+        .arg("-xf")
+        .arg(archive)
+        .arg("-C")
+        .arg(dest)
+        .stdin(Stdio::null())
+        .output()
+        .map_err(|e| format!("failed to run tar: {}", e))?;
+    if output.status.success() {
+        Ok(())
+    } else {
+        Err(String::from_utf8_lossy(&output.stderr).trim().to_string())
+    }
+}
+
+fn expand_home(path: &Path) -> PathBuf {
+    match (path.strip_prefix("~"), calvin_home_dir()) {
+        (Ok(rest), Some(home)) => home.join(rest),
+        _ => path.to_path_buf(),
+    }
+}
+
+/// Delete every symlink in a fetched tree
+///
+/// A fetched layer is untrusted: a link could point outside the tree (e.g. at
+/// `~/.ssh/id_rsa`, which would then be deployed as an asset) or loop back on
+/// itself. Only regular files and directories are kept.
+fn remove_symlinks(dir: &Path) -> std::io::Result<()> {
+    for entry in std::fs::read_dir(dir)? {
+        let entry = entry?;
+        let file_type = entry.file_type()?;
+        if file_type.is_symlink() {
+            std::fs::remove_file(entry.path())?;
+        } else if file_type.is_dir() {
+            remove_symlinks(&entry.path())?;
+        }
+    }
+    Ok(())
+}
+
+/// Hash a directory tree: every file's relative path and content, in path order
+fn hash_tree(root: &Path) -> std::io::Result<String> {
+    let mut files = Vec::new();
+    collect_files(root, root, &mut files)?;
+    files.sort();
+
+    let mut manifest = String::new();
+    for relative in files {
+        let content = std::fs::read(root.join(&relative))?;
+        manifest.push_str(&format!(
+            "{}\0{}\n",
+            relative,
+            ContentHash::from_bytes(&content).hex()
+        ));
+    }
+    Ok(ContentHash::from_content(&manifest).as_str().to_string())
+}
+
+/// Collect the regular files under `dir`, never following symlinks
+fn collect_files(root: &Path, dir: &Path, files: &mut Vec<String>) -> std::io::Result<()> {
+    for entry in std::fs::read_dir(dir)? {
+        let path = entry?.path();
+        let file_type = std::fs::symlink_metadata(&path)?.file_type();
+        if file_type.is_symlink() {
+            continue;
+        }
+        if file_type.is_dir() {
+            collect_files(root, &path, files)?;
+        } else if let Ok(relative) = path.strip_prefix(root) {
+            files.push(relative.to_string_lossy().replace('\\', "/"));
+        }
+    }
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use tempfile::tempdir;
+
+    fn run_git(repo: &Path, args: &[&str]) {
+        let status = Command::new("git")
+            .arg("-C")
+            .arg(repo)
+            .args([
+                "-c",
+                "user.name=Calvin",
+                "-c",
+                "user.email=calvin@example.com",
+            ])
+            .args(args)
+            .stdout(Stdio::null())
+            .stderr(Stdio::null())
+            .status()
+            .unwrap();
+        assert!(status.success(), "git {:?} failed", args);
+    }
+
+    /// A repository with `policy.md` committed twice, tagged `v1` after the first commit
+    ///
+    /// Returns its path, which git accepts as a clone URL.
+    fn repo_with_tag(dir: &Path) -> String {
+        std::fs::create_dir_all(dir).unwrap();
+        run_git(dir, &["init", "--quiet"]);
+        std::fs::write(dir.join("policy.md"), "v1").unwrap();
+        run_git(dir, &["add", "."]);
+        run_git(dir, &["commit", "--quiet", "-m", "v1"]);
+        run_git(dir, &["tag", "v1"]);
+        std::fs::write(dir.join("policy.md"), "v2").unwrap();
+        run_git(dir, &["commit", "--quiet", "-am", "v2"]);
+        dir.display().to_string()
+    }
+
+    #[test]
+    fn fetches_git_ref_into_the_cache() {
+        let dir = tempdir().unwrap();
+        let url = repo_with_tag(&dir.path().join("repo"));
+        let fetcher = CachedLayerFetcher::new(dir.path().join("cache"));
+        let layer = RemoteLayer::Git {
+            url,
+            reference: Some("v1".to_string()),
+        };
+
+        let fetched = fetcher.fetch(&layer, None).unwrap();
+
+        assert_eq!(
+            std::fs::read_to_string(fetched.path.join("policy.md")).unwrap(),
+            "v1"
+        );
+        assert!(!fetched.path.join(".git").exists());
+        assert_eq!(fetched.pin.commit().map(str::len), Some(40));
+        assert!(fetched.path.starts_with(dir.path().join("cache")));
+        assert!(fetched.pin.hash().starts_with("sha256:"));
+    }
+
+    #[test]
+    fn pinned_fetch_uses_commit_and_cache() {
+        let dir = tempdir().unwrap();
+        let url = repo_with_tag(&dir.path().join("repo"));
+        let fetcher = CachedLayerFetcher::new(dir.path().join("cache"));
+        let tagged = RemoteLayer::Git {
+            url: url.clone(),
+            reference: Some("v1".to_string()),
+        };
+        let pin = fetcher.fetch(&tagged, None).unwrap().pin;
+
+        // Pinned to the tag's commit even though HEAD moved on
+        let head = RemoteLayer::Git {
+            url,
+            reference: None,
+        };
+        std::fs::remove_dir_all(dir.path().join("cache")).unwrap();
+        let fetched = fetcher.fetch(&head, Some(&pin)).unwrap();
+        assert_eq!(fetched.pin, pin);
+        assert_eq!(
+            std::fs::read_to_string(fetched.path.join("policy.md")).unwrap(),
+            "v1"
+        );
+
+        // Cached: the repository is not needed any more
+        std::fs::remove_dir_all(dir.path().join("repo")).unwrap();
+        assert_eq!(fetcher.fetch(&head, Some(&pin)).unwrap(), fetched);
+    }
+
+    #[test]
+    fn hash_mismatch_is_rejected() {
+        let dir = tempdir().unwrap();
+        let url = repo_with_tag(&dir.path().join("repo"));
+        let fetcher = CachedLayerFetcher::new(dir.path().join("cache"));
+        let layer = RemoteLayer::Git {
+            url,
+            reference: None,
+        };
+        let fetched = fetcher.fetch(&layer, None).unwrap();
+        let tampered = LayerPin::new(fetched.pin.commit().map(str::to_string), "sha256:00");
+
+        let err = fetcher.fetch(&layer, Some(&tampered)).unwrap_err();
+
+        assert!(matches!(err, LayerFetchError::HashMismatch { .. }), "{err}");
+    }
+
+    #[test]
+    fn unknown_ref_is_reported() {
+        let dir = tempdir().unwrap();
+        let url = repo_with_tag(&dir.path().join("repo"));
+        let fetcher = CachedLayerFetcher::new(dir.path().join("cache"));
+        let layer = RemoteLayer::Git {
+            url,
+            reference: Some("v9".to_string()),
+        };
+
+        let err = fetcher.fetch(&layer, None).unwrap_err();
+
+        assert!(err.to_string().contains("ref `v9` not found"), "{err}");
+    }
+
+    #[test]
+    fn fetches_tarballs() {
+        let dir = tempdir().unwrap();
+        let pack = dir.path().join("pack");
+        std::fs::create_dir_all(pack.join("policies")).unwrap();
+        std::fs::write(pack.join("policies/style.md"), "style").unwrap();
+        let archive = dir.path().join("pack.tar.gz");
+        let status = Command::new("tar")
+            .arg("-czf")
+            .arg(&archive)
+            .arg("-C")
+            .arg(&pack)
+            .arg(".")
+            .status()
+            .unwrap();
+        assert!(status.success());
+        let fetcher = CachedLayerFetcher::new(dir.path().join("cache"));
+
+        let fetched = fetcher
+            .fetch(&RemoteLayer::Tarball { path: archive }, None)
+            .unwrap();
+
+        assert_eq!(fetched.pin.commit(), None);
+        assert_eq!(
+            std::fs::read_to_string(fetched.path.join("policies/style.md")).unwrap(),
+            "style"
+        );
+        assert_eq!(fetched.pin.hash(), hash_tree(&pack).unwrap());
+    }
+
+    #[cfg(unix)]
+    #[test]
+    fn symlinks_in_fetched_layers_are_dropped() {
+        let dir = tempdir().unwrap();
+        let secret = dir.path().join("id_rsa");
+        std::fs::write(&secret, "PRIVATE KEY").unwrap();
+        let repo = dir.path().join("repo");
+        let url = repo_with_tag(&repo);
+        std::fs::create_dir_all(repo.join("policies")).unwrap();
+        std::os::unix::fs::symlink(&secret, repo.join("policies/key.md")).unwrap();
+        std::os::unix::fs::symlink("..", repo.join("policies/loop")).unwrap();
+        run_git(&repo, &["add", "."]);
+        run_git(&repo, &["commit", "--quiet", "-m", "links"]);
+        let fetcher = CachedLayerFetcher::new(dir.path().join("cache"));
+        let layer = RemoteLayer::Git {
+            url,
+            reference: None,
+        };
+
+        let fetched = fetcher.fetch(&layer, None).unwrap();
+
+        let policies = fetched.path.join("policies");
+        assert!(policies.is_dir());
+        assert!(std::fs::symlink_metadata(policies.join("key.md")).is_err());
+        assert!(std::fs::symlink_metadata(policies.join("loop")).is_err());
+        assert_eq!(
+            std::fs::read_to_string(fetched.path.join("policy.md")).unwrap(),
+            "v2"
+        );
+    }
+
+    #[cfg(unix)]
+    #[test]
+    fn hash_tree_skips_symlinks() {
+        let dir = tempdir().unwrap();
+        std::fs::write(dir.path().join("a.md"), "a").unwrap();
+        let before = hash_tree(dir.path()).unwrap();
+        std::os::unix::fs::symlink(dir.path(), dir.path().join("loop")).unwrap();
+
+        assert_eq!(hash_tree(dir.path()).unwrap(), before);
+    }
+}
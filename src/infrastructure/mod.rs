@@ -9,8 +9,10 @@
 //! - `config/` - Configuration loading implementations
 //! - `events/` - Event sink implementations (JSON, Console)
 //! - `fs/` - File system implementations (Local, Remote)
+//! - `layer/` - Layer loading and remote layer fetching (git, tarballs)
 //! - `repositories/` - Repository implementations (Lockfile, Asset)
 //! - `sync/` - Sync destination implementations (Local, Remote)
+//! - `tool_probe` - Installed tool detection (binaries on `PATH`, config dirs)
 
 pub mod adapters;
 pub mod config;
@@ -20,13 +22,18 @@ pub mod fs;
 pub mod layer;
 pub mod repositories;
 pub mod sync;
+pub mod tool_probe;
 
 // Re-export for convenience
-pub use adapters::{all_adapters, get_adapter, ClaudeCodeAdapter, CursorAdapter};
+pub use adapters::{
+    all_adapters, all_adapters_for_config, get_adapter, get_adapter_for_config, ClaudeCodeAdapter,
+    CursorAdapter,
+};
 pub use config::TomlConfigRepository;
 pub use conflict::InteractiveResolver;
 pub use events::JsonEventSink;
 pub use fs::{calvin_home_dir, LocalFs, RemoteFs, CALVIN_TEST_HOME_VAR};
-pub use layer::FsLayerLoader;
+pub use layer::{CachedLayerFetcher, FsLayerLoader};
 pub use repositories::{FsAssetRepository, TomlLockfileRepository, TomlRegistryRepository};
 pub use sync::{LocalHomeDestination, LocalProjectDestination, RemoteDestination};
+pub use tool_probe::SystemToolProbe;
@@ -2,11 +2,12 @@
 //!
 //! Loads assets from the file system by parsing PromptPack files.
 
-use crate::domain::entities::{Asset, AssetKind};
+use crate::domain::entities::{Asset, AssetKind, McpServer, Partial};
 use crate::domain::ports::AssetRepository;
-use crate::domain::value_objects::{IgnorePatterns, Scope, Target};
-use anyhow::Result;
+use crate::domain::value_objects::IgnorePatterns;
+use anyhow::{Context, Result};
 use std::collections::HashMap;
+use std::fs;
 use std::path::Path;
 
 /// Asset repository that loads from the file system
@@ -36,6 +37,39 @@ impl<'a> IgnoreContext<'a> {
     }
 }
 
+/// Directory-based asset whose supplemental files are being loaded
+#[derive(Clone, Copy)]
+enum SupplementalOwner<'a> {
+    Skill(&'a str),
+    Hook(&'a str),
+}
+
+impl SupplementalOwner<'_> {
+    fn kind(&self) -> &'static str {
+        match self {
+            SupplementalOwner::Skill(_) => "skill",
+            SupplementalOwner::Hook(_) => "hook",
+        }
+    }
+
+    /// Definition file of the asset, not a supplemental
+    fn entrypoint(&self) -> &'static str {
+        match self {
+            SupplementalOwner::Skill(_) => "SKILL.md",
+            SupplementalOwner::Hook(_) => "HOOK.md",
+        }
+    }
+}
+
+impl std::fmt::Display for SupplementalOwner<'_> {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            SupplementalOwner::Skill(id) => write!(f, "Skill '{}'", id),
+            SupplementalOwner::Hook(id) => write!(f, "Hook '{}'", id),
+        }
+    }
+}
+
 impl FsAssetRepository {
     /// Create a new repository
     pub fn new() -> Self {
@@ -52,83 +86,7 @@ impl Default for FsAssetRepository {
 impl FsAssetRepository {
     /// Convert a legacy PromptAsset to domain Asset
     fn convert_prompt_asset(pa: crate::models::PromptAsset) -> Asset {
-        let kind = match pa.frontmatter.kind {
-            crate::models::AssetKind::Policy => AssetKind::Policy,
-            crate::models::AssetKind::Action => AssetKind::Action,
-            crate::models::AssetKind::Agent => AssetKind::Agent,
-            crate::models::AssetKind::Skill => AssetKind::Skill,
-        };
-
-        let scope = match pa.frontmatter.scope {
-            crate::models::Scope::Project => Scope::Project,
-            crate::models::Scope::User => Scope::User,
-        };
-
-        let targets: Vec<Target> = pa
-            .frontmatter
-            .targets
-            .iter()
-            .map(|t| match t {
-                crate::models::Target::ClaudeCode => Target::ClaudeCode,
-                crate::models::Target::Cursor => Target::Cursor,
-                crate::models::Target::VSCode => Target::VSCode,
-                crate::models::Target::Antigravity => Target::Antigravity,
-                crate::models::Target::Codex => Target::Codex,
-                crate::models::Target::OpenCode => Target::OpenCode,
-                crate::models::Target::All => Target::All,
-            })
-            .collect();
-
-        let mut asset = Asset::new(
-            &pa.id,
-            &pa.source_path,
-            &pa.frontmatter.description,
-            &pa.content,
-        )
-        .with_kind(kind)
-        .with_scope(scope)
-        .with_targets(targets);
-
-        // Set apply pattern if present
-        if let Some(apply) = &pa.frontmatter.apply {
-            asset = asset.with_apply(apply.clone());
-        }
-
-        if !pa.frontmatter.allowed_tools.is_empty() {
-            asset = asset.with_allowed_tools(pa.frontmatter.allowed_tools.clone());
-        }
-
-        // Agent fields - use effective_* methods to support both Claude Code native format
-        // and legacy Calvin format
-        asset = asset.with_agent_name(pa.frontmatter.name.clone());
-
-        let effective_tools = pa.frontmatter.effective_tools();
-        if !effective_tools.is_empty() {
-            asset = asset.with_agent_tools(effective_tools);
-        }
-        if pa.frontmatter.model.is_some() {
-            asset = asset.with_agent_model(pa.frontmatter.model.clone());
-        }
-        let effective_perm = pa
-            .frontmatter
-            .effective_permission_mode()
-            .map(|s| s.to_string());
-        if effective_perm.is_some() {
-            asset = asset.with_agent_permission_mode(effective_perm);
-        }
-        let effective_skills = pa.frontmatter.effective_skills();
-        if !effective_skills.is_empty() {
-            asset = asset.with_agent_skills(effective_skills);
-        }
-
-        asset = asset
-            .with_opencode_mode(pa.frontmatter.mode.clone())
-            .with_temperature(pa.frontmatter.temperature)
-            .with_opencode_model(pa.frontmatter.opencode_model.clone())
-            .with_command_agent(pa.frontmatter.agent.clone())
-            .with_command_subtask(pa.frontmatter.subtask);
-
-        asset
+        Asset::from(pa)
     }
 
     /// Load skills from the skills/ directory.
@@ -212,6 +170,15 @@ impl FsAssetRepository {
             );
         }
 
+        // Skills are directories; a higher layer's skill always replaces the lower one.
+        if frontmatter.merge != crate::models::MergeStrategy::Replace {
+            anyhow::bail!(
+                "Skill '{}' does not support 'merge: {}' (skills always replace lower layers; remove the field)",
+                id,
+                frontmatter.merge
+            );
+        }
+
         // `kind: skill` is optional for skills, but if present must be `skill`.
         if yaml_has_key(&extracted.yaml, "kind")
             && frontmatter.kind != crate::models::AssetKind::Skill
@@ -230,11 +197,12 @@ impl FsAssetRepository {
             .to_path_buf();
 
         let prompt_asset =
-            crate::models::PromptAsset::new(id, rel_source_path, frontmatter, extracted.body);
+            crate::models::PromptAsset::new(id, rel_source_path, frontmatter, extracted.body)
+                .with_body_line(extracted.end_line + 1);
         let mut asset = Asset::from(prompt_asset);
 
         let (supplementals, binary_supplementals, warnings) =
-            Self::load_skill_supplementals_internal(skill_dir, id, ctx)?;
+            Self::load_supplementals_internal(skill_dir, SupplementalOwner::Skill(id), ctx)?;
         asset = asset.with_supplementals(supplementals);
         asset = asset.with_binary_supplementals(binary_supplementals);
         if !warnings.is_empty() {
@@ -244,14 +212,155 @@ impl FsAssetRepository {
         Ok(asset.with_kind(AssetKind::Skill))
     }
 
-    /// Load skill supplementals from a skill directory.
+    /// Load hooks from the hooks/ directory.
+    ///
+    /// A hook is either a single `hooks/<id>.md` file or a `hooks/<id>/HOOK.md`
+    /// directory whose other files (scripts) are supplementals.
+    fn load_hooks_internal(
+        source: &Path,
+        ctx: Option<&IgnoreContext>,
+    ) -> Result<(Vec<Asset>, usize)> {
+        let hooks_root = source.join("hooks");
+        if !hooks_root.exists() {
+            return Ok((Vec::new(), 0));
+        }
+
+        if !hooks_root.is_dir() {
+            anyhow::bail!(
+                "Expected 'hooks' to be a directory: {}",
+                hooks_root.display()
+            );
+        }
+
+        let mut hooks = Vec::new();
+        let mut ignored_count = 0;
+
+        for entry in std::fs::read_dir(&hooks_root)? {
+            let entry = entry?;
+            let path = entry.path();
+            let name = entry.file_name().to_string_lossy().to_string();
+
+            // Skip hidden entries and READMEs inside hooks/
+            if name.starts_with('.') || name == "README.md" {
+                continue;
+            }
+
+            if let Some(c) = ctx {
+                if c.is_ignored(&path, path.is_dir()) {
+                    ignored_count += 1;
+                    continue;
+                }
+            }
+
+            if path.is_dir() {
+                let hook_md_path = path.join("HOOK.md");
+                if !hook_md_path.is_file() {
+                    anyhow::bail!("Hook directory '{}' missing HOOK.md", name);
+                }
+                hooks.push(Self::load_hook_internal(
+                    source,
+                    &hook_md_path,
+                    &name,
+                    Some(&path),
+                    ctx,
+                )?);
+            } else if let Some(id) = name.strip_suffix(".md") {
+                hooks.push(Self::load_hook_internal(source, &path, id, None, ctx)?);
+            }
+        }
+
+        hooks.sort_by(|a, b| a.id().cmp(b.id()));
+        Ok((hooks, ignored_count))
+    }
+
+    /// Load a single hook from its definition file (and directory, if any).
+    fn load_hook_internal(
+        source_root: &Path,
+        hook_md_path: &Path,
+        id: &str,
+        hook_dir: Option<&Path>,
+        ctx: Option<&IgnoreContext>,
+    ) -> Result<Asset> {
+        let raw = std::fs::read_to_string(hook_md_path)?;
+
+        let extracted = crate::parser::extract_frontmatter(&raw, hook_md_path)?;
+        let mut frontmatter = crate::parser::parse_frontmatter(&extracted.yaml, hook_md_path)?;
+
+        // `kind: hook` is optional for hooks, but if present must be `hook`.
+        if yaml_has_key(&extracted.yaml, "kind")
+            && frontmatter.kind != crate::models::AssetKind::Hook
+        {
+            anyhow::bail!("Hook '{}' frontmatter kind must be 'hook' (or omit it)", id);
+        }
+        frontmatter.kind = crate::models::AssetKind::Hook;
+
+        if frontmatter.merge != crate::models::MergeStrategy::Replace {
+            anyhow::bail!(
+                "Hook '{}' does not support 'merge: {}' (hooks always replace lower layers; remove the field)",
+                id,
+                frontmatter.merge
+            );
+        }
+        if frontmatter.apply.is_some() {
+            anyhow::bail!(
+                "Hook '{}' does not support 'apply' in frontmatter (use 'matcher')",
+                id
+            );
+        }
+        let Some(event) = frontmatter.event else {
+            anyhow::bail!(
+                "Hook '{}' is missing 'event' in frontmatter (e.g. event: PreToolUse)",
+                id
+            );
+        };
+        if frontmatter
+            .command
+            .as_deref()
+            .is_none_or(|c| c.trim().is_empty())
+        {
+            anyhow::bail!("Hook '{}' is missing 'command' in frontmatter", id);
+        }
+        if frontmatter.matcher.is_some() && !event.supports_matcher() {
+            anyhow::bail!(
+                "Hook '{}': {} hooks do not support 'matcher' (remove the field)",
+                id,
+                event
+            );
+        }
+
+        // Make source_path relative to the layer root.
+        let rel_source_path = hook_md_path
+            .strip_prefix(source_root)
+            .unwrap_or(hook_md_path)
+            .to_path_buf();
+
+        let prompt_asset =
+            crate::models::PromptAsset::new(id, rel_source_path, frontmatter, extracted.body)
+                .with_body_line(extracted.end_line + 1);
+        let mut asset = Asset::from(prompt_asset);
+
+        if let Some(dir) = hook_dir {
+            let (supplementals, binary_supplementals, warnings) =
+                Self::load_supplementals_internal(dir, SupplementalOwner::Hook(id), ctx)?;
+            asset = asset
+                .with_supplementals(supplementals)
+                .with_binary_supplementals(binary_supplementals);
+            if !warnings.is_empty() {
+                asset = asset.with_warnings(warnings);
+            }
+        }
+
+        Ok(asset.with_kind(AssetKind::Hook))
+    }
+
+    /// Load supplementals from a skill or hook directory.
     ///
     /// Returns (text supplemental files, binary supplemental files, warnings).
     /// Binary files are loaded separately and a warning is emitted to inform the user.
     #[allow(clippy::type_complexity)]
-    fn load_skill_supplementals_internal(
+    fn load_supplementals_internal(
         skill_dir: &Path,
-        skill_id: &str,
+        owner: SupplementalOwner,
         ctx: Option<&IgnoreContext>,
     ) -> Result<(
         HashMap<std::path::PathBuf, String>,
@@ -261,10 +370,10 @@ impl FsAssetRepository {
         let mut text_out = HashMap::new();
         let mut binary_out = HashMap::new();
         let mut warnings = Vec::new();
-        Self::load_skill_supplementals_recursive(
+        Self::load_supplementals_recursive(
             skill_dir,
             skill_dir,
-            skill_id,
+            owner,
             ctx,
             &mut text_out,
             &mut binary_out,
@@ -273,10 +382,10 @@ impl FsAssetRepository {
         Ok((text_out, binary_out, warnings))
     }
 
-    fn load_skill_supplementals_recursive(
+    fn load_supplementals_recursive(
         skill_root: &Path,
         current: &Path,
-        skill_id: &str,
+        owner: SupplementalOwner,
         ctx: Option<&IgnoreContext>,
         text_out: &mut HashMap<std::path::PathBuf, String>,
         binary_out: &mut HashMap<std::path::PathBuf, Vec<u8>>,
@@ -290,7 +399,8 @@ impl FsAssetRepository {
             // Security: do not follow symlinks.
             if file_type.is_symlink() {
                 anyhow::bail!(
-                    "Symlinks are not supported in skill directories: {}",
+                    "Symlinks are not supported in {} directories: {}",
+                    owner.kind(),
                     path.display()
                 );
             }
@@ -312,8 +422,8 @@ impl FsAssetRepository {
                     }
                 }
 
-                Self::load_skill_supplementals_recursive(
-                    skill_root, &path, skill_id, ctx, text_out, binary_out, warnings,
+                Self::load_supplementals_recursive(
+                    skill_root, &path, owner, ctx, text_out, binary_out, warnings,
                 )?;
                 continue;
             }
@@ -322,7 +432,7 @@ impl FsAssetRepository {
                 continue;
             }
 
-            if path.file_name() == Some(std::ffi::OsStr::new("SKILL.md")) {
+            if path.file_name() == Some(std::ffi::OsStr::new(owner.entrypoint())) {
                 continue;
             }
 
@@ -348,15 +458,15 @@ impl FsAssetRepository {
                 // Store binary file and emit an informational message
                 let size_kb = bytes.len() as f64 / 1024.0;
                 warnings.push(format!(
-                    "Skill '{}': binary file '{}' will be deployed ({:.1} KB)",
-                    skill_id,
+                    "{}: binary file '{}' will be deployed ({:.1} KB)",
+                    owner,
                     rel.display(),
                     size_kb
                 ));
                 binary_out.insert(rel, bytes);
             } else {
                 let content = String::from_utf8(bytes).map_err(|_| {
-                    anyhow::anyhow!("Invalid UTF-8 in skill file: {}", rel.display())
+                    anyhow::anyhow!("Invalid UTF-8 in {} file: {}", owner.kind(), rel.display())
                 })?;
                 text_out.insert(rel, content);
             }
@@ -407,8 +517,13 @@ impl AssetRepository for FsAssetRepository {
         let (skills, skills_ignored) = Self::load_skills_internal(source, Some(&ctx))?;
         ignored_count += skills_ignored;
 
+        // Load hooks with ignore filtering
+        let (hooks, hooks_ignored) = Self::load_hooks_internal(source, Some(&ctx))?;
+        ignored_count += hooks_ignored;
+
         let mut assets = filtered_assets;
         assets.extend(skills);
+        assets.extend(hooks);
 
         Ok((assets, ignored_count))
     }
@@ -436,10 +551,54 @@ impl AssetRepository for FsAssetRepository {
             }
         }
 
+        // Hook: .../hooks/<id>/HOOK.md or .../hooks/<id>.md
+        let hook_dir = path
+            .parent()
+            .filter(|_| path.file_name() == Some(std::ffi::OsStr::new("HOOK.md")));
+        let hooks_root = match hook_dir {
+            Some(dir) => dir.parent(),
+            None => path.parent(),
+        };
+        if hooks_root.and_then(|p| p.file_name()) == Some(std::ffi::OsStr::new("hooks")) {
+            let id = hook_dir
+                .unwrap_or(path)
+                .file_stem()
+                .and_then(|n| n.to_str())
+                .unwrap_or("unknown");
+            let source_root = hooks_root
+                .and_then(|p| p.parent())
+                .unwrap_or_else(|| Path::new("."));
+            return Self::load_hook_internal(source_root, path, id, hook_dir, None);
+        }
+
         // Use the existing parser for single-file assets
         let pa = crate::parser::parse_file(path)?;
         Ok(Self::convert_prompt_asset(pa))
     }
+
+    fn load_mcp_servers(&self, source: &Path) -> Result<Vec<McpServer>> {
+        super::mcp::load_mcp_servers(source)
+    }
+
+    fn load_partials(&self, source: &Path) -> Result<Vec<Partial>> {
+        super::partials::load_partials(source)
+    }
+
+    fn load_format_version(&self, source: &Path) -> Result<Option<String>> {
+        let config_path = source.join("config.toml");
+        if !config_path.is_file() {
+            return Ok(None);
+        }
+        let content = fs::read_to_string(&config_path)
+            .with_context(|| format!("Failed to read {}", config_path.display()))?;
+        let value: toml::Value = toml::from_str(&content)
+            .with_context(|| format!("Invalid TOML: {}", config_path.display()))?;
+        Ok(value
+            .get("format")
+            .and_then(|format| format.get("version"))
+            .and_then(|version| version.as_str())
+            .map(str::to_string))
+    }
 }
 
 fn is_binary(content: &[u8]) -> bool {
@@ -459,6 +618,7 @@ fn yaml_has_key(yaml: &str, key: &str) -> bool {
 #[cfg(test)]
 mod tests {
     use super::*;
+    use crate::domain::value_objects::{Scope, Target};
     use tempfile::tempdir;
 
     fn create_test_asset(dir: &Path, name: &str, content: &str) {
@@ -609,6 +769,25 @@ Body
         );
     }
 
+    #[test]
+    #[allow(non_snake_case)]
+    fn test_load_skill_directory_invalid__merge_strategy() {
+        let dir = tempdir().unwrap();
+        std::fs::create_dir_all(dir.path().join("skills/my-skill")).unwrap();
+        std::fs::write(
+            dir.path().join("skills/my-skill/SKILL.md"),
+            "---\ndescription: My skill\nmerge: append\n---\nBody\n",
+        )
+        .unwrap();
+
+        let err = FsAssetRepository::new().load_all(dir.path()).unwrap_err();
+        assert!(
+            err.to_string().contains("does not support 'merge: append'"),
+            "{}",
+            err
+        );
+    }
+
     #[test]
     #[allow(non_snake_case)]
     fn test_load_skill_directory_valid__skips_hidden_files() {
@@ -881,4 +1060,79 @@ scope: project
             .supplementals()
             .contains_key(&std::path::PathBuf::from("notes.txt")));
     }
+
+    #[test]
+    fn test_load_hook_file_and_directory() {
+        let dir = tempdir().unwrap();
+        std::fs::create_dir_all(dir.path().join("hooks/format")).unwrap();
+        std::fs::write(
+            dir.path().join("hooks/lint.md"),
+            "---\ndescription: Lint on stop\nevent: Stop\ncommand: make lint\n---\n",
+        )
+        .unwrap();
+        std::fs::write(
+            dir.path().join("hooks/format/HOOK.md"),
+            r#"---
+description: Format edited files
+event: PostToolUse
+matcher: Edit|Write
+command: .claude/hooks/format/fmt.sh
+timeout: 30
+---
+"#,
+        )
+        .unwrap();
+        std::fs::write(dir.path().join("hooks/format/fmt.sh"), "cargo fmt\n").unwrap();
+
+        let repo = FsAssetRepository::new();
+        let mut assets = repo.load_all(dir.path()).unwrap();
+        assets.sort_by(|a, b| a.id().cmp(b.id()));
+
+        assert_eq!(assets.len(), 2);
+        assert!(assets.iter().all(|a| a.kind() == AssetKind::Hook));
+        let format = assets[0].hook().unwrap();
+        assert_eq!(
+            format.event,
+            crate::domain::value_objects::HookEvent::PostToolUse
+        );
+        assert_eq!(format.matcher.as_deref(), Some("Edit|Write"));
+        assert_eq!(format.timeout, Some(30));
+        assert!(assets[0]
+            .supplementals()
+            .contains_key(&std::path::PathBuf::from("fmt.sh")));
+        assert_eq!(assets[1].id(), "lint");
+        assert_eq!(assets[1].hook().unwrap().command, "make lint");
+    }
+
+    #[test]
+    fn test_load_hook_rejects_invalid_frontmatter() {
+        let cases = [
+            ("description: x\ncommand: make lint", "missing 'event'"),
+            ("description: x\nevent: Stop", "missing 'command'"),
+            (
+                "description: x\nevent: Stop\nmatcher: Bash\ncommand: make lint",
+                "do not support 'matcher'",
+            ),
+            (
+                "description: x\nevent: PreToolUse\napply: \"*.rs\"\ncommand: x",
+                "use 'matcher'",
+            ),
+            (
+                "description: x\nevent: Stop\ncommand: x\nmerge: append",
+                "does not support 'merge: append'",
+            ),
+        ];
+        for (yaml, expected) in cases {
+            let dir = tempdir().unwrap();
+            std::fs::create_dir_all(dir.path().join("hooks")).unwrap();
+            std::fs::write(
+                dir.path().join("hooks/bad.md"),
+                format!("---\n{}\n---\n", yaml),
+            )
+            .unwrap();
+
+            let err = FsAssetRepository::new().load_all(dir.path()).unwrap_err();
+            assert!(err.to_string().contains(expected), "{}: {}", yaml, err);
+        }
+    }
 }
@@ -3,10 +3,11 @@
 //! Implements the LockfileRepository port using TOML format.
 
 use crate::domain::entities::{
-    normalize_lockfile_path, parse_lockfile_path, Lockfile, LockfileEntry,
+    normalize_lockfile_path, parse_lockfile_path, LayerPin, Lockfile, LockfileEntry,
 };
 use crate::domain::ports::file_system::FileSystem;
 use crate::domain::ports::lockfile_repository::{LockfileError, LockfileRepository};
+use crate::domain::value_objects::Target;
 use crate::infrastructure::fs::LocalFs;
 use serde::{Deserialize, Serialize};
 use std::collections::BTreeMap;
@@ -51,9 +52,26 @@ struct TomlFileEntry {
     source_file: Option<String>,
     #[serde(default)]
     overrides: Option<String>,
+    /// Partial files included into the source asset (`{{> … }}`)
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    includes: Vec<String>,
+    /// Layers merged into the source asset (`merge: append`, ...), lowest first
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    contributing_layers: Vec<String>,
     /// Whether this is a binary file (defaults to false for backwards compatibility)
     #[serde(default, skip_serializing_if = "is_false")]
     is_binary: bool,
+    /// Keys Calvin owns in a structurally merged file (`deploy --merge`)
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    owned_keys: Vec<String>,
+}
+
+/// TOML representation of a pinned remote layer
+#[derive(Debug, Clone, Serialize, Deserialize)]
+struct TomlLayerPin {
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    commit: Option<String>,
+    hash: String,
 }
 
 /// Helper for serde skip_serializing_if
@@ -65,6 +83,24 @@ fn is_false(b: &bool) -> bool {
 #[derive(Debug, Clone, Serialize, Deserialize)]
 struct TomlLockfile {
     version: u32,
+    /// Adapter output format version per target (`calvin migrate --adapter`)
+    #[serde(default, skip_serializing_if = "BTreeMap::is_empty")]
+    adapters: BTreeMap<String, u32>,
+    /// Pinned remote layers, keyed by source (`calvin layers update`)
+    #[serde(default, skip_serializing_if = "BTreeMap::is_empty")]
+    layers: BTreeMap<String, TomlLayerPin>,
+    #[serde(default)]
+    files: BTreeMap<String, TomlFileEntry>,
+    /// Per-remote deploy state, keyed by remote name (`deploy --remote`)
+    #[serde(default, skip_serializing_if = "BTreeMap::is_empty")]
+    remotes: BTreeMap<String, TomlRemote>,
+}
+
+/// TOML representation of one remote's deploy state
+#[derive(Debug, Clone, Serialize, Deserialize)]
+struct TomlRemote {
+    #[serde(default, skip_serializing_if = "BTreeMap::is_empty")]
+    adapters: BTreeMap<String, u32>,
     #[serde(default)]
     files: BTreeMap<String, TomlFileEntry>,
 }
@@ -95,44 +131,46 @@ impl LockfileRepository for TomlLockfileRepository {
         }
 
         let mut lockfile = Lockfile::new();
-        for (key, entry) in toml_lockfile.files {
-            lockfile.set_entry(
-                key,
-                LockfileEntry::with_parts(
-                    entry.hash,
-                    entry.source_layer,
-                    entry.source_layer_path.map(|p| parse_lockfile_path(&p)),
-                    entry.source_asset,
-                    entry.source_file.map(|p| parse_lockfile_path(&p)),
-                    entry.overrides,
-                )
-                .with_binary(entry.is_binary),
-            );
+        read_state(&mut lockfile, toml_lockfile.adapters, toml_lockfile.files);
+        for (source, pin) in toml_lockfile.layers {
+            lockfile.set_layer_pin(source, LayerPin::new(pin.commit, pin.hash));
+        }
+        for (name, remote) in toml_lockfile.remotes {
+            read_state(lockfile.remote_mut(&name), remote.adapters, remote.files);
         }
 
         Ok(lockfile)
     }
 
     fn save(&self, lockfile: &Lockfile, path: &Path) -> Result<(), LockfileError> {
-        let mut files = BTreeMap::new();
-        for (key, entry) in lockfile.entries() {
-            files.insert(
-                key.to_string(),
-                TomlFileEntry {
-                    hash: entry.hash().to_string(),
-                    source_layer: entry.source_layer().map(|s| s.to_string()),
-                    source_layer_path: entry.source_layer_path().map(normalize_lockfile_path),
-                    source_asset: entry.source_asset().map(|s| s.to_string()),
-                    source_file: entry.source_file().map(normalize_lockfile_path),
-                    overrides: entry.overrides().map(|s| s.to_string()),
-                    is_binary: entry.is_binary(),
-                },
-            );
-        }
-
         let toml_lockfile = TomlLockfile {
             version: lockfile.version(),
-            files,
+            adapters: write_adapters(lockfile),
+            layers: lockfile
+                .layer_pins()
+                .map(|(source, pin)| {
+                    (
+                        source.to_string(),
+                        TomlLayerPin {
+                            commit: pin.commit().map(str::to_string),
+                            hash: pin.hash().to_string(),
+                        },
+                    )
+                })
+                .collect(),
+            files: write_files(lockfile),
+            remotes: lockfile
+                .remotes()
+                .map(|(name, remote)| {
+                    (
+                        name.to_string(),
+                        TomlRemote {
+                            adapters: write_adapters(remote),
+                            files: write_files(remote),
+                        },
+                    )
+                })
+                .collect(),
         };
 
         let content = toml::to_string_pretty(&toml_lockfile)
@@ -154,6 +192,78 @@ impl LockfileRepository for TomlLockfileRepository {
     }
 }
 
+/// Fill adapter versions and file entries (top level or one remote's section)
+fn read_state(
+    lockfile: &mut Lockfile,
+    adapters: BTreeMap<String, u32>,
+    files: BTreeMap<String, TomlFileEntry>,
+) {
+    for (id, version) in adapters {
+        // Ignore targets this build can't name (lockfile from a newer Calvin);
+        // custom targets are recorded under their declared name
+        if let Ok(target) = id.parse::<Target>() {
+            lockfile.set_adapter_version(target, version);
+        }
+    }
+    for (key, entry) in files {
+        lockfile.set_entry(
+            key,
+            LockfileEntry::with_parts(
+                entry.hash,
+                entry.source_layer,
+                entry.source_layer_path.map(|p| parse_lockfile_path(&p)),
+                entry.source_asset,
+                entry.source_file.map(|p| parse_lockfile_path(&p)),
+                entry.overrides,
+            )
+            .with_includes(
+                entry
+                    .includes
+                    .iter()
+                    .map(|p| parse_lockfile_path(p))
+                    .collect(),
+            )
+            .with_contributing_layers(entry.contributing_layers)
+            .with_binary(entry.is_binary)
+            .with_owned_keys(entry.owned_keys),
+        );
+    }
+}
+
+fn write_adapters(lockfile: &Lockfile) -> BTreeMap<String, u32> {
+    lockfile
+        .adapter_versions()
+        .map(|(id, version)| (id.to_string(), version))
+        .collect()
+}
+
+fn write_files(lockfile: &Lockfile) -> BTreeMap<String, TomlFileEntry> {
+    lockfile
+        .entries()
+        .map(|(key, entry)| {
+            (
+                key.to_string(),
+                TomlFileEntry {
+                    hash: entry.hash().to_string(),
+                    source_layer: entry.source_layer().map(|s| s.to_string()),
+                    source_layer_path: entry.source_layer_path().map(normalize_lockfile_path),
+                    source_asset: entry.source_asset().map(|s| s.to_string()),
+                    source_file: entry.source_file().map(normalize_lockfile_path),
+                    overrides: entry.overrides().map(|s| s.to_string()),
+                    includes: entry
+                        .includes()
+                        .iter()
+                        .map(|p| normalize_lockfile_path(p))
+                        .collect(),
+                    contributing_layers: entry.contributing_layers().to_vec(),
+                    is_binary: entry.is_binary(),
+                    owned_keys: entry.owned_keys().to_vec(),
+                },
+            )
+        })
+        .collect()
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -359,4 +469,144 @@ hash = "sha256:abc"
         assert!(msg.contains("lockfile format incompatible"));
         assert!(msg.contains("calvin migrate"));
     }
+
+    #[test]
+    fn save_and_load_roundtrip_preserves_owned_keys() {
+        let dir = tempdir().unwrap();
+        let lockfile_path = dir.path().join("calvin.lock");
+        let repo = TomlLockfileRepository::new();
+
+        let mut lockfile = Lockfile::new();
+        lockfile.set_entry(
+            "project:.mcp.json",
+            LockfileEntry::new("sha256:abc").with_owned_keys(vec!["/mcpServers/github".into()]),
+        );
+        lockfile.set("project:.claude/commands/test.md", "sha256:def");
+        repo.save(&lockfile, &lockfile_path).unwrap();
+
+        let content = std::fs::read_to_string(&lockfile_path).unwrap();
+        assert_eq!(content.matches("owned_keys").count(), 1);
+
+        let loaded = repo.load(&lockfile_path).unwrap();
+        let entry = loaded.get("project:.mcp.json").unwrap();
+        assert_eq!(entry.owned_keys(), ["/mcpServers/github".to_string()]);
+        assert!(entry.is_merged());
+        assert!(!loaded
+            .get("project:.claude/commands/test.md")
+            .unwrap()
+            .is_merged());
+    }
+
+    #[test]
+    fn save_and_load_roundtrip_preserves_includes() {
+        let dir = tempdir().unwrap();
+        let lockfile_path = dir.path().join("calvin.lock");
+        let repo = TomlLockfileRepository::new();
+
+        let mut lockfile = Lockfile::new();
+        lockfile.set_entry(
+            "project:.cursor/rules/security/RULE.md",
+            LockfileEntry::new("sha256:abc").with_includes(vec![PathBuf::from(
+                "/home/me/.calvin/.promptpack/partials/secrets.md",
+            )]),
+        );
+        lockfile.set("project:.cursor/rules/style/RULE.md", "sha256:def");
+        repo.save(&lockfile, &lockfile_path).unwrap();
+
+        let content = std::fs::read_to_string(&lockfile_path).unwrap();
+        assert_eq!(content.matches("includes").count(), 1);
+        assert!(
+            content.contains(r#"includes = ["/home/me/.calvin/.promptpack/partials/secrets.md"]"#)
+        );
+
+        let loaded = repo.load(&lockfile_path).unwrap();
+        assert_eq!(
+            loaded
+                .get("project:.cursor/rules/security/RULE.md")
+                .unwrap()
+                .includes(),
+            [PathBuf::from(
+                "/home/me/.calvin/.promptpack/partials/secrets.md"
+            )]
+        );
+    }
+
+    #[test]
+    fn save_and_load_roundtrip_preserves_adapter_versions() {
+        let dir = tempdir().unwrap();
+        let lockfile_path = dir.path().join("calvin.lock");
+        let repo = TomlLockfileRepository::new();
+
+        let mut lockfile = Lockfile::new();
+        lockfile.set("project:.cursor/rules/a.mdc", "sha256:abc");
+        lockfile.set_adapter_version(Target::Cursor, 1);
+        lockfile.set_adapter_version(Target::ClaudeCode, 2);
+        repo.save(&lockfile, &lockfile_path).unwrap();
+
+        let content = std::fs::read_to_string(&lockfile_path).unwrap();
+        assert!(content.contains("[adapters]"), "{}", content);
+        assert!(content.contains("claude-code = 2"), "{}", content);
+
+        let loaded = repo.load(&lockfile_path).unwrap();
+        assert_eq!(loaded.adapter_version(Target::Cursor), Some(1));
+        assert_eq!(loaded.adapter_version(Target::ClaudeCode), Some(2));
+    }
+
+    #[test]
+    fn save_and_load_roundtrip_preserves_layer_pins() {
+        let dir = tempdir().unwrap();
+        let lockfile_path = dir.path().join("calvin.lock");
+        let repo = TomlLockfileRepository::new();
+
+        let mut lockfile = Lockfile::new();
+        lockfile.set_layer_pin(
+            "git+file:///srv/team.git#v1.2",
+            LayerPin::new(Some("0123abcd".to_string()), "sha256:aaa"),
+        );
+        lockfile.set_layer_pin("/srv/team.tar.gz", LayerPin::new(None, "sha256:bbb"));
+        repo.save(&lockfile, &lockfile_path).unwrap();
+
+        let content = std::fs::read_to_string(&lockfile_path).unwrap();
+        assert!(
+            content.contains("[layers.\"git+file:///srv/team.git#v1.2\"]"),
+            "{}",
+            content
+        );
+        assert!(content.contains("commit = \"0123abcd\""), "{}", content);
+
+        let loaded = repo.load(&lockfile_path).unwrap();
+        let pin = loaded.layer_pin("git+file:///srv/team.git#v1.2").unwrap();
+        assert_eq!(pin.commit(), Some("0123abcd"));
+        assert_eq!(pin.hash(), "sha256:aaa");
+        let pin = loaded.layer_pin("/srv/team.tar.gz").unwrap();
+        assert_eq!(pin.commit(), None);
+        assert_eq!(pin.hash(), "sha256:bbb");
+    }
+
+    #[test]
+    fn save_and_load_roundtrip_keeps_remote_state_apart() {
+        let dir = tempdir().unwrap();
+        let lockfile_path = dir.path().join("calvin.lock");
+        let repo = TomlLockfileRepository::new();
+
+        let mut lockfile = Lockfile::new();
+        lockfile.set("project:.cursor/rules/local/RULE.md", "sha256:local");
+        let web = lockfile.remote_mut("web-1");
+        web.set("project:.cursor/rules/style/RULE.md", "sha256:web");
+        web.set_adapter_version(Target::Cursor, 2);
+        repo.save(&lockfile, &lockfile_path).unwrap();
+
+        let content = std::fs::read_to_string(&lockfile_path).unwrap();
+        assert!(content.contains("[remotes.web-1.files."), "{}", content);
+
+        let loaded = repo.load(&lockfile_path).unwrap();
+        assert_eq!(loaded.len(), 1);
+        let web = loaded.remote("web-1").unwrap();
+        assert_eq!(
+            web.get_hash("project:.cursor/rules/style/RULE.md"),
+            Some("sha256:web")
+        );
+        assert_eq!(web.adapter_version(Target::Cursor), Some(2));
+        assert!(loaded.remote("web-2").is_none());
+    }
 }
@@ -0,0 +1,97 @@
+//! MCP server definition loading
+//!
+//! Reads `<promptpack>/mcp/*.toml`. Each file defines one server; the file stem
+//! is the server name.
+
+use anyhow::{Context, Result};
+use std::path::Path;
+
+use crate::config::McpServerConfig;
+use crate::domain::entities::McpServer;
+
+/// Load MCP server definitions from `<source>/mcp/*.toml`, sorted by name.
+///
+/// A missing `mcp/` directory yields an empty list.
+pub(crate) fn load_mcp_servers(source: &Path) -> Result<Vec<McpServer>> {
+    let dir = source.join("mcp");
+    if !dir.is_dir() {
+        return Ok(Vec::new());
+    }
+
+    let mut servers = Vec::new();
+    for entry in std::fs::read_dir(&dir)
+        .with_context(|| format!("Failed to read MCP directory {}", dir.display()))?
+    {
+        let path = entry?.path();
+        if !path.is_file() || path.extension().and_then(|e| e.to_str()) != Some("toml") {
+            continue;
+        }
+        let Some(name) = path.file_stem().and_then(|s| s.to_str()) else {
+            continue;
+        };
+
+        let content = std::fs::read_to_string(&path)
+            .with_context(|| format!("Failed to read {}", path.display()))?;
+        let config: McpServerConfig = toml::from_str(&content)
+            .with_context(|| format!("Invalid MCP server definition {}", path.display()))?;
+        servers.push(config.into_server(name));
+    }
+
+    servers.sort_by(|a, b| a.name().cmp(b.name()));
+    Ok(servers)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::domain::value_objects::Target;
+    use tempfile::tempdir;
+
+    #[test]
+    fn missing_mcp_dir_yields_no_servers() {
+        let dir = tempdir().unwrap();
+        assert!(load_mcp_servers(dir.path()).unwrap().is_empty());
+    }
+
+    #[test]
+    fn loads_servers_named_after_file_stem() {
+        let dir = tempdir().unwrap();
+        std::fs::create_dir_all(dir.path().join("mcp")).unwrap();
+        std::fs::write(
+            dir.path().join("mcp/github.toml"),
+            r#"
+command = "npx"
+args = ["-y", "@modelcontextprotocol/server-github"]
+targets = ["claude-code", "cursor"]
+
+[env]
+GITHUB_TOKEN = "${GITHUB_TOKEN}"
+"#,
+        )
+        .unwrap();
+        std::fs::write(dir.path().join("mcp/README.md"), "ignored").unwrap();
+
+        let servers = load_mcp_servers(dir.path()).unwrap();
+
+        assert_eq!(servers.len(), 1);
+        let github = &servers[0];
+        assert_eq!(github.name(), "github");
+        assert_eq!(github.command(), "npx");
+        assert_eq!(github.args().len(), 2);
+        assert_eq!(github.env()["GITHUB_TOKEN"], "${GITHUB_TOKEN}");
+        assert_eq!(
+            github.targets(),
+            Some(&[Target::ClaudeCode, Target::Cursor][..])
+        );
+    }
+
+    #[test]
+    fn invalid_definition_is_an_error() {
+        let dir = tempdir().unwrap();
+        std::fs::create_dir_all(dir.path().join("mcp")).unwrap();
+        std::fs::write(dir.path().join("mcp/broken.toml"), "args = []").unwrap();
+
+        let err = load_mcp_servers(dir.path()).unwrap_err();
+        assert!(err.to_string().contains("broken.toml"));
+    }
+}
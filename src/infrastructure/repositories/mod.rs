@@ -4,6 +4,8 @@
 
 mod asset;
 mod lockfile;
+mod mcp;
+mod partials;
 mod registry;
 
 pub use asset::FsAssetRepository;
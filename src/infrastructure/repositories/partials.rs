@@ -0,0 +1,85 @@
+//! Partial loading
+//!
+//! Reads `<promptpack>/partials/**`. Every text file is a partial, keyed by its
+//! path relative to the promptpack root (e.g. `partials/secrets.md`).
+
+use anyhow::{Context, Result};
+use std::path::Path;
+
+use crate::domain::entities::Partial;
+
+/// Load partials from `<source>/partials/`, sorted by path.
+///
+/// A missing `partials/` directory yields an empty list. Hidden files and
+/// directories are skipped.
+pub(crate) fn load_partials(source: &Path) -> Result<Vec<Partial>> {
+    let dir = source.join("partials");
+    if !dir.is_dir() {
+        return Ok(Vec::new());
+    }
+
+    let mut partials = Vec::new();
+    load_recursive(source, &dir, &mut partials)?;
+    partials.sort_by(|a, b| a.path().cmp(b.path()));
+    Ok(partials)
+}
+
+fn load_recursive(source: &Path, dir: &Path, partials: &mut Vec<Partial>) -> Result<()> {
+    for entry in std::fs::read_dir(dir)
+        .with_context(|| format!("Failed to read partials directory {}", dir.display()))?
+    {
+        let path = entry?.path();
+        let hidden = path
+            .file_name()
+            .and_then(|n| n.to_str())
+            .is_some_and(|n| n.starts_with('.'));
+        if hidden {
+            continue;
+        }
+        if path.is_dir() {
+            load_recursive(source, &path, partials)?;
+            continue;
+        }
+
+        let content = std::fs::read_to_string(&path)
+            .with_context(|| format!("Failed to read partial {}", path.display()))?;
+        let relative = path.strip_prefix(source).unwrap_or(&path);
+        let include_path = relative
+            .components()
+            .map(|c| c.as_os_str().to_string_lossy())
+            .collect::<Vec<_>>()
+            .join("/");
+        partials.push(Partial::new(include_path, content));
+    }
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use tempfile::tempdir;
+
+    #[test]
+    fn missing_partials_dir_yields_no_partials() {
+        let dir = tempdir().unwrap();
+        assert!(load_partials(dir.path()).unwrap().is_empty());
+    }
+
+    #[test]
+    fn loads_nested_partials_by_relative_path() {
+        let dir = tempdir().unwrap();
+        std::fs::create_dir_all(dir.path().join("partials/testing")).unwrap();
+        std::fs::write(dir.path().join("partials/secrets.md"), "No secrets.\n").unwrap();
+        std::fs::write(dir.path().join("partials/testing/rust.md"), "cargo test\n").unwrap();
+        std::fs::write(dir.path().join("partials/.draft.md"), "hidden").unwrap();
+
+        let partials = load_partials(dir.path()).unwrap();
+
+        let paths: Vec<_> = partials.iter().map(|p| p.path()).collect();
+        assert_eq!(
+            paths,
+            vec!["partials/secrets.md", "partials/testing/rust.md"]
+        );
+        assert_eq!(partials[0].content(), "No secrets.\n");
+    }
+}
@@ -10,5 +10,6 @@ mod remote;
 
 pub use local::{LocalHomeDestination, LocalProjectDestination};
 pub use remote::{
-    detect_strategy, RemoteDestination, RsyncTransfer, ScpTransfer, TransferStrategy,
+    detect_strategy, shell_quote, strategy_for, RemoteDestination, RemoteSession, RsyncTransfer,
+    ScpTransfer, SessionOutput, SessionTransfer, SshOptions, TransferStrategy,
 };
@@ -1,28 +1,35 @@
 //! Remote Sync Destination
 //!
 //! Implements SyncDestination for remote servers via SSH.
-//! Uses a pluggable transfer strategy (rsync preferred, scp fallback).
+//! Single file operations share one SSH session per remote; batch transfers
+//! use a pluggable transfer strategy (rsync preferred, scp fallback).
 
 mod rsync;
 mod scp;
+mod session;
+mod session_transfer;
+mod ssh;
 mod transfer;
 
 pub use rsync::RsyncTransfer;
 pub use scp::ScpTransfer;
-pub use transfer::{detect_strategy, TransferStrategy};
+pub use session::{shell_quote, RemoteSession, SessionOutput};
+pub use session_transfer::SessionTransfer;
+pub use ssh::SshOptions;
+pub use transfer::{detect_strategy, strategy_for, TransferStrategy};
 
+use crate::config::{RemoteConfig, RemoteTransfer};
 use crate::domain::entities::OutputFile;
 use crate::domain::ports::{SyncDestination, SyncDestinationError, SyncOptions, SyncResult};
 use crate::domain::value_objects::Scope;
-use std::io::Write;
 use std::path::{Path, PathBuf};
-use std::process::{Command, Stdio};
-use std::sync::Mutex;
+use std::sync::{Arc, Mutex};
 
 /// Sync destination for remote servers
 ///
-/// Uses SSH for single file operations and a configurable transfer
-/// strategy (rsync or scp) for batch operations.
+/// Runs single file operations over one [`RemoteSession`] and uses a
+/// configurable transfer strategy (rsync, scp or the session) for batch
+/// operations.
 pub struct RemoteDestination {
     /// Remote host (e.g., "ubuntu-server" or "user@host")
     host: String,
@@ -30,6 +37,12 @@ pub struct RemoteDestination {
     remote_path: String,
     /// Source directory (for lockfile path)
     source: PathBuf,
+    /// Port and identity file for every connection
+    ssh: SshOptions,
+    /// Batch transfer strategy
+    transfer: RemoteTransfer,
+    /// Shell shared by every single file operation
+    session: Arc<RemoteSession>,
     /// Cached remote $HOME value (for `~` expansion)
     cached_home: Mutex<Option<String>>,
 }
@@ -39,27 +52,43 @@ impl RemoteDestination {
     ///
     /// Format: "host:path" or "user@host:path"
     pub fn new(remote: &str, source: PathBuf) -> Self {
-        let (host, remote_path) = if let Some((h, p)) = remote.split_once(':') {
-            (h.to_string(), p.to_string())
-        } else {
-            (remote.to_string(), ".".to_string())
-        };
+        Self::from_remote(&RemoteConfig::from_spec(remote), source)
+    }
 
+    /// Create a remote destination from a `[[remotes]]` entry
+    pub fn from_remote(remote: &RemoteConfig, source: PathBuf) -> Self {
+        let ssh = SshOptions {
+            port: remote.port,
+            identity_file: remote.identity_file.clone(),
+        };
+        let session = RemoteSession::ssh(&remote.host, &ssh);
         Self {
-            host,
-            remote_path,
+            host: remote.host.clone(),
+            remote_path: remote.path.clone(),
             source,
+            ssh,
+            transfer: remote.transfer,
+            session: Arc::new(session),
             cached_home: Mutex::new(None),
         }
     }
 
+    /// Use another session (e.g. a local shell in tests)
+    pub fn with_session(mut self, session: RemoteSession) -> Self {
+        self.session = Arc::new(session);
+        self
+    }
+
     /// Build the remote destination string
     fn remote_dest(&self) -> String {
         format!("{}:{}", self.host, self.remote_path)
     }
 
-    fn shell_quote(s: &str) -> String {
-        format!("'{}'", s.replace('\'', "'\\''"))
+    /// Run a command over the session, mapping a dead session to a connection error
+    fn run(&self, command: &str) -> Result<SessionOutput, SyncDestinationError> {
+        self.session
+            .run(command)
+            .map_err(|e| SyncDestinationError::ConnectionError(e.to_string()))
     }
 
     fn remote_home(&self) -> Option<String> {
@@ -69,15 +98,11 @@ impl RemoteDestination {
             }
         }
 
-        let output = Command::new("ssh")
-            .arg(&self.host)
-            .arg("echo $HOME")
-            .output()
-            .ok()?;
-        if !output.status.success() {
+        let output = self.run("echo $HOME").ok()?;
+        if !output.success() {
             return None;
         }
-        let home = String::from_utf8_lossy(&output.stdout).trim().to_string();
+        let home = output.text();
         if home.is_empty() {
             return None;
         }
@@ -151,53 +176,39 @@ impl SyncDestination for RemoteDestination {
         let Ok(remote_file) = self.remote_file(path) else {
             return false;
         };
-        Command::new("ssh")
-            .arg(&self.host)
-            .arg(format!("test -f {}", Self::shell_quote(&remote_file)))
-            .stdout(Stdio::null())
-            .stderr(Stdio::null())
-            .status()
-            .map(|s| s.success())
+        self.run(&format!("test -f {}", shell_quote(&remote_file)))
+            .map(|output| output.success())
             .unwrap_or(false)
     }
 
     fn read(&self, path: &Path) -> Result<String, SyncDestinationError> {
         let remote_file = self.remote_file(path)?;
-        let output = Command::new("ssh")
-            .arg(&self.host)
-            .arg(format!("cat {}", Self::shell_quote(&remote_file)))
-            .output()
-            .map_err(|e| SyncDestinationError::ConnectionError(e.to_string()))?;
+        let output = self.run(&format!("cat {}", shell_quote(&remote_file)))?;
 
-        if !output.status.success() {
+        if !output.success() {
             return Err(SyncDestinationError::IoError(format!(
                 "Failed to read {}: {}",
                 path.display(),
-                String::from_utf8_lossy(&output.stderr)
+                output.text()
             )));
         }
 
-        String::from_utf8(output.stdout).map_err(|e| SyncDestinationError::IoError(e.to_string()))
+        String::from_utf8(output.output).map_err(|e| SyncDestinationError::IoError(e.to_string()))
     }
 
     fn hash(&self, path: &Path) -> Result<String, SyncDestinationError> {
         let remote_file = self.remote_file(path)?;
-        let output = Command::new("ssh")
-            .arg(&self.host)
-            .arg(format!("sha256sum {}", Self::shell_quote(&remote_file)))
-            .output()
-            .map_err(|e| SyncDestinationError::ConnectionError(e.to_string()))?;
+        let output = self.run(&format!("sha256sum {}", shell_quote(&remote_file)))?;
 
-        if !output.status.success() {
+        if !output.success() {
             return Err(SyncDestinationError::IoError(format!(
                 "Failed to hash {}: {}",
                 path.display(),
-                String::from_utf8_lossy(&output.stderr)
+                output.text()
             )));
         }
 
-        let output_str = String::from_utf8_lossy(&output.stdout);
-        if let Some(hash) = output_str.split_whitespace().next() {
+        if let Some(hash) = output.text().split_whitespace().next() {
             Ok(format!("sha256:{}", hash))
         } else {
             Err(SyncDestinationError::IoError(
@@ -207,40 +218,21 @@ impl SyncDestination for RemoteDestination {
     }
 
     fn write_file(&self, path: &Path, content: &str) -> Result<(), SyncDestinationError> {
+        self.write_binary(path, content.as_bytes())
+    }
+
+    fn write_binary(&self, path: &Path, content: &[u8]) -> Result<(), SyncDestinationError> {
         let remote_file = self.remote_file(path)?;
-        let remote_dir = Path::new(&remote_file)
-            .parent()
-            .map(|p| p.display().to_string())
-            .unwrap_or_default();
-
-        let mut child = Command::new("ssh")
-            .arg(&self.host)
-            .arg(format!(
-                "mkdir -p {} && cat > {}",
-                Self::shell_quote(&remote_dir),
-                Self::shell_quote(&remote_file)
-            ))
-            .stdin(Stdio::piped())
-            .stdout(Stdio::null())
-            .stderr(Stdio::piped())
-            .spawn()
+        let output = self
+            .session
+            .write_file(&remote_file, content)
             .map_err(|e| SyncDestinationError::ConnectionError(e.to_string()))?;
 
-        if let Some(stdin) = child.stdin.as_mut() {
-            stdin
-                .write_all(content.as_bytes())
-                .map_err(|e| SyncDestinationError::IoError(e.to_string()))?;
-        }
-
-        let output = child
-            .wait_with_output()
-            .map_err(|e| SyncDestinationError::IoError(e.to_string()))?;
-
-        if !output.status.success() {
+        if !output.success() {
             return Err(SyncDestinationError::IoError(format!(
                 "Failed to write {}: {}",
                 path.display(),
-                String::from_utf8_lossy(&output.stderr)
+                output.text()
             )));
         }
 
@@ -249,15 +241,9 @@ impl SyncDestination for RemoteDestination {
 
     fn delete_file(&self, path: &Path) -> Result<(), SyncDestinationError> {
         let remote_file = self.remote_file(path)?;
-        let status = Command::new("ssh")
-            .arg(&self.host)
-            .arg(format!("rm -f {}", Self::shell_quote(&remote_file)))
-            .stdout(Stdio::null())
-            .stderr(Stdio::null())
-            .status()
-            .map_err(|e| SyncDestinationError::ConnectionError(e.to_string()))?;
+        let output = self.run(&format!("rm -f {}", shell_quote(&remote_file)))?;
 
-        if !status.success() {
+        if !output.success() {
             return Err(SyncDestinationError::IoError(format!(
                 "Failed to delete {}",
                 path.display()
@@ -272,12 +258,14 @@ impl SyncDestination for RemoteDestination {
         outputs: &[OutputFile],
         options: &SyncOptions,
     ) -> Result<SyncResult, SyncDestinationError> {
-        // Detect available transfer strategy
-        let strategy = detect_strategy().ok_or_else(|| {
-            SyncDestinationError::NotAvailable(
-                "No transfer method available. Install rsync (preferred) or ensure scp is in PATH."
-                    .to_string(),
-            )
+        // Use the configured transfer strategy (detected when `auto`)
+        let strategy = strategy_for(self.transfer, &self.session).ok_or_else(|| {
+            SyncDestinationError::NotAvailable(match self.transfer {
+                RemoteTransfer::Auto => "No transfer method available. Install rsync (preferred) or ensure scp is in PATH.".to_string(),
+                RemoteTransfer::Rsync => "rsync is not installed".to_string(),
+                RemoteTransfer::Scp => "scp is not in PATH".to_string(),
+                RemoteTransfer::Session => "Remote session is not available".to_string(),
+            })
         })?;
 
         // Create staging directory
@@ -301,6 +289,7 @@ impl SyncDestination for RemoteDestination {
         strategy.transfer(
             staging_root,
             &self.host,
+            &self.ssh,
             &remote_path,
             &staged_files,
             options,
@@ -394,6 +383,41 @@ mod tests {
         assert_eq!(dest.remote_path, ".");
     }
 
+    #[test]
+    fn from_remote_carries_ssh_options() {
+        let remote = RemoteConfig {
+            port: Some(2222),
+            identity_file: Some(PathBuf::from("/keys/fleet")),
+            transfer: RemoteTransfer::Scp,
+            ..RemoteConfig::from_spec("deploy@web-1:/srv/app")
+        };
+        let dest = RemoteDestination::from_remote(&remote, PathBuf::from("."));
+        assert_eq!(dest.display_name(), "deploy@web-1:/srv/app");
+        assert_eq!(dest.ssh.ssh_args(), ["-p", "2222", "-i", "/keys/fleet"]);
+        assert_eq!(dest.transfer, RemoteTransfer::Scp);
+    }
+
+    #[cfg(unix)]
+    #[test]
+    fn file_operations_share_one_session() {
+        let remote = tempfile::tempdir().unwrap();
+        let spec = format!("host:{}", remote.path().display());
+        let dest = RemoteDestination::new(&spec, PathBuf::from("."))
+            .with_session(RemoteSession::with_command("sh", Vec::new()));
+        let path = Path::new(".claude/commands/test.md");
+
+        assert!(!dest.exists(path));
+        dest.write_file(path, "# Test\n").unwrap();
+        assert!(dest.exists(path));
+        assert_eq!(dest.read(path).unwrap(), "# Test\n");
+        assert_eq!(
+            dest.hash(path).unwrap(),
+            crate::domain::value_objects::ContentHash::from_content("# Test\n").to_string()
+        );
+        dest.delete_file(path).unwrap();
+        assert!(!remote.path().join(path).exists());
+    }
+
     #[test]
     fn parses_user_at_host_format() {
         let dest = RemoteDestination::new("admin@192.168.1.1:~/projects", PathBuf::from("."));
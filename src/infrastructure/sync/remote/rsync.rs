@@ -3,6 +3,7 @@
 //! Uses rsync for efficient incremental file transfers.
 //! This is the preferred method on Unix systems.
 
+use super::ssh::SshOptions;
 use super::transfer::TransferStrategy;
 use crate::domain::ports::{SyncDestinationError, SyncOptions, SyncResult};
 use std::path::{Path, PathBuf};
@@ -40,6 +41,7 @@ impl TransferStrategy for RsyncTransfer {
         &self,
         staging_root: &Path,
         remote_host: &str,
+        ssh: &SshOptions,
         remote_path: &str,
         staged_files: &[PathBuf],
         options: &SyncOptions,
@@ -50,7 +52,7 @@ impl TransferStrategy for RsyncTransfer {
         cmd.arg("-avz")
             .arg("--progress")
             .arg("-e")
-            .arg("ssh")
+            .arg(ssh.rsync_shell())
             .arg(format!("{}/", staging_root.display())) // trailing slash = copy contents
             .arg(&remote_dest)
             .stdin(Stdio::inherit()); // Allow password input
@@ -4,6 +4,7 @@
 //! This is the fallback method when rsync is not available,
 //! particularly on Windows systems with OpenSSH.
 
+use super::ssh::SshOptions;
 use super::transfer::TransferStrategy;
 use crate::domain::ports::{SyncDestinationError, SyncOptions, SyncResult};
 use std::collections::HashSet;
@@ -38,6 +39,7 @@ impl ScpTransfer {
     /// Create remote directories via SSH
     fn create_remote_dirs(
         remote_host: &str,
+        ssh: &SshOptions,
         remote_path: &str,
         dirs: &HashSet<PathBuf>,
         json_mode: bool,
@@ -53,8 +55,8 @@ impl ScpTransfer {
 
         let mkdir_cmd = format!("mkdir -p {}", shell_quote_paths(&dirs_to_create));
 
-        let status = Command::new("ssh")
-            .arg(remote_host)
+        let status = ssh
+            .command(remote_host)
             .arg(&mkdir_cmd)
             .stdout(Stdio::null())
             .stderr(if json_mode {
@@ -98,6 +100,7 @@ impl TransferStrategy for ScpTransfer {
         &self,
         staging_root: &Path,
         remote_host: &str,
+        ssh: &SshOptions,
         remote_path: &str,
         staged_files: &[PathBuf],
         options: &SyncOptions,
@@ -106,12 +109,13 @@ impl TransferStrategy for ScpTransfer {
 
         // Step 1: Create remote directories (scp doesn't do this automatically)
         let parent_dirs = Self::collect_parent_dirs(staged_files);
-        Self::create_remote_dirs(remote_host, remote_path, &parent_dirs, options.json)?;
+        Self::create_remote_dirs(remote_host, ssh, remote_path, &parent_dirs, options.json)?;
 
         // Step 2: Build scp command
         let mut cmd = Command::new("scp");
         cmd.arg("-r") // recursive
             .arg("-p") // preserve timestamps
+            .args(ssh.scp_args())
             .stdin(Stdio::inherit()); // Allow password input
 
         if !options.json && options.verbose {
@@ -0,0 +1,471 @@
+//! Remote Shell Session
+//!
+//! One long-lived `ssh host sh` process shared by every operation on a remote,
+//! instead of a fresh `ssh` per existence check, hash, read or write.
+//!
+//! Protocol: each request is written to the shell's stdin as a command group;
+//! the reply is the command's combined output followed by a marker line
+//! `<marker> <exit status>`. The marker is unique per session, so file
+//! content cannot be mistaken for the end of a reply.
+//!
+//! Stdout and stderr are drained on background threads, so a chatty remote
+//! cannot fill a pipe and stall the shell, and a reply that stops arriving
+//! fails after the session timeout instead of blocking forever.
+
+use super::ssh::SshOptions;
+use std::io::{self, BufRead, BufReader, Read, Write};
+use std::process::{Child, ChildStdin, Command, Stdio};
+use std::sync::mpsc::{self, Receiver, RecvTimeoutError};
+use std::sync::Mutex;
+use std::thread::{self, JoinHandle};
+use std::time::{Duration, SystemTime, UNIX_EPOCH};
+
+/// Longest wait for the next line of a reply before the session is dropped
+const DEFAULT_TIMEOUT: Duration = Duration::from_secs(120);
+
+/// Largest slice of file content sent in one command by [`RemoteSession::write_file`]
+const WRITE_CHUNK_SIZE: usize = 32 * 1024;
+
+/// Reply to one session command
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct SessionOutput {
+    /// Exit status of the command
+    pub status: i32,
+    /// Combined stdout and stderr
+    pub output: Vec<u8>,
+}
+
+impl SessionOutput {
+    pub fn success(&self) -> bool {
+        self.status == 0
+    }
+
+    /// Output as text (lossy), trimmed
+    pub fn text(&self) -> String {
+        String::from_utf8_lossy(&self.output).trim().to_string()
+    }
+}
+
+/// A shell on the remote host, started on first use and reused until dropped
+pub struct RemoteSession {
+    /// Program that starts the shell (`ssh`, or `sh` for a local session)
+    program: String,
+    args: Vec<String>,
+    marker: String,
+    timeout: Duration,
+    process: Mutex<Option<ShellProcess>>,
+}
+
+struct ShellProcess {
+    child: Child,
+    stdin: ChildStdin,
+    /// Stdout lines, read on a background thread
+    lines: Receiver<io::Result<Vec<u8>>>,
+    /// Everything the shell wrote to stderr, collected on a background thread
+    stderr: Option<JoinHandle<String>>,
+}
+
+impl RemoteSession {
+    /// Session over `ssh <options> <host> sh`
+    pub fn ssh(host: &str, ssh: &SshOptions) -> Self {
+        let mut args = ssh.ssh_args();
+        args.push(host.to_string());
+        args.push("sh".to_string());
+        Self::with_command("ssh", args)
+    }
+
+    /// Session over any program that runs a POSIX shell reading commands from stdin
+    ///
+    /// Used with a local `sh` to exercise the protocol without SSH.
+    pub fn with_command(program: impl Into<String>, args: Vec<String>) -> Self {
+        let nonce = SystemTime::now()
+            .duration_since(UNIX_EPOCH)
+            .map(|d| d.as_nanos())
+            .unwrap_or_default();
+        Self {
+            program: program.into(),
+            args,
+            marker: format!("__calvin_{}_{}__", std::process::id(), nonce),
+            timeout: DEFAULT_TIMEOUT,
+            process: Mutex::new(None),
+        }
+    }
+
+    /// Set how long to wait for the next line of a reply (default 120s)
+    pub fn with_timeout(mut self, timeout: Duration) -> Self {
+        self.timeout = timeout;
+        self
+    }
+
+    /// Arguments of the program that starts the shell
+    pub fn args(&self) -> &[String] {
+        &self.args
+    }
+
+    /// Run a shell command (may span several lines) and wait for its reply
+    ///
+    /// Returns `Err` only when the session itself fails (e.g. the connection
+    /// is refused); a failing command is reported through `status`.
+    pub fn run(&self, command: &str) -> io::Result<SessionOutput> {
+        let mut guard = self
+            .process
+            .lock()
+            .map_err(|_| io::Error::other("remote session lock poisoned"))?;
+        if guard.is_none() {
+            *guard = Some(self.spawn()?);
+        }
+        let process = guard.as_mut().expect("session process was just started");
+
+        let request = format!(
+            "{{\n{}\n}} </dev/null 2>&1; printf '\\n%s %s\\n' '{}' \"$?\"\n",
+            command, self.marker
+        );
+        let reply = process
+            .stdin
+            .write_all(request.as_bytes())
+            .and_then(|_| process.stdin.flush())
+            .and_then(|_| read_reply(&process.lines, &self.marker, self.timeout));
+
+        match reply {
+            Ok(Some(output)) => Ok(output),
+            Err(e) if e.kind() == io::ErrorKind::TimedOut => {
+                // The remote stopped answering; kill it and reconnect next time.
+                guard.take().expect("session process exists").kill();
+                Err(e)
+            }
+            Ok(None) | Err(_) => {
+                // The shell is gone; report why and reconnect on the next call.
+                let process = guard.take().expect("session process exists");
+                Err(io::Error::new(
+                    io::ErrorKind::BrokenPipe,
+                    format!(
+                        "remote session closed: {}",
+                        process.close().trim().trim_start_matches('\n')
+                    ),
+                ))
+            }
+        }
+    }
+
+    /// Write a file on the remote, creating parent directories
+    ///
+    /// Content travels as `printf` octal escapes appended to a temporary file
+    /// in chunks of 32 KiB, so any bytes (including NUL)
+    /// survive and no single command grows with the file. The temporary file
+    /// replaces `path` only once every chunk has arrived.
+    pub fn write_file(&self, path: &str, content: &[u8]) -> io::Result<SessionOutput> {
+        let tmp = shell_quote(&format!("{}.calvin-tmp", path));
+        let parent = match path.rfind('/') {
+            Some(0) => "/",
+            Some(i) => &path[..i],
+            None => ".",
+        };
+
+        let mut chunks = content.chunks(WRITE_CHUNK_SIZE).peekable();
+        let mut command = format!(
+            "mkdir -p {} && printf '{}' > {}",
+            shell_quote(parent),
+            printf_escape(chunks.next().unwrap_or_default()),
+            tmp
+        );
+        loop {
+            if chunks.peek().is_none() {
+                command.push_str(&format!(" && mv -f {} {}", tmp, shell_quote(path)));
+            }
+            let output = self.run(&command)?;
+            if !output.success() {
+                let _ = self.run(&format!("rm -f {}", tmp));
+                return Ok(output);
+            }
+            match chunks.next() {
+                Some(chunk) => {
+                    command = format!("printf '{}' >> {}", printf_escape(chunk), tmp);
+                }
+                None => return Ok(output),
+            }
+        }
+    }
+
+    fn spawn(&self) -> io::Result<ShellProcess> {
+        let mut child = Command::new(&self.program)
+            .args(&self.args)
+            .stdin(Stdio::piped())
+            .stdout(Stdio::piped())
+            .stderr(Stdio::piped())
+            .spawn()?;
+        let stdin = child.stdin.take().expect("stdin is piped");
+        let mut stdout = BufReader::new(child.stdout.take().expect("stdout is piped"));
+        let mut stderr_pipe = child.stderr.take().expect("stderr is piped");
+
+        let (sender, lines) = mpsc::channel();
+        thread::spawn(move || loop {
+            let mut line = Vec::new();
+            match stdout.read_until(b'\n', &mut line) {
+                Ok(0) => break,
+                Ok(_) => {
+                    if sender.send(Ok(line)).is_err() {
+                        break;
+                    }
+                }
+                Err(e) => {
+                    let _ = sender.send(Err(e));
+                    break;
+                }
+            }
+        });
+        let stderr = thread::spawn(move || {
+            let mut stderr = Vec::new();
+            let _ = stderr_pipe.read_to_end(&mut stderr);
+            String::from_utf8_lossy(&stderr).into_owned()
+        });
+
+        Ok(ShellProcess {
+            child,
+            stdin,
+            lines,
+            stderr: Some(stderr),
+        })
+    }
+}
+
+impl ShellProcess {
+    /// End the shell and return whatever it wrote to stderr
+    fn close(self) -> String {
+        let Self {
+            mut child,
+            stdin,
+            stderr,
+            ..
+        } = self;
+        drop(stdin);
+        let _ = child.wait();
+        stderr
+            .and_then(|handle| handle.join().ok())
+            .unwrap_or_default()
+    }
+
+    /// Stop a shell that no longer answers
+    fn kill(mut self) -> String {
+        let _ = self.child.kill();
+        self.close()
+    }
+}
+
+impl Drop for RemoteSession {
+    fn drop(&mut self) {
+        if let Ok(mut guard) = self.process.lock() {
+            if let Some(process) = guard.take() {
+                process.close();
+            }
+        }
+    }
+}
+
+/// Read one reply: output lines up to the marker line. `None` on EOF.
+///
+/// Fails with `TimedOut` when no line arrives within `timeout`.
+fn read_reply(
+    lines: &Receiver<io::Result<Vec<u8>>>,
+    marker: &str,
+    timeout: Duration,
+) -> io::Result<Option<SessionOutput>> {
+    let mut output = Vec::new();
+    loop {
+        let line = match lines.recv_timeout(timeout) {
+            Ok(line) => line?,
+            Err(RecvTimeoutError::Disconnected) => return Ok(None),
+            Err(RecvTimeoutError::Timeout) => {
+                return Err(io::Error::new(
+                    io::ErrorKind::TimedOut,
+                    format!("remote session timed out after {}s", timeout.as_secs_f64()),
+                ))
+            }
+        };
+        if let Some(rest) = line.strip_prefix(marker.as_bytes()) {
+            let status = String::from_utf8_lossy(rest).trim().parse().unwrap_or(-1);
+            // Drop the newline printed before the marker.
+            output.pop();
+            return Ok(Some(SessionOutput { status, output }));
+        }
+        output.extend_from_slice(&line);
+    }
+}
+
+/// Quote a string for the remote shell
+pub fn shell_quote(s: &str) -> String {
+    format!("'{}'", s.replace('\'', "'\\''"))
+}
+
+/// Escape bytes for a single-quoted `printf` format string
+///
+/// A leading `-` is escaped too, or `printf` would read the format as an option.
+fn printf_escape(content: &[u8]) -> String {
+    let mut escaped = String::with_capacity(content.len());
+    for (i, &byte) in content.iter().enumerate() {
+        match byte {
+            b'-' if i == 0 => escaped.push_str("\\055"),
+            b'\\' | b'%' | b'\'' => escaped.push_str(&format!("\\{:03o}", byte)),
+            b' '..=b'~' => escaped.push(byte as char),
+            _ => escaped.push_str(&format!("\\{:03o}", byte)),
+        }
+    }
+    escaped
+}
+
+#[cfg(all(test, unix))]
+mod tests {
+    use super::*;
+    use tempfile::tempdir;
+
+    fn local_session() -> RemoteSession {
+        RemoteSession::with_command("sh", Vec::new())
+    }
+
+    #[test]
+    fn run_returns_output_and_status() {
+        let session = local_session();
+
+        let ok = session.run("echo hello").unwrap();
+        assert!(ok.success());
+        assert_eq!(ok.output, b"hello\n");
+
+        let failed = session.run("echo oops >&2; (exit 3)").unwrap();
+        assert_eq!(failed.status, 3);
+        assert_eq!(failed.text(), "oops");
+    }
+
+    #[test]
+    fn run_preserves_output_without_trailing_newline() {
+        let session = local_session();
+        assert_eq!(session.run("printf abc").unwrap().output, b"abc");
+        assert_eq!(session.run("printf ''").unwrap().output, b"");
+    }
+
+    #[test]
+    fn commands_share_one_shell() {
+        let session = local_session();
+        let first = session.run("echo $$").unwrap();
+        let second = session.run("echo $$").unwrap();
+        assert_eq!(first, second);
+    }
+
+    #[test]
+    fn write_file_round_trips_any_bytes() {
+        let dir = tempdir().unwrap();
+        let path = dir.path().join("nested/dir/file.bin");
+        let content: Vec<u8> = b"---\n50% done\\n 'quoted'\n\0\x89PNG\r\n"
+            .iter()
+            .copied()
+            .chain(0..=255)
+            .collect();
+
+        let session = local_session();
+        let written = session
+            .write_file(&path.display().to_string(), &content)
+            .unwrap();
+        assert!(written.success(), "{}", written.text());
+        assert_eq!(std::fs::read(&path).unwrap(), content);
+
+        let read = session
+            .run(&format!("cat {}", shell_quote(&path.display().to_string())))
+            .unwrap();
+        assert_eq!(read.output, content);
+    }
+
+    #[test]
+    fn write_file_sends_large_content_in_chunks() {
+        let dir = tempdir().unwrap();
+        let path = dir.path().join("large.bin");
+        let content: Vec<u8> = (0..WRITE_CHUNK_SIZE * 2 + 7)
+            .map(|i| {
+                if i % WRITE_CHUNK_SIZE == 0 {
+                    b'-'
+                } else {
+                    (i % 251) as u8
+                }
+            })
+            .collect();
+
+        let session = local_session();
+        let written = session
+            .write_file(&path.display().to_string(), &content)
+            .unwrap();
+        assert!(written.success(), "{}", written.text());
+        assert_eq!(std::fs::read(&path).unwrap(), content);
+        assert!(!dir.path().join("large.bin.calvin-tmp").exists());
+    }
+
+    #[test]
+    fn write_file_failure_leaves_target_untouched() {
+        let dir = tempdir().unwrap();
+        let blocker = dir.path().join("blocker");
+        std::fs::write(&blocker, "not a directory").unwrap();
+        let path = blocker.join("file.md");
+
+        let written = local_session()
+            .write_file(&path.display().to_string(), b"content")
+            .unwrap();
+        assert!(!written.success());
+        assert_eq!(
+            std::fs::read_to_string(&blocker).unwrap(),
+            "not a directory"
+        );
+    }
+
+    #[test]
+    fn stderr_is_drained_while_commands_run() {
+        // More stderr than a pipe buffer holds would stall an undrained shell.
+        let script = "head -c 262144 /dev/zero >&2; exec sh".to_string();
+        let session = RemoteSession::with_command("sh", vec!["-c".to_string(), script])
+            .with_timeout(Duration::from_secs(10));
+
+        assert_eq!(session.run("echo ok").unwrap().text(), "ok");
+    }
+
+    #[test]
+    fn unanswered_command_times_out_and_reconnects() {
+        let session = local_session().with_timeout(Duration::from_millis(200));
+
+        let err = session.run("sleep 5").unwrap_err();
+        assert_eq!(err.kind(), io::ErrorKind::TimedOut);
+        assert_eq!(session.run("echo back").unwrap().text(), "back");
+    }
+
+    #[test]
+    fn closed_session_reports_stderr_and_reconnects() {
+        let dir = tempdir().unwrap();
+        let attempts = dir.path().join("attempts");
+        let script = format!(
+            "echo x >> '{}'; echo 'ssh: connect to host down port 22: Connection refused' >&2; exit 255",
+            attempts.display()
+        );
+        let session = RemoteSession::with_command("sh", vec!["-c".to_string(), script]);
+
+        let err = session.run("true").unwrap_err();
+        assert!(err.to_string().contains("Connection refused"), "{}", err);
+        let _ = session.run("true");
+        assert_eq!(
+            std::fs::read_to_string(&attempts).unwrap().lines().count(),
+            2
+        );
+    }
+
+    #[test]
+    fn ssh_session_passes_options_before_host() {
+        let options = SshOptions {
+            port: Some(2222),
+            identity_file: None,
+        };
+        let session = RemoteSession::ssh("user@host", &options);
+        assert_eq!(session.program, "ssh");
+        assert_eq!(session.args, ["-p", "2222", "user@host", "sh"]);
+    }
+
+    #[test]
+    fn printf_escape_keeps_plain_text_readable() {
+        assert_eq!(printf_escape(b"a b"), "a b");
+        assert_eq!(printf_escape(b"100%"), "100\\045");
+        assert_eq!(printf_escape(b"it's\n"), "it\\047s\\012");
+        assert_eq!(printf_escape(b"---\n"), "\\055--\\012");
+    }
+}
@@ -0,0 +1,115 @@
+//! Session Transfer Strategy
+//!
+//! Writes staged files over the remote's shared shell session.
+//! Needs neither rsync nor scp, and opens no connection beyond the session.
+
+use super::session::RemoteSession;
+use super::ssh::SshOptions;
+use super::transfer::TransferStrategy;
+use crate::domain::ports::{SyncDestinationError, SyncOptions, SyncResult};
+use std::path::{Path, PathBuf};
+use std::sync::Arc;
+
+/// Transfer strategy using an open [`RemoteSession`]
+///
+/// The session is already bound to a host, so the host and SSH options
+/// passed to [`TransferStrategy::transfer`] are not used.
+pub struct SessionTransfer {
+    session: Arc<RemoteSession>,
+}
+
+impl SessionTransfer {
+    pub fn new(session: Arc<RemoteSession>) -> Self {
+        Self { session }
+    }
+}
+
+impl TransferStrategy for SessionTransfer {
+    fn name(&self) -> &'static str {
+        "session"
+    }
+
+    fn is_available(&self) -> bool {
+        true
+    }
+
+    fn transfer(
+        &self,
+        staging_root: &Path,
+        _remote_host: &str,
+        _ssh: &SshOptions,
+        remote_path: &str,
+        staged_files: &[PathBuf],
+        _options: &SyncOptions,
+    ) -> Result<SyncResult, SyncDestinationError> {
+        let mut result = SyncResult {
+            written: vec![],
+            skipped: vec![],
+            errors: vec![],
+        };
+
+        for file in staged_files {
+            let content = std::fs::read(staging_root.join(file))
+                .map_err(|e| SyncDestinationError::IoError(e.to_string()))?;
+            let remote_file = format!("{}/{}", remote_path.trim_end_matches('/'), file.display());
+
+            // A dead session fails every file; stop at the first one.
+            let output = self
+                .session
+                .write_file(&remote_file, &content)
+                .map_err(|e| SyncDestinationError::ConnectionError(e.to_string()))?;
+            if output.success() {
+                result.written.push(file.clone());
+            } else {
+                result
+                    .errors
+                    .push(format!("{}: {}", file.display(), output.text()));
+            }
+        }
+
+        Ok(result)
+    }
+}
+
+#[cfg(all(test, unix))]
+mod tests {
+    use super::*;
+    use tempfile::tempdir;
+
+    #[test]
+    fn transfers_staged_files_over_local_session() {
+        let staging = tempdir().unwrap();
+        let remote = tempdir().unwrap();
+        std::fs::create_dir_all(staging.path().join(".claude/commands")).unwrap();
+        std::fs::write(staging.path().join(".claude/commands/test.md"), "# Test\n").unwrap();
+        std::fs::write(staging.path().join("AGENTS.md"), "agents").unwrap();
+
+        let session = Arc::new(RemoteSession::with_command("sh", Vec::new()));
+        let transfer = SessionTransfer::new(session);
+        let staged = vec![
+            PathBuf::from(".claude/commands/test.md"),
+            PathBuf::from("AGENTS.md"),
+        ];
+        let result = transfer
+            .transfer(
+                staging.path(),
+                "unused",
+                &SshOptions::default(),
+                &remote.path().display().to_string(),
+                &staged,
+                &SyncOptions::default(),
+            )
+            .unwrap();
+
+        assert_eq!(result.written, staged);
+        assert!(result.errors.is_empty());
+        assert_eq!(
+            std::fs::read_to_string(remote.path().join(".claude/commands/test.md")).unwrap(),
+            "# Test\n"
+        );
+        assert_eq!(
+            std::fs::read_to_string(remote.path().join("AGENTS.md")).unwrap(),
+            "agents"
+        );
+    }
+}
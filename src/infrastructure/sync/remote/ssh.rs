@@ -0,0 +1,119 @@
+//! SSH Connection Options
+//!
+//! Port and identity file shared by every `ssh`, `rsync -e` and `scp`
+//! invocation for one remote.
+
+use std::path::PathBuf;
+use std::process::Command;
+
+/// Connection options passed to `ssh` and the transfer tools
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct SshOptions {
+    /// SSH port (`ssh -p`, `scp -P`)
+    pub port: Option<u16>,
+    /// Private key (`-i`)
+    pub identity_file: Option<PathBuf>,
+}
+
+impl SshOptions {
+    /// Arguments for `ssh` itself
+    pub fn ssh_args(&self) -> Vec<String> {
+        self.args("-p")
+    }
+
+    /// Arguments for `scp`, which spells the port flag `-P`
+    pub fn scp_args(&self) -> Vec<String> {
+        self.args("-P")
+    }
+
+    /// Remote shell for `rsync -e`
+    pub fn rsync_shell(&self) -> String {
+        std::iter::once("ssh".to_string())
+            .chain(self.ssh_args().iter().map(|a| shell_quote(a)))
+            .collect::<Vec<_>>()
+            .join(" ")
+    }
+
+    /// Start an `ssh <options> <host>` command
+    pub fn command(&self, host: &str) -> Command {
+        let mut cmd = Command::new("ssh");
+        cmd.args(self.ssh_args()).arg(host);
+        cmd
+    }
+
+    fn args(&self, port_flag: &str) -> Vec<String> {
+        let mut args = Vec::new();
+        if let Some(port) = self.port {
+            args.push(port_flag.to_string());
+            args.push(port.to_string());
+        }
+        if let Some(identity) = &self.identity_file {
+            args.push("-i".to_string());
+            args.push(expand_tilde(identity));
+        }
+        args
+    }
+}
+
+/// Expand a leading `~/` to the local home directory (keys live on this machine)
+fn expand_tilde(path: &std::path::Path) -> String {
+    match path.strip_prefix("~") {
+        Ok(rest) => match crate::infrastructure::calvin_home_dir() {
+            Some(home) => home.join(rest).display().to_string(),
+            None => path.display().to_string(),
+        },
+        Err(_) => path.display().to_string(),
+    }
+}
+
+fn shell_quote(s: &str) -> String {
+    if s.chars()
+        .all(|c| c.is_ascii_alphanumeric() || "-_./:@".contains(c))
+    {
+        s.to_string()
+    } else {
+        format!("'{}'", s.replace('\'', "'\\''"))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn default_options_add_no_arguments() {
+        let options = SshOptions::default();
+        assert!(options.ssh_args().is_empty());
+        assert_eq!(options.rsync_shell(), "ssh");
+    }
+
+    #[test]
+    fn port_flag_differs_between_ssh_and_scp() {
+        let options = SshOptions {
+            port: Some(2222),
+            identity_file: Some(PathBuf::from("/keys/fleet")),
+        };
+        assert_eq!(options.ssh_args(), ["-p", "2222", "-i", "/keys/fleet"]);
+        assert_eq!(options.scp_args(), ["-P", "2222", "-i", "/keys/fleet"]);
+    }
+
+    #[test]
+    fn rsync_shell_quotes_paths_with_spaces() {
+        let options = SshOptions {
+            port: None,
+            identity_file: Some(PathBuf::from("/my keys/fleet")),
+        };
+        assert_eq!(options.rsync_shell(), "ssh -i '/my keys/fleet'");
+    }
+
+    #[test]
+    fn command_puts_options_before_host() {
+        let options = SshOptions {
+            port: Some(22),
+            identity_file: None,
+        };
+        let cmd = options.command("user@host");
+        let args: Vec<_> = cmd.get_args().map(|a| a.to_string_lossy()).collect();
+        assert_eq!(args, ["-p", "22", "user@host"]);
+    }
+}
@@ -2,8 +2,12 @@
 //!
 //! Defines the interface for batch file transfers to remote servers.
 
+use super::session::RemoteSession;
+use super::ssh::SshOptions;
+use crate::config::RemoteTransfer;
 use crate::domain::ports::{SyncDestinationError, SyncOptions, SyncResult};
 use std::path::{Path, PathBuf};
+use std::sync::Arc;
 
 /// Strategy for transferring files to a remote server
 pub trait TransferStrategy: Send + Sync {
@@ -18,6 +22,7 @@ pub trait TransferStrategy: Send + Sync {
     /// # Arguments
     /// * `staging_root` - Local directory containing staged files
     /// * `remote_host` - Remote host (e.g., "user@host")
+    /// * `ssh` - Port and identity file for the connection
     /// * `remote_path` - Remote base path (e.g., "/home/user/project")
     /// * `staged_files` - List of file paths that were staged (for result reporting)
     /// * `options` - Sync options (dry_run, verbose, json)
@@ -25,6 +30,7 @@ pub trait TransferStrategy: Send + Sync {
         &self,
         staging_root: &Path,
         remote_host: &str,
+        ssh: &SshOptions,
         remote_path: &str,
         staged_files: &[PathBuf],
         options: &SyncOptions,
@@ -48,6 +54,25 @@ pub fn detect_strategy() -> Option<Box<dyn TransferStrategy>> {
     None
 }
 
+/// Return the strategy configured for a remote (`transfer = "auto" | "rsync" | "scp" | "session"`)
+///
+/// `Auto` detects the best available tool; an explicit choice is used only if installed.
+/// `Session` writes over the remote's already-open `session`.
+pub fn strategy_for(
+    transfer: RemoteTransfer,
+    session: &Arc<RemoteSession>,
+) -> Option<Box<dyn TransferStrategy>> {
+    let strategy: Box<dyn TransferStrategy> = match transfer {
+        RemoteTransfer::Auto => return detect_strategy(),
+        RemoteTransfer::Rsync => Box::new(super::rsync::RsyncTransfer),
+        RemoteTransfer::Scp => Box::new(super::scp::ScpTransfer),
+        RemoteTransfer::Session => Box::new(super::session_transfer::SessionTransfer::new(
+            Arc::clone(session),
+        )),
+    };
+    strategy.is_available().then_some(strategy)
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -57,4 +82,17 @@ mod tests {
         // Just verify it doesn't panic, actual result depends on system
         let _ = detect_strategy();
     }
+
+    #[test]
+    fn strategy_for_explicit_choice_uses_that_tool() {
+        let session = Arc::new(RemoteSession::with_command("sh", Vec::new()));
+        if let Some(strategy) = strategy_for(RemoteTransfer::Scp, &session) {
+            assert_eq!(strategy.name(), "scp");
+        }
+        if let Some(strategy) = strategy_for(RemoteTransfer::Rsync, &session) {
+            assert_eq!(strategy.name(), "rsync");
+        }
+        let strategy = strategy_for(RemoteTransfer::Session, &session).unwrap();
+        assert_eq!(strategy.name(), "session");
+    }
 }
@@ -0,0 +1,51 @@
+//! System Tool Probe
+//!
+//! Implements the ToolProbe port by running binaries from `PATH` and checking
+//! config directories under the home directory.
+
+use std::process::{Command, Stdio};
+
+use crate::domain::ports::ToolProbe;
+use crate::infrastructure::fs::calvin_home_dir;
+
+/// Probe the local machine for installed tools
+#[derive(Debug, Clone, Copy, Default)]
+pub struct SystemToolProbe;
+
+impl SystemToolProbe {
+    pub fn new() -> Self {
+        Self
+    }
+}
+
+impl ToolProbe for SystemToolProbe {
+    fn command_output(&self, program: &str, args: &[&str]) -> Option<String> {
+        let output = Command::new(program)
+            .args(args)
+            .stdin(Stdio::null())
+            .stderr(Stdio::null())
+            .output()
+            .ok()?;
+        output
+            .status
+            .success()
+            .then(|| String::from_utf8_lossy(&output.stdout).into_owned())
+    }
+
+    fn home_path_exists(&self, relative: &str) -> bool {
+        calvin_home_dir().is_some_and(|home| home.join(relative).exists())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn missing_binary_yields_none() {
+        let probe = SystemToolProbe::new();
+        assert!(probe
+            .command_output("calvin-definitely-not-installed", &["--version"])
+            .is_none());
+    }
+}
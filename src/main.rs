@@ -8,7 +8,7 @@ use clap::Parser;
 mod commands;
 mod ui;
 
-use calvin::presentation::{Cli, ColorWhen, Commands};
+use calvin::presentation::{Cli, ColorWhen, Commands, LayersAction};
 
 /// Guard that ensures terminal cursor is visible when dropped.
 /// This fixes dialoguer issue #77 where cursor remains hidden after Ctrl+C.
@@ -82,6 +82,7 @@ fn dispatch(
             yes,
             dry_run,
             cleanup,
+            merge,
             targets,
             layers,
             no_user_layer,
@@ -99,6 +100,7 @@ fn dispatch(
             is_interactive_run(json, yes),
             dry_run,
             cleanup,
+            merge,
             json,
             verbose,
             color,
@@ -184,7 +186,12 @@ fn dispatch(
         Commands::Projects { prune } => {
             commands::projects::cmd_projects(prune, json, verbose, color, no_animation)
         }
-        Commands::Layers => commands::layers::cmd_layers(json, verbose, color, no_animation),
+        Commands::Layers { action } => match action {
+            None => commands::layers::cmd_layers(json, verbose, color, no_animation),
+            Some(LayersAction::Update) => {
+                commands::layers::cmd_layers_update(json, verbose, color, no_animation)
+            }
+        },
         Commands::Provenance { filter } => commands::provenance::cmd_provenance(
             filter.as_deref(),
             json,
@@ -22,6 +22,8 @@ pub enum AssetKind {
     Agent,
     /// Directory-based skills (SKILL.md + supplementals)
     Skill,
+    /// Lifecycle hooks (Claude Code settings `hooks`, optional script supplementals)
+    Hook,
 }
 
 /// Scope of the asset (where it should be installed)
@@ -36,7 +38,7 @@ pub enum Scope {
 }
 
 // Re-export Target from domain layer for backward compatibility
-pub use crate::domain::value_objects::Target;
+pub use crate::domain::value_objects::{Activation, HookEvent, MergeStrategy, Target};
 
 /// Agent tools specification.
 ///
@@ -49,6 +51,27 @@ pub enum ToolsField {
     List(Vec<String>),
 }
 
+impl ToolsField {
+    /// Tool names, trimmed, without empty entries
+    pub fn to_list(&self) -> Vec<String> {
+        match self {
+            ToolsField::String(tools_str) => split_comma_list(tools_str),
+            ToolsField::List(list) => list
+                .iter()
+                .map(|s| s.trim().to_string())
+                .filter(|s| !s.is_empty())
+                .collect(),
+        }
+    }
+}
+
+fn split_comma_list(s: &str) -> Vec<String> {
+    s.split(',')
+        .map(|s| s.trim().to_string())
+        .filter(|s| !s.is_empty())
+        .collect()
+}
+
 /// YAML frontmatter extracted from source files
 ///
 /// Only `description` is required. All other fields have sensible defaults.
@@ -72,6 +95,10 @@ pub struct Frontmatter {
     #[serde(default)]
     pub apply: Option<String>,
 
+    /// When a policy is activated (`always`, `glob`, `model-decision`, `manual`)
+    #[serde(default)]
+    pub activation: Option<Activation>,
+
     #[serde(default, rename = "allowed-tools")]
     pub allowed_tools: Vec<String>,
 
@@ -105,6 +132,23 @@ pub struct Frontmatter {
     #[serde(default)]
     pub subtask: Option<bool>,
 
+    // === Hook fields (`kind: hook`) ===
+    /// Claude Code lifecycle event the hook runs on (e.g. `PreToolUse`)
+    #[serde(default)]
+    pub event: Option<HookEvent>,
+
+    /// Tool-name pattern the hook is limited to (e.g. `Edit|Write`)
+    #[serde(default)]
+    pub matcher: Option<String>,
+
+    /// Shell command the hook runs
+    #[serde(default)]
+    pub command: Option<String>,
+
+    /// Hook timeout in seconds
+    #[serde(default)]
+    pub timeout: Option<u32>,
+
     #[serde(default, rename = "permissionMode")]
     pub permission_mode_camel: Option<String>,
 
@@ -117,6 +161,14 @@ pub struct Frontmatter {
     #[serde(default, rename = "agent-skills")]
     pub agent_skills: Vec<String>,
 
+    /// How this asset combines with the same asset from a lower layer
+    #[serde(default)]
+    pub merge: MergeStrategy,
+
+    /// Per-target field overrides (`overrides: { cursor: { apply: "*.ts" } }`)
+    #[serde(default, skip_serializing_if = "HashMap::is_empty")]
+    pub overrides: HashMap<Target, FrontmatterOverride>,
+
     /// Extra frontmatter fields not recognized by Calvin.
     ///
     /// These are preserved and passed through to adapters so platforms
@@ -135,6 +187,7 @@ impl Frontmatter {
             scope: Scope::default(),
             targets: Vec::new(),
             apply: None,
+            activation: None,
             allowed_tools: Vec::new(),
             tools: None,
             agent_tools: Vec::new(),
@@ -144,10 +197,16 @@ impl Frontmatter {
             opencode_model: None,
             agent: None,
             subtask: None,
+            event: None,
+            matcher: None,
+            command: None,
+            timeout: None,
             permission_mode_camel: None,
             permission_mode: None,
             skills: None,
             agent_skills: Vec::new(),
+            merge: MergeStrategy::default(),
+            overrides: HashMap::new(),
             extra: HashMap::new(),
         }
     }
@@ -155,18 +214,7 @@ impl Frontmatter {
     /// Get effective tools as Vec<String>, merging `tools` (comma-sep) and `agent-tools` (list)
     pub fn effective_tools(&self) -> Vec<String> {
         if let Some(ref tools) = self.tools {
-            match tools {
-                ToolsField::String(tools_str) => tools_str
-                    .split(',')
-                    .map(|s| s.trim().to_string())
-                    .filter(|s| !s.is_empty())
-                    .collect(),
-                ToolsField::List(list) => list
-                    .iter()
-                    .map(|s| s.trim().to_string())
-                    .filter(|s| !s.is_empty())
-                    .collect(),
-            }
+            tools.to_list()
         } else if !self.agent_tools.is_empty() {
             self.agent_tools.clone()
         } else {
@@ -177,11 +225,7 @@ impl Frontmatter {
     /// Get effective skills as Vec<String>, merging `skills` (comma-sep) and `agent-skills` (list)
     pub fn effective_skills(&self) -> Vec<String> {
         if let Some(ref skills_str) = self.skills {
-            skills_str
-                .split(',')
-                .map(|s| s.trim().to_string())
-                .filter(|s| !s.is_empty())
-                .collect()
+            split_comma_list(skills_str)
         } else if !self.agent_skills.is_empty() {
             self.agent_skills.clone()
         } else {
@@ -206,6 +250,9 @@ impl Frontmatter {
                 Target::Antigravity,
                 Target::Codex,
                 Target::OpenCode,
+                Target::GeminiCli,
+                Target::Windsurf,
+                Target::Cline,
             ]
         } else {
             self.targets.clone()
@@ -213,6 +260,123 @@ impl Frontmatter {
     }
 }
 
+/// Frontmatter fields overridden for a single target
+///
+/// Uses the same keys as [`Frontmatter`]; every field is optional. Keys Calvin doesn't
+/// recognize are passed through to that target's generated frontmatter only.
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+pub struct FrontmatterOverride {
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub description: Option<String>,
+
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub name: Option<String>,
+
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub apply: Option<String>,
+
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub activation: Option<Activation>,
+
+    #[serde(
+        default,
+        rename = "allowed-tools",
+        skip_serializing_if = "Option::is_none"
+    )]
+    pub allowed_tools: Option<Vec<String>>,
+
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub tools: Option<ToolsField>,
+
+    #[serde(
+        default,
+        rename = "agent-tools",
+        skip_serializing_if = "Option::is_none"
+    )]
+    pub agent_tools: Option<Vec<String>>,
+
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub model: Option<String>,
+
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub mode: Option<String>,
+
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub temperature: Option<f32>,
+
+    #[serde(
+        default,
+        rename = "opencode-model",
+        skip_serializing_if = "Option::is_none"
+    )]
+    pub opencode_model: Option<String>,
+
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub agent: Option<String>,
+
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub subtask: Option<bool>,
+
+    #[serde(
+        default,
+        rename = "permissionMode",
+        skip_serializing_if = "Option::is_none"
+    )]
+    pub permission_mode_camel: Option<String>,
+
+    #[serde(
+        default,
+        rename = "permission-mode",
+        skip_serializing_if = "Option::is_none"
+    )]
+    pub permission_mode: Option<String>,
+
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub skills: Option<String>,
+
+    #[serde(
+        default,
+        rename = "agent-skills",
+        skip_serializing_if = "Option::is_none"
+    )]
+    pub agent_skills: Option<Vec<String>>,
+
+    /// Unrecognized fields, emitted for this target only
+    #[serde(flatten)]
+    pub extra: HashMap<String, serde_yaml_ng::Value>,
+}
+
+impl FrontmatterOverride {
+    /// Fields that describe the asset itself and cannot vary per target
+    pub const FIXED_FIELDS: &'static [&'static str] =
+        &["kind", "scope", "targets", "merge", "overrides"];
+
+    /// Effective tools override (`tools` wins over `agent-tools`, like [`Frontmatter::effective_tools`])
+    pub fn effective_tools(&self) -> Option<Vec<String>> {
+        match (&self.tools, &self.agent_tools) {
+            (Some(tools), _) => Some(tools.to_list()),
+            (None, Some(list)) => Some(list.clone()),
+            (None, None) => None,
+        }
+    }
+
+    /// Effective skills override (`skills` wins over `agent-skills`)
+    pub fn effective_skills(&self) -> Option<Vec<String>> {
+        match (&self.skills, &self.agent_skills) {
+            (Some(skills), _) => Some(split_comma_list(skills)),
+            (None, Some(list)) => Some(list.clone()),
+            (None, None) => None,
+        }
+    }
+
+    /// Effective permission mode override (`permissionMode` wins over `permission-mode`)
+    pub fn effective_permission_mode(&self) -> Option<&str> {
+        self.permission_mode_camel
+            .as_deref()
+            .or(self.permission_mode.as_deref())
+    }
+}
+
 /// A parsed prompt asset with frontmatter and content
 #[derive(Debug, Clone, PartialEq)]
 pub struct PromptAsset {
@@ -227,6 +391,9 @@ pub struct PromptAsset {
 
     /// Content body (after frontmatter)
     pub content: String,
+
+    /// Line of the source file where the content body starts (1-indexed)
+    pub body_line: usize,
 }
 
 impl PromptAsset {
@@ -242,8 +409,15 @@ impl PromptAsset {
             source_path: source_path.into(),
             frontmatter,
             content: content.into(),
+            body_line: 1,
         }
     }
+
+    /// Builder: set the line where the content body starts
+    pub fn with_body_line(mut self, line: usize) -> Self {
+        self.body_line = line;
+        self
+    }
 }
 
 #[cfg(test)]
@@ -351,13 +525,16 @@ allowed-tools:
         let fm = Frontmatter::new("Test");
         let targets = fm.effective_targets();
 
-        assert_eq!(targets.len(), 6);
+        assert_eq!(targets.len(), 9);
         assert!(targets.contains(&Target::ClaudeCode));
         assert!(targets.contains(&Target::Cursor));
         assert!(targets.contains(&Target::VSCode));
         assert!(targets.contains(&Target::Antigravity));
         assert!(targets.contains(&Target::Codex));
         assert!(targets.contains(&Target::OpenCode));
+        assert!(targets.contains(&Target::GeminiCli));
+        assert!(targets.contains(&Target::Windsurf));
+        assert!(targets.contains(&Target::Cline));
     }
 
     #[test]
@@ -366,7 +543,7 @@ allowed-tools:
         fm.targets = vec![Target::All];
         let targets = fm.effective_targets();
 
-        assert_eq!(targets.len(), 6);
+        assert_eq!(targets.len(), 9);
     }
 
     #[test]
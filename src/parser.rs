@@ -8,7 +8,7 @@ use std::path::Path;
 
 use crate::docs;
 use crate::error::{CalvinError, CalvinResult};
-use crate::models::{Frontmatter, PromptAsset};
+use crate::models::{Activation, AssetKind, Frontmatter, FrontmatterOverride, PromptAsset};
 
 /// Delimiter for frontmatter sections
 const FRONTMATTER_DELIMITER: &str = "---";
@@ -79,10 +79,72 @@ pub fn extract_frontmatter(content: &str, file: &Path) -> CalvinResult<Extracted
 ///
 /// Validates that required fields are present.
 pub fn parse_frontmatter(yaml: &str, file: &Path) -> CalvinResult<Frontmatter> {
-    serde_yaml_ng::from_str(yaml).map_err(|e| CalvinError::InvalidFrontmatter {
-        file: file.to_path_buf(),
-        message: format_yaml_frontmatter_error(yaml, &e),
-    })
+    let frontmatter: Frontmatter =
+        serde_yaml_ng::from_str(yaml).map_err(|e| CalvinError::InvalidFrontmatter {
+            file: file.to_path_buf(),
+            message: format_yaml_frontmatter_error(yaml, &e),
+        })?;
+    validate_overrides(&frontmatter)
+        .and_then(|()| validate_activation(&frontmatter))
+        .map_err(|message| CalvinError::InvalidFrontmatter {
+            file: file.to_path_buf(),
+            message,
+        })?;
+    Ok(frontmatter)
+}
+
+/// Reject per-target overrides of fields that define the asset itself
+fn validate_overrides(frontmatter: &Frontmatter) -> Result<(), String> {
+    let mut targets: Vec<_> = frontmatter.overrides.iter().collect();
+    targets.sort_by_key(|(target, _)| target.id());
+
+    for (target, fields) in targets {
+        if target.is_all() {
+            return Err(
+                "overrides.all is not supported; set shared fields at the top level".to_string(),
+            );
+        }
+        if let Some(field) = FrontmatterOverride::FIXED_FIELDS
+            .iter()
+            .find(|field| fields.extra.contains_key(**field))
+        {
+            return Err(format!(
+                "overrides.{}.{}: `{}` cannot be overridden per target",
+                target.id(),
+                field,
+                field
+            ));
+        }
+    }
+    Ok(())
+}
+
+/// `activation` is policy-only, and `glob` needs `apply` patterns to match
+fn validate_activation(frontmatter: &Frontmatter) -> Result<(), String> {
+    let overridden = frontmatter
+        .overrides
+        .values()
+        .any(|fields| fields.activation.is_some());
+    if frontmatter.kind != AssetKind::Policy && (frontmatter.activation.is_some() || overridden) {
+        return Err("`activation` is only supported for policies (kind: policy)".to_string());
+    }
+
+    if frontmatter.activation == Some(Activation::Glob) && frontmatter.apply.is_none() {
+        return Err("`activation: glob` requires `apply` patterns".to_string());
+    }
+    let mut targets: Vec<_> = frontmatter.overrides.iter().collect();
+    targets.sort_by_key(|(target, _)| target.id());
+    for (target, fields) in targets {
+        let activation = fields.activation.or(frontmatter.activation);
+        let apply = fields.apply.as_ref().or(frontmatter.apply.as_ref());
+        if activation == Some(Activation::Glob) && apply.is_none() {
+            return Err(format!(
+                "overrides.{}: `activation: glob` requires `apply` patterns",
+                target.id()
+            ));
+        }
+    }
+    Ok(())
 }
 
 /// Parse a single PromptPack source file
@@ -94,7 +156,8 @@ pub fn parse_file(path: &Path) -> CalvinResult<PromptAsset> {
     // Derive ID from filename using shared function
     let id = derive_id(path);
 
-    Ok(PromptAsset::new(id, path, frontmatter, extracted.body))
+    Ok(PromptAsset::new(id, path, frontmatter, extracted.body)
+        .with_body_line(extracted.end_line + 1))
 }
 
 /// Parse all PromptPack files in a directory recursively
@@ -126,14 +189,15 @@ fn parse_directory_recursive(
         let path = entry.path();
 
         if path.is_dir() {
-            // Skip the skills directory - skills are directory-based assets and are loaded separately.
+            // Skip the skills and hooks directories - they are loaded separately.
             // This prevents parsing skill supplementals (which often have no frontmatter) as prompt assets.
+            // Partials are snippets included by other assets (`{{> partials/... }}`), not assets.
             if let Ok(rel) = path.strip_prefix(root) {
-                if rel
-                    .components()
-                    .next()
-                    .is_some_and(|c| c == Component::Normal(std::ffi::OsStr::new("skills")))
-                {
+                if rel.components().next().is_some_and(|c| {
+                    c == Component::Normal(std::ffi::OsStr::new("skills"))
+                        || c == Component::Normal(std::ffi::OsStr::new("hooks"))
+                        || c == Component::Normal(std::ffi::OsStr::new("partials"))
+                }) {
                     continue;
                 }
             }
@@ -235,6 +299,7 @@ pub fn derive_id(path: &Path) -> String {
 #[cfg(test)]
 mod tests {
     use super::*;
+    use crate::models::Target;
     use std::fs;
     use std::path::PathBuf;
     use tempfile::tempdir;
@@ -426,6 +491,139 @@ description: A skill
         assert_eq!(assets[0].source_path, PathBuf::from("policies/ok.md"));
     }
 
+    #[test]
+    fn test_parse_directory_skips_partials_directory() {
+        let dir = tempdir().unwrap();
+        let promptpack = dir.path().join(".promptpack");
+        fs::create_dir_all(promptpack.join("partials")).unwrap();
+        fs::write(
+            promptpack.join("ok.md"),
+            "---\ndescription: OK\n---\n{{> partials/secrets.md }}\n",
+        )
+        .unwrap();
+        fs::write(
+            promptpack.join("partials/secrets.md"),
+            "Never commit secrets.\n",
+        )
+        .unwrap();
+
+        let assets = parse_directory(&promptpack).unwrap();
+        assert_eq!(assets.len(), 1);
+        assert_eq!(assets[0].id, "ok");
+    }
+
+    #[test]
+    fn test_parse_frontmatter_overrides_per_target() {
+        let yaml = r#"
+description: Style
+apply: "*.rs"
+overrides:
+  cursor:
+    apply: "*.ts"
+    alwaysApply: false
+  claude:
+    description: Claude style
+"#;
+        let fm = parse_frontmatter(yaml, Path::new("style.md")).unwrap();
+
+        let cursor = &fm.overrides[&Target::Cursor];
+        assert_eq!(cursor.apply.as_deref(), Some("*.ts"));
+        assert_eq!(
+            cursor.extra.get("alwaysApply"),
+            Some(&serde_yaml_ng::Value::Bool(false))
+        );
+        assert_eq!(
+            fm.overrides[&Target::ClaudeCode].description.as_deref(),
+            Some("Claude style")
+        );
+        // Overrides never leak into the shared extra fields.
+        assert!(fm.extra.is_empty());
+    }
+
+    #[test]
+    fn test_parse_frontmatter_rejects_fixed_field_override() {
+        let yaml = "description: X\noverrides:\n  cursor:\n    kind: agent\n";
+        let err = parse_frontmatter(yaml, Path::new("x.md")).unwrap_err();
+        assert!(err.to_string().contains("overrides.cursor.kind"), "{}", err);
+    }
+
+    #[test]
+    fn test_parse_frontmatter_merge_strategy() {
+        use crate::models::MergeStrategy;
+
+        let fm = parse_frontmatter("description: X\nmerge: append\n", Path::new("x.md")).unwrap();
+        assert_eq!(fm.merge, MergeStrategy::Append);
+        assert!(!fm.extra.contains_key("merge"));
+
+        let fm = parse_frontmatter("description: X\n", Path::new("x.md")).unwrap();
+        assert_eq!(fm.merge, MergeStrategy::Replace);
+
+        assert!(parse_frontmatter("description: X\nmerge: mix\n", Path::new("x.md")).is_err());
+        let err = parse_frontmatter(
+            "description: X\noverrides:\n  cursor:\n    merge: append\n",
+            Path::new("x.md"),
+        )
+        .unwrap_err();
+        assert!(
+            err.to_string().contains("overrides.cursor.merge"),
+            "{}",
+            err
+        );
+    }
+
+    #[test]
+    fn test_parse_frontmatter_activation() {
+        let fm = parse_frontmatter(
+            "description: X\nkind: policy\nactivation: model-decision\n",
+            Path::new("x.md"),
+        )
+        .unwrap();
+        assert_eq!(fm.activation, Some(Activation::ModelDecision));
+        assert!(!fm.extra.contains_key("activation"));
+
+        let cases = [
+            (
+                "description: X\nkind: action\nactivation: manual\n",
+                "only supported for policies",
+            ),
+            (
+                "description: X\nkind: policy\nactivation: glob\n",
+                "requires `apply`",
+            ),
+            (
+                "description: X\nkind: policy\noverrides:\n  cursor:\n    activation: glob\n",
+                "overrides.cursor",
+            ),
+            (
+                "description: X\nkind: policy\nactivation: auto\n",
+                "activation",
+            ),
+        ];
+        for (yaml, expected) in cases {
+            let err = parse_frontmatter(yaml, Path::new("x.md")).unwrap_err();
+            assert!(err.to_string().contains(expected), "{}: {}", yaml, err);
+        }
+    }
+
+    #[test]
+    fn test_parse_frontmatter_rejects_unknown_override_target() {
+        let yaml = "description: X\noverrides:\n  Note Pad:\n    apply: \"*\"\n";
+        assert!(matches!(
+            parse_frontmatter(yaml, Path::new("x.md")),
+            Err(CalvinError::InvalidFrontmatter { .. })
+        ));
+    }
+
+    #[test]
+    fn test_parse_frontmatter_keeps_custom_target_names() {
+        // Checked against `[[targets.custom]]` at deploy time
+        let yaml =
+            "description: X\ntargets: [cursor, aider]\noverrides:\n  aider:\n    apply: \"*\"\n";
+        let fm = parse_frontmatter(yaml, Path::new("x.md")).unwrap();
+        assert_eq!(fm.targets, vec![Target::Cursor, Target::custom("aider")]);
+        assert!(fm.overrides.contains_key(&Target::custom("aider")));
+    }
+
     // === TDD Cycle: Full Parse Flow ===
 
     #[test]
@@ -61,7 +61,7 @@ pub enum Commands {
         #[arg(long, conflicts_with_all = ["home", "remote"])]
         project: bool,
 
-        /// Remote destination (user@host:/path)
+        /// Remote destination (user@host:/path), [[remotes]] names (web-1,web-2), or `all`
         #[arg(long, conflicts_with_all = ["home", "project"])]
         remote: Option<String>,
 
@@ -81,7 +81,13 @@ pub enum Commands {
         #[arg(long)]
         cleanup: bool,
 
-        /// Target platforms (will prompt interactively if not specified)
+        /// Merge shared config files (opencode.json, MCP configs, ...) key by key
+        /// instead of replacing them; user-authored keys are preserved
+        #[arg(long)]
+        merge: bool,
+
+        /// Target platforms: built-in ids or `[[targets.custom]]` names (will prompt
+        /// interactively if not specified)
         #[arg(short, long, value_delimiter = ',')]
         targets: Option<Vec<Target>>,
 
@@ -161,7 +167,7 @@ pub enum Commands {
     /// Migrate assets or adapters to newer versions
     #[command(hide = true)]
     Migrate {
-        /// Target format version (e.g., "1.0")
+        /// Target source format version (e.g., "1.0")
         #[arg(long)]
         format: Option<String>,
 
@@ -243,7 +249,10 @@ pub enum Commands {
     },
 
     /// Show the resolved multi-layer stack
-    Layers,
+    Layers {
+        #[command(subcommand)]
+        action: Option<LayersAction>,
+    },
 
     /// Show lockfile provenance for outputs
     Provenance {
@@ -253,6 +262,13 @@ pub enum Commands {
     },
 }
 
+/// `calvin layers` subcommands
+#[derive(Subcommand, Debug, Clone, Copy, PartialEq, Eq)]
+pub enum LayersAction {
+    /// Fetch the latest revision of git/tarball layers and re-pin them in calvin.lock
+    Update,
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -290,6 +306,16 @@ mod tests {
         }
     }
 
+    #[test]
+    fn test_cli_parse_deploy_merge() {
+        let cli = Cli::try_parse_from(["calvin", "deploy", "--merge"]).unwrap();
+        if let Some(Commands::Deploy { merge, .. }) = cli.command {
+            assert!(merge);
+        } else {
+            panic!("Expected Deploy command");
+        }
+    }
+
     #[test]
     fn test_cli_parse_deploy_cleanup() {
         let cli = Cli::try_parse_from(["calvin", "deploy", "--cleanup"]).unwrap();
@@ -300,6 +326,23 @@ mod tests {
         }
     }
 
+    #[test]
+    fn test_cli_parse_layers_update() {
+        let cli = Cli::try_parse_from(["calvin", "layers"]).unwrap();
+        assert!(matches!(
+            cli.command,
+            Some(Commands::Layers { action: None })
+        ));
+
+        let cli = Cli::try_parse_from(["calvin", "layers", "update"]).unwrap();
+        assert!(matches!(
+            cli.command,
+            Some(Commands::Layers {
+                action: Some(LayersAction::Update)
+            })
+        ));
+    }
+
     #[test]
     fn test_cli_parse_check() {
         // v0.2.0 refactor: new unified command (replaces doctor + audit)
@@ -3,12 +3,14 @@
 //! Creates use cases with infrastructure dependencies wired up.
 //! This is the dependency injection point for the application.
 
-use crate::application::{DeployUseCase, DiffUseCase, RegistryUseCase};
+use crate::application::{
+    CheckUseCase, DeployUseCase, DiffUseCase, MigrateFormatUseCase, RegistryUseCase,
+};
 use crate::domain::ports::TargetAdapter;
 use crate::infrastructure::fs::DestinationFs;
 use crate::infrastructure::{
-    all_adapters, ClaudeCodeAdapter, CursorAdapter, FsAssetRepository, LocalFs,
-    TomlLockfileRepository, TomlRegistryRepository,
+    all_adapters, all_adapters_for_config, get_adapter_for_config, FsAssetRepository, LocalFs,
+    SystemToolProbe, TomlLockfileRepository, TomlRegistryRepository,
 };
 use std::path::PathBuf;
 use std::sync::Arc;
@@ -19,6 +21,9 @@ pub type ConcreteDeployUseCase = DeployUseCase<FsAssetRepository, TomlLockfileRe
 /// Type alias for the concrete DiffUseCase with all dependencies
 pub type ConcreteDiffUseCase = DiffUseCase<FsAssetRepository, TomlLockfileRepository, LocalFs>;
 
+/// Type alias for the concrete MigrateFormatUseCase with all dependencies
+pub type ConcreteMigrateFormatUseCase = MigrateFormatUseCase<FsAssetRepository, LocalFs>;
+
 /// Type alias for remote deploy use case
 pub type RemoteDeployUseCase<D> =
     DeployUseCase<FsAssetRepository, TomlLockfileRepository, DestinationFs<D>>;
@@ -81,6 +86,19 @@ pub fn create_diff_use_case_with_adapters(
     DiffUseCase::new(asset_repo, lockfile_repo, file_system, adapters)
 }
 
+/// Create a source format migration use case
+pub fn create_migrate_format_use_case() -> ConcreteMigrateFormatUseCase {
+    MigrateFormatUseCase::new(FsAssetRepository::new(), LocalFs::new())
+}
+
+/// Create a check use case that also checks installed tool versions and asset downgrades
+pub fn create_check_use_case(config: crate::config::Config) -> CheckUseCase {
+    let adapters = all_adapters_for_config(&config);
+    CheckUseCase::new(config)
+        .with_tool_detection(all_adapters(), Box::new(SystemToolProbe::new()))
+        .with_downgrade_check(adapters, Box::new(FsAssetRepository::new()))
+}
+
 /// Create a deploy use case for a remote destination
 ///
 /// Uses the SyncDestination abstraction to support SSH/rsync.
@@ -104,7 +122,7 @@ pub fn create_deploy_use_case_for_remote(
 
 /// Create a deploy use case for a remote destination with specific adapters
 pub fn create_deploy_use_case_for_remote_with_adapters(
-    remote_spec: &str,
+    remote: &crate::config::RemoteConfig,
     source: PathBuf,
     adapters: Vec<Box<dyn TargetAdapter>>,
 ) -> RemoteDeployUseCase<crate::infrastructure::RemoteDestination> {
@@ -112,7 +130,7 @@ pub fn create_deploy_use_case_for_remote_with_adapters(
 
     let asset_repo = FsAssetRepository::new();
     let lockfile_repo = TomlLockfileRepository::new();
-    let destination = Arc::new(RemoteDestination::new(remote_spec, source));
+    let destination = Arc::new(RemoteDestination::from_remote(remote, source));
     let file_system = DestinationFs::new(destination);
 
     let registry_use_case = create_registry_use_case();
@@ -139,11 +157,18 @@ pub fn registry_path() -> std::path::PathBuf {
 /// - Specific targets: Only those adapters
 pub fn create_adapters_for_targets(
     targets: &[crate::domain::value_objects::Target],
+) -> Vec<Box<dyn TargetAdapter>> {
+    create_adapters_for_config(targets, &crate::config::Config::default())
+}
+
+/// Create adapters for specific targets, configured by their `[targets.<name>]` tables
+///
+/// Same target semantics as [`create_adapters_for_targets`].
+pub fn create_adapters_for_config(
+    targets: &[crate::domain::value_objects::Target],
+    config: &crate::config::Config,
 ) -> Vec<Box<dyn TargetAdapter>> {
     use crate::domain::value_objects::Target;
-    use crate::infrastructure::adapters::{
-        AntigravityAdapter, CodexAdapter, OpenCodeAdapter, VSCodeAdapter,
-    };
 
     // Empty list means "no targets" - return empty adapter list
     if targets.is_empty() {
@@ -152,27 +177,13 @@ pub fn create_adapters_for_targets(
 
     // Target::All means all adapters
     if targets.contains(&Target::All) {
-        return all_adapters();
-    }
-
-    let mut adapters: Vec<Box<dyn TargetAdapter>> = Vec::new();
-
-    for target in targets {
-        match target {
-            Target::ClaudeCode => adapters.push(Box::new(ClaudeCodeAdapter::new())),
-            Target::Cursor => adapters.push(Box::new(CursorAdapter::new())),
-            Target::VSCode => adapters.push(Box::new(VSCodeAdapter::new())),
-            Target::Antigravity => adapters.push(Box::new(AntigravityAdapter::new())),
-            Target::Codex => adapters.push(Box::new(CodexAdapter::new())),
-            Target::OpenCode => adapters.push(Box::new(OpenCodeAdapter::new())),
-            Target::All => {
-                // Handled above
-                return all_adapters();
-            }
-        }
+        return all_adapters_for_config(config);
     }
 
-    adapters
+    targets
+        .iter()
+        .filter_map(|target| get_adapter_for_config(*target, config))
+        .collect()
 }
 
 #[cfg(test)]
@@ -214,6 +225,6 @@ mod tests {
     #[test]
     fn create_adapters_for_all_returns_all() {
         let adapters = create_adapters_for_targets(&[Target::All]);
-        assert_eq!(adapters.len(), 6); // All concrete adapters
+        assert_eq!(adapters.len(), 9); // All concrete adapters
     }
 }
@@ -28,5 +28,5 @@ pub mod cli;
 pub mod factory;
 pub mod output;
 
-pub use cli::{Cli, ColorWhen, Commands};
+pub use cli::{Cli, ColorWhen, Commands, LayersAction};
 pub use factory::create_deploy_use_case;
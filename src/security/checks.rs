@@ -5,7 +5,7 @@
 //! # Size Justification
 //!
 //! calvin-no-split: This file is intentionally kept as a single unit because:
-//! - All 8 check functions follow the same pattern (check_xxx)
+//! - All 11 check functions follow the same pattern (check_xxx)
 //! - They share common imports and helper constants
 //! - Splitting by platform would create many tiny files (20-150 lines each)
 //! - The current structure allows easy comparison between platforms
@@ -13,6 +13,7 @@
 use std::path::Path;
 
 use crate::config::{Config, SecurityMode};
+use crate::infrastructure::adapters::windsurf::RULE_CHAR_LIMIT as WINDSURF_RULE_CHAR_LIMIT;
 
 use super::report::DoctorSink;
 use super::types::{CheckStatus, SecurityCheck};
@@ -424,19 +425,22 @@ pub fn check_cursor(root: &Path, mode: SecurityMode, config: &Config, sink: &mut
 pub fn check_vscode(root: &Path, _mode: SecurityMode, sink: &mut impl DoctorSink) {
     let platform = "VS Code";
 
-    // Check .github/copilot-instructions.md exists
+    // Policies go to .github/instructions/ (or .github/copilot-instructions.md when merged)
     let instructions = root.join(".github/copilot-instructions.md");
+    let instructions_dir = root.join(".github/instructions");
     if instructions.exists() {
         sink.add_pass(
             platform,
             "instructions",
             ".github/copilot-instructions.md exists",
         );
+    } else if instructions_dir.is_dir() {
+        sink.add_pass(platform, "instructions", ".github/instructions/ exists");
     } else {
         sink.add_warning(
             platform,
             "instructions",
-            "No copilot-instructions.md found",
+            "No Copilot instructions found",
             Some("Run `calvin deploy` to generate instructions"),
         );
     }
@@ -497,6 +501,81 @@ pub fn check_antigravity(root: &Path, mode: SecurityMode, sink: &mut impl Doctor
     }
 }
 
+pub fn check_windsurf(root: &Path, mode: SecurityMode, sink: &mut impl DoctorSink) {
+    let platform = "Windsurf";
+
+    // Check .windsurf/rules/ exists and every rule fits the character limit
+    let rules_dir = root.join(".windsurf/rules");
+    if rules_dir.exists() {
+        let rules: Vec<_> = std::fs::read_dir(&rules_dir)
+            .map(|rd| {
+                rd.filter_map(Result::ok)
+                    .map(|e| e.path())
+                    .filter(|p| p.extension().is_some_and(|ext| ext == "md"))
+                    .collect()
+            })
+            .unwrap_or_default();
+        let oversized: Vec<String> = rules
+            .iter()
+            .filter(|p| {
+                std::fs::read_to_string(p)
+                    .is_ok_and(|c| c.chars().count() > WINDSURF_RULE_CHAR_LIMIT)
+            })
+            .filter_map(|p| p.file_name().map(|n| n.to_string_lossy().to_string()))
+            .collect();
+        if oversized.is_empty() {
+            let msg = if rules.is_empty() {
+                "OK".to_string()
+            } else {
+                format!("{} synced", rules.len())
+            };
+            sink.add_pass(platform, "rules", &msg);
+        } else {
+            sink.add_warning(
+                platform,
+                "rules_size",
+                &format!(
+                    "Rules over {} characters are truncated: {}",
+                    WINDSURF_RULE_CHAR_LIMIT,
+                    oversized.join(", ")
+                ),
+                Some("Split large policies into several smaller ones"),
+            );
+        }
+    } else {
+        sink.add_warning(
+            platform,
+            "rules",
+            "No rules directory found",
+            Some("Run `calvin deploy --targets windsurf` to generate rules"),
+        );
+    }
+
+    // Check .windsurf/workflows/ exists
+    let workflows_dir = root.join(".windsurf/workflows");
+    if workflows_dir.exists() {
+        let count = std::fs::read_dir(&workflows_dir)
+            .map(|rd| rd.count())
+            .unwrap_or(0);
+        let msg = if count == 0 {
+            "OK".to_string()
+        } else {
+            format!("{} synced", count)
+        };
+        sink.add_pass(platform, "workflows", &msg);
+    }
+
+    // Turbo auto-execution is a per-user setting
+    if mode == SecurityMode::Strict {
+        sink.add_warning(
+            platform,
+            "auto_execution",
+            "Cannot detect Cascade auto-execution level from project",
+            Some("Ensure terminal auto-execution is not set to 'Turbo' in Windsurf settings"),
+        );
+    }
+}
+
 pub fn check_codex(root: &Path, _mode: SecurityMode, sink: &mut impl DoctorSink) {
     let platform = "Codex";
 
@@ -562,6 +641,88 @@ pub fn check_codex(root: &Path, _mode: SecurityMode, sink: &mut impl DoctorSink)
     }
 }
 
+fn count_files_with_extension(dir: &Path, extension: &str) -> usize {
+    std::fs::read_dir(dir)
+        .map(|rd| {
+            rd.filter_map(Result::ok)
+                .filter(|e| e.path().extension().is_some_and(|ext| ext == extension))
+                .count()
+        })
+        .unwrap_or(0)
+}
+
+pub fn check_gemini_cli(root: &Path, mode: SecurityMode, sink: &mut impl DoctorSink) {
+    let platform = "Gemini CLI";
+    let home = dirs::home_dir();
+
+    // Custom commands (prefer project-scope, fall back to user-scope)
+    let project_commands = root.join(".gemini/commands");
+    let user_commands = home.as_ref().map(|h| h.join(".gemini/commands"));
+    if project_commands.exists() {
+        let count = count_files_with_extension(&project_commands, "toml");
+        let msg = if count == 0 {
+            "OK".to_string()
+        } else {
+            format!("{} synced", count)
+        };
+        sink.add_pass(platform, "commands", &msg);
+    } else if let Some(user_commands) = user_commands.filter(|dir| dir.exists()) {
+        let count = count_files_with_extension(&user_commands, "toml");
+        sink.add_pass(
+            platform,
+            "commands",
+            &format!("User commands installed ({} commands)", count),
+        );
+    } else {
+        sink.add_warning(
+            platform,
+            "commands",
+            "No commands directory found",
+            Some("Run `calvin deploy --targets gemini-cli` to generate commands"),
+        );
+    }
+
+    // Context file
+    if root.join("GEMINI.md").exists() {
+        sink.add_pass(platform, "gemini_md", "GEMINI.md exists");
+    }
+
+    // Auto-accepting tool calls bypasses confirmation prompts
+    if mode == SecurityMode::Yolo {
+        return;
+    }
+    let settings_files = std::iter::once(root.join(".gemini/settings.json"))
+        .chain(home.map(|h| h.join(".gemini/settings.json")));
+    for settings_file in settings_files {
+        let Ok(content) = std::fs::read_to_string(&settings_file) else {
+            continue;
+        };
+        let Ok(settings) = serde_json::from_str::<serde_json::Value>(&content) else {
+            sink.add_warning(
+                platform,
+                "settings",
+                &format!("Invalid settings file: {}", settings_file.display()),
+                Some("Check settings.json for JSON syntax errors"),
+            );
+            continue;
+        };
+        let auto_accept = settings
+            .get("autoAccept")
+            .or_else(|| settings.pointer("/tools/autoAccept"))
+            .and_then(|v| v.as_bool())
+            .unwrap_or(false);
+        if auto_accept {
+            let message = format!("autoAccept is enabled in {}", settings_file.display());
+            let recommendation = Some("Disable autoAccept so tool calls require confirmation");
+            if mode == SecurityMode::Strict {
+                sink.add_error(platform, "auto_accept", &message, recommendation);
+            } else {
+                sink.add_warning(platform, "auto_accept", &message, recommendation);
+            }
+        }
+    }
+}
+
 // === User-scope check functions ===
 
 pub fn check_claude_code_user(
@@ -659,3 +820,31 @@ pub fn check_antigravity_user(home: &Path, _mode: SecurityMode, sink: &mut impl
     }
     // No warning for missing user dirs - they're optional
 }
+
+pub fn check_windsurf_user(home: &Path, _mode: SecurityMode, sink: &mut impl DoctorSink) {
+    let platform = "Windsurf (User)";
+
+    // Check ~/.codeium/windsurf/memories/global_rules.md exists
+    if home
+        .join(".codeium/windsurf/memories/global_rules.md")
+        .exists()
+    {
+        sink.add_pass(platform, "global_rules", "global_rules.md exists");
+    }
+
+    // Check ~/.codeium/windsurf/global_workflows/ exists
+    let workflows_dir = home.join(".codeium/windsurf/global_workflows");
+    if workflows_dir.exists() {
+        let count = std::fs::read_dir(&workflows_dir)
+            .map(|rd| {
+                rd.filter_map(Result::ok)
+                    .filter(|e| e.path().extension().is_some_and(|ext| ext == "md"))
+                    .count()
+            })
+            .unwrap_or(0);
+        if count > 0 {
+            sink.add_pass(platform, "workflows", &format!("{} installed", count));
+        }
+    }
+    // No warning for missing user dirs - they're optional
+}
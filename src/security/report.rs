@@ -285,11 +285,18 @@ fn run_doctor_into(
     // Codex checks (already handles both project and user scope)
     checks::check_codex(project_root, mode, sink);
 
+    // Windsurf checks
+    checks::check_windsurf(project_root, mode, sink);
+
+    // Gemini CLI checks (also handles both project and user scope)
+    checks::check_gemini_cli(project_root, mode, sink);
+
     // === User-scope checks (home directory) ===
     if let Some(home) = dirs::home_dir() {
         // Only check user dirs if they exist (user may have deployed --home)
         checks::check_claude_code_user(&home, mode, config, sink);
         checks::check_cursor_user(&home, mode, sink);
         checks::check_antigravity_user(&home, mode, sink);
+        checks::check_windsurf_user(&home, mode, sink);
     }
 }
@@ -350,3 +350,116 @@ additional_allowlist = ["internal-code-server"]
         "additional_allowlist should suppress MCP warnings for allowed servers"
     );
 }
+
+#[test]
+fn test_gemini_cli_commands_and_context_pass() {
+    let dir = tempdir().unwrap();
+    fs::create_dir_all(dir.path().join(".gemini/commands")).unwrap();
+    fs::write(
+        dir.path().join(".gemini/commands/review.toml"),
+        "prompt = \"Review\"\n",
+    )
+    .unwrap();
+    fs::write(dir.path().join("GEMINI.md"), "# Context").unwrap();
+
+    let mut report = DoctorReport::new();
+    super::checks::check_gemini_cli(dir.path(), SecurityMode::Balanced, &mut report);
+
+    let commands = report
+        .checks
+        .iter()
+        .find(|c| c.platform == "Gemini CLI" && c.name == "commands")
+        .unwrap();
+    assert_eq!(commands.status, CheckStatus::Pass);
+    assert_eq!(commands.message, "1 synced");
+    assert!(report
+        .checks
+        .iter()
+        .any(|c| c.name == "gemini_md" && c.status == CheckStatus::Pass));
+}
+
+#[test]
+fn test_gemini_cli_auto_accept_is_flagged() {
+    let dir = tempdir().unwrap();
+    fs::create_dir_all(dir.path().join(".gemini")).unwrap();
+    fs::write(
+        dir.path().join(".gemini/settings.json"),
+        r#"{"tools": {"autoAccept": true}}"#,
+    )
+    .unwrap();
+    let project_settings = dir
+        .path()
+        .join(".gemini/settings.json")
+        .display()
+        .to_string();
+    let find = |report: &DoctorReport| {
+        report
+            .checks
+            .iter()
+            .find(|c| c.name == "auto_accept" && c.message.contains(&project_settings))
+            .map(|c| c.status)
+    };
+
+    let mut report = DoctorReport::new();
+    super::checks::check_gemini_cli(dir.path(), SecurityMode::Balanced, &mut report);
+    assert_eq!(find(&report), Some(CheckStatus::Warning));
+
+    let mut report = DoctorReport::new();
+    super::checks::check_gemini_cli(dir.path(), SecurityMode::Strict, &mut report);
+    assert_eq!(find(&report), Some(CheckStatus::Error));
+
+    let mut report = DoctorReport::new();
+    super::checks::check_gemini_cli(dir.path(), SecurityMode::Yolo, &mut report);
+    assert_eq!(find(&report), None);
+}
+
+#[test]
+fn test_windsurf_oversized_rule_warns() {
+    let dir = tempdir().unwrap();
+    fs::create_dir_all(dir.path().join(".windsurf/rules")).unwrap();
+    fs::write(dir.path().join(".windsurf/rules/small.md"), "ok").unwrap();
+    fs::write(dir.path().join(".windsurf/rules/big.md"), "x".repeat(6_001)).unwrap();
+
+    let mut report = DoctorReport::new();
+    super::checks::check_windsurf(dir.path(), SecurityMode::Balanced, &mut report);
+
+    let check = report
+        .checks
+        .iter()
+        .find(|c| c.platform == "Windsurf" && c.name == "rules_size")
+        .unwrap();
+    assert_eq!(check.status, CheckStatus::Warning);
+    assert!(check.message.contains("big.md"));
+    assert!(!check.message.contains("small.md"));
+}
+
+#[test]
+fn test_windsurf_rules_pass() {
+    let dir = tempdir().unwrap();
+    fs::create_dir_all(dir.path().join(".windsurf/rules")).unwrap();
+    fs::write(dir.path().join(".windsurf/rules/style.md"), "ok").unwrap();
+
+    let mut report = DoctorReport::new();
+    super::checks::check_windsurf(dir.path(), SecurityMode::Balanced, &mut report);
+
+    assert_eq!(report.warnings(), 0);
+    assert!(report
+        .checks
+        .iter()
+        .any(|c| c.name == "rules" && c.message == "1 synced"));
+}
+
+#[test]
+fn test_vscode_instruction_files_pass() {
+    let dir = tempdir().unwrap();
+    fs::create_dir_all(dir.path().join(".github/instructions")).unwrap();
+
+    let mut report = DoctorReport::new();
+    super::checks::check_vscode(dir.path(), SecurityMode::Balanced, &mut report);
+
+    assert_eq!(report.warnings(), 0);
+    assert!(report
+        .checks
+        .iter()
+        .any(|c| c.name == "instructions" && c.status == CheckStatus::Pass));
+}
@@ -6,6 +6,7 @@
 // Note: Generic events (StartEvent, CompleteEvent, etc.) are available for future migrations.
 // Clean-specific events are actively used in src/commands/clean.rs.
 
+use calvin::application::DeployResult;
 use serde::Serialize;
 
 /// Event emitted when a command starts.
@@ -426,6 +427,70 @@ impl ProjectCompleteEvent {
     }
 }
 
+// --- Deploy Remote Events ---
+
+/// Per-host result of `deploy --remote` (one event per remote).
+#[derive(Debug, Clone, Serialize)]
+pub struct RemoteDeployEvent {
+    pub event: &'static str,
+    pub command: &'static str,
+    pub remote: String,
+    pub destination: String,
+    pub status: &'static str,
+    pub written: usize,
+    pub skipped: usize,
+    pub deleted: usize,
+    pub warnings: usize,
+    pub errors: Vec<String>,
+}
+
+impl RemoteDeployEvent {
+    pub fn new(remote: &str, destination: String, result: &DeployResult) -> Self {
+        Self {
+            event: "progress",
+            command: "deploy",
+            remote: remote.to_string(),
+            destination,
+            status: if result.is_success() {
+                "success"
+            } else {
+                "failed"
+            },
+            written: result.written.len(),
+            skipped: result.skipped.len(),
+            deleted: result.deleted.len(),
+            warnings: result.warnings.len(),
+            errors: result.errors.clone(),
+        }
+    }
+}
+
+/// Deploy complete event after all remotes finished.
+#[derive(Debug, Clone, Serialize)]
+pub struct RemoteDeployCompleteEvent {
+    pub event: &'static str,
+    pub command: &'static str,
+    pub status: &'static str,
+    pub remotes: usize,
+    pub failed: usize,
+}
+
+impl RemoteDeployCompleteEvent {
+    pub fn new(remotes: usize, failed: usize) -> Self {
+        Self {
+            event: "complete",
+            command: "deploy",
+            status: match failed {
+                0 => "success",
+                n if n == remotes => "failed",
+                _ => "partial",
+            },
+            remotes,
+            failed,
+        }
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -679,4 +744,35 @@ mod tests {
         assert_eq!(json["skipped"], 2);
         assert_eq!(json["errors"], 1);
     }
+
+    #[test]
+    fn remote_deploy_event_reports_host_status() {
+        let mut result = DeployResult::new();
+        result
+            .written
+            .push(std::path::PathBuf::from(".cursor/rules/a/RULE.md"));
+        let json = serde_json::to_value(RemoteDeployEvent::new(
+            "web-1",
+            "web-1:~/app".to_string(),
+            &result,
+        ))
+        .unwrap();
+        assert_eq!(json["event"], "progress");
+        assert_eq!(json["remote"], "web-1");
+        assert_eq!(json["status"], "success");
+        assert_eq!(json["written"], 1);
+
+        result.errors.push("connection refused".to_string());
+        let json =
+            serde_json::to_value(RemoteDeployEvent::new("web-1", String::new(), &result)).unwrap();
+        assert_eq!(json["status"], "failed");
+        assert_eq!(json["errors"][0], "connection refused");
+    }
+
+    #[test]
+    fn remote_deploy_complete_event_status() {
+        assert_eq!(RemoteDeployCompleteEvent::new(3, 0).status, "success");
+        assert_eq!(RemoteDeployCompleteEvent::new(3, 1).status, "partial");
+        assert_eq!(RemoteDeployCompleteEvent::new(3, 3).status, "failed");
+    }
 }
@@ -6,13 +6,16 @@ use is_terminal::IsTerminal;
 use std::path::Path;
 
 /// All available targets for interactive selection (excludes Target::All)
-pub const ALL_TARGETS: [Target; 6] = [
+pub const ALL_TARGETS: [Target; 9] = [
     Target::ClaudeCode,
     Target::Cursor,
     Target::VSCode,
     Target::Codex,
     Target::OpenCode,
     Target::Antigravity,
+    Target::GeminiCli,
+    Target::Windsurf,
+    Target::Cline,
 ];
 
 /// Get display name for a target
@@ -23,7 +26,11 @@ fn target_display_name(t: &Target) -> &'static str {
         Target::VSCode => "VS Code (.vscode/)",
         Target::Codex => "Codex (.codex/)",
         Target::OpenCode => "OpenCode (.opencode/)",
-        Target::Antigravity => "Antigravity/Gemini (.gemini/)",
+        Target::Antigravity => "Antigravity (.agent/)",
+        Target::GeminiCli => "Gemini CLI (.gemini/)",
+        Target::Windsurf => "Windsurf (.windsurf/)",
+        Target::Cline => "Cline / Roo Code (.clinerules/)",
+        Target::Custom(name) => name.as_str(),
         Target::All => "All platforms",
     }
 }
@@ -37,6 +44,10 @@ fn target_kebab(t: &Target) -> &'static str {
         Target::Codex => "codex",
         Target::OpenCode => "opencode",
         Target::Antigravity => "antigravity",
+        Target::GeminiCli => "gemini-cli",
+        Target::Windsurf => "windsurf",
+        Target::Cline => "cline",
+        Target::Custom(name) => name.as_str(),
         Target::All => "all",
     }
 }
@@ -66,13 +77,18 @@ pub fn select_targets_interactive_with_save(
         return Some(targets);
     }
 
-    let items: Vec<&str> = ALL_TARGETS.iter().map(target_display_name).collect();
+    // Built-in targets, then those declared in `[[targets.custom]]`
+    let choices: Vec<Target> = ALL_TARGETS
+        .into_iter()
+        .chain(config.custom_targets())
+        .collect();
+    let items: Vec<&str> = choices.iter().map(target_display_name).collect();
 
     // Get enabled targets from config for default selection
     let enabled = config.enabled_targets();
 
     // Set defaults based on previously enabled targets in config
-    let defaults: Vec<bool> = ALL_TARGETS.iter().map(|t| enabled.contains(t)).collect();
+    let defaults: Vec<bool> = choices.iter().map(|t| enabled.contains(t)).collect();
 
     // Use CalvinTheme for ●/○ icons
     let theme = CalvinTheme::new(crate::ui::terminal::detect_capabilities().supports_unicode);
@@ -89,7 +105,7 @@ pub fn select_targets_interactive_with_save(
         return None;
     }
 
-    let selected: Vec<Target> = selection.iter().map(|&i| ALL_TARGETS[i]).collect();
+    let selected: Vec<Target> = selection.iter().map(|&i| choices[i]).collect();
 
     // DP-7: Configuration Remembers - save if different
     if let Some(path) = config_path {
@@ -3,6 +3,8 @@ use std::path::Path;
 use crate::ui::blocks::header::CommandHeader;
 use crate::ui::blocks::summary::ResultSummary;
 use crate::ui::primitives::icon::Icon;
+use crate::ui::primitives::text::{truncate_middle, ColoredText};
+use crate::ui::widgets::r#box::{Box, BoxStyle};
 use calvin::application::DeployResult;
 
 pub fn render_deploy_header(
@@ -95,6 +97,97 @@ pub fn render_deploy_summary(
     summary.render(supports_color, supports_unicode)
 }
 
+/// Render one line per remote after a multi-remote deploy
+///
+/// `hosts` holds `(name, destination, result)` in the order the remotes were selected.
+pub fn render_remote_deploy_summary(
+    action: &str,
+    asset_count: usize,
+    target_count: usize,
+    hosts: &[(&str, &str, &DeployResult)],
+    supports_color: bool,
+    supports_unicode: bool,
+) -> String {
+    let failed = hosts.iter().filter(|(_, _, r)| !r.is_success()).count();
+    let (style, icon, title) = if failed == 0 {
+        (
+            BoxStyle::Success,
+            Icon::Success,
+            ColoredText::success(format!("{action} Complete")),
+        )
+    } else {
+        (
+            BoxStyle::Warning,
+            Icon::Warning,
+            ColoredText::warning(format!("{action} Results")),
+        )
+    };
+
+    let mut b = Box::with_title(format!(
+        "{} {}",
+        icon.colored(supports_color, supports_unicode),
+        title.bold().render(supports_color)
+    ))
+    .style(style);
+    b.add_empty();
+    b.add_line(format!(
+        "{} assets → {} targets on {} remotes",
+        asset_count,
+        target_count,
+        hosts.len()
+    ));
+    b.add_empty();
+
+    let width = hosts
+        .iter()
+        .map(|(name, _, _)| name.len())
+        .max()
+        .unwrap_or(0);
+    let dest_width = hosts.iter().map(|(_, d, _)| d.len()).max().unwrap_or(0);
+    for (name, destination, result) in hosts {
+        let (icon, detail) = if result.is_success() {
+            (
+                Icon::Success,
+                format!(
+                    "{} written, {} skipped, {} deleted",
+                    result.written.len(),
+                    result.skipped.len(),
+                    result.deleted.len()
+                ),
+            )
+        } else {
+            (
+                Icon::Error,
+                // Full messages follow on stderr; keep the row readable.
+                format!(
+                    "{} errors: {}",
+                    result.errors.len(),
+                    truncate_middle(result.errors.first().map(String::as_str).unwrap_or(""), 60)
+                ),
+            )
+        };
+        b.add_line(format!(
+            "{} {:<width$}  {}  {}",
+            icon.colored(supports_color, supports_unicode),
+            name,
+            ColoredText::dim(format!("{:<dest_width$}", destination)).render(supports_color),
+            detail
+        ));
+    }
+
+    if failed > 0 {
+        b.add_empty();
+        b.add_line(format!(
+            "{} {} of {} remotes failed",
+            Icon::Warning.colored(supports_color, supports_unicode),
+            failed,
+            hosts.len()
+        ));
+    }
+
+    b.render(supports_color, supports_unicode)
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -124,9 +217,46 @@ mod tests {
             warnings: vec![],
             asset_count: 1,
             output_count: 1,
+            includes: Default::default(),
         };
 
         let rendered = render_deploy_summary("Deploy", 1, 1, &result, false, false);
         assert!(rendered.contains("1 files written"));
     }
+
+    #[test]
+    fn remote_summary_lists_each_host() {
+        let ok = DeployResult {
+            written: vec![PathBuf::from("a")],
+            ..DeployResult::new()
+        };
+        let failed = DeployResult {
+            errors: vec!["connection refused".to_string()],
+            ..DeployResult::new()
+        };
+
+        let rendered = render_remote_deploy_summary(
+            "Deploy",
+            1,
+            1,
+            &[
+                ("web-1", "web-1:~/app", &ok),
+                ("web-2", "web-2:~/app", &failed),
+            ],
+            false,
+            false,
+        );
+        assert!(rendered.contains("Deploy Results"), "{}", rendered);
+        assert!(
+            rendered.contains("web-1  web-1:~/app  1 written"),
+            "{}",
+            rendered
+        );
+        assert!(
+            rendered.contains("web-2  web-2:~/app  1 errors: connection refused"),
+            "{}",
+            rendered
+        );
+        assert!(rendered.contains("1 of 2 remotes failed"), "{}", rendered);
+    }
 }
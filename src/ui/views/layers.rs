@@ -1,4 +1,6 @@
 use calvin::application::layers::LayerQueryResult;
+use calvin::application::LayerUpdate;
+use calvin::domain::entities::LayerPin;
 
 use crate::ui::blocks::header::CommandHeader;
 use crate::ui::blocks::summary::ResultSummary;
@@ -65,3 +67,64 @@ impl<'a> LayersView<'a> {
         out
     }
 }
+
+pub struct LayersUpdateView<'a> {
+    updates: &'a [LayerUpdate],
+}
+
+impl<'a> LayersUpdateView<'a> {
+    pub fn new(updates: &'a [LayerUpdate]) -> Self {
+        Self { updates }
+    }
+
+    pub fn render(&self, supports_color: bool, supports_unicode: bool) -> String {
+        let mut out = String::new();
+
+        let mut header = CommandHeader::new(Icon::Remote, "Calvin Layers Update");
+        header.add("Remote layers", self.updates.len().to_string());
+        out.push_str(&header.render(supports_color, supports_unicode));
+        out.push('\n');
+
+        if self.updates.is_empty() {
+            out.push_str(&format!(
+                "{} {}\n",
+                Icon::Warning.colored(supports_color, supports_unicode),
+                ColoredText::warning("No git or tarball layers configured.").render(supports_color)
+            ));
+            return out;
+        }
+
+        let mut b = Box::with_title("Pinned Layers").style(BoxStyle::Info);
+        for update in self.updates {
+            let revision = match &update.previous {
+                Some(previous) if update.is_changed() => {
+                    format!("{} → {}", short_pin(previous), short_pin(&update.current))
+                }
+                Some(_) => format!("{} (unchanged)", short_pin(&update.current)),
+                None => format!("{} (new)", short_pin(&update.current)),
+            };
+            b.add_line(format!("{}  {}", update.source, revision));
+        }
+        out.push_str(&b.render(supports_color, supports_unicode));
+        out.push('\n');
+
+        let changed = self.updates.iter().filter(|u| u.is_changed()).count();
+        let mut summary = ResultSummary::success("Layers Updated");
+        summary.add_stat("updated", changed);
+        summary.add_stat("unchanged", self.updates.len() - changed);
+        if changed > 0 {
+            summary.with_next_step("Run `calvin deploy` to apply the new layer revisions");
+        }
+        out.push_str(&summary.render(supports_color, supports_unicode));
+
+        out
+    }
+}
+
+/// Abbreviated commit (git) or content hash (tarballs)
+fn short_pin(pin: &LayerPin) -> String {
+    let id = pin
+        .commit()
+        .unwrap_or_else(|| pin.hash().trim_start_matches("sha256:"));
+    id.chars().take(12).collect()
+}
@@ -63,6 +63,17 @@ impl<'a> ProvenanceView<'a> {
                     ColoredText::dim(format!("layer: {layer}")).render(supports_color)
                 ));
             }
+            if !entry.contributing_layers().is_empty() {
+                b.add_line(format!(
+                    "  {} {}",
+                    Icon::Arrow.colored(supports_color, supports_unicode),
+                    ColoredText::dim(format!(
+                        "merged from: {}",
+                        entry.contributing_layers().join(" + ")
+                    ))
+                    .render(supports_color)
+                ));
+            }
             if let Some(asset) = entry.source_asset() {
                 b.add_line(format!(
                     "  {} {}",
@@ -77,6 +88,14 @@ impl<'a> ProvenanceView<'a> {
                     ColoredText::dim(format!("file: {}", file.display())).render(supports_color)
                 ));
             }
+            for include in entry.includes() {
+                b.add_line(format!(
+                    "  {} {}",
+                    Icon::Arrow.colored(supports_color, supports_unicode),
+                    ColoredText::dim(format!("include: {}", include.display()))
+                        .render(supports_color)
+                ));
+            }
             if let Some(overrides) = entry.overrides() {
                 b.add_line(format!(
                     "  {} {}",
@@ -1,18 +1,58 @@
 use crate::ui::widgets::r#box::{Box, BoxStyle};
+use calvin::application::{ToolCompatibility, ToolVersionReport};
 
 pub fn render_version(
     calvin_version: &str,
-    adapters: &[(String, String)],
+    reports: &[ToolVersionReport],
     supports_color: bool,
     supports_unicode: bool,
 ) -> String {
     let mut b = Box::with_style(BoxStyle::Info);
     b.add_line(format!("Calvin v{}", calvin_version));
-    b.add_line("Source Format: 1.0");
+    b.add_line(format!(
+        "Source Format: {}",
+        calvin::domain::value_objects::FormatVersion::CURRENT
+    ));
     b.add_empty();
     b.add_line("Adapters:");
-    for (name, version) in adapters {
-        b.add_line(format!("  - {:<12} v{}", name, version));
+    for report in reports {
+        let mut line = format!(
+            "  - {:<12} v{}",
+            report.target.display_name(),
+            report.output_format_version
+        );
+        if let Some(range) = report.tested_range() {
+            line.push_str(&format!("  (tested: {})", range));
+        }
+        b.add_line(line);
+    }
+
+    b.add_empty();
+    b.add_line("Detected Tools:");
+    let mut any_detected = false;
+    for report in reports {
+        let Some(detected) = &report.detected else {
+            continue;
+        };
+        any_detected = true;
+        let version = detected
+            .version
+            .map(|v| v.to_string())
+            .unwrap_or_else(|| "installed (version unknown)".to_string());
+        let note = match report.compatibility {
+            ToolCompatibility::Untested => "  newer than tested",
+            ToolCompatibility::TooOld => "  older than supported",
+            _ => "",
+        };
+        b.add_line(format!(
+            "  - {:<12} {}{}",
+            report.target.display_name(),
+            version,
+            note
+        ));
+    }
+    if !any_detected {
+        b.add_line("  (none found)");
     }
     b.render(supports_color, supports_unicode)
 }
@@ -20,15 +60,54 @@ pub fn render_version(
 #[cfg(test)]
 mod tests {
     use super::*;
+    use calvin::domain::ports::DetectedTool;
+    use calvin::domain::value_objects::{Target, ToolVersion};
+
+    fn report(
+        detected: Option<DetectedTool>,
+        compatibility: ToolCompatibility,
+    ) -> ToolVersionReport {
+        ToolVersionReport {
+            target: Target::Cursor,
+            output_format_version: 1,
+            min_ide_version: None,
+            max_tested_version: Some(ToolVersion::new(1, 7, 0)),
+            detected,
+            compatibility,
+        }
+    }
 
     #[test]
     fn version_renders_with_themed_borders() {
         let rendered = render_version(
             "0.0.0",
-            &[("Cursor".to_string(), "1".to_string())],
+            &[report(None, ToolCompatibility::NotInstalled)],
             false,
             true,
         );
         assert!(rendered.starts_with('╭'));
+        assert!(rendered.contains("(tested: <= 1.7.x)"));
+        assert!(rendered.contains("(none found)"));
+    }
+
+    #[test]
+    fn version_marks_untested_tools() {
+        let rendered = render_version(
+            "0.0.0",
+            &[report(
+                Some(DetectedTool {
+                    version: Some(ToolVersion::new(2, 1, 0)),
+                    location: "cursor --version".to_string(),
+                }),
+                ToolCompatibility::Untested,
+            )],
+            false,
+            false,
+        );
+        assert!(
+            rendered.contains("2.1.0  newer than tested"),
+            "{}",
+            rendered
+        );
     }
 }
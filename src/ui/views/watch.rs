@@ -36,6 +36,13 @@ pub fn render_watch_event(
             Icon::Arrow.colored(supports_color, supports_unicode),
             path
         ),
+        WatchEvent::PartialChanged { path, assets } => format!(
+            "{} {} Partial {} is included by: {}\n",
+            prefix,
+            Icon::Arrow.colored(supports_color, supports_unicode),
+            path,
+            assets.join(", ")
+        ),
         WatchEvent::SyncStarted => format!(
             "{} {} Syncing...\n",
             prefix,
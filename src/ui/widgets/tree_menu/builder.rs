@@ -96,6 +96,20 @@ pub fn infer_target_from_path(path: &Path) -> String {
         "vscode".to_string()
     } else if path_str.contains(".codex/") || path_str.contains(".codex\\") {
         "codex".to_string()
+    } else if path_str.contains(".windsurf/")
+        || path_str.contains(".windsurf\\")
+        || path_str.contains(".codeium/windsurf/")
+        || path_str.contains(".codeium\\windsurf\\")
+    {
+        "windsurf".to_string()
+    } else if path_str.contains(".clinerules")
+        || path_str.contains(".roomodes")
+        || path_str.contains("Documents/Cline/")
+        || path_str.contains("Documents\\Cline\\")
+    {
+        "cline".to_string()
+    } else if path_str.contains(".gemini/commands/") || path_str.contains(".gemini\\commands\\") {
+        "gemini-cli".to_string()
     } else if path_str.contains(".gemini/") || path_str.contains(".gemini\\") {
         "antigravity".to_string()
     } else if path_str.contains("AGENTS.md")
@@ -206,6 +220,18 @@ mod tests {
             )),
             "antigravity"
         );
+        assert_eq!(
+            infer_target_from_path(&PathBuf::from("/Users/test/.gemini/commands/test.toml")),
+            "gemini-cli"
+        );
+        assert_eq!(
+            infer_target_from_path(&PathBuf::from("/project/.windsurf/rules/test.md")),
+            "windsurf"
+        );
+        assert_eq!(
+            infer_target_from_path(&PathBuf::from("/project/.clinerules/workflows/test.md")),
+            "cline"
+        );
         assert_eq!(
             infer_target_from_path(&PathBuf::from("/project/AGENTS.md")),
             "agents-md"
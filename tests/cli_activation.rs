@@ -0,0 +1,116 @@
+//! Integration tests for policy `activation`: Cursor rule types, Windsurf triggers, and
+//! downgrade warnings on targets that cannot represent a mode.
+
+mod common;
+
+use common::*;
+
+const MANUAL_POLICY: &str = r#"---
+kind: policy
+description: Release checklist
+activation: manual
+---
+Bump the version before tagging.
+"#;
+
+const MODEL_DECISION_POLICY: &str = r#"---
+kind: policy
+description: Database migrations
+activation: model-decision
+apply: "migrations/**"
+---
+Never edit an applied migration.
+"#;
+
+#[test]
+fn cursor_rules_follow_activation() {
+    let env = TestEnv::builder()
+        .with_project_asset("policies/release.md", MANUAL_POLICY)
+        .with_project_asset("policies/migrations.md", MODEL_DECISION_POLICY)
+        .build();
+
+    let result = env.run(&["deploy", "--yes"]);
+    assert!(result.success, "{}", result.combined_output());
+
+    let manual = env.read_deployed_file(".cursor/rules/release/RULE.md");
+    assert!(
+        manual.starts_with("---\nalwaysApply: false\n---\n"),
+        "{}",
+        manual
+    );
+
+    let requested = env.read_deployed_file(".cursor/rules/migrations/RULE.md");
+    assert!(
+        requested.starts_with("---\ndescription: Database migrations\nalwaysApply: false\n---\n"),
+        "{}",
+        requested
+    );
+}
+
+#[test]
+fn windsurf_rules_use_matching_trigger() {
+    let env = TestEnv::builder()
+        .with_project_asset("policies/release.md", MANUAL_POLICY)
+        .with_project_config("[targets]\nenabled = [\"windsurf\"]\n")
+        .build();
+
+    let result = env.run(&["deploy", "--yes"]);
+    assert!(result.success, "{}", result.combined_output());
+
+    let rule = env.read_deployed_file(".windsurf/rules/release.md");
+    assert!(
+        rule.starts_with("---\ntrigger: manual\ndescription: Release checklist\n---\n"),
+        "{}",
+        rule
+    );
+}
+
+#[test]
+fn deploy_and_check_report_unsupported_activation() {
+    let env = TestEnv::builder()
+        .with_project_asset("policies/migrations.md", MODEL_DECISION_POLICY)
+        .with_project_config("[targets]\nenabled = [\"vscode\"]\n")
+        .build();
+
+    let result = env.run(&["deploy", "--yes"]);
+    assert!(result.success, "{}", result.combined_output());
+    assert!(
+        result.combined_output().contains(
+            "Policy 'migrations' activation 'model-decision' is not supported on VS Code; applied as 'manual'"
+        ),
+        "{}",
+        result.combined_output()
+    );
+
+    let instructions = env.read_deployed_file(".github/instructions/migrations.instructions.md");
+    assert!(!instructions.contains("applyTo"), "{}", instructions);
+
+    let result = env.run(&["check"]);
+    assert!(
+        result
+            .combined_output()
+            .contains("Policy 'migrations' activation 'model-decision' is not supported"),
+        "{}",
+        result.combined_output()
+    );
+}
+
+#[test]
+fn activation_on_action_is_rejected() {
+    let env = TestEnv::builder()
+        .with_project_asset(
+            "actions/deploy.md",
+            "---\nkind: action\ndescription: Deploy\nactivation: manual\n---\nDeploy.\n",
+        )
+        .build();
+
+    let result = env.run(&["deploy", "--yes"]);
+    assert!(!result.success);
+    assert!(
+        result
+            .combined_output()
+            .contains("`activation` is only supported for policies"),
+        "{}",
+        result.combined_output()
+    );
+}
@@ -0,0 +1,123 @@
+//! Integration tests for versioned adapters (TD-18): adapter format versions in
+//! `calvin.lock`, `calvin migrate --adapter`, and installed tool detection.
+
+mod common;
+
+use common::*;
+
+#[test]
+fn deploy_records_adapter_format_versions() {
+    let env = TestEnv::builder()
+        .with_project_asset("test.md", ALL_TARGETS_POLICY)
+        .with_project_config(CONFIG_CURSOR_ONLY)
+        .build();
+
+    let result = env.run(&["deploy", "--yes"]);
+    assert!(result.success, "{}", result.combined_output());
+
+    let lockfile = env.read_lockfile();
+    assert!(lockfile.contains("[adapters]"), "{}", lockfile);
+    assert!(lockfile.contains("cursor = 1"), "{}", lockfile);
+    assert!(!lockfile.contains("claude-code ="), "{}", lockfile);
+}
+
+#[test]
+fn migrate_adapter_regenerates_unrecorded_outputs() {
+    let env = TestEnv::builder()
+        .with_project_asset("test.md", ALL_TARGETS_POLICY)
+        .with_project_config(CONFIG_CURSOR_ONLY)
+        .build();
+    assert!(env.run(&["deploy", "--yes"]).success);
+
+    // Simulate a lockfile written before adapter versions were recorded.
+    let lockfile = env.read_lockfile().replace("[adapters]\ncursor = 1\n", "");
+    env.write_project_file("calvin.lock", &lockfile);
+
+    let dry_run = env.run(&["migrate", "--adapter", "cursor", "--dry-run"]);
+    assert!(dry_run.success, "{}", dry_run.combined_output());
+    assert!(
+        dry_run
+            .stdout
+            .contains("Regenerate Cursor outputs (adapter format unrecorded → v1)"),
+        "{}",
+        dry_run.stdout
+    );
+    assert!(!env.read_lockfile().contains("[adapters]"));
+
+    let result = env.run(&["migrate", "--adapter", "cursor"]);
+    assert!(result.success, "{}", result.combined_output());
+    assert!(env.read_lockfile().contains("cursor = 1"));
+
+    let again = env.run(&["migrate", "--adapter", "cursor"]);
+    assert!(again.success, "{}", again.combined_output());
+    assert!(
+        again.stdout.contains("No migration needed"),
+        "{}",
+        again.stdout
+    );
+}
+
+#[test]
+fn migrate_adapter_rejects_unknown_target() {
+    let env = TestEnv::builder()
+        .with_project_asset("test.md", ALL_TARGETS_POLICY)
+        .build();
+
+    let result = env.run(&["migrate", "--adapter", "notepad"]);
+
+    assert!(!result.success, "{}", result.combined_output());
+}
+
+#[test]
+fn version_json_reports_adapter_versions() {
+    let env = TestEnv::builder().build();
+
+    let result = env.run(&["version", "--json"]);
+    assert!(result.success, "{}", result.combined_output());
+
+    let complete = result
+        .stdout
+        .lines()
+        .filter_map(|line| serde_json::from_str::<serde_json::Value>(line).ok())
+        .find(|v| v["event"] == "complete")
+        .expect("complete event");
+    let adapters = complete["data"]["adapters"].as_array().unwrap();
+    let claude = adapters
+        .iter()
+        .find(|a| a["target"] == "claude-code")
+        .expect("claude-code adapter");
+    assert_eq!(claude["output_format_version"], 1);
+    assert!(claude["max_tested_version"].is_string());
+}
+
+#[cfg(unix)]
+#[test]
+fn check_warns_when_installed_tool_is_newer_than_tested() {
+    use std::os::unix::fs::PermissionsExt;
+
+    let env = TestEnv::builder()
+        .with_project_asset("test.md", ALL_TARGETS_POLICY)
+        .build();
+
+    // A fake `claude` binary reporting a far-future version.
+    let bin_dir = env.home_path("bin");
+    std::fs::create_dir_all(&bin_dir).unwrap();
+    let claude = bin_dir.join("claude");
+    std::fs::write(&claude, "#!/bin/sh\necho '99.0.0 (Claude Code)'\n").unwrap();
+    std::fs::set_permissions(&claude, std::fs::Permissions::from_mode(0o755)).unwrap();
+
+    let path = format!(
+        "{}:{}",
+        bin_dir.display(),
+        std::env::var("PATH").unwrap_or_default()
+    );
+    let result = env.run_with_env(&["check"], &[("PATH", path.as_str())]);
+
+    assert!(
+        result
+            .combined_output()
+            .contains("Claude Code 99.0.0 is newer than Calvin's adapter was tested against"),
+        "{}",
+        result.combined_output()
+    );
+}
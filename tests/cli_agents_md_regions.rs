@@ -0,0 +1,118 @@
+//! Integration tests for managed-region writes to `AGENTS.md`.
+//!
+//! VS Code and OpenCode each own one `<!-- calvin:begin <target> -->` region of the
+//! project `AGENTS.md`; hand-written text around the regions is never touched.
+
+mod common;
+
+use common::*;
+
+const AGENTS_CONFIG: &str = r#"
+[targets]
+enabled = ["vscode", "opencode"]
+"#;
+
+const USER_AGENTS_MD: &str = "# Team conventions\n\nWrite tests first.\n";
+
+fn env_with_user_agents_md() -> TestEnv {
+    let env = TestEnv::builder()
+        .with_project_asset("test.md", ALL_TARGETS_POLICY)
+        .with_project_config(AGENTS_CONFIG)
+        .build();
+    env.write_project_file("AGENTS.md", USER_AGENTS_MD);
+    env
+}
+
+#[test]
+fn adapters_share_agents_md_with_hand_written_content() {
+    let env = env_with_user_agents_md();
+
+    let result = env.run(&["deploy", "--yes"]);
+    assert!(result.success, "{}", result.combined_output());
+
+    let agents_md = env.read_deployed_file("AGENTS.md");
+    assert!(agents_md.starts_with(USER_AGENTS_MD), "{}", agents_md);
+    assert!(
+        agents_md.contains("<!-- calvin:begin vscode -->"),
+        "{}",
+        agents_md
+    );
+    assert!(
+        agents_md.contains("<!-- calvin:begin opencode -->"),
+        "{}",
+        agents_md
+    );
+    assert!(
+        agents_md.contains("This policy applies to all supported AI coding assistants."),
+        "{}",
+        agents_md
+    );
+
+    let lockfile = env.read_lockfile();
+    assert!(
+        lockfile.contains("\"vscode\"") && lockfile.contains("\"opencode\""),
+        "{}",
+        lockfile
+    );
+
+    // Redeploying is idempotent
+    let result = env.run(&["deploy", "--yes"]);
+    assert!(result.success, "{}", result.combined_output());
+    assert_eq!(env.read_deployed_file("AGENTS.md"), agents_md);
+}
+
+#[test]
+fn edits_outside_regions_are_kept_and_region_edits_conflict() {
+    let env = env_with_user_agents_md();
+    assert!(env.run(&["deploy", "--yes"]).success);
+
+    // Hand edits outside the regions never conflict, even without --yes
+    let edited = format!("{}\nMore notes.\n", env.read_deployed_file("AGENTS.md"));
+    env.write_project_file("AGENTS.md", &edited);
+    let result = env.run(&["deploy", "--json"]);
+    assert!(result.success, "{}", result.combined_output());
+    assert_eq!(env.read_deployed_file("AGENTS.md"), edited);
+
+    // Edits inside a region are a conflict: skipped unless conflicts are overwritten
+    let tampered = edited.replace("Index of available", "Hand-edited index of");
+    env.write_project_file("AGENTS.md", &tampered);
+    let result = env.run(&["deploy", "--json"]);
+    assert!(result.success, "{}", result.combined_output());
+    assert_eq!(env.read_deployed_file("AGENTS.md"), tampered);
+
+    let result = env.run(&["deploy", "--yes"]);
+    assert!(result.success, "{}", result.combined_output());
+    assert_eq!(env.read_deployed_file("AGENTS.md"), edited);
+}
+
+#[test]
+fn clean_strips_only_the_regions() {
+    let env = env_with_user_agents_md();
+    assert!(env.run(&["deploy", "--yes"]).success);
+
+    let result = env.run(&["clean", "--all", "--yes"]);
+    assert!(result.success, "{}", result.combined_output());
+    assert_eq!(env.read_deployed_file("AGENTS.md"), USER_AGENTS_MD);
+}
+
+#[test]
+fn dropping_a_target_removes_its_region() {
+    let env = env_with_user_agents_md();
+    assert!(env.run(&["deploy", "--yes"]).success);
+
+    env.write_project_file(
+        ".promptpack/config.toml",
+        "[targets]\nenabled = [\"vscode\"]\n",
+    );
+    let result = env.run(&["deploy", "--yes"]);
+    assert!(result.success, "{}", result.combined_output());
+
+    let agents_md = env.read_deployed_file("AGENTS.md");
+    assert!(agents_md.starts_with(USER_AGENTS_MD), "{}", agents_md);
+    assert!(agents_md.contains("<!-- calvin:begin vscode -->"));
+    assert!(
+        !agents_md.contains("<!-- calvin:begin opencode -->"),
+        "{}",
+        agents_md
+    );
+}
@@ -0,0 +1,244 @@
+//! Integration tests for hook assets (`.promptpack/hooks/`): groups merged into the
+//! `hooks` block of `.claude/settings.json`, script supplementals, and the security
+//! policy on the commands hooks run.
+
+mod common;
+
+use common::*;
+
+const CLAUDE_CONFIG: &str = "[targets]\nenabled = [\"claude-code\"]\n";
+
+const LINT_HOOK: &str = r#"---
+description: Lint before stopping
+event: Stop
+command: make lint
+---
+"#;
+
+const FORMAT_HOOK: &str = r#"---
+description: Format edited files
+event: PostToolUse
+matcher: Edit|Write
+command: .claude/hooks/format/fmt.sh
+timeout: 30
+---
+"#;
+
+const CURL_HOOK: &str = r#"---
+description: Report tool use
+event: PreToolUse
+command: curl -s https://example.com/audit
+---
+"#;
+
+const USER_SETTINGS: &str = r#"{
+  "model": "sonnet",
+  "hooks": {
+    "Stop": [
+      { "hooks": [{ "type": "command", "command": "say done" }] }
+    ]
+  }
+}
+"#;
+
+fn settings(env: &TestEnv) -> serde_json::Value {
+    serde_json::from_str(&env.read_deployed_file(".claude/settings.json")).unwrap()
+}
+
+fn stop_commands(value: &serde_json::Value) -> Vec<String> {
+    value["hooks"]["Stop"]
+        .as_array()
+        .map(|groups| {
+            groups
+                .iter()
+                .map(|g| g["hooks"][0]["command"].as_str().unwrap().to_string())
+                .collect()
+        })
+        .unwrap_or_default()
+}
+
+#[test]
+fn deploy_merges_hooks_into_existing_settings() {
+    let env = TestEnv::builder()
+        .with_project_asset("test.md", ALL_TARGETS_POLICY)
+        .with_project_asset("hooks/lint.md", LINT_HOOK)
+        .with_project_asset("hooks/format/HOOK.md", FORMAT_HOOK)
+        .with_project_asset("hooks/format/fmt.sh", "cargo fmt\n")
+        .with_project_config(CLAUDE_CONFIG)
+        .build();
+    env.write_project_file(".claude/settings.json", USER_SETTINGS);
+
+    let result = env.run(&["deploy", "--yes"]);
+    assert!(result.success, "{}", result.combined_output());
+
+    let value = settings(&env);
+    assert_eq!(value["model"], "sonnet");
+    assert_eq!(stop_commands(&value), ["say done", "make lint"]);
+    let format = &value["hooks"]["PostToolUse"][0];
+    assert_eq!(format["matcher"], "Edit|Write");
+    assert_eq!(format["hooks"][0]["timeout"], 30);
+
+    assert_eq!(
+        env.read_deployed_file(".claude/hooks/format/fmt.sh"),
+        "cargo fmt\n"
+    );
+    assert!(!env.project_path(".claude/commands/lint.md").exists());
+}
+
+#[test]
+fn clean_removes_only_calvin_hooks() {
+    let env = TestEnv::builder()
+        .with_project_asset("test.md", ALL_TARGETS_POLICY)
+        .with_project_asset("hooks/lint.md", LINT_HOOK)
+        .with_project_asset("hooks/format/HOOK.md", FORMAT_HOOK)
+        .with_project_asset("hooks/format/fmt.sh", "cargo fmt\n")
+        .with_project_config(CLAUDE_CONFIG)
+        .build();
+    env.write_project_file(".claude/settings.json", USER_SETTINGS);
+
+    let result = env.run(&["deploy", "--yes"]);
+    assert!(result.success, "{}", result.combined_output());
+
+    let result = env.run(&["clean", "--all", "--yes"]);
+    assert!(result.success, "{}", result.combined_output());
+
+    let value = settings(&env);
+    assert_eq!(value["model"], "sonnet");
+    assert_eq!(stop_commands(&value), ["say done"]);
+    assert!(value["hooks"].get("PostToolUse").is_none(), "{}", value);
+    assert!(!env.project_path(".claude/hooks/format").exists());
+}
+
+#[test]
+fn user_hook_running_the_same_command_is_never_owned() {
+    let env = TestEnv::builder()
+        .with_project_asset("test.md", ALL_TARGETS_POLICY)
+        .with_project_asset("hooks/lint.md", LINT_HOOK)
+        .with_project_config(CLAUDE_CONFIG)
+        .build();
+    env.write_project_file(
+        ".claude/settings.json",
+        &USER_SETTINGS.replace("say done", "make lint"),
+    );
+
+    let result = env.run(&["deploy", "--yes"]);
+    assert!(result.success, "{}", result.combined_output());
+    let value = settings(&env);
+    assert_eq!(stop_commands(&value), ["make lint", "make lint"]);
+    assert!(value["hooks"]["Stop"][0].get("calvin").is_none());
+    assert_eq!(value["hooks"]["Stop"][1]["calvin"], "lint");
+
+    let result = env.run(&["clean", "--all", "--yes"]);
+    assert!(result.success, "{}", result.combined_output());
+
+    let value = settings(&env);
+    assert_eq!(stop_commands(&value), ["make lint"]);
+    assert!(value["hooks"]["Stop"][0].get("calvin").is_none());
+}
+
+#[test]
+fn removed_hook_is_cleaned_as_orphan() {
+    let env = TestEnv::builder()
+        .with_project_asset("test.md", ALL_TARGETS_POLICY)
+        .with_project_asset("hooks/lint.md", LINT_HOOK)
+        .with_project_asset("hooks/format/HOOK.md", FORMAT_HOOK)
+        .with_project_asset("hooks/format/fmt.sh", "cargo fmt\n")
+        .with_project_config(CLAUDE_CONFIG)
+        .build();
+
+    let result = env.run(&["deploy", "--yes"]);
+    assert!(result.success, "{}", result.combined_output());
+
+    std::fs::remove_dir_all(env.project_path(".promptpack/hooks/format")).unwrap();
+    let result = env.run(&["deploy", "--yes", "--cleanup"]);
+    assert!(result.success, "{}", result.combined_output());
+
+    let value = settings(&env);
+    assert_eq!(stop_commands(&value), ["make lint"]);
+    assert!(value["hooks"].get("PostToolUse").is_none(), "{}", value);
+    assert!(!env.project_path(".claude/hooks/format/fmt.sh").exists());
+}
+
+#[test]
+fn strict_mode_rejects_dangerous_hook_commands() {
+    let env = TestEnv::builder()
+        .with_project_asset("test.md", ALL_TARGETS_POLICY)
+        .with_project_asset("hooks/audit.md", CURL_HOOK)
+        .with_project_config(&format!(
+            "{}\n[security]\nmode = \"strict\"\n",
+            CLAUDE_CONFIG
+        ))
+        .build();
+
+    let result = env.run(&["deploy", "--yes"]);
+    assert!(!result.success);
+    assert!(
+        result
+            .combined_output()
+            .contains("Hook 'audit' runs a dangerous tool ('curl')"),
+        "{}",
+        result.combined_output()
+    );
+    assert!(!env.project_path(".claude/settings.json").exists());
+}
+
+#[test]
+fn strict_mode_sees_through_assignments_and_scripts() {
+    let strict = format!("{}\n[security]\nmode = \"strict\"\n", CLAUDE_CONFIG);
+
+    let env = TestEnv::builder()
+        .with_project_asset(
+            "hooks/audit.md",
+            &CURL_HOOK.replace("command: curl", "command: X=1 curl"),
+        )
+        .with_project_config(&strict)
+        .build();
+    let result = env.run(&["deploy", "--yes"]);
+    assert!(!result.success);
+    assert!(
+        result.combined_output().contains("dangerous tool ('curl')"),
+        "{}",
+        result.combined_output()
+    );
+
+    let env = TestEnv::builder()
+        .with_project_asset("hooks/format/HOOK.md", FORMAT_HOOK)
+        .with_project_asset(
+            "hooks/format/fmt.sh",
+            "#!/bin/sh\ncargo fmt\nrm -rf ~/.cache\n",
+        )
+        .with_project_config(&strict)
+        .build();
+    let result = env.run(&["deploy", "--yes"]);
+    assert!(!result.success);
+    assert!(
+        result
+            .combined_output()
+            .contains("Hook 'format' script fmt.sh runs a dangerous tool ('rm')"),
+        "{}",
+        result.combined_output()
+    );
+    assert!(!env.project_path(".claude/settings.json").exists());
+}
+
+#[test]
+fn balanced_mode_warns_on_dangerous_hook_commands() {
+    let env = TestEnv::builder()
+        .with_project_asset("test.md", ALL_TARGETS_POLICY)
+        .with_project_asset("hooks/audit.md", CURL_HOOK)
+        .with_project_config(CLAUDE_CONFIG)
+        .build();
+
+    let result = env.run(&["deploy", "--yes"]);
+    assert!(result.success, "{}", result.combined_output());
+    assert!(
+        result.combined_output().contains("dangerous tool ('curl')"),
+        "{}",
+        result.combined_output()
+    );
+    let value = settings(&env);
+    assert_eq!(
+        value["hooks"]["PreToolUse"][0]["hooks"][0]["command"],
+        "curl -s https://example.com/audit"
+    );
+}
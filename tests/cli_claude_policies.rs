@@ -0,0 +1,140 @@
+//! Integration tests for `[targets.claude-code] policies` (memory and rules modes).
+//!
+//! In memory mode policies land in a managed section of `CLAUDE.md`; hand-written
+//! content around the section survives deploy and clean.
+
+mod common;
+
+use common::*;
+
+const SECURITY_POLICY: &str = r#"---
+kind: policy
+description: Security rules
+scope: project
+targets: [claude-code]
+---
+Never commit secrets.
+"#;
+
+const RUST_POLICY: &str = r#"---
+kind: policy
+description: Rust rules
+scope: project
+targets: [claude-code]
+apply: "src/**/*.rs"
+---
+Run clippy before committing.
+"#;
+
+const USER_CLAUDE_MD: &str = "# Project notes\n\nHand-written context.\n";
+
+fn config(mode: &str) -> String {
+    format!(
+        "[targets]\nenabled = [\"claude-code\"]\n\n[targets.claude-code]\npolicies = \"{}\"\n",
+        mode
+    )
+}
+
+#[test]
+fn memory_mode_writes_managed_section_and_keeps_user_content() {
+    let env = TestEnv::builder()
+        .with_project_asset("policies/security.md", SECURITY_POLICY)
+        .with_project_config(&config("memory"))
+        .build();
+    env.write_project_file("CLAUDE.md", USER_CLAUDE_MD);
+
+    let result = env.run(&["deploy", "--yes"]);
+    assert!(result.success, "{}", result.combined_output());
+
+    let memory = env.read_deployed_file("CLAUDE.md");
+    assert!(memory.starts_with(USER_CLAUDE_MD), "{}", memory);
+    assert!(
+        memory.contains("<!-- calvin:begin claude-code -->\n## Security rules\n\nNever commit secrets.\n<!-- calvin:end -->"),
+        "{}",
+        memory
+    );
+    assert!(!env.project_path(".claude/commands/security.md").exists());
+
+    let lockfile = env.read_lockfile();
+    assert!(lockfile.contains("\"claude-code\""), "{}", lockfile);
+
+    // Hand edits outside the section survive a redeploy
+    let edited = memory.replace("Hand-written context.", "Edited context.");
+    env.write_project_file("CLAUDE.md", &edited);
+    let result = env.run(&["deploy", "--yes"]);
+    assert!(result.success, "{}", result.combined_output());
+    assert_eq!(env.read_deployed_file("CLAUDE.md"), edited);
+
+    let result = env.run(&["clean", "--all", "--yes"]);
+    assert!(result.success, "{}", result.combined_output());
+    assert_eq!(
+        env.read_deployed_file("CLAUDE.md"),
+        "# Project notes\n\nEdited context.\n"
+    );
+}
+
+#[test]
+fn memory_mode_creates_claude_md_when_missing() {
+    let env = TestEnv::builder()
+        .with_project_asset("policies/security.md", SECURITY_POLICY)
+        .with_project_config(&config("memory"))
+        .build();
+
+    let result = env.run(&["deploy", "--yes"]);
+    assert!(result.success, "{}", result.combined_output());
+    assert!(env
+        .read_deployed_file("CLAUDE.md")
+        .starts_with("<!-- calvin:begin claude-code -->"));
+
+    // Nothing but the section: clean removes the file
+    let result = env.run(&["clean", "--all", "--yes"]);
+    assert!(result.success, "{}", result.combined_output());
+    assert!(!env.project_path("CLAUDE.md").exists());
+}
+
+#[test]
+fn memory_mode_reports_path_scoped_policies() {
+    let env = TestEnv::builder()
+        .with_project_asset("policies/rust.md", RUST_POLICY)
+        .with_project_config(&config("memory"))
+        .build();
+
+    let result = env.run(&["deploy", "--yes"]);
+    assert!(result.success, "{}", result.combined_output());
+    assert!(env
+        .read_deployed_file("CLAUDE.md")
+        .contains("Run clippy before committing."));
+    assert!(
+        result
+            .combined_output()
+            .contains("Policy 'rust' apply 'src/**/*.rs' is ignored in CLAUDE.md"),
+        "{}",
+        result.combined_output()
+    );
+}
+
+#[test]
+fn rules_mode_writes_path_scoped_rules() {
+    let env = TestEnv::builder()
+        .with_project_asset("policies/security.md", SECURITY_POLICY)
+        .with_project_asset("policies/rust.md", RUST_POLICY)
+        .with_project_config(&config("rules"))
+        .build();
+
+    let result = env.run(&["deploy", "--yes"]);
+    assert!(result.success, "{}", result.combined_output());
+
+    let rust = env.read_deployed_file(".claude/rules/rust.md");
+    assert!(
+        rust.starts_with("---\npaths:\n- src/**/*.rs\n---\n\nRun clippy before committing."),
+        "{}",
+        rust
+    );
+    let security = env.read_deployed_file(".claude/rules/security.md");
+    assert!(
+        security.starts_with("Never commit secrets."),
+        "{}",
+        security
+    );
+    assert!(!env.project_path("CLAUDE.md").exists());
+}
@@ -0,0 +1,152 @@
+//! Integration tests for the Cline target: `.clinerules/` rules and workflows, and
+//! the Roo Code flavor's custom modes merged into a shared `.roomodes`.
+
+mod common;
+
+use common::*;
+
+const CLINE_CONFIG: &str = r#"
+[targets]
+enabled = ["cline"]
+"#;
+
+const ROO_CONFIG: &str = r#"
+[targets]
+enabled = ["cline"]
+
+[targets.cline]
+flavor = "roo"
+"#;
+
+const REVIEWER_AGENT: &str = r#"---
+kind: agent
+description: Reviews pull requests
+targets: [cline]
+tools: Read, Grep, Bash
+model: sonnet
+---
+You review code for correctness.
+"#;
+
+const USER_ROOMODES: &str = r#"{
+  "customModes": [
+    {
+      "slug": "translator",
+      "name": "Translator",
+      "roleDefinition": "You translate.",
+      "groups": ["read"]
+    }
+  ]
+}
+"#;
+
+#[test]
+fn policies_become_rules_and_agents_become_workflows() {
+    let env = TestEnv::builder()
+        .with_project_asset("test.md", ALL_TARGETS_POLICY)
+        .with_project_asset("agents/reviewer.md", REVIEWER_AGENT)
+        .with_project_config(CLINE_CONFIG)
+        .build();
+
+    let result = env.run(&["deploy", "--yes"]);
+    assert!(result.success, "{}", result.combined_output());
+
+    let rule = env.read_deployed_file(".clinerules/test.md");
+    assert!(
+        rule.contains("This policy applies to all supported AI coding assistants."),
+        "{}",
+        rule
+    );
+    assert!(env
+        .project_path(".clinerules/workflows/reviewer.md")
+        .exists());
+    assert!(!env.project_path(".roomodes").exists());
+}
+
+#[test]
+fn roo_flavor_merges_agents_into_roomodes_per_mode() {
+    let env = TestEnv::builder()
+        .with_project_asset("agents/reviewer.md", REVIEWER_AGENT)
+        .with_project_config(ROO_CONFIG)
+        .build();
+    env.write_project_file(".roomodes", USER_ROOMODES);
+
+    let result = env.run(&["deploy", "--yes"]);
+    assert!(result.success, "{}", result.combined_output());
+
+    let roomodes: serde_json::Value =
+        serde_json::from_str(&env.read_deployed_file(".roomodes")).unwrap();
+    let modes = roomodes["customModes"].as_array().unwrap();
+    assert_eq!(modes.len(), 2, "{}", roomodes);
+    assert_eq!(modes[0]["slug"], "translator");
+    assert_eq!(modes[1]["slug"], "reviewer");
+    assert_eq!(modes[1]["whenToUse"], "Reviews pull requests");
+    assert_eq!(modes[1]["groups"], serde_json::json!(["read", "command"]));
+    assert!(!env
+        .project_path(".clinerules/workflows/reviewer.md")
+        .exists());
+
+    let lockfile = env.read_lockfile();
+    assert!(lockfile.contains("/customModes/reviewer"), "{}", lockfile);
+
+    // Clean removes only Calvin's mode
+    let result = env.run(&["clean", "--all", "--yes"]);
+    assert!(result.success, "{}", result.combined_output());
+    let roomodes: serde_json::Value =
+        serde_json::from_str(&env.read_deployed_file(".roomodes")).unwrap();
+    assert_eq!(
+        roomodes,
+        serde_json::from_str::<serde_json::Value>(USER_ROOMODES).unwrap()
+    );
+}
+
+const USER_ROOMODES_YAML: &str = r#"# Team modes
+customModes:
+  - slug: translator
+    name: Translator
+    roleDefinition: You translate.
+    groups:
+      - read
+"#;
+
+#[test]
+fn roo_flavor_merges_into_a_yaml_roomodes() {
+    let env = TestEnv::builder()
+        .with_project_asset("agents/reviewer.md", REVIEWER_AGENT)
+        .with_project_config(ROO_CONFIG)
+        .build();
+    env.write_project_file(".roomodes", USER_ROOMODES_YAML);
+
+    let result = env.run(&["deploy", "--yes"]);
+    assert!(result.success, "{}", result.combined_output());
+    assert!(
+        !result.combined_output().contains("Structured merge failed"),
+        "{}",
+        result.combined_output()
+    );
+
+    let content = env.read_deployed_file(".roomodes");
+    assert!(!content.trim_start().starts_with('{'), "{}", content);
+    let roomodes: serde_json::Value = serde_yaml_ng::from_str(&content).unwrap();
+    let slugs: Vec<&str> = roomodes["customModes"]
+        .as_array()
+        .unwrap()
+        .iter()
+        .map(|mode| mode["slug"].as_str().unwrap())
+        .collect();
+    assert_eq!(slugs, ["translator", "reviewer"], "{}", content);
+
+    // Redeploying leaves the merged file as it is
+    let result = env.run(&["deploy", "--yes"]);
+    assert!(result.success, "{}", result.combined_output());
+    assert_eq!(env.read_deployed_file(".roomodes"), content);
+
+    let result = env.run(&["clean", "--all", "--yes"]);
+    assert!(result.success, "{}", result.combined_output());
+    let roomodes: serde_json::Value =
+        serde_yaml_ng::from_str(&env.read_deployed_file(".roomodes")).unwrap();
+    assert_eq!(
+        roomodes,
+        serde_yaml_ng::from_str::<serde_json::Value>(USER_ROOMODES_YAML).unwrap()
+    );
+}
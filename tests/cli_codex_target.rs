@@ -0,0 +1,117 @@
+//! Integration tests for the Codex target: policies in `AGENTS.md` and the
+//! `security.mode` sandbox settings merged into `.codex/config.toml`.
+
+mod common;
+
+use common::*;
+
+const CODEX_CONFIG: &str = r#"
+[targets]
+enabled = ["codex"]
+"#;
+
+const GITHUB_SERVER: &str = r#"
+command = "npx"
+args = ["-y", "@modelcontextprotocol/server-github"]
+"#;
+
+const USER_CODEX_CONFIG: &str = "# My settings\nmodel = \"o3\"\n";
+
+#[test]
+fn policies_land_in_agents_md_region() {
+    let env = TestEnv::builder()
+        .with_project_asset("test.md", ALL_TARGETS_POLICY)
+        .with_project_config(CODEX_CONFIG)
+        .build();
+
+    let result = env.run(&["deploy", "--yes"]);
+    assert!(result.success, "{}", result.combined_output());
+
+    let agents_md = env.read_deployed_file("AGENTS.md");
+    assert!(
+        agents_md.starts_with("<!-- calvin:begin codex -->"),
+        "{}",
+        agents_md
+    );
+    assert!(
+        agents_md.contains("This policy applies to all supported AI coding assistants."),
+        "{}",
+        agents_md
+    );
+    assert!(!env.project_path(".codex/prompts/test.md").exists());
+}
+
+#[test]
+fn security_mode_is_merged_into_config_toml() {
+    let env = TestEnv::builder()
+        .with_project_asset("test.md", ALL_TARGETS_POLICY)
+        .with_project_asset("mcp/github.toml", GITHUB_SERVER)
+        .with_project_config(&format!(
+            "{}\n[security]\nmode = \"strict\"\n",
+            CODEX_CONFIG
+        ))
+        .build();
+    env.write_project_file(".codex/config.toml", USER_CODEX_CONFIG);
+
+    let result = env.run(&["deploy", "--yes"]);
+    assert!(result.success, "{}", result.combined_output());
+
+    let config = env.read_deployed_file(".codex/config.toml");
+    assert!(config.starts_with(USER_CODEX_CONFIG), "{}", config);
+    let parsed: toml::Table = toml::from_str(&config).unwrap();
+    assert_eq!(parsed["sandbox_mode"].as_str(), Some("read-only"));
+    assert_eq!(parsed["approval_policy"].as_str(), Some("untrusted"));
+    assert_eq!(
+        parsed["mcp_servers"]["github"]["command"].as_str(),
+        Some("npx")
+    );
+
+    let lockfile = env.read_lockfile();
+    assert!(lockfile.contains("/sandbox_mode"), "{}", lockfile);
+    assert!(lockfile.contains("/mcp_servers/github"), "{}", lockfile);
+
+    // Clean strips only Calvin's keys
+    let result = env.run(&["clean", "--all", "--yes"]);
+    assert!(result.success, "{}", result.combined_output());
+    assert_eq!(
+        env.read_deployed_file(".codex/config.toml"),
+        USER_CODEX_CONFIG
+    );
+}
+
+#[test]
+fn agents_md_policies_are_not_duplicated_with_opencode() {
+    let env = TestEnv::builder()
+        .with_project_asset("test.md", ALL_TARGETS_POLICY)
+        .with_project_asset(
+            "codex-only.md",
+            "---\nkind: policy\ndescription: Codex sandbox notes\ntargets: [codex]\n---\nOnly Codex reads this.\n",
+        )
+        .with_project_config("[targets]\nenabled = [\"codex\", \"opencode\"]\n")
+        .build();
+
+    let result = env.run(&["deploy", "--yes"]);
+    assert!(result.success, "{}", result.combined_output());
+
+    // OpenCode's region carries the shared policy; Codex keeps only its own
+    let agents_md = env.read_deployed_file("AGENTS.md");
+    assert_eq!(
+        agents_md
+            .matches("This policy applies to all supported AI coding assistants.")
+            .count(),
+        1,
+        "{}",
+        agents_md
+    );
+    assert!(
+        agents_md.contains("<!-- calvin:begin opencode -->"),
+        "{}",
+        agents_md
+    );
+    assert!(
+        agents_md.contains("<!-- calvin:begin codex -->"),
+        "{}",
+        agents_md
+    );
+    assert_eq!(agents_md.matches("Only Codex reads this.").count(), 1);
+}
@@ -0,0 +1,221 @@
+//! Integration tests for declarative custom targets (`[[targets.custom]]`): per-asset
+//! path templates with mapped frontmatter, and aggregate files shared with hand-written
+//! content through a managed region.
+
+mod common;
+
+use common::*;
+
+const CUSTOM_CONFIG: &str = r#"
+[targets]
+enabled = ["claude-code", "aider", "zed"]
+
+[[targets.custom]]
+name = "aider"
+aggregate = { path = "{scope_root}/CONVENTIONS.md" }
+
+[[targets.custom]]
+name = "zed"
+paths.action = "{scope_root}/.zed/prompts/{id}.md"
+frontmatter = { title = "description" }
+"#;
+
+/// Names a built-in and a custom target in `targets:`
+const STYLE_POLICY: &str = r#"---
+kind: policy
+description: Style guide
+targets: [claude-code, aider]
+---
+Prefer early returns.
+"#;
+
+const DEPLOY_ACTION: &str = r#"---
+kind: action
+description: Deploy the app
+targets: [zed]
+---
+Run the deploy script.
+"#;
+
+const HANDWRITTEN_CONVENTIONS: &str = "# Conventions\n\nPrefer small functions.\n";
+
+#[test]
+fn deploys_aggregate_and_per_asset_files() {
+    let env = TestEnv::builder()
+        .with_project_asset("test.md", ALL_TARGETS_POLICY)
+        .with_project_asset("policies/style.md", STYLE_POLICY)
+        .with_project_asset("actions/deploy.md", DEPLOY_ACTION)
+        .with_project_config(CUSTOM_CONFIG)
+        .build();
+    env.write_project_file("CONVENTIONS.md", HANDWRITTEN_CONVENTIONS);
+
+    let result = env.run(&["deploy", "--yes"]);
+    assert!(result.success, "{}", result.combined_output());
+
+    let conventions = env.read_deployed_file("CONVENTIONS.md");
+    assert!(
+        conventions.starts_with(HANDWRITTEN_CONVENTIONS),
+        "{}",
+        conventions
+    );
+    assert!(conventions.contains("<!-- calvin:begin aider -->"));
+    assert!(conventions.contains("Prefer early returns."));
+    // Un-targeted assets reach custom targets as they reach built-ins
+    assert!(conventions.contains("This policy applies to all supported AI coding assistants."));
+    assert!(!conventions.contains("Run the deploy script."));
+
+    let prompt = env.read_deployed_file(".zed/prompts/deploy.md");
+    assert!(
+        prompt.starts_with("---\ntitle: Deploy the app\n---\n\nRun the deploy script."),
+        "{}",
+        prompt
+    );
+    assert!(!env.project_path(".zed/prompts/test.md").exists());
+
+    let lockfile = env.read_lockfile();
+    assert!(lockfile.contains("CONVENTIONS.md"), "{}", lockfile);
+    assert!(lockfile.contains(".zed/prompts/deploy.md"), "{}", lockfile);
+    assert!(lockfile.contains("aider = "), "{}", lockfile);
+    assert!(lockfile.contains("zed = "), "{}", lockfile);
+}
+
+#[test]
+fn each_custom_target_is_selected_by_name() {
+    let env = TestEnv::builder()
+        .with_project_asset("policies/style.md", STYLE_POLICY)
+        .with_project_asset("actions/deploy.md", DEPLOY_ACTION)
+        .with_project_config(CUSTOM_CONFIG)
+        .build();
+
+    let result = env.run(&["deploy", "--yes", "--targets", "zed"]);
+    assert!(result.success, "{}", result.combined_output());
+
+    assert!(env.project_path(".zed/prompts/deploy.md").exists());
+    assert!(!env.project_path("CONVENTIONS.md").exists());
+    assert!(!env.project_path(".claude").exists());
+
+    let lockfile = env.read_lockfile();
+    assert!(lockfile.contains("zed = "), "{}", lockfile);
+    assert!(!lockfile.contains("aider = "), "{}", lockfile);
+}
+
+#[test]
+fn undeclared_custom_targets_are_rejected() {
+    let env = TestEnv::builder()
+        .with_project_asset("policies/style.md", STYLE_POLICY)
+        .with_project_config(CUSTOM_CONFIG)
+        .build();
+
+    let result = env.run(&["deploy", "--yes", "--targets", "aidr"]);
+    assert!(!result.success);
+    assert!(
+        result.combined_output().contains("Did you mean 'aider'?"),
+        "{}",
+        result.combined_output()
+    );
+
+    let env = TestEnv::builder()
+        .with_project_asset("policies/style.md", STYLE_POLICY)
+        .with_project_config(&CUSTOM_CONFIG.replace("\"zed\"]", "\"zed\", \"cody\"]"))
+        .build();
+
+    let result = env.run(&["deploy", "--yes"]);
+    assert!(!result.success);
+    assert!(
+        result.combined_output().contains("invalid target 'cody'"),
+        "{}",
+        result.combined_output()
+    );
+}
+
+#[test]
+fn removed_asset_is_cleaned_as_orphan() {
+    let env = TestEnv::builder()
+        .with_project_asset("test.md", ALL_TARGETS_POLICY)
+        .with_project_asset("policies/style.md", STYLE_POLICY)
+        .with_project_asset("actions/deploy.md", DEPLOY_ACTION)
+        .with_project_config(CUSTOM_CONFIG)
+        .build();
+
+    let result = env.run(&["deploy", "--yes"]);
+    assert!(result.success, "{}", result.combined_output());
+    assert!(env.project_path(".zed/prompts/deploy.md").exists());
+
+    env.remove_project_asset("actions/deploy.md");
+    let result = env.run(&["deploy", "--yes", "--cleanup"]);
+    assert!(result.success, "{}", result.combined_output());
+
+    assert!(!env.project_path(".zed/prompts/deploy.md").exists());
+    assert!(env.project_path("CONVENTIONS.md").exists());
+}
+
+#[test]
+fn clean_keeps_handwritten_content() {
+    let env = TestEnv::builder()
+        .with_project_asset("test.md", ALL_TARGETS_POLICY)
+        .with_project_asset("policies/style.md", STYLE_POLICY)
+        .with_project_asset("actions/deploy.md", DEPLOY_ACTION)
+        .with_project_config(CUSTOM_CONFIG)
+        .build();
+    env.write_project_file("CONVENTIONS.md", HANDWRITTEN_CONVENTIONS);
+
+    let result = env.run(&["deploy", "--yes"]);
+    assert!(result.success, "{}", result.combined_output());
+
+    let result = env.run(&["clean", "--all", "--yes"]);
+    assert!(result.success, "{}", result.combined_output());
+
+    assert_eq!(
+        env.read_deployed_file("CONVENTIONS.md").trim_end(),
+        HANDWRITTEN_CONVENTIONS.trim_end()
+    );
+    assert!(!env.project_path(".zed/prompts/deploy.md").exists());
+}
+
+#[test]
+fn invalid_custom_target_is_rejected() {
+    let env = TestEnv::builder()
+        .with_project_asset("test.md", ALL_TARGETS_POLICY)
+        .with_project_config(
+            r#"
+[[targets.custom]]
+name = "cursor"
+paths.policy = "{id}.md"
+"#,
+        )
+        .build();
+
+    let result = env.run(&["deploy", "--yes"]);
+    assert!(!result.success);
+    assert!(
+        result.combined_output().contains("built-in"),
+        "{}",
+        result.combined_output()
+    );
+}
+
+#[test]
+fn paths_outside_the_project_are_rejected() {
+    let env = TestEnv::builder()
+        .with_project_asset("policies/style.md", STYLE_POLICY)
+        .with_project_config(
+            r#"
+[targets]
+enabled = ["aider"]
+
+[[targets.custom]]
+name = "aider"
+paths.policy = "../escaped/{id}.md"
+"#,
+        )
+        .build();
+
+    let result = env.run(&["deploy", "--yes"]);
+    assert!(!result.success);
+    assert!(
+        result.combined_output().contains("cannot contain '..'"),
+        "{}",
+        result.combined_output()
+    );
+    assert!(!env.project_path("../escaped/style.md").exists());
+}
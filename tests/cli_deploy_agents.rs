@@ -210,7 +210,7 @@ targets: [claude-code]
 }
 
 #[test]
-fn deploy_vscode_agent_generates_instruction_file() {
+fn deploy_vscode_agent_generates_custom_agent_file() {
     let env = TestEnv::builder().build();
 
     env.write_project_file(
@@ -220,6 +220,10 @@ kind: agent
 description: Code review agent
 scope: project
 targets: [vscode]
+permissionMode: plan
+overrides:
+  vscode:
+    tools: [codebase, search]
 ---
 You are a code reviewer.
 "#,
@@ -232,17 +236,75 @@ You are a code reviewer.
         result.combined_output()
     );
 
-    let instruction_path = env.project_path(".github/instructions/reviewer.instructions.md");
+    let agent_path = env.project_path(".github/agents/reviewer.agent.md");
     assert!(
-        instruction_path.exists(),
-        "vscode should create .github/instructions/reviewer.instructions.md"
+        agent_path.exists(),
+        "vscode should create .github/agents/reviewer.agent.md"
     );
+    assert!(!env
+        .project_path(".github/instructions/reviewer.instructions.md")
+        .exists());
 
-    let content = std::fs::read_to_string(&instruction_path).unwrap();
+    let content = std::fs::read_to_string(&agent_path).unwrap();
     assert!(
-        content.contains("Code review agent"),
+        content.contains("description: Code review agent"),
         "expected description in output:\n{content}"
     );
+    assert!(
+        content.contains("tools:\n- codebase\n- search\n"),
+        "expected override tools in output:\n{content}"
+    );
+    assert!(
+        result.combined_output().contains(
+            "Agent 'reviewer' compiled as a custom agent on VS Code; ignores permissionMode"
+        ),
+        "expected downgrade warning:\n{}",
+        result.combined_output()
+    );
+}
+
+#[test]
+fn deploy_and_check_report_agent_downgraded_on_antigravity() {
+    let env = TestEnv::builder()
+        .with_project_config("[targets]\nenabled = [\"antigravity\"]\n")
+        .build();
+
+    env.write_project_file(
+        ".promptpack/agents/reviewer.md",
+        r#"---
+kind: agent
+description: Code review agent
+tools:
+  - Read
+---
+You are a code reviewer.
+"#,
+    );
+
+    let result = env.run(&["deploy", "--yes"]);
+    assert!(result.success, "{}", result.combined_output());
+    assert!(
+        result
+            .combined_output()
+            .contains("Agent 'reviewer' compiled as a workflow on Antigravity; ignores tools"),
+        "{}",
+        result.combined_output()
+    );
+    let workflow = env.read_deployed_file(".agent/workflows/reviewer.md");
+    assert!(
+        workflow.contains("You are a code reviewer."),
+        "{}",
+        workflow
+    );
+
+    let result = env.run(&["check"]);
+    assert!(
+        result
+            .combined_output()
+            .contains("Agent 'reviewer' compiled as a workflow on Antigravity"),
+        "{}",
+        result.combined_output()
+    );
 }
 
 #[test]
@@ -0,0 +1,230 @@
+//! Integration tests for MCP server compilation (`.promptpack/mcp/*.toml`).
+//!
+//! Deploy compiles each allowlisted server into every target's native MCP config
+//! and tracks the generated files in the lockfile.
+
+mod common;
+
+use common::*;
+
+const GITHUB_SERVER: &str = r#"
+command = "npx"
+args = ["-y", "@modelcontextprotocol/server-github"]
+
+[env]
+GITHUB_TOKEN = "${GITHUB_TOKEN}"
+"#;
+
+const UNKNOWN_SERVER: &str = r#"
+command = "/opt/tools/db-bridge"
+"#;
+
+const MCP_TARGETS_CONFIG: &str = r#"
+[targets]
+enabled = ["claude-code", "cursor", "vscode", "codex", "opencode", "gemini-cli"]
+"#;
+
+fn parse_json(content: &str) -> serde_json::Value {
+    serde_json::from_str(content).unwrap()
+}
+
+#[test]
+fn deploy_compiles_mcp_servers_for_every_target() {
+    let env = TestEnv::builder()
+        .with_project_asset("test.md", ALL_TARGETS_POLICY)
+        .with_project_asset("mcp/github.toml", GITHUB_SERVER)
+        .with_project_config(MCP_TARGETS_CONFIG)
+        .build();
+
+    let result = env.run(&["deploy", "--yes"]);
+    assert!(
+        result.success,
+        "deploy failed:\n{}",
+        result.combined_output()
+    );
+
+    let claude = parse_json(&env.read_deployed_file(".mcp.json"));
+    assert_eq!(claude["mcpServers"]["github"]["command"], "npx");
+    assert_eq!(
+        claude["mcpServers"]["github"]["env"]["GITHUB_TOKEN"],
+        "${GITHUB_TOKEN}"
+    );
+
+    let cursor = parse_json(&env.read_deployed_file(".cursor/mcp.json"));
+    assert_eq!(
+        cursor["mcpServers"]["github"]["args"][1],
+        "@modelcontextprotocol/server-github"
+    );
+
+    let vscode = parse_json(&env.read_deployed_file(".vscode/mcp.json"));
+    assert_eq!(vscode["servers"]["github"]["type"], "stdio");
+
+    let codex = env.read_deployed_file(".codex/config.toml");
+    assert!(codex.contains("[mcp_servers.github]"), "{}", codex);
+
+    let opencode = parse_json(&env.read_deployed_file("opencode.json"));
+    assert_eq!(opencode["mcp"]["github"]["type"], "local");
+    assert_eq!(opencode["mcp"]["github"]["command"][0], "npx");
+
+    let gemini = parse_json(&env.read_deployed_file(".gemini/settings.json"));
+    assert_eq!(gemini["mcpServers"]["github"]["command"], "npx");
+
+    let lockfile = env.read_lockfile();
+    for path in [
+        ".mcp.json",
+        ".cursor/mcp.json",
+        ".vscode/mcp.json",
+        ".codex/config.toml",
+        "opencode.json",
+        ".gemini/settings.json",
+    ] {
+        assert!(
+            lockfile.contains(&format!("project:{}", path)),
+            "{} should be tracked in lockfile:\n{}",
+            path,
+            lockfile
+        );
+    }
+}
+
+#[test]
+fn deploy_skips_mcp_servers_outside_allowlist() {
+    let env = TestEnv::builder()
+        .with_project_asset("test.md", ALL_TARGETS_POLICY)
+        .with_project_asset("mcp/github.toml", GITHUB_SERVER)
+        .with_project_asset("mcp/db.toml", UNKNOWN_SERVER)
+        .with_project_config(MCP_TARGETS_CONFIG)
+        .build();
+
+    let result = env.run(&["deploy", "--yes"]);
+    assert!(
+        result.success,
+        "deploy failed:\n{}",
+        result.combined_output()
+    );
+    assert!(
+        result
+            .combined_output()
+            .contains("MCP server 'db' (`/opt/tools/db-bridge`) is not in the MCP allowlist"),
+        "expected allowlist warning:\n{}",
+        result.combined_output()
+    );
+
+    let claude = parse_json(&env.read_deployed_file(".mcp.json"));
+    assert!(claude["mcpServers"].get("github").is_some());
+    assert!(claude["mcpServers"].get("db").is_none());
+}
+
+#[test]
+fn deploy_allows_custom_allowlisted_mcp_server() {
+    let env = TestEnv::builder()
+        .with_project_asset("test.md", ALL_TARGETS_POLICY)
+        .with_project_asset("mcp/db.toml", UNKNOWN_SERVER)
+        .with_project_config(&format!(
+            "{}\n[security.mcp]\nadditional_allowlist = [\"/opt/tools/\"]\n",
+            MCP_TARGETS_CONFIG
+        ))
+        .build();
+
+    let result = env.run(&["deploy", "--yes"]);
+    assert!(
+        result.success,
+        "deploy failed:\n{}",
+        result.combined_output()
+    );
+
+    let claude = parse_json(&env.read_deployed_file(".mcp.json"));
+    assert_eq!(
+        claude["mcpServers"]["db"]["command"],
+        "/opt/tools/db-bridge"
+    );
+}
+
+#[test]
+fn deploy_strict_mode_refuses_unknown_mcp_server() {
+    let env = TestEnv::builder()
+        .with_project_asset("test.md", ALL_TARGETS_POLICY)
+        .with_project_asset("mcp/db.toml", UNKNOWN_SERVER)
+        .with_project_config(&format!(
+            "{}\n[security]\nmode = \"strict\"\n",
+            MCP_TARGETS_CONFIG
+        ))
+        .build();
+
+    let result = env.run(&["deploy", "--yes"]);
+    assert!(
+        !result.success,
+        "strict deploy should fail:\n{}",
+        result.combined_output()
+    );
+    assert!(
+        result
+            .combined_output()
+            .contains("not in the MCP allowlist"),
+        "{}",
+        result.combined_output()
+    );
+    assert!(!env.project_path(".mcp.json").exists());
+}
+
+#[test]
+fn unmergeable_tracked_mcp_config_is_kept_and_stays_tracked() {
+    let env = TestEnv::builder()
+        .with_project_asset("test.md", ALL_TARGETS_POLICY)
+        .with_project_asset("mcp/github.toml", GITHUB_SERVER)
+        .with_project_config(MCP_TARGETS_CONFIG)
+        .build();
+
+    // Merged deploys track the file with the keys Calvin owns
+    let result = env.run(&["deploy", "--yes", "--merge"]);
+    assert!(result.success, "{}", result.combined_output());
+
+    // The user turns the tracked file into JSONC, which Calvin can't merge
+    let jsonc = format!(
+        "// Team MCP servers\n{}",
+        env.read_deployed_file(".vscode/mcp.json")
+    );
+    env.write_project_file(".vscode/mcp.json", &jsonc);
+
+    let result = env.run(&["deploy", "--yes", "--cleanup"]);
+    assert!(!result.success, "{}", result.combined_output());
+    assert!(
+        result
+            .combined_output()
+            .contains("Structured merge failed for .vscode/mcp.json"),
+        "{}",
+        result.combined_output()
+    );
+
+    assert_eq!(env.read_deployed_file(".vscode/mcp.json"), jsonc);
+    let lockfile = env.read_lockfile();
+    assert!(
+        lockfile.contains("project:.vscode/mcp.json"),
+        "{}",
+        lockfile
+    );
+    // Other outputs still deploy
+    assert!(env.project_path(".cursor/mcp.json").exists());
+}
+
+#[test]
+fn gemini_settings_keep_user_settings() {
+    let env = TestEnv::builder()
+        .with_project_asset("test.md", ALL_TARGETS_POLICY)
+        .with_project_asset("mcp/github.toml", GITHUB_SERVER)
+        .with_project_config("[targets]\nenabled = [\"gemini-cli\"]\n")
+        .build();
+    env.write_project_file(
+        ".gemini/settings.json",
+        "{\n  \"theme\": \"GitHub\",\n  \"mcpServers\": {\n    \"local\": {\"command\": \"./bridge\"}\n  }\n}\n",
+    );
+
+    // Merged without --merge: settings.json is never replaced wholesale
+    let result = env.run(&["deploy", "--yes"]);
+    assert!(result.success, "{}", result.combined_output());
+
+    let settings = parse_json(&env.read_deployed_file(".gemini/settings.json"));
+    assert_eq!(settings["theme"], "GitHub");
+    assert_eq!(settings["mcpServers"]["local"]["command"], "./bridge");
+    assert_eq!(settings["mcpServers"]["github"]["command"], "npx");
+}
@@ -0,0 +1,135 @@
+//! Integration tests for `deploy --merge` (structural merge of shared config files).
+//!
+//! Merged files keep user-authored keys; Calvin owns only the keys it generated,
+//! records them in the lockfile, and removes only those keys on redeploy or clean.
+
+mod common;
+
+use common::*;
+
+const GITHUB_SERVER: &str = r#"
+command = "npx"
+args = ["-y", "@modelcontextprotocol/server-github"]
+"#;
+
+const FS_SERVER: &str = r#"
+command = "npx"
+args = ["-y", "@modelcontextprotocol/server-filesystem"]
+"#;
+
+const OPENCODE_CONFIG: &str = r#"
+[targets]
+enabled = ["opencode"]
+"#;
+
+const USER_OPENCODE_JSON: &str = r#"{
+  "theme": "dark",
+  "mcp": {
+    "mine": { "type": "local", "command": ["my-server"] }
+  }
+}
+"#;
+
+fn parse_json(content: &str) -> serde_json::Value {
+    serde_json::from_str(content).unwrap()
+}
+
+fn env_with_user_opencode_json() -> TestEnv {
+    let env = TestEnv::builder()
+        .with_project_asset("test.md", ALL_TARGETS_POLICY)
+        .with_project_asset("mcp/github.toml", GITHUB_SERVER)
+        .with_project_asset("mcp/fs.toml", FS_SERVER)
+        .with_project_config(OPENCODE_CONFIG)
+        .build();
+    env.write_project_file("opencode.json", USER_OPENCODE_JSON);
+    env
+}
+
+#[test]
+fn deploy_merge_preserves_user_keys_and_records_owned_keys() {
+    let env = env_with_user_opencode_json();
+
+    let result = env.run(&["deploy", "--merge", "--yes"]);
+    assert!(
+        result.success,
+        "deploy failed:\n{}",
+        result.combined_output()
+    );
+
+    let opencode = parse_json(&env.read_deployed_file("opencode.json"));
+    assert_eq!(opencode["theme"], "dark");
+    assert_eq!(opencode["mcp"]["mine"]["command"][0], "my-server");
+    assert_eq!(opencode["mcp"]["github"]["type"], "local");
+    assert_eq!(opencode["mcp"]["fs"]["type"], "local");
+
+    let lockfile = env.read_lockfile();
+    assert!(
+        lockfile.contains("owned_keys = [") && lockfile.contains("\"/mcp/github\""),
+        "owned keys should be recorded:\n{}",
+        lockfile
+    );
+    assert!(!lockfile.contains("\"/mcp/mine\""), "{}", lockfile);
+
+    // Redeploy is idempotent and stays merged without the flag.
+    let again = env.run(&["deploy", "--yes"]);
+    assert!(again.success, "{}", again.combined_output());
+    assert_eq!(
+        parse_json(&env.read_deployed_file("opencode.json")),
+        opencode
+    );
+}
+
+#[test]
+fn redeploy_removes_only_stale_owned_keys() {
+    let env = env_with_user_opencode_json();
+    let first = env.run(&["deploy", "--merge", "--yes"]);
+    assert!(first.success, "{}", first.combined_output());
+
+    env.remove_project_asset("mcp/fs.toml");
+    let result = env.run(&["deploy", "--merge", "--yes"]);
+    assert!(result.success, "{}", result.combined_output());
+
+    let opencode = parse_json(&env.read_deployed_file("opencode.json"));
+    assert!(opencode["mcp"].get("fs").is_none(), "{}", opencode);
+    assert!(opencode["mcp"].get("github").is_some());
+    assert!(opencode["mcp"].get("mine").is_some());
+    assert_eq!(opencode["theme"], "dark");
+}
+
+#[test]
+fn clean_removes_only_owned_keys() {
+    let env = env_with_user_opencode_json();
+    let deploy = env.run(&["deploy", "--merge", "--yes"]);
+    assert!(deploy.success, "{}", deploy.combined_output());
+
+    let clean = env.run(&["clean", "--all", "--yes"]);
+    assert!(clean.success, "{}", clean.combined_output());
+
+    let opencode = parse_json(&env.read_deployed_file("opencode.json"));
+    assert_eq!(
+        opencode,
+        serde_json::json!({
+            "theme": "dark",
+            "mcp": { "mine": { "type": "local", "command": ["my-server"] } }
+        })
+    );
+}
+
+#[test]
+fn deploy_without_merge_replaces_shared_file() {
+    let env = env_with_user_opencode_json();
+
+    let result = env.run(&["deploy", "--yes"]);
+    assert!(result.success, "{}", result.combined_output());
+
+    // Whole-file mode: the generated file wins and nothing is recorded as owned.
+    let opencode = parse_json(&env.read_deployed_file("opencode.json"));
+    assert!(opencode.get("theme").is_none(), "{}", opencode);
+    assert!(opencode["mcp"].get("github").is_some());
+    let lockfile = env.read_lockfile();
+    let entry = lockfile
+        .split("\n[")
+        .find(|section| section.contains("project:opencode.json"))
+        .unwrap();
+    assert!(!entry.contains("owned_keys"), "{}", lockfile);
+}
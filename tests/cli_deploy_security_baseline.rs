@@ -0,0 +1,128 @@
+//! Integration tests for the Claude Code security baseline (`.claude/settings.json`).
+//!
+//! Deploy generates `permissions.deny` from `[security]` config and merges it into any
+//! existing user-authored settings instead of overwriting the file.
+
+mod common;
+
+use common::*;
+
+const CLAUDE_ONLY_CONFIG: &str = r#"
+[targets]
+enabled = ["claude-code"]
+
+[security.deny]
+patterns = ["secrets/**"]
+exclude = [".env.example"]
+"#;
+
+fn deny_list(settings: &str) -> Vec<String> {
+    let parsed: serde_json::Value = serde_json::from_str(settings).unwrap();
+    parsed["permissions"]["deny"]
+        .as_array()
+        .expect("permissions.deny should be an array")
+        .iter()
+        .filter_map(|v| v.as_str().map(str::to_string))
+        .collect()
+}
+
+#[test]
+fn deploy_generates_claude_settings_from_security_config() {
+    let env = TestEnv::builder()
+        .with_project_asset("test.md", ALL_TARGETS_POLICY)
+        .with_project_config(CLAUDE_ONLY_CONFIG)
+        .build();
+
+    let result = env.run(&["deploy", "--yes"]);
+    assert!(
+        result.success,
+        "deploy failed:\n{}",
+        result.combined_output()
+    );
+
+    let deny = deny_list(&env.read_deployed_file(".claude/settings.json"));
+    assert!(deny.contains(&".env".to_string()));
+    assert!(deny.contains(&"secrets/**".to_string()));
+    assert!(
+        !deny.contains(&".env.*".to_string()),
+        "excluded pattern should be removed: {:?}",
+        deny
+    );
+
+    let check = env.run(&["check"]);
+    assert!(
+        !check
+            .combined_output()
+            .contains("permissions.deny not configured"),
+        "check should accept generated settings:\n{}",
+        check.combined_output()
+    );
+}
+
+#[test]
+fn deploy_merges_into_existing_user_settings() {
+    let env = TestEnv::builder()
+        .with_project_asset("test.md", ALL_TARGETS_POLICY)
+        .with_project_config(CLAUDE_ONLY_CONFIG)
+        .build();
+    env.write_project_file(
+        ".claude/settings.json",
+        r#"{
+  "model": "opus",
+  "permissions": {
+    "allow": ["Bash(npm test)"],
+    "deny": ["build/**"]
+  }
+}
+"#,
+    );
+
+    let result = env.run(&["deploy", "--yes"]);
+    assert!(
+        result.success,
+        "deploy failed:\n{}",
+        result.combined_output()
+    );
+
+    let settings = env.read_deployed_file(".claude/settings.json");
+    let parsed: serde_json::Value = serde_json::from_str(&settings).unwrap();
+    assert_eq!(parsed["model"], "opus");
+    assert_eq!(parsed["permissions"]["allow"][0], "Bash(npm test)");
+
+    let deny = deny_list(&settings);
+    assert_eq!(deny[0], "build/**", "user entries keep their position");
+    assert!(deny.contains(&".env".to_string()));
+    assert!(deny.contains(&"secrets/**".to_string()));
+
+    // Redeploy is idempotent.
+    let again = env.run(&["deploy", "--yes"]);
+    assert!(
+        again.success,
+        "redeploy failed:\n{}",
+        again.combined_output()
+    );
+    assert_eq!(env.read_deployed_file(".claude/settings.json"), settings);
+}
+
+#[test]
+fn deploy_leaves_unparseable_settings_untouched() {
+    let env = TestEnv::builder()
+        .with_project_asset("test.md", ALL_TARGETS_POLICY)
+        .with_project_config(CLAUDE_ONLY_CONFIG)
+        .build();
+    env.write_project_file(".claude/settings.json", "{ not json");
+
+    let result = env.run(&["deploy", "--yes"]);
+    assert!(!result.success, "{}", result.combined_output());
+    assert!(
+        result
+            .combined_output()
+            .contains("Structured merge failed for .claude/settings.json"),
+        "expected error:\n{}",
+        result.combined_output()
+    );
+    assert_eq!(
+        env.read_deployed_file(".claude/settings.json"),
+        "{ not json"
+    );
+}
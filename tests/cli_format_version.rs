@@ -0,0 +1,127 @@
+//! Integration tests for source format versioning (`[format] version`) and
+//! `calvin migrate --format`.
+
+mod common;
+
+use common::*;
+
+const UNVERSIONED_CONFIG: &str = r#"# Team settings
+[targets]
+enabled = ["claude-code"]
+"#;
+
+const CURRENT_CONFIG: &str = r#"# Team settings
+[format]
+version = "1.0"
+
+[targets]
+enabled = ["claude-code"]
+
+[security]
+deny = ["*.secret"]
+"#;
+
+#[test]
+fn deploy_fails_for_newer_format() {
+    let env = TestEnv::builder()
+        .with_project_asset("test.md", ALL_TARGETS_POLICY)
+        .with_project_config("[format]\nversion = \"9.0\"\n")
+        .build();
+
+    let result = env.run(&["deploy", "--yes"]);
+
+    assert!(
+        !result.success,
+        "deploy should fail:\n{}",
+        result.combined_output()
+    );
+    assert!(
+        result
+            .combined_output()
+            .contains("declares source format 9.0, but this version of Calvin supports up to 1.0"),
+        "{}",
+        result.combined_output()
+    );
+}
+
+#[test]
+fn deploy_accepts_current_format_without_warning() {
+    let env = TestEnv::builder()
+        .with_project_asset("test.md", ALL_TARGETS_POLICY)
+        .with_project_config(CURRENT_CONFIG)
+        .build();
+
+    let result = env.run(&["deploy", "--yes"]);
+
+    assert!(
+        result.success,
+        "deploy failed:\n{}",
+        result.combined_output()
+    );
+    assert!(
+        !result
+            .combined_output()
+            .contains("deprecated source format"),
+        "{}",
+        result.combined_output()
+    );
+}
+
+#[test]
+fn migrate_format_dry_run_shows_diff_without_writing() {
+    let env = TestEnv::builder()
+        .with_project_asset("test.md", ALL_TARGETS_POLICY)
+        .with_project_config(UNVERSIONED_CONFIG)
+        .build();
+
+    let result = env.run(&["migrate", "--format", "1.0", "--dry-run"]);
+
+    assert!(result.success, "{}", result.combined_output());
+    assert!(
+        result.stdout.contains("+ version = \"1.0\""),
+        "{}",
+        result.stdout
+    );
+    assert!(result.stdout.contains("Dry run"), "{}", result.stdout);
+    assert_eq!(
+        std::fs::read_to_string(env.project_path(".promptpack/config.toml")).unwrap(),
+        UNVERSIONED_CONFIG
+    );
+}
+
+#[test]
+fn migrate_format_declares_current_version() {
+    let env = TestEnv::builder()
+        .with_project_asset("test.md", ALL_TARGETS_POLICY)
+        .with_project_config(UNVERSIONED_CONFIG)
+        .build();
+
+    let result = env.run(&["migrate", "--format", "1.0"]);
+    assert!(result.success, "{}", result.combined_output());
+
+    let config = std::fs::read_to_string(env.project_path(".promptpack/config.toml")).unwrap();
+    assert!(config.starts_with("# Team settings"), "{}", config);
+    assert!(config.contains("version = \"1.0\""), "{}", config);
+    assert!(config.contains("enabled = [\"claude-code\"]"), "{}", config);
+
+    let deploy = env.run(&["deploy", "--yes"]);
+    assert!(deploy.success, "{}", deploy.combined_output());
+}
+
+#[test]
+fn migrate_format_rejects_unsupported_target() {
+    let env = TestEnv::builder()
+        .with_project_asset("test.md", ALL_TARGETS_POLICY)
+        .build();
+
+    let result = env.run(&["migrate", "--format", "9.0"]);
+
+    assert!(!result.success, "{}", result.combined_output());
+    assert!(
+        result
+            .combined_output()
+            .contains("format 9.0 is newer than this version of Calvin supports"),
+        "{}",
+        result.combined_output()
+    );
+}
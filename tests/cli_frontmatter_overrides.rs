@@ -0,0 +1,81 @@
+//! Integration tests for per-target frontmatter overrides (`overrides:`)
+
+mod common;
+
+use common::*;
+
+const POLICY_WITH_OVERRIDES: &str = r#"---
+kind: policy
+description: Rust style
+scope: project
+targets: [cursor, vscode]
+apply: "**/*.rs"
+overrides:
+  cursor:
+    apply: "src/**/*.rs"
+    priority: high
+  vscode:
+    description: Rust style for Copilot
+---
+Use rustfmt.
+"#;
+
+#[test]
+fn deploy_applies_overrides_only_to_their_target() {
+    let env = TestEnv::builder()
+        .with_project_asset("rust-style.md", POLICY_WITH_OVERRIDES)
+        .build();
+
+    let result = env.run(&[
+        "deploy",
+        "--yes",
+        "--targets",
+        "cursor",
+        "--targets",
+        "vs-code",
+    ]);
+    assert!(result.success, "{}", result.combined_output());
+
+    let cursor =
+        std::fs::read_to_string(env.project_path(".cursor/rules/rust-style/RULE.md")).unwrap();
+    assert!(cursor.contains("globs: src/**/*.rs\n"), "{}", cursor);
+    assert!(cursor.contains("priority: high"), "{}", cursor);
+    assert!(cursor.contains("description: Rust style\n"), "{}", cursor);
+
+    let vscode = std::fs::read_to_string(
+        env.project_path(".github/instructions/rust-style.instructions.md"),
+    )
+    .unwrap();
+    assert!(vscode.contains("applyTo: '**/*.rs'"), "{}", vscode);
+    assert!(vscode.contains("Rust style for Copilot"), "{}", vscode);
+    assert!(!vscode.contains("priority"), "{}", vscode);
+}
+
+#[test]
+fn deploy_rejects_override_of_kind() {
+    let env = TestEnv::builder()
+        .with_project_asset(
+            "bad.md",
+            r#"---
+kind: policy
+description: Bad
+overrides:
+  cursor:
+    kind: action
+---
+Body
+"#,
+        )
+        .build();
+
+    let result = env.run(&["deploy", "--yes", "--targets", "cursor"]);
+
+    assert!(!result.success, "{}", result.combined_output());
+    assert!(
+        result
+            .combined_output()
+            .contains("`kind` cannot be overridden per target"),
+        "{}",
+        result.combined_output()
+    );
+}
@@ -0,0 +1,84 @@
+//! Integration tests for layer merge strategies (`merge: append | prepend | ...`)
+
+mod common;
+
+use common::*;
+
+const USER_CODE_STYLE: &str = r#"---
+kind: policy
+description: Code style
+scope: project
+targets: [cursor]
+---
+- Use rustfmt
+"#;
+
+#[test]
+fn deploy_appends_project_bullets_to_user_policy() {
+    let env = TestEnv::builder()
+        .with_user_asset("code-style.md", USER_CODE_STYLE)
+        .with_project_asset(
+            "code-style.md",
+            r#"---
+kind: policy
+description: Code style
+scope: project
+targets: [cursor]
+merge: append
+---
+- No unwrap() outside tests
+"#,
+        )
+        .build();
+
+    let result = env.run(&["deploy", "--yes", "--targets", "cursor"]);
+    assert!(result.success, "{}", result.combined_output());
+
+    let rule =
+        std::fs::read_to_string(env.project_path(".cursor/rules/code-style/RULE.md")).unwrap();
+    assert!(
+        rule.contains("- Use rustfmt\n\n- No unwrap() outside tests\n"),
+        "{}",
+        rule
+    );
+
+    let lockfile = env.read_lockfile();
+    assert!(lockfile.contains("contributing_layers = ["), "{}", lockfile);
+    assert!(lockfile.contains("overrides = \"user\""), "{}", lockfile);
+
+    let provenance = env.run(&["provenance", "--json"]);
+    assert!(
+        provenance
+            .stdout
+            .contains(r#""contributing_layers":["user","project"]"#),
+        "{}",
+        provenance.stdout
+    );
+}
+
+#[test]
+fn template_errors_in_merged_bodies_point_at_the_contributing_file() {
+    let env = TestEnv::builder()
+        .with_user_asset("code-style.md", USER_CODE_STYLE)
+        .with_project_asset(
+            "code-style.md",
+            "---\ndescription: Code style\ntargets: [cursor]\nmerge: prepend\n---\nIntro\n{{ missing }}\n",
+        )
+        .build();
+
+    let result = env.run(&["deploy", "--yes", "--targets", "cursor"]);
+
+    assert!(!result.success, "{}", result.combined_output());
+    let output = result.combined_output();
+    assert!(
+        output
+            .contains(".promptpack/code-style.md: Line 7, column 1: undefined variable `missing`"),
+        "{}",
+        output
+    );
+    assert!(
+        !output.contains(".calvin/.promptpack/code-style.md: Line"),
+        "{}",
+        output
+    );
+}
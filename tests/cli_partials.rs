@@ -0,0 +1,84 @@
+//! Integration tests for partial includes (`{{> partials/… }}`)
+
+mod common;
+
+use common::*;
+
+const SECURITY_POLICY: &str = r#"---
+kind: policy
+description: Security rules
+scope: project
+targets: [cursor]
+---
+# Security
+
+{{> partials/secrets.md }}
+{{> partials/testing.md }}
+"#;
+
+#[test]
+fn deploy_resolves_partials_across_layers_and_records_them() {
+    let env = TestEnv::builder()
+        .with_user_asset("partials/secrets.md", "User secrets rules.\n")
+        .with_user_asset("partials/testing.md", "Run tests for {{ target }}.\n")
+        .with_project_asset("partials/secrets.md", "Project secrets rules.\n")
+        .with_project_asset("security.md", SECURITY_POLICY)
+        .build();
+
+    let result = env.run(&["deploy", "--yes", "--targets", "cursor"]);
+    assert!(result.success, "{}", result.combined_output());
+
+    let rule = std::fs::read_to_string(env.project_path(".cursor/rules/security/RULE.md")).unwrap();
+    assert!(
+        rule.contains("# Security\n\nProject secrets rules.\nRun tests for cursor.\n"),
+        "{}",
+        rule
+    );
+    assert!(!rule.contains("User secrets"), "{}", rule);
+    assert!(
+        !env.project_path(".cursor/rules/secrets").exists(),
+        "partials must not be compiled as assets"
+    );
+
+    let lockfile = env.read_lockfile();
+    assert!(lockfile.contains("includes = ["), "{}", lockfile);
+    assert!(
+        lockfile.contains(".promptpack/partials/secrets.md"),
+        "{}",
+        lockfile
+    );
+    assert!(
+        lockfile.contains(".calvin/.promptpack/partials/testing.md"),
+        "{}",
+        lockfile
+    );
+
+    let provenance = env.run(&["provenance", "--json"]);
+    assert!(provenance.success, "{}", provenance.combined_output());
+    assert!(
+        provenance.stdout.contains("\"includes\":["),
+        "{}",
+        provenance.stdout
+    );
+}
+
+#[test]
+fn deploy_reports_missing_partials_with_position() {
+    let env = TestEnv::builder()
+        .with_project_asset(
+            "style.md",
+            "---\ndescription: Style\n---\nIntro\n{{> partials/missing.md }}\n",
+        )
+        .build();
+
+    let result = env.run(&["deploy", "--yes", "--targets", "cursor"]);
+
+    assert!(!result.success, "{}", result.combined_output());
+    assert!(
+        result.combined_output().contains(
+            "style.md: Line 5, column 1: partial `partials/missing.md` not found in any layer"
+        ),
+        "{}",
+        result.combined_output()
+    );
+}
@@ -0,0 +1,242 @@
+//! Integration tests for remote (git/tarball) additional layers and `calvin layers update`
+//!
+//! Uses local bare repositories (`git+file://…`), so no network access is needed.
+
+#![cfg(unix)]
+
+mod common;
+
+use std::path::Path;
+use std::process::Command;
+
+use common::*;
+
+fn git(dir: &Path, args: &[&str]) {
+    let output = Command::new("git")
+        .current_dir(dir)
+        .args(["-c", "user.name=Team", "-c", "user.email=team@example.com"])
+        .args(args)
+        .output()
+        .unwrap();
+    assert!(
+        output.status.success(),
+        "git {:?}: {}",
+        args,
+        String::from_utf8_lossy(&output.stderr)
+    );
+}
+
+fn team_policy(body: &str) -> String {
+    format!(
+        "---\nkind: policy\ndescription: Team rules\nscope: project\ntargets: [cursor]\n---\n{}\n",
+        body
+    )
+}
+
+/// Publish `body` as `.promptpack/policies/team.md` to the bare repo `remote`
+fn publish(work: &Path, remote: &Path, body: &str) {
+    let policy = work.join(".promptpack/policies/team.md");
+    std::fs::create_dir_all(policy.parent().unwrap()).unwrap();
+    std::fs::write(policy, team_policy(body)).unwrap();
+    git(work, &["add", "."]);
+    git(work, &["commit", "--quiet", "-m", body]);
+    git(
+        work,
+        &["push", "--quiet", &remote.to_string_lossy(), "HEAD:main"],
+    );
+}
+
+/// A bare repository with one published commit, and its working clone
+fn team_repo(env: &TestEnv) -> (std::path::PathBuf, std::path::PathBuf) {
+    let remote = env.home_path("srv/team.git");
+    let work = env.home_path("work/team");
+    std::fs::create_dir_all(&remote).unwrap();
+    std::fs::create_dir_all(&work).unwrap();
+    git(
+        &remote,
+        &["init", "--quiet", "--bare", "--initial-branch=main"],
+    );
+    git(&work, &["init", "--quiet"]);
+    publish(&work, &remote, "Team rules v1");
+    (remote, work)
+}
+
+fn use_layer(env: &TestEnv, spec: &str) {
+    env.write_home_file(
+        ".config/calvin/config.toml",
+        &format!("[sources]\nadditional_layers = [\"{}\"]\n", spec),
+    );
+}
+
+fn deployed_team_rule(env: &TestEnv) -> String {
+    std::fs::read_to_string(env.project_path(".cursor/rules/team/RULE.md")).unwrap()
+}
+
+#[test]
+fn git_layer_is_pinned_until_layers_update() {
+    let env = TestEnv::builder()
+        .with_project_asset("project.md", SIMPLE_POLICY)
+        .build();
+    let (remote, work) = team_repo(&env);
+    let spec = format!("git+file://{}#main", remote.display());
+    use_layer(&env, &spec);
+
+    let result = env.run(&["deploy", "--yes", "--targets", "cursor"]);
+    assert!(result.success, "{}", result.combined_output());
+    assert!(deployed_team_rule(&env).contains("Team rules v1"));
+
+    let lockfile = env.read_lockfile();
+    assert!(
+        lockfile.contains(&format!("[layers.\"{}\"]", spec)),
+        "{}",
+        lockfile
+    );
+    assert!(lockfile.contains("commit = \""), "{}", lockfile);
+    assert!(env.home_path(".calvin/cache/layers").is_dir());
+
+    // A new commit on `main` is not picked up while the layer is pinned
+    publish(&work, &remote, "Team rules v2");
+    let result = env.run(&["deploy", "--yes", "--targets", "cursor"]);
+    assert!(result.success, "{}", result.combined_output());
+    assert!(deployed_team_rule(&env).contains("Team rules v1"));
+
+    let result = env.run(&["layers", "update"]);
+    assert!(result.success, "{}", result.combined_output());
+    assert!(result.stdout.contains(&spec), "{}", result.stdout);
+    assert!(result.stdout.contains("→"), "{}", result.stdout);
+    assert_ne!(env.read_lockfile(), lockfile);
+
+    let result = env.run(&["deploy", "--yes", "--targets", "cursor"]);
+    assert!(result.success, "{}", result.combined_output());
+    assert!(deployed_team_rule(&env).contains("Team rules v2"));
+}
+
+#[test]
+fn pinned_git_layer_deploys_from_cache_without_the_remote() {
+    let env = TestEnv::builder()
+        .with_project_asset("project.md", SIMPLE_POLICY)
+        .build();
+    let (remote, _work) = team_repo(&env);
+    use_layer(&env, &format!("git+file://{}", remote.display()));
+
+    let result = env.run(&["deploy", "--yes", "--targets", "cursor"]);
+    assert!(result.success, "{}", result.combined_output());
+
+    std::fs::remove_dir_all(&remote).unwrap();
+    std::fs::remove_file(env.project_path(".cursor/rules/team/RULE.md")).unwrap();
+
+    let result = env.run(&["deploy", "--yes", "--targets", "cursor"]);
+    assert!(result.success, "{}", result.combined_output());
+    assert!(deployed_team_rule(&env).contains("Team rules v1"));
+}
+
+#[test]
+fn unknown_git_ref_fails_deploy() {
+    let env = TestEnv::builder()
+        .with_project_asset("project.md", SIMPLE_POLICY)
+        .build();
+    let (remote, _work) = team_repo(&env);
+    use_layer(&env, &format!("git+file://{}#v9", remote.display()));
+
+    let result = env.run(&["deploy", "--yes", "--targets", "cursor"]);
+
+    assert!(!result.success, "{}", result.combined_output());
+    assert!(
+        result.combined_output().contains("ref `v9` not found"),
+        "{}",
+        result.combined_output()
+    );
+}
+
+#[test]
+fn tarball_layer_from_layer_flag() {
+    let env = TestEnv::builder()
+        .with_project_asset("project.md", SIMPLE_POLICY)
+        .build();
+    let pack = env.home_path("pack");
+    std::fs::create_dir_all(pack.join("policies")).unwrap();
+    std::fs::write(
+        pack.join("policies/team.md"),
+        team_policy("Team rules from tarball"),
+    )
+    .unwrap();
+    let archive = env.home_path("team.tar.gz");
+    let status = Command::new("tar")
+        .arg("-czf")
+        .arg(&archive)
+        .arg("-C")
+        .arg(&pack)
+        .arg(".")
+        .status()
+        .unwrap();
+    assert!(status.success());
+    let archive = archive.to_string_lossy().to_string();
+
+    let result = env.run(&[
+        "deploy",
+        "--yes",
+        "--targets",
+        "cursor",
+        "--layer",
+        &archive,
+    ]);
+    assert!(result.success, "{}", result.combined_output());
+    assert!(deployed_team_rule(&env).contains("Team rules from tarball"));
+
+    let lockfile = env.read_lockfile();
+    assert!(lockfile.contains("[layers."), "{}", lockfile);
+    assert!(lockfile.contains("hash = \"sha256:"), "{}", lockfile);
+}
+
+#[test]
+fn dry_run_does_not_pin_remote_layers() {
+    let env = TestEnv::builder()
+        .with_project_asset("project.md", SIMPLE_POLICY)
+        .build();
+    let (remote, _work) = team_repo(&env);
+    use_layer(&env, &format!("git+file://{}#main", remote.display()));
+
+    let result = env.run(&["deploy", "--dry-run", "--yes", "--targets", "cursor"]);
+
+    assert!(result.success, "{}", result.combined_output());
+    assert!(
+        !env.read_lockfile().contains("[layers."),
+        "{}",
+        env.read_lockfile()
+    );
+    assert!(!env.project_path(".cursor/rules/team/RULE.md").exists());
+}
+
+#[test]
+fn diff_includes_remote_layer_assets() {
+    let env = TestEnv::builder()
+        .with_project_asset("project.md", SIMPLE_POLICY)
+        .build();
+    let (remote, _work) = team_repo(&env);
+    use_layer(&env, &format!("git+file://{}#main", remote.display()));
+    env.write_project_file(
+        ".promptpack/config.toml",
+        "[targets]\nenabled = [\"cursor\"]\n",
+    );
+
+    let result = env.run(&["diff"]);
+
+    assert!(result.success, "{}", result.combined_output());
+    assert!(
+        result.stdout.contains(".cursor/rules/team/RULE.md"),
+        "{}",
+        result.combined_output()
+    );
+    assert!(
+        !result
+            .combined_output()
+            .contains("Remote layer not fetched"),
+        "{}",
+        result.combined_output()
+    );
+    assert!(
+        !env.read_lockfile().contains("[layers."),
+        "{}",
+        env.read_lockfile()
+    );
+}
@@ -0,0 +1,302 @@
+//! Integration tests for named `[[remotes]]` and `deploy --remote` fan-out.
+//!
+//! Uses a fake `ssh` on PATH that runs the remote command locally, so each
+//! "host" is a temp directory and no network is involved.
+
+#![cfg(unix)]
+
+mod common;
+
+use std::fs;
+use std::os::unix::fs::PermissionsExt;
+use std::path::{Path, PathBuf};
+
+use common::*;
+use tempfile::TempDir;
+
+const POLICY: &str = r#"---
+kind: policy
+description: Code style
+---
+Use four spaces.
+"#;
+
+/// Fake hosts: a PATH entry with the `ssh` stand-in, one directory per host, and an ssh log
+struct FakeFleet {
+    root: TempDir,
+}
+
+impl FakeFleet {
+    fn new() -> Self {
+        let root = tempfile::tempdir().unwrap();
+        let bin = root.path().join("bin");
+        fs::create_dir_all(&bin).unwrap();
+
+        // Strip ssh options, log the call, refuse host `down`, run everything else locally.
+        let ssh = bin.join("ssh");
+        fs::write(
+            &ssh,
+            format!(
+                r#"#!/bin/sh
+echo "$@" >> "{log}"
+while [ $# -gt 0 ]; do
+  case "$1" in
+    -p|-i|-o) shift 2 ;;
+    *) break ;;
+  esac
+done
+host="$1"
+shift
+if [ "$host" = "down" ]; then
+  echo "ssh: connect to host down port 22: Connection refused" >&2
+  exit 255
+fi
+exec sh -c "$*"
+"#,
+                log = root.path().join("ssh.log").display()
+            ),
+        )
+        .unwrap();
+        fs::set_permissions(&ssh, fs::Permissions::from_mode(0o755)).unwrap();
+
+        Self { root }
+    }
+
+    fn host_dir(&self, name: &str) -> PathBuf {
+        self.root.path().join("hosts").join(name)
+    }
+
+    fn path_var(&self) -> String {
+        format!(
+            "{}:{}",
+            self.root.path().join("bin").display(),
+            std::env::var("PATH").unwrap_or_default()
+        )
+    }
+
+    fn log(&self) -> String {
+        fs::read_to_string(self.root.path().join("ssh.log")).unwrap_or_default()
+    }
+
+    fn config(&self, remotes: &[(&str, &str, &str)]) -> String {
+        let mut config = String::from("[targets]\nenabled = [\"cursor\"]\n");
+        for (name, host, extra) in remotes {
+            config.push_str(&format!(
+                "\n[[remotes]]\nname = \"{}\"\nhost = \"{}\"\npath = \"{}\"\n{}",
+                name,
+                host,
+                self.host_dir(name).display(),
+                extra
+            ));
+        }
+        config
+    }
+}
+
+fn deployed(host_dir: &Path) -> bool {
+    host_dir.join(".cursor/rules/style/RULE.md").exists()
+}
+
+#[test]
+fn deploy_remote_all_fans_out_to_every_host() {
+    let fleet = FakeFleet::new();
+    let env = TestEnv::builder()
+        .with_project_asset("policies/style.md", POLICY)
+        .with_project_config(&fleet.config(&[
+            (
+                "web-1",
+                "deploy@web-1",
+                "port = 2222\nidentity_file = \"~/.ssh/fleet\"\n",
+            ),
+            ("web-2", "web-2", ""),
+        ]))
+        .build();
+
+    let result = env.run_with_env(
+        &["deploy", "--remote", "all", "--yes"],
+        &[("PATH", &fleet.path_var())],
+    );
+    assert!(result.success, "{}", result.combined_output());
+
+    assert!(deployed(&fleet.host_dir("web-1")));
+    assert!(deployed(&fleet.host_dir("web-2")));
+    assert!(
+        result.stdout.contains("Deploy Complete"),
+        "{}",
+        result.stdout
+    );
+    assert!(result.stdout.contains("on 2 remotes"), "{}", result.stdout);
+
+    let log = fleet.log();
+    let identity = env.home_path(".ssh/fleet");
+    assert!(
+        log.contains(&format!("-p 2222 -i {} deploy@web-1", identity.display())),
+        "{}",
+        log
+    );
+    assert!(
+        log.lines().any(|line| line.starts_with("web-2 ")),
+        "{}",
+        log
+    );
+}
+
+#[test]
+fn failed_host_does_not_abort_other_remotes() {
+    let fleet = FakeFleet::new();
+    let env = TestEnv::builder()
+        .with_project_asset("policies/style.md", POLICY)
+        .with_project_config(&fleet.config(&[("web-1", "web-1", ""), ("down", "down", "")]))
+        .build();
+
+    let result = env.run_with_env(
+        &["deploy", "--remote", "web-1,down", "--yes"],
+        &[("PATH", &fleet.path_var())],
+    );
+    assert!(!result.success, "{}", result.combined_output());
+
+    assert!(deployed(&fleet.host_dir("web-1")));
+    assert!(!deployed(&fleet.host_dir("down")));
+    assert!(
+        result.stdout.contains("1 of 2 remotes failed"),
+        "{}",
+        result.stdout
+    );
+    assert!(
+        result
+            .stderr
+            .contains("Deploy failed on 1 of 2 remotes:\n- down:"),
+        "{}",
+        result.stderr
+    );
+}
+
+#[test]
+fn json_deploy_reports_each_remote() {
+    let fleet = FakeFleet::new();
+    let env = TestEnv::builder()
+        .with_project_asset("policies/style.md", POLICY)
+        .with_project_config(&fleet.config(&[("web-1", "web-1", ""), ("down", "down", "")]))
+        .build();
+
+    let result = env.run_with_env(
+        &["deploy", "--json", "--remote", "all", "--yes"],
+        &[("PATH", &fleet.path_var())],
+    );
+    assert!(!result.success);
+
+    let events: Vec<serde_json::Value> = result
+        .stdout
+        .lines()
+        .filter_map(|line| serde_json::from_str(line).ok())
+        .collect();
+    let host = |name: &str| {
+        events
+            .iter()
+            .find(|e| e["remote"] == name)
+            .unwrap_or_else(|| panic!("no event for {}: {}", name, result.stdout))
+    };
+
+    assert_eq!(host("web-1")["status"], "success");
+    assert_eq!(host("web-1")["written"], 1);
+    assert_eq!(host("down")["status"], "failed");
+    assert!(!host("down")["errors"].as_array().unwrap().is_empty());
+
+    let complete = events
+        .iter()
+        .find(|e| e["event"] == "complete")
+        .expect("complete event");
+    assert_eq!(complete["status"], "partial");
+    assert_eq!(complete["remotes"], 2);
+    assert_eq!(complete["failed"], 1);
+}
+
+#[test]
+fn each_remote_deploys_over_one_ssh_connection() {
+    let fleet = FakeFleet::new();
+    let env = TestEnv::builder()
+        .with_project_asset("policies/style.md", POLICY)
+        .with_project_asset("policies/naming.md", POLICY)
+        .with_project_asset("policies/errors.md", POLICY)
+        .with_project_config(&fleet.config(&[("web-1", "web-1", ""), ("web-2", "web-2", "")]))
+        .build();
+
+    let result = env.run_with_env(
+        &["deploy", "--remote", "all", "--yes"],
+        &[("PATH", &fleet.path_var())],
+    );
+    assert!(result.success, "{}", result.combined_output());
+    assert!(fleet
+        .host_dir("web-2")
+        .join(".cursor/rules/errors/RULE.md")
+        .exists());
+
+    // Existence checks, hashes and writes for every file share the session.
+    let log = fleet.log();
+    let mut spawns: Vec<&str> = log.lines().collect();
+    spawns.sort_unstable();
+    assert_eq!(spawns, ["web-1 sh", "web-2 sh"], "{}", log);
+}
+
+#[test]
+fn remote_all_without_configured_remotes_fails() {
+    let env = TestEnv::builder()
+        .with_project_asset("policies/style.md", POLICY)
+        .build();
+
+    let result = env.run(&["deploy", "--remote", "all", "--yes"]);
+    assert!(!result.success);
+    assert!(
+        result.combined_output().contains("[[remotes]]"),
+        "{}",
+        result.combined_output()
+    );
+}
+
+#[test]
+fn each_remote_keeps_its_own_lockfile_state() {
+    let fleet = FakeFleet::new();
+    let env = TestEnv::builder()
+        .with_project_asset("policies/style.md", POLICY)
+        .with_project_config(&fleet.config(&[("web-1", "web-1", ""), ("web-2", "web-2", "")]))
+        .build();
+    let path_var = fleet.path_var();
+    let path = [("PATH", path_var.as_str())];
+
+    // web-1 was deployed before; web-2 has a hand-written rule at the same path.
+    let result = env.run_with_env(&["deploy", "--remote", "web-1", "--yes"], &path);
+    assert!(result.success, "{}", result.combined_output());
+    let hand_written = fleet.host_dir("web-2").join(".cursor/rules/style/RULE.md");
+    fs::create_dir_all(hand_written.parent().unwrap()).unwrap();
+    fs::write(&hand_written, "Hand-written rule\n").unwrap();
+
+    env.remove_project_asset("policies/style.md");
+    env.write_project_file(".promptpack/policies/naming.md", POLICY);
+    let result = env.run_with_env(&["deploy", "--remote", "all", "--yes", "--cleanup"], &path);
+    assert!(result.success, "{}", result.combined_output());
+
+    assert!(!deployed(&fleet.host_dir("web-1")));
+    assert_eq!(
+        fs::read_to_string(&hand_written).unwrap(),
+        "Hand-written rule\n"
+    );
+
+    let content = env.read_lockfile();
+    let lockfile: toml::Value = toml::from_str(&content).unwrap();
+    let files = |section: &toml::Value| -> Vec<String> {
+        section
+            .get("files")
+            .and_then(|files| files.as_table())
+            .map(|files| files.keys().cloned().collect())
+            .unwrap_or_default()
+    };
+    for remote in ["web-1", "web-2"] {
+        assert_eq!(
+            files(&lockfile["remotes"][remote]),
+            ["project:.cursor/rules/naming/RULE.md"],
+            "{}",
+            content
+        );
+    }
+    assert!(files(&lockfile).is_empty(), "{}", content);
+}
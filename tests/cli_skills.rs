@@ -513,3 +513,115 @@ kind: skill
         result.combined_output()
     );
 }
+
+const SKILL_FALLBACK_CONFIG: &str = r#"
+[targets]
+enabled = ["vscode", "antigravity"]
+
+[targets.vscode]
+skills = "fallback"
+
+[targets.antigravity]
+skills = "fallback"
+"#;
+
+const FALLBACK_SKILL: &str = r#"---
+description: Draft a conventional commit message.
+kind: skill
+targets: [vscode, antigravity]
+---
+# Instructions
+
+Follow `reference.md`.
+"#;
+
+#[test]
+fn deploy_skill_fallback_compiles_instructions_and_workflows() {
+    let env = TestEnv::builder()
+        .with_project_config(SKILL_FALLBACK_CONFIG)
+        .build();
+    write_project_skill(
+        &env,
+        "draft-commit",
+        FALLBACK_SKILL,
+        &[("reference.md", "# Reference\n")],
+    );
+
+    let result = env.run(&["deploy", "--yes"]);
+    assert!(
+        result.success,
+        "deploy failed:\n{}",
+        result.combined_output()
+    );
+    assert!(
+        !result.stderr.contains("Skills skipped for:"),
+        "{}",
+        result.combined_output()
+    );
+    assert!(
+        result.combined_output().contains(
+            "Skill 'draft-commit' compiled as an instruction file on VS Code: no native skill support"
+        ),
+        "{}",
+        result.combined_output()
+    );
+
+    let instructions = env.read_deployed_file(".github/instructions/draft-commit.instructions.md");
+    assert!(
+        instructions.contains("Follow `reference.md`.")
+            && instructions.contains("- `.github/skills/draft-commit/reference.md`"),
+        "{}",
+        instructions
+    );
+    assert_eq!(
+        env.read_deployed_file(".github/skills/draft-commit/reference.md"),
+        "# Reference\n"
+    );
+
+    let workflow = env.read_deployed_file(".agent/workflows/draft-commit.md");
+    assert!(
+        workflow.contains("- `.agent/skills/draft-commit/reference.md`"),
+        "{}",
+        workflow
+    );
+    assert!(env
+        .project_path(".agent/skills/draft-commit/reference.md")
+        .exists());
+
+    let result = env.run(&["check"]);
+    assert!(
+        result
+            .combined_output()
+            .contains("Skill 'draft-commit' compiled as a workflow on Antigravity"),
+        "{}",
+        result.combined_output()
+    );
+}
+
+#[test]
+fn clean_removes_skill_fallback_files() {
+    let env = TestEnv::builder()
+        .with_project_config(SKILL_FALLBACK_CONFIG)
+        .build();
+    write_project_skill(
+        &env,
+        "draft-commit",
+        FALLBACK_SKILL,
+        &[("scripts/check.sh", "exit 0\n")],
+    );
+
+    let result = env.run(&["deploy", "--yes"]);
+    assert!(result.success, "{}", result.combined_output());
+    assert!(env
+        .project_path(".github/skills/draft-commit/scripts/check.sh")
+        .exists());
+
+    let result = env.run(&["clean", "--all", "--yes"]);
+    assert!(result.success, "{}", result.combined_output());
+
+    assert!(!env.project_path(".github/skills/draft-commit").exists());
+    assert!(!env.project_path(".agent/skills/draft-commit").exists());
+    assert!(!env
+        .project_path(".github/instructions/draft-commit.instructions.md")
+        .exists());
+}
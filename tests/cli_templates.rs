@@ -0,0 +1,109 @@
+//! Integration tests for asset body templates (`{{ var }}`, `{% if %}`)
+
+mod common;
+
+use common::*;
+
+const TEMPLATED_POLICY: &str = r#"---
+kind: policy
+description: Project style
+scope: project
+targets: [cursor, vscode]
+---
+# {{ project }} ({{ company }}, {{ lang }})
+{% if target == "cursor" %}
+Cursor rules apply.
+{% else %}
+Built for {{ target }}.
+{% endif %}
+"#;
+
+const PROJECT_VARS: &str = r#"
+[vars]
+project = "calvin"
+lang = "rust"
+"#;
+
+#[test]
+fn deploy_renders_vars_and_target_conditionals() {
+    let env = TestEnv::builder()
+        .with_project_asset("style.md", TEMPLATED_POLICY)
+        .with_project_config(PROJECT_VARS)
+        .with_user_promptpack_config("[vars]\ncompany = \"Acme\"\nlang = \"go\"\n")
+        .build();
+
+    let result = env.run(&[
+        "deploy",
+        "--yes",
+        "--targets",
+        "cursor",
+        "--targets",
+        "vs-code",
+    ]);
+    assert!(result.success, "{}", result.combined_output());
+
+    let cursor = std::fs::read_to_string(env.project_path(".cursor/rules/style/RULE.md")).unwrap();
+    assert!(cursor.contains("# calvin (Acme, rust)\n"), "{}", cursor);
+    assert!(cursor.contains("Cursor rules apply.\n"), "{}", cursor);
+    assert!(!cursor.contains("Built for"), "{}", cursor);
+    assert!(!cursor.contains("{%"), "{}", cursor);
+
+    let vscode =
+        std::fs::read_to_string(env.project_path(".github/instructions/style.instructions.md"))
+            .unwrap();
+    assert!(vscode.contains("Built for vscode.\n"), "{}", vscode);
+    assert!(!vscode.contains("Cursor rules apply."), "{}", vscode);
+}
+
+#[test]
+fn deploy_reports_undefined_variables_with_position() {
+    let env = TestEnv::builder()
+        .with_project_asset(
+            "style.md",
+            "---\ndescription: Style\n---\nIntro\nUse {{ projct }}.\n",
+        )
+        .with_project_config(PROJECT_VARS)
+        .build();
+
+    let result = env.run(&["deploy", "--yes", "--targets", "cursor"]);
+
+    assert!(!result.success, "{}", result.combined_output());
+    assert!(
+        result
+            .combined_output()
+            .contains("style.md: Line 5, column 5: undefined variable `projct`"),
+        "{}",
+        result.combined_output()
+    );
+    assert!(!env.project_path(".cursor/rules/style/RULE.md").exists());
+}
+
+const VUE_POLICY: &str = r#"---
+kind: policy
+description: Vue conventions
+---
+# Vue
+
+Bind text with `{{ message }}`:
+
+```vue
+<template>
+  <p>{{ message }}</p>
+  {% if ok %}
+</template>
+```
+"#;
+
+#[test]
+fn deploy_copies_literal_braces_in_code_verbatim() {
+    let env = TestEnv::builder()
+        .with_project_asset("vue.md", VUE_POLICY)
+        .build();
+
+    let result = env.run(&["deploy", "--yes", "--targets", "cursor"]);
+    assert!(result.success, "{}", result.combined_output());
+
+    let cursor = std::fs::read_to_string(env.project_path(".cursor/rules/vue/RULE.md")).unwrap();
+    let body = VUE_POLICY.split("---\n").nth(2).unwrap();
+    assert!(cursor.contains(body), "{}", cursor);
+}
@@ -0,0 +1,124 @@
+//! Integration tests for the VS Code target: actions as Copilot prompt files, and
+//! un-scoped policies merged into `.github/copilot-instructions.md`.
+
+mod common;
+
+use common::*;
+
+const VSCODE_CONFIG: &str = r#"
+[targets]
+enabled = ["vscode"]
+"#;
+
+const MERGED_CONFIG: &str = r#"
+[targets]
+enabled = ["vscode"]
+
+[targets.vscode]
+policies = "merged"
+"#;
+
+const RUST_POLICY: &str = r#"---
+kind: policy
+description: Rust rules
+apply: "**/*.rs"
+---
+Run clippy before committing.
+"#;
+
+const DEPLOY_ACTION: &str = r#"---
+kind: action
+description: Deploy the app
+overrides:
+  vscode:
+    tools: [runCommands]
+---
+Run the deploy script.
+"#;
+
+const HANDWRITTEN_INSTRUCTIONS: &str = "# Team notes\n\nPrefer small PRs.\n";
+
+#[test]
+fn actions_become_prompt_files() {
+    let env = TestEnv::builder()
+        .with_project_asset("test.md", ALL_TARGETS_POLICY)
+        .with_project_asset("actions/deploy.md", DEPLOY_ACTION)
+        .with_project_config(VSCODE_CONFIG)
+        .build();
+
+    let result = env.run(&["deploy", "--yes"]);
+    assert!(result.success, "{}", result.combined_output());
+
+    let prompt = env.read_deployed_file(".github/prompts/deploy.prompt.md");
+    assert!(
+        prompt.starts_with(
+            "---\ndescription: Deploy the app\nmode: agent\ntools:\n- runCommands\n---\n\nRun the deploy script."
+        ),
+        "{}",
+        prompt
+    );
+    assert!(!env
+        .project_path(".github/instructions/deploy.instructions.md")
+        .exists());
+    assert!(env
+        .project_path(".github/instructions/test.instructions.md")
+        .exists());
+    assert!(!env.project_path(".github/copilot-instructions.md").exists());
+}
+
+#[test]
+fn merged_mode_aggregates_unscoped_policies() {
+    let env = TestEnv::builder()
+        .with_project_asset("test.md", ALL_TARGETS_POLICY)
+        .with_project_asset("policies/rust.md", RUST_POLICY)
+        .with_project_config(MERGED_CONFIG)
+        .build();
+    env.write_project_file(".github/copilot-instructions.md", HANDWRITTEN_INSTRUCTIONS);
+
+    let result = env.run(&["deploy", "--yes"]);
+    assert!(result.success, "{}", result.combined_output());
+
+    let merged = env.read_deployed_file(".github/copilot-instructions.md");
+    assert!(merged.starts_with(HANDWRITTEN_INSTRUCTIONS), "{}", merged);
+    assert!(
+        merged.contains("<!-- calvin:begin vscode -->"),
+        "{}",
+        merged
+    );
+    assert!(merged.contains("This policy applies to all supported AI coding assistants."));
+    assert!(!merged.contains("Run clippy before committing."));
+    assert!(!env
+        .project_path(".github/instructions/test.instructions.md")
+        .exists());
+    assert!(env
+        .project_path(".github/instructions/rust.instructions.md")
+        .exists());
+
+    let lockfile = env.read_lockfile();
+    assert!(
+        lockfile.contains(".github/copilot-instructions.md"),
+        "{}",
+        lockfile
+    );
+}
+
+#[test]
+fn clean_keeps_handwritten_copilot_instructions() {
+    let env = TestEnv::builder()
+        .with_project_asset("test.md", ALL_TARGETS_POLICY)
+        .with_project_config(MERGED_CONFIG)
+        .build();
+    env.write_project_file(".github/copilot-instructions.md", HANDWRITTEN_INSTRUCTIONS);
+
+    let result = env.run(&["deploy", "--yes"]);
+    assert!(result.success, "{}", result.combined_output());
+
+    let result = env.run(&["clean", "--all", "--yes"]);
+    assert!(result.success, "{}", result.combined_output());
+
+    assert_eq!(
+        env.read_deployed_file(".github/copilot-instructions.md")
+            .trim_end(),
+        HANDWRITTEN_INSTRUCTIONS.trim_end()
+    );
+}
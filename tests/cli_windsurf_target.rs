@@ -0,0 +1,77 @@
+//! Integration tests for the Windsurf target: rules, workflows and the
+//! character-limit warnings surfaced during deploy.
+
+mod common;
+
+use common::*;
+
+const WINDSURF_CONFIG: &str = r#"
+[targets]
+enabled = ["windsurf"]
+"#;
+
+const RUST_POLICY: &str = r#"---
+kind: policy
+description: Rust rules
+scope: project
+targets: [windsurf]
+apply: "**/*.rs"
+---
+Run clippy before committing.
+"#;
+
+const DEPLOY_ACTION: &str = r#"---
+kind: action
+description: Deploy the app
+targets: [windsurf]
+---
+Run the deploy script.
+"#;
+
+#[test]
+fn policies_become_rules_and_actions_become_workflows() {
+    let env = TestEnv::builder()
+        .with_project_asset("policies/rust.md", RUST_POLICY)
+        .with_project_asset("actions/deploy.md", DEPLOY_ACTION)
+        .with_project_config(WINDSURF_CONFIG)
+        .build();
+
+    let result = env.run(&["deploy", "--yes"]);
+    assert!(result.success, "{}", result.combined_output());
+
+    let rule = env.read_deployed_file(".windsurf/rules/rust.md");
+    assert!(
+        rule.starts_with("---\ntrigger: glob\nglobs: '**/*.rs'\ndescription: Rust rules\n---\n"),
+        "{}",
+        rule
+    );
+    let workflow = env.read_deployed_file(".windsurf/workflows/deploy.md");
+    assert!(
+        workflow.starts_with("---\ndescription: Deploy the app\n---\n\nRun the deploy script."),
+        "{}",
+        workflow
+    );
+}
+
+#[test]
+fn oversized_rule_warns_during_deploy() {
+    let big_policy = format!(
+        "---\nkind: policy\ndescription: Huge\ntargets: [windsurf]\n---\n{}\n",
+        "x".repeat(7_000)
+    );
+    let env = TestEnv::builder()
+        .with_project_asset("policies/huge.md", &big_policy)
+        .with_project_config(WINDSURF_CONFIG)
+        .build();
+
+    let result = env.run(&["deploy", "--yes"]);
+    assert!(result.success, "{}", result.combined_output());
+
+    let output = result.combined_output();
+    assert!(
+        output.contains("exceeds Windsurf's 6000 character limit"),
+        "{}",
+        output
+    );
+    assert!(env.project_path(".windsurf/rules/huge.md").exists());
+}
@@ -29,3 +29,6 @@ mod agents;
 
 #[path = "contracts/opencode.rs"]
 mod opencode;
+
+#[path = "contracts/gemini_cli.rs"]
+mod gemini_cli;
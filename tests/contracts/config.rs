@@ -69,7 +69,7 @@ mod config_priority {
         );
 
         // VSCode should NOT be deployed - project overrides user config
-        let vscode_path = env.project_path(".github/copilot-instructions.md");
+        let vscode_path = env.project_path(".github/instructions");
         assert!(
             !vscode_path.exists(),
             "VSCode should NOT be deployed - project config overrides user config"
@@ -108,7 +108,7 @@ mod empty_array_disable {
         // Check that no target directories were created
         let has_cursor = env.project_path(".cursor").exists();
         let has_claude = env.project_path(".claude").exists();
-        let has_vscode = env.project_path(".github/copilot-instructions.md").exists();
+        let has_vscode = env.project_path(".github/instructions").exists();
 
         assert!(
             !has_cursor && !has_claude && !has_vscode,
@@ -0,0 +1,138 @@
+//! Contract tests for Gemini CLI target support.
+//!
+//! These contracts define the required behavior for deploying PromptPack assets
+//! to Gemini CLI's `GEMINI.md` context files and `.gemini/commands/` TOML commands.
+
+use crate::common::*;
+
+#[test]
+fn contract_gemini_cli_action_becomes_toml_command() {
+    let env = TestEnv::builder().build();
+
+    env.write_project_file(
+        ".promptpack/actions/test.md",
+        r#"---
+kind: action
+description: Test action
+scope: project
+targets: [gemini-cli]
+---
+Run tests for $ARGUMENTS
+"#,
+    );
+
+    let result = env.run(&["deploy", "--yes", "--targets", "gemini-cli"]);
+    assert!(
+        result.success,
+        "deploy failed:\n{}",
+        result.combined_output()
+    );
+
+    let content = env.read_deployed_file(".gemini/commands/test.toml");
+    let command: toml::Table =
+        toml::from_str(&content).expect("CONTRACT: Gemini commands MUST be valid TOML");
+    assert_eq!(
+        command["description"].as_str(),
+        Some("Test action"),
+        "CONTRACT: Command MUST carry the action description"
+    );
+    assert_eq!(
+        command["prompt"].as_str(),
+        Some("Run tests for {{args}}"),
+        "CONTRACT: $ARGUMENTS MUST be mapped to {{{{args}}}}"
+    );
+}
+
+#[test]
+fn contract_gemini_cli_action_user_path() {
+    let env = TestEnv::builder().build();
+
+    env.write_project_file(
+        ".promptpack/actions/global.md",
+        r#"---
+kind: action
+description: Global action
+scope: user
+targets: [gemini-cli]
+---
+Do something globally.
+"#,
+    );
+
+    let result = env.run(&["deploy", "--yes", "--targets", "gemini-cli"]);
+    assert!(
+        result.success,
+        "deploy failed:\n{}",
+        result.combined_output()
+    );
+
+    assert!(
+        env.home_path(".gemini/commands/global.toml").exists(),
+        "CONTRACT: User-scope action MUST deploy to ~/.gemini/commands/<id>.toml"
+    );
+}
+
+#[test]
+fn contract_gemini_cli_policy_compiles_to_gemini_md() {
+    let env = TestEnv::builder().build();
+
+    env.write_project_file(
+        ".promptpack/policies/security.md",
+        r#"---
+kind: policy
+description: Security guidelines
+scope: project
+targets: [gemini-cli]
+---
+Never expose secrets.
+"#,
+    );
+    env.write_project_file("GEMINI.md", "# Hand-written context\n");
+
+    let result = env.run(&["deploy", "--yes", "--targets", "gemini-cli"]);
+    assert!(
+        result.success,
+        "deploy failed:\n{}",
+        result.combined_output()
+    );
+
+    let content = env.read_deployed_file("GEMINI.md");
+    assert!(
+        content.starts_with("# Hand-written context\n"),
+        "CONTRACT: Hand-written GEMINI.md content MUST be preserved"
+    );
+    assert!(
+        content.contains("<!-- calvin:begin gemini-cli -->")
+            && content.contains("Never expose secrets."),
+        "CONTRACT: Policies MUST compile into the gemini-cli region of GEMINI.md"
+    );
+}
+
+#[test]
+fn contract_gemini_cli_policy_user_scope_compiles_to_home_gemini_md() {
+    let env = TestEnv::builder().build();
+
+    env.write_project_file(
+        ".promptpack/policies/global-security.md",
+        r#"---
+kind: policy
+description: Global security guidelines
+scope: user
+targets: [gemini-cli]
+---
+Never expose secrets globally.
+"#,
+    );
+
+    let result = env.run(&["deploy", "--yes", "--targets", "gemini-cli"]);
+    assert!(
+        result.success,
+        "deploy failed:\n{}",
+        result.combined_output()
+    );
+
+    assert!(
+        env.home_path(".gemini/GEMINI.md").exists(),
+        "CONTRACT: User-scope policies MUST compile to ~/.gemini/GEMINI.md"
+    );
+}
@@ -34,6 +34,10 @@ fn compile_assets_with_adapters(
             Target::Antigravity => DomainTarget::Antigravity,
             Target::Codex => DomainTarget::Codex,
             Target::OpenCode => DomainTarget::OpenCode,
+            Target::GeminiCli => DomainTarget::GeminiCli,
+            Target::Windsurf => DomainTarget::Windsurf,
+            Target::Cline => DomainTarget::Cline,
+            Target::Custom(name) => DomainTarget::Custom(*name),
             Target::All => DomainTarget::All,
         })
         .collect();
@@ -233,6 +237,44 @@ mod snapshot_tests {
 
         assert_snapshot!("codex_prompt", &prompt_output.content());
     }
+
+    #[test]
+    fn test_golden_windsurf_rule() {
+        let dir = tempdir().unwrap();
+        let source = dir.path().join(".promptpack");
+        create_test_promptpack(&source);
+
+        let assets = parse_directory(&source).unwrap();
+        let config = Config::default();
+        let targets = vec![Target::Windsurf];
+        let outputs = compile_assets_with_adapters(&assets, &targets, &config);
+
+        let rule_output = outputs
+            .iter()
+            .find(|o| o.path().to_string_lossy().contains("code-style"))
+            .expect("Should have code-style output");
+
+        assert_snapshot!("windsurf_rule", &rule_output.content());
+    }
+
+    #[test]
+    fn test_golden_windsurf_workflow() {
+        let dir = tempdir().unwrap();
+        let source = dir.path().join(".promptpack");
+        create_test_promptpack(&source);
+
+        let assets = parse_directory(&source).unwrap();
+        let config = Config::default();
+        let targets = vec![Target::Windsurf];
+        let outputs = compile_assets_with_adapters(&assets, &targets, &config);
+
+        let workflow_output = outputs
+            .iter()
+            .find(|o| o.path().to_string_lossy().contains("generate-tests"))
+            .expect("Should have generate-tests output");
+
+        assert_snapshot!("windsurf_workflow", &workflow_output.content());
+    }
 }
 
 #[cfg(test)]
@@ -95,7 +95,7 @@ fn new_engine_deploys_to_project() {
     // - Cursor: .cursor/rules/<id>/RULE.md
     // - VSCode: .github/instructions/<id>.instructions.md
     // - Antigravity: .agent/workflows/<id>.md
-    // - Codex: .codex/prompts/<id>.md
+    // - Codex: AGENTS.md (codex region)
     assert!(
         file_exists_with_content(
             project_dir,
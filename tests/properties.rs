@@ -18,3 +18,6 @@ mod layer_resolver;
 
 #[path = "properties/path_handling.rs"]
 mod path_handling;
+
+#[path = "properties/adapter_frontmatter.rs"]
+mod adapter_frontmatter;
@@ -0,0 +1,136 @@
+//! Property tests for the frontmatter adapters emit into generated files.
+
+use std::path::Path;
+
+use proptest::prelude::*;
+
+use calvin::domain::entities::Asset;
+use calvin::models::PromptAsset;
+use calvin::serde_yaml_ng::{Mapping, Value};
+use calvin::{all_adapters, extract_frontmatter, AssetKind, Frontmatter, Target};
+
+fn yaml_text() -> impl Strategy<Value = String> {
+    // Characters that broke hand-built frontmatter: `: `, `#`, quotes and newlines.
+    // Lines that are exactly "---" would end the frontmatter block, so they are excluded.
+    proptest::string::string_regex("[A-Za-z0-9 _:#'\"\\-\n]{1,40}")
+        .unwrap()
+        .prop_filter("no delimiter line", |s| {
+            s.lines().all(|line| line.trim() != "---")
+        })
+}
+
+fn extra_fields() -> impl Strategy<Value = Vec<(String, String)>> {
+    // `x-` keeps the generated keys out of the fields Calvin recognizes; keys are
+    // unique, as they are in a real frontmatter block.
+    proptest::collection::btree_map("x-[a-z]{1,8}", yaml_text(), 0..=3)
+        .prop_map(|fields| fields.into_iter().collect())
+}
+
+/// Targets whose policy files carry the asset's description, `apply` glob and
+/// passthrough (`x-*`) keys in their frontmatter
+const POLICY_FRONTMATTER_TARGETS: &[Target] = &[
+    Target::Cursor,
+    Target::VSCode,
+    Target::Antigravity,
+    Target::Windsurf,
+];
+
+fn asset(
+    kind: AssetKind,
+    description: &str,
+    apply: Option<&str>,
+    extra: &[(String, String)],
+) -> Asset {
+    let mut frontmatter = Frontmatter::new(description);
+    frontmatter.kind = kind;
+    frontmatter.apply = apply.map(str::to_string);
+    for (key, value) in extra {
+        frontmatter
+            .extra
+            .insert(key.clone(), Value::String(value.clone()));
+    }
+    let id = format!("{:?}", kind).to_lowercase();
+    PromptAsset::new(&id, format!("{}.md", id), frontmatter, "Body text.").into()
+}
+
+proptest! {
+    #![proptest_config(ProptestConfig {
+        cases: 96,
+        .. ProptestConfig::default()
+    })]
+
+    /// PROPERTY: Every frontmatter block an adapter writes parses back to the source values.
+    #[test]
+    fn property_adapter_frontmatter_round_trips(
+        description in yaml_text(),
+        apply in proptest::option::of("[a-z*/.]{1,12}"),
+        extra in extra_fields(),
+    ) {
+        let assets = [
+            (AssetKind::Policy, asset(AssetKind::Policy, &description, apply.as_deref(), &extra)),
+            (AssetKind::Action, asset(AssetKind::Action, &description, None, &extra)),
+            (AssetKind::Agent, asset(AssetKind::Agent, &description, None, &extra)),
+        ];
+
+        for adapter in all_adapters() {
+            for (kind, asset) in &assets {
+                let outputs = adapter.compile(asset).unwrap();
+                for output in outputs.iter().filter(|o| o.content().starts_with("---\n")) {
+                    let context = format!("{:?} {}", adapter.target(), output.path().display());
+                    let extracted = extract_frontmatter(output.content(), Path::new("out.md"))
+                        .unwrap_or_else(|e| panic!("{}: {}", context, e));
+                    // `extract_frontmatter` joins lines, dropping the newline that ends the
+                    // last one (significant for block scalars that keep trailing newlines).
+                    let yaml = format!("{}\n", extracted.yaml);
+                    let fields: Mapping = calvin::serde_yaml_ng::from_str(&yaml)
+                        .unwrap_or_else(|e| panic!("{}: {}\n{}", context, e, extracted.yaml));
+
+                    let target = adapter.target();
+                    let full_policy = *kind == AssetKind::Policy
+                        && POLICY_FRONTMATTER_TARGETS.contains(&target);
+
+                    // Cline policies carry only their `paths` globs.
+                    if target == Target::Cline {
+                        let paths = fields.get("paths").and_then(Value::as_sequence);
+                        let first = paths.and_then(|p| p.first()).and_then(Value::as_str);
+                        prop_assert_eq!(first, apply.as_deref(), "{}", context);
+                        continue;
+                    }
+
+                    prop_assert_eq!(
+                        fields.get("description").and_then(Value::as_str),
+                        Some(description.as_str()),
+                        "{}",
+                        context
+                    );
+                    if target == Target::VSCode && *kind == AssetKind::Policy {
+                        // Copilot needs `applyTo: '**'` to attach always-active policies.
+                        prop_assert_eq!(
+                            fields.get("applyTo").and_then(Value::as_str),
+                            Some(apply.as_deref().unwrap_or("**")),
+                            "{}",
+                            context
+                        );
+                    } else if full_policy {
+                        prop_assert_eq!(
+                            fields.get("globs").and_then(Value::as_str),
+                            apply.as_deref(),
+                            "{}",
+                            context
+                        );
+                    } else if let Some(value) = fields.get("globs") {
+                        prop_assert_eq!(value.as_str(), apply.as_deref(), "{}", context);
+                    }
+                    for (key, value) in &extra {
+                        match fields.get(key.as_str()) {
+                            Some(emitted) => {
+                                prop_assert_eq!(emitted.as_str(), Some(value.as_str()), "{}", context)
+                            }
+                            None => prop_assert!(!full_policy, "{}: missing {}", context, key),
+                        }
+                    }
+                }
+            }
+        }
+    }
+}
@@ -24,19 +24,15 @@ fn remote_destination_expands_tilde_using_remote_home() {
     let log_path = temp.path().join("ssh.log");
 
     // Fake `ssh`:
-    // - logs args to ssh.log
-    // - returns a stable fake $HOME for `echo $HOME`
+    // - logs args and the session's commands (stdin) to ssh.log
+    // - runs them in a local shell whose $HOME is a stable fake
     let ssh_path = bin_dir.join("ssh");
     fs::write(
         &ssh_path,
         format!(
             r#"#!/bin/sh
-set -eu
 echo "$@" >> "{log}"
-if [ "${{2-}}" = "echo \$HOME" ]; then
-  echo "/home/fake"
-fi
-exit 0
+tee -a "{log}" | HOME=/home/fake sh
 "#,
             log = log_path.display()
         ),
@@ -55,14 +51,21 @@ exit 0
     // Trigger an SSH operation that needs to resolve the remote base path.
     let _ = dest.exists(Path::new(".claude/commands/test.md"));
 
+    // Close the session so the log is complete.
+    drop(dest);
     let log = fs::read_to_string(&log_path).unwrap_or_default();
 
     assert!(
         log.contains("echo $HOME"),
-        "expected remote home lookup via `ssh host 'echo $HOME'`; log:\n{log}"
+        "expected remote home lookup via `echo $HOME` on the session; log:\n{log}"
     );
     assert!(
         log.contains("/home/fake/projects/.claude/commands/test.md"),
         "expected `~/projects` to expand to `/home/fake/projects`; log:\n{log}"
     );
+    assert_eq!(
+        log.lines().filter(|line| *line == "host sh").count(),
+        1,
+        "expected both commands over one ssh session; log:\n{log}"
+    );
 }